@@ -0,0 +1,14 @@
+CREATE TABLE `jobs_jobrun` (
+    `id` int(11) NOT NULL AUTO_INCREMENT,
+    `job` varchar(32) NOT NULL,
+    `manual` tinyint(1) NOT NULL,
+    `started_at` datetime(6) NOT NULL,
+    `finished_at` datetime(6) DEFAULT NULL,
+    `result` longtext DEFAULT NULL,
+    `error` longtext DEFAULT NULL,
+    `user_id` int(11) DEFAULT NULL,
+    PRIMARY KEY (`id`),
+    KEY `jobs_jobrun_job_started_at_idx` (`job`,`started_at`),
+    KEY `jobs_jobrun_user_id_fk_user_user_id` (`user_id`),
+    CONSTRAINT `jobs_jobrun_user_id_fk_user_user_id` FOREIGN KEY (`user_id`) REFERENCES `user_user` (`id`) ON DELETE SET NULL
+) ENGINE=InnoDB AUTO_INCREMENT=1 DEFAULT CHARSET=utf8
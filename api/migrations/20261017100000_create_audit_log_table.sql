@@ -0,0 +1,16 @@
+CREATE TABLE `audit_log` (
+    `id` int(11) NOT NULL AUTO_INCREMENT,
+    `time` datetime(6) NOT NULL,
+    `user_id` int(11) NOT NULL,
+    `impersonated_user_id` int(11) DEFAULT NULL,
+    `method` varchar(8) NOT NULL,
+    `route` varchar(255) NOT NULL,
+    `object_type` varchar(32) NOT NULL,
+    `object_id` int(11) DEFAULT NULL,
+    `data_before` longtext DEFAULT NULL,
+    `data_after` longtext DEFAULT NULL,
+    PRIMARY KEY (`id`),
+    KEY `audit_log_time_idx` (`time`),
+    KEY `audit_log_object_type_time_idx` (`object_type`,`time`),
+    KEY `audit_log_user_id_time_idx` (`user_id`,`time`)
+) ENGINE=InnoDB AUTO_INCREMENT=1 DEFAULT CHARSET=utf8
@@ -0,0 +1,29 @@
+CREATE TABLE `pricing_billingpolicy` (
+    `id` int(11) NOT NULL AUTO_INCREMENT,
+    `status` varchar(18) NOT NULL,
+    `user_class` smallint(5) unsigned DEFAULT NULL,
+    `factor` double NOT NULL,
+    `start_time` datetime(6) NOT NULL,
+    `end_time` datetime(6) DEFAULT NULL,
+    PRIMARY KEY (`id`),
+    KEY `pricing_billingpolicy_status_idx` (`status`)
+) ENGINE=InnoDB AUTO_INCREMENT=1 DEFAULT CHARSET=utf8;
+
+-- bill the previously hard-coded consuming states at full price
+INSERT INTO `pricing_billingpolicy` (`status`, `user_class`, `factor`, `start_time`, `end_time`)
+VALUES
+    ('ACTIVE', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('BUILD', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('HARD_REBOOT', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('MIGRATING', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('PASSWORD', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('PAUSED', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('REBOOT', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('REBUILD', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('RESCUE', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('RESIZE', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('REVERT_RESIZE', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('SHUTOFF', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('SUSPENDED', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('UNKNOWN', NULL, 1, '1970-01-01 00:00:00', NULL),
+    ('VERIFY_RESIZE', NULL, 1, '1970-01-01 00:00:00', NULL);
@@ -0,0 +1,13 @@
+CREATE TABLE `accounting_volumestate` (
+    `state_ptr_id` int(11) NOT NULL,
+    `volume_id` varchar(36) NOT NULL,
+    `volume_name` varchar(255) NOT NULL,
+    `status` varchar(32) NOT NULL,
+    `size` int(10) unsigned NOT NULL,
+    `user_id` int(11) NOT NULL,
+    PRIMARY KEY (`state_ptr_id`),
+    KEY `accounting_volumestate_volume_id_idx` (`volume_id`),
+    KEY `accounting_volumestate_user_id_fk_user_user_id` (`user_id`),
+    CONSTRAINT `accounting_volumestate_state_ptr_id_fk_accounting_state_id` FOREIGN KEY (`state_ptr_id`) REFERENCES `accounting_state` (`id`),
+    CONSTRAINT `accounting_volumestate_user_id_fk_user_user_id` FOREIGN KEY (`user_id`) REFERENCES `user_user` (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8;
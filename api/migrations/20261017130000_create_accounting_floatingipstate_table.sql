@@ -0,0 +1,12 @@
+CREATE TABLE `accounting_floatingipstate` (
+    `state_ptr_id` int(11) NOT NULL,
+    `floating_ip_id` varchar(36) NOT NULL,
+    `address` varchar(45) NOT NULL,
+    `pool` varchar(16) NOT NULL,
+    `user_id` int(11) NOT NULL,
+    PRIMARY KEY (`state_ptr_id`),
+    KEY `accounting_floatingipstate_floating_ip_id_idx` (`floating_ip_id`),
+    KEY `accounting_floatingipstate_user_id_fk_user_user_id` (`user_id`),
+    CONSTRAINT `accounting_floatingipstate_state_ptr_id_fk_accounting_state_id` FOREIGN KEY (`state_ptr_id`) REFERENCES `accounting_state` (`id`),
+    CONSTRAINT `accounting_floatingipstate_user_id_fk_user_user_id` FOREIGN KEY (`user_id`) REFERENCES `user_user` (`id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8;
@@ -0,0 +1,9 @@
+CREATE TABLE `pricing_floatingipprice` (
+    `id` int(11) NOT NULL AUTO_INCREMENT,
+    `user_class` smallint(5) unsigned NOT NULL,
+    `pool` varchar(16) NOT NULL,
+    `unit_price` double NOT NULL,
+    `start_time` datetime(6) NOT NULL,
+    PRIMARY KEY (`id`),
+    UNIQUE KEY `pricing_floatingipprice_user_class_pool_start_time_uniq` (`user_class`,`pool`,`start_time`)
+) ENGINE=InnoDB AUTO_INCREMENT=1 DEFAULT CHARSET=utf8;
@@ -0,0 +1,10 @@
+CREATE TABLE `pricing_resourceprice` (
+    `id` int(11) NOT NULL AUTO_INCREMENT,
+    `user_class` smallint(5) unsigned NOT NULL,
+    `vcpu_price` double NOT NULL,
+    `ram_price` double NOT NULL,
+    `gpu_price` double NOT NULL,
+    `start_time` datetime(6) NOT NULL,
+    PRIMARY KEY (`id`),
+    UNIQUE KEY `pricing_resourceprice_user_class_start_time_uniq` (`user_class`,`start_time`)
+) ENGINE=InnoDB AUTO_INCREMENT=1 DEFAULT CHARSET=utf8;
@@ -0,0 +1,14 @@
+CREATE TABLE `pricing_priceoverride` (
+    `id` int(11) NOT NULL AUTO_INCREMENT,
+    `project_id` int(11) NOT NULL,
+    `flavor_id` bigint(20) DEFAULT NULL,
+    `discount` double DEFAULT NULL,
+    `unit_price` double DEFAULT NULL,
+    `start_time` datetime(6) NOT NULL,
+    `end_time` datetime(6) DEFAULT NULL,
+    PRIMARY KEY (`id`),
+    KEY `pricing_priceoverride_project_id_fk_user_project_id` (`project_id`),
+    KEY `pricing_priceoverride_flavor_id_fk_resources_flavor_id` (`flavor_id`),
+    CONSTRAINT `pricing_priceoverride_project_id_fk_user_project_id` FOREIGN KEY (`project_id`) REFERENCES `user_project` (`id`),
+    CONSTRAINT `pricing_priceoverride_flavor_id_fk_resources_flavor_id` FOREIGN KEY (`flavor_id`) REFERENCES `resources_flavor` (`id`)
+) ENGINE=InnoDB AUTO_INCREMENT=1 DEFAULT CHARSET=utf8;
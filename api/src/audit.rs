@@ -0,0 +1,159 @@
+//! Write path of the audit log of mutating API operations.
+//!
+//! Every endpoint that creates, modifies or deletes something takes an [Audit]
+//! extractor and records the operation with it, in the same transaction as the
+//! operation itself. This way an operation is only ever committed together with
+//! its audit log entry.
+
+use std::future::{Ready, ready};
+
+use actix_web::{FromRequest, HttpMessage, HttpRequest, dev::Payload};
+use anyhow::Context;
+use avina_wire::{audit::ObjectType, user::User};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{MySql, Transaction};
+
+use crate::{
+    authentication::Impersonator,
+    database::audit::audit_log::{
+        NewAuditLogEntry, insert_audit_log_entry_into_db,
+    },
+    error::{UnexpectedOnlyError, internal_server_error},
+};
+
+/// Who performed a request on which route, for recording it in the audit log.
+///
+/// When an admin impersonates another user, the admin is recorded as acting
+/// user, and the other user as impersonated one.
+#[derive(Debug)]
+pub struct Audit {
+    /// ID of the user that actually performed the request.
+    user_id: u32,
+    /// ID of the user impersonated during the request.
+    impersonated_user_id: Option<u32>,
+    /// HTTP method of the request.
+    method: String,
+    /// Route pattern of the request, e.g., `/api/user/users/{user_id}/`.
+    route: String,
+}
+
+impl FromRequest for Audit {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let extensions = req.extensions();
+        let Some(user) = extensions.get::<User>() else {
+            return ready(Err(internal_server_error(
+                "Audit requires an authenticated user",
+            )));
+        };
+        let (user_id, impersonated_user_id) =
+            match extensions.get::<Impersonator>() {
+                Some(Impersonator(impersonator)) => {
+                    (impersonator.id, Some(user.id))
+                }
+                None => (user.id, None),
+            };
+        ready(Ok(Audit {
+            user_id,
+            impersonated_user_id,
+            method: req.method().to_string(),
+            route: req
+                .match_pattern()
+                .unwrap_or_else(|| req.path().to_string()),
+        }))
+    }
+}
+
+impl Audit {
+    /// Record the creation of the given object.
+    pub async fn created<T: Serialize>(
+        &self,
+        transaction: &mut Transaction<'_, MySql>,
+        object_type: ObjectType,
+        object_id: u32,
+        after: &T,
+    ) -> Result<(), UnexpectedOnlyError> {
+        self.record(
+            transaction,
+            object_type,
+            Some(object_id),
+            None,
+            Some(snapshot(after)?),
+        )
+        .await
+    }
+
+    /// Record the modification of the given object from one state to another.
+    pub async fn modified<B: Serialize, A: Serialize>(
+        &self,
+        transaction: &mut Transaction<'_, MySql>,
+        object_type: ObjectType,
+        object_id: u32,
+        before: &B,
+        after: &A,
+    ) -> Result<(), UnexpectedOnlyError> {
+        self.record(
+            transaction,
+            object_type,
+            Some(object_id),
+            Some(snapshot(before)?),
+            Some(snapshot(after)?),
+        )
+        .await
+    }
+
+    /// Record the deletion of the given object.
+    pub async fn deleted<T: Serialize>(
+        &self,
+        transaction: &mut Transaction<'_, MySql>,
+        object_type: ObjectType,
+        object_id: u32,
+        before: &T,
+    ) -> Result<(), UnexpectedOnlyError> {
+        self.record(
+            transaction,
+            object_type,
+            Some(object_id),
+            Some(snapshot(before)?),
+            None,
+        )
+        .await
+    }
+
+    /// Record an operation with arbitrary snapshots.
+    ///
+    /// This is meant for operations not targeting a single object, e.g., bulk
+    /// operations, otherwise [Audit::created], [Audit::modified] or
+    /// [Audit::deleted] should be used.
+    #[tracing::instrument(name = "audit_record", skip(transaction))]
+    pub async fn record(
+        &self,
+        transaction: &mut Transaction<'_, MySql>,
+        object_type: ObjectType,
+        object_id: Option<u32>,
+        before: Option<Value>,
+        after: Option<Value>,
+    ) -> Result<(), UnexpectedOnlyError> {
+        let entry = NewAuditLogEntry {
+            user_id: self.user_id,
+            impersonated_user_id: self.impersonated_user_id,
+            method: self.method.clone(),
+            route: self.route.clone(),
+            object_type,
+            object_id,
+            before,
+            after,
+        };
+        insert_audit_log_entry_into_db(transaction, &entry).await?;
+        Ok(())
+    }
+}
+
+/// Serialize the given object into a JSON snapshot.
+fn snapshot<T: Serialize>(object: &T) -> Result<Value, UnexpectedOnlyError> {
+    Ok(serde_json::to_value(object)
+        .context("Failed to serialize audit log snapshot")?)
+}
@@ -1,5 +1,7 @@
 //! Middleware functions for authentication.
 
+use std::{collections::HashMap, sync::Mutex};
+
 use actix_web::{
     HttpMessage,
     body::MessageBody,
@@ -8,11 +10,21 @@ use actix_web::{
     web::Data,
 };
 use avina_wire::user::{Project, User};
+use chrono::{DateTime, TimeDelta, Utc};
+use sha2::{Digest, Sha256};
 use sqlx::MySqlPool;
 
 use crate::{
-    error::{bad_request_error, internal_server_error, unauthorized_error},
-    openstack::{OpenStack, ProjectMinimal as OpenstackProjectMinimal},
+    configuration::TokenCacheSettings,
+    error::{
+        bad_request_error, forbidden_error, internal_server_error,
+        unauthorized_error,
+    },
+    metrics::record_cache_lookup,
+    openstack::{
+        OpenStack, ProjectMinimal as OpenstackProjectMinimal, RevocationEvent,
+        TokenRenewalError, ValidatedToken,
+    },
 };
 
 // TODO: revise error handling here as well and test errors
@@ -21,10 +33,174 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct Token(pub String);
 
+/// Margin subtracted from the last revocation check to tolerate clock skew
+/// between `avina-api` and Keystone.
+const REVOCATION_CLOCK_SKEW_SECONDS: i64 = 60;
+
+/// SHA-256 hash of a token, so that tokens are not kept in memory as is.
+type TokenHash = [u8; 32];
+
+struct TokenCacheValue {
+    token: ValidatedToken,
+    valid_until: DateTime<Utc>,
+}
+
+impl TokenCacheValue {
+    /// Check whether the cached token is revoked by the given event.
+    fn is_revoked_by(&self, event: &RevocationEvent) -> bool {
+        let matches =
+            |attribute: &Option<String>, value: Option<&String>| match (
+                attribute, value,
+            ) {
+                (None, _) => true,
+                (Some(attribute), Some(value)) => attribute == value,
+                (Some(_), None) => false,
+            };
+        matches(&event.audit_id, self.token.audit_ids.first())
+            && matches(&event.audit_chain_id, self.token.audit_ids.last())
+            && matches(
+                &event.user_id,
+                self.token.user.as_ref().map(|user| &user.id),
+            )
+            && matches(&event.project_id, Some(&self.token.project.id))
+            && self
+                .token
+                .issued_at
+                .is_none_or(|issued_at| issued_at <= event.issued_before)
+    }
+}
+
+/// Bounded cache of user token validations against Keystone.
+///
+/// Entries are keyed by the hash of the token and expire with the token or
+/// after the configured maximum age, whatever comes first. When the cache is
+/// full, expired entries and then the ones expiring next are evicted.
+pub struct TokenCache {
+    entries: HashMap<TokenHash, TokenCacheValue>,
+    max_age: TimeDelta,
+    max_entries: usize,
+    revocation_check_interval: TimeDelta,
+    revocations_checked_at: DateTime<Utc>,
+}
+
+impl TokenCache {
+    pub fn new(settings: &TokenCacheSettings) -> Self {
+        Self {
+            entries: HashMap::new(),
+            max_age: TimeDelta::seconds(settings.max_age as i64),
+            max_entries: settings.max_entries,
+            revocation_check_interval: TimeDelta::seconds(
+                settings.revocation_check_interval as i64,
+            ),
+            revocations_checked_at: Utc::now(),
+        }
+    }
+
+    fn hash(token: &str) -> TokenHash {
+        Sha256::digest(token.as_bytes()).into()
+    }
+
+    fn get(&mut self, token: &str) -> Option<OpenstackProjectMinimal> {
+        let key = Self::hash(token);
+        let value = self.entries.get(&key)?;
+        if value.valid_until <= Utc::now() {
+            self.entries.remove(&key);
+            return None;
+        }
+        Some(value.token.project.clone())
+    }
+
+    fn set(&mut self, token: &str, validated: ValidatedToken) {
+        let now = Utc::now();
+        let mut valid_until = now + self.max_age;
+        if let Some(expires_at) = validated.expires_at {
+            valid_until = valid_until.min(expires_at);
+        }
+        if valid_until <= now || self.max_entries == 0 {
+            return;
+        }
+        if self.entries.len() >= self.max_entries {
+            self.entries.retain(|_, value| value.valid_until > now);
+        }
+        while self.entries.len() >= self.max_entries {
+            let Some(key) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, value)| value.valid_until)
+                .map(|(key, _)| *key)
+            else {
+                break;
+            };
+            self.entries.remove(&key);
+        }
+        self.entries.insert(
+            Self::hash(token),
+            TokenCacheValue {
+                token: validated,
+                valid_until,
+            },
+        );
+    }
+
+    /// Start a revocation check, if it is due.
+    ///
+    /// Returns the timestamp since when revocation events need to be
+    /// retrieved, or [None] when no check is due.
+    fn start_revocation_check(&mut self) -> Option<DateTime<Utc>> {
+        let now = Utc::now();
+        if self.entries.is_empty() {
+            self.revocations_checked_at = now;
+            return None;
+        }
+        if now - self.revocations_checked_at < self.revocation_check_interval {
+            return None;
+        }
+        let since = self.revocations_checked_at
+            - TimeDelta::seconds(REVOCATION_CLOCK_SKEW_SECONDS);
+        self.revocations_checked_at = now;
+        Some(since)
+    }
+
+    fn revoke(&mut self, events: &[RevocationEvent]) {
+        self.entries.retain(|_, value| {
+            !events.iter().any(|event| value.is_revoked_by(event))
+        });
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Drop cached validations of tokens revoked in Keystone in the meantime.
+///
+/// When the revocation events cannot be retrieved, the whole cache is
+/// cleared, as revoked tokens could otherwise still be accepted.
+async fn check_token_revocations(
+    openstack: &OpenStack,
+    token_cache: &Mutex<TokenCache>,
+) {
+    let Some(since) = token_cache.lock().unwrap().start_revocation_check()
+    else {
+        return;
+    };
+    match openstack.get_revocation_events(since).await {
+        Ok(events) => token_cache.lock().unwrap().revoke(&events),
+        Err(error) => {
+            tracing::warn!(
+                "Clearing token cache, as revocation events could not be \
+                retrieved: {error}"
+            );
+            token_cache.lock().unwrap().clear();
+        }
+    }
+}
+
 /// Middleware function for validating the API token.
 ///
 /// This extracts the token from the X-Auth-Token header, validates it against Keystone and appends
-/// it and the OpenStack project ID to the request extensions.
+/// it and the OpenStack project ID to the request extensions. Validations are cached in the
+/// [TokenCache] to avoid a Keystone round trip for every request.
 pub async fn require_valid_token(
     req: ServiceRequest,
     next: Next<impl MessageBody>,
@@ -35,23 +211,113 @@ pub async fn require_valid_token(
     let Ok(token) = token.to_str() else {
         return Err(bad_request_error("Token is not a valid string"));
     };
-    let Some(openstack) = req.app_data::<Data<OpenStack>>() else {
+    let token = token.to_string();
+    let Some(openstack) = req.app_data::<Data<OpenStack>>().cloned() else {
         return Err(internal_server_error(
             "No OpenStack client in application state",
         ));
     };
-    let Ok(os_project) = openstack.validate_user_token(token).await else {
-        return Err(unauthorized_error("Failed to validate user token"));
+    let Some(token_cache) = req.app_data::<Data<Mutex<TokenCache>>>().cloned()
+    else {
+        return Err(internal_server_error(
+            "No token cache in application state",
+        ));
     };
-    req.extensions_mut().insert(Token(token.into()));
+    check_token_revocations(&openstack, &token_cache).await;
+    let cached = token_cache.lock().unwrap().get(&token);
+    record_cache_lookup("token", cached.is_some());
+    let os_project = match cached {
+        Some(os_project) => os_project,
+        None => {
+            let validated = match openstack.validate_user_token(&token).await {
+                Ok(validated) => validated,
+                Err(error) if error.is::<TokenRenewalError>() => {
+                    tracing::error!("Could not validate user token: {error:?}");
+                    return Err(internal_server_error(
+                        "Internal server error, contact admin or check logs",
+                    ));
+                }
+                Err(_) => {
+                    return Err(unauthorized_error(
+                        "Failed to validate user token",
+                    ));
+                }
+            };
+            let os_project = validated.project.clone();
+            token_cache.lock().unwrap().set(&token, validated);
+            os_project
+        }
+    };
+    req.extensions_mut().insert(Token(token));
     req.extensions_mut().insert(os_project);
     next.call(req).await
 }
 
+/// Wrapper type for the actual admin user behind an impersonated request.
+///
+/// This is only appended to the request extensions, when an admin impersonates another user via
+/// the X-Impersonate header, so that endpoints can still tell who actually made the request.
+#[derive(Clone, Debug)]
+pub struct Impersonator(pub User);
+
+/// Database row containing both a user and the project it belongs to.
+struct UserAndProjectRow {
+    user_id: i32,
+    user_name: String,
+    user_openstack_id: String,
+    user_role: u32,
+    user_is_staff: i8,
+    user_is_active: i8,
+    project_id: i32,
+    project_name: String,
+    project_openstack_id: String,
+    project_user_class: u32,
+}
+
+impl TryFrom<UserAndProjectRow> for (User, Project) {
+    type Error = actix_web::Error;
+
+    fn try_from(row: UserAndProjectRow) -> Result<Self, Self::Error> {
+        let user = User {
+            id: row.user_id as u32,
+            name: row.user_name,
+            openstack_id: row.user_openstack_id,
+            project: row.project_id as u32,
+            project_name: row.project_name.clone(),
+            role: row.user_role,
+            is_staff: row.user_is_staff != 0,
+            is_active: row.user_is_active != 0,
+        };
+
+        let user_class = match row.project_user_class.try_into() {
+            Ok(u) => u,
+            Err(_) => {
+                return Err(internal_server_error(
+                    "Failed to parse user class",
+                ));
+            }
+        };
+
+        let project = Project {
+            id: row.project_id as u32,
+            name: row.project_name,
+            openstack_id: row.project_openstack_id,
+            user_class,
+        };
+
+        Ok((user, project))
+    }
+}
+
 /// Middleware function for appending the user and project.
 ///
 /// This takes the previously added project ID extension and retrieves the corresponding avina user
 /// and project from the database, and pushes them into the request extensions, as well.
+///
+/// If the request carries an X-Impersonate header with a user ID and the authenticated user is an
+/// admin, the user with that ID and its project are pushed instead, so that the endpoints behave
+/// exactly as they would for that user. The actual admin user is appended as [Impersonator] in
+/// that case. Non-admin users trying to impersonate get an HTTP 403 FORBIDDEN.
 pub async fn extract_user_and_project(
     req: ServiceRequest,
     next: Next<impl MessageBody>,
@@ -70,21 +336,8 @@ pub async fn extract_user_and_project(
         ));
     };
 
-    struct Row {
-        user_id: i32,
-        user_name: String,
-        user_openstack_id: String,
-        user_role: u32,
-        user_is_staff: i8,
-        user_is_active: i8,
-        project_id: i32,
-        project_name: String,
-        project_openstack_id: String,
-        project_user_class: u32,
-    }
-
     let Ok(row) = sqlx::query_as!(
-        Row,
+        UserAndProjectRow,
         r#"
         SELECT
             user.id AS user_id,
@@ -112,34 +365,91 @@ pub async fn extract_user_and_project(
             "Failed to retrieve user and project from database",
         ));
     };
+    let (user, project) = row.try_into()?;
 
-    let user = User {
-        id: row.user_id as u32,
-        name: row.user_name,
-        openstack_id: row.user_openstack_id,
-        project: row.project_id as u32,
-        project_name: row.project_name.clone(),
-        role: row.user_role,
-        is_staff: row.user_is_staff != 0,
-        is_active: row.user_is_active != 0,
+    let impersonate = match req.headers().get("X-Impersonate") {
+        Some(impersonate) => {
+            let Some(impersonate) = impersonate
+                .to_str()
+                .ok()
+                .and_then(|i| i.trim().parse::<u32>().ok())
+            else {
+                return Err(bad_request_error(
+                    "Impersonate header is not a valid user ID",
+                ));
+            };
+            Some(impersonate)
+        }
+        None => None,
     };
 
-    let user_class = match row.project_user_class.try_into() {
-        Ok(u) => u,
+    let Some(impersonated_user_id) = impersonate else {
+        req.extensions_mut().insert(user);
+        req.extensions_mut().insert(project);
+        return next.call(req).await;
+    };
+
+    if !user.is_staff {
+        tracing::warn!(
+            "Non-admin user {} (ID {}) tried to impersonate user with ID {}",
+            user.name,
+            user.id,
+            impersonated_user_id
+        );
+        return Err(forbidden_error(
+            "Admin privileges required for impersonation",
+        ));
+    }
+
+    let row = match sqlx::query_as!(
+        UserAndProjectRow,
+        r#"
+        SELECT
+            user.id AS user_id,
+            user.name AS user_name,
+            user.openstack_id AS user_openstack_id,
+            user.role AS user_role,
+            user.is_staff AS user_is_staff,
+            user.is_active AS user_is_active,
+            project.id AS project_id,
+            project.name AS project_name,
+            project.openstack_id AS project_openstack_id,
+            project.user_class AS project_user_class
+        FROM user_user AS user, user_project AS project
+        WHERE
+            user.project_id = project.id AND
+            user.id = ?
+        "#,
+        impersonated_user_id
+    )
+    .fetch_optional(db_pool.get_ref())
+    .await
+    {
+        Ok(Some(row)) => row,
+        Ok(None) => {
+            return Err(bad_request_error(
+                "User to impersonate does not exist",
+            ));
+        }
         Err(_) => {
-            return Err(internal_server_error("Failed to parse user class"));
+            return Err(internal_server_error(
+                "Failed to retrieve impersonated user and project from database",
+            ));
         }
     };
+    let (impersonated_user, impersonated_project) = row.try_into()?;
 
-    let project = Project {
-        id: row.project_id as u32,
-        name: row.project_name,
-        openstack_id: row.project_openstack_id,
-        user_class,
-    };
+    tracing::info!(
+        "Admin user {} (ID {}) impersonates user {} (ID {})",
+        user.name,
+        user.id,
+        impersonated_user.name,
+        impersonated_user.id
+    );
 
-    req.extensions_mut().insert(user);
-    req.extensions_mut().insert(project);
+    req.extensions_mut().insert(impersonated_user);
+    req.extensions_mut().insert(impersonated_project);
+    req.extensions_mut().insert(Impersonator(user));
 
     next.call(req).await
 }
@@ -4,10 +4,17 @@
 //! that implement [serde::Deserialize]. The [config] crate is used to parse this
 //! either from a configuration file or environment variables.
 
+use std::str::FromStr;
+
+use actix_web::http::{Uri, header::HeaderName};
+use avina_wire::jobs::Job;
 use secrecy::{ExposeSecret, SecretString};
 use serde::Deserialize;
-use serde_aux::field_attributes::deserialize_number_from_string;
+use serde_aux::field_attributes::{
+    deserialize_number_from_string, deserialize_option_number_from_string,
+};
 use sqlx::mysql::{MySqlConnectOptions, MySqlSslMode};
+use strum::IntoEnumIterator;
 
 /// Wrapper combining all other settings.
 #[derive(Clone, serde::Deserialize)]
@@ -15,6 +22,8 @@ pub struct Settings {
     pub database: DatabaseSettings,
     pub application: ApplicationSettings,
     pub openstack: OpenStackSettings,
+    #[serde(default)]
+    pub jobs: JobsSettings,
 }
 
 /// Settings for the application, the API backend, itself.
@@ -46,6 +55,240 @@ pub struct ApplicationSettings {
     /// configured nor this enabled, a runtime error is thrown, when [`crate::ldap::AvinaLdap`] is
     /// used.
     pub avina_ldap_default: Option<bool>,
+    /// Settings for caching the validation of user tokens.
+    #[serde(default)]
+    pub token_cache: TokenCacheSettings,
+    /// Settings for CORS (Cross-Origin Resource Sharing).
+    #[serde(default)]
+    pub cors: CorsSettings,
+    /// Token required for accessing the metrics endpoint, open when not set.
+    pub metrics_token: Option<SecretString>,
+}
+
+/// HTTP methods that may be allowed for cross-origin requests.
+const CORS_METHODS: [&str; 7] =
+    ["GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"];
+
+/// A list of strings, given either as a sequence, e.g., in the configuration
+/// files, or as a comma-separated string, e.g., in environment variables.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum StringList {
+    Sequence(Vec<String>),
+    CommaSeparated(String),
+}
+
+impl From<StringList> for Vec<String> {
+    fn from(list: StringList) -> Self {
+        match list {
+            StringList::Sequence(items) => items,
+            StringList::CommaSeparated(s) => s
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+/// Deserialize a list of strings from a sequence or a comma-separated string.
+fn deserialize_string_list<'de, D>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(StringList::deserialize(deserializer)?.into())
+}
+
+/// Deserialize an optional list of strings from a sequence or a
+/// comma-separated string.
+fn deserialize_option_string_list<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<StringList>::deserialize(deserializer)?.map(Into::into))
+}
+
+/// Settings for CORS (Cross-Origin Resource Sharing).
+///
+/// Origins have to be given exactly as sent by browsers in the `Origin`
+/// header, i.e., scheme, host and optionally port, without a trailing slash.
+#[derive(Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct CorsSettings {
+    /// Origins allowed to make cross-origin requests.
+    #[serde(deserialize_with = "deserialize_string_list")]
+    pub allowed_origins: Vec<String>,
+    /// HTTP methods allowed for cross-origin requests, any when not set.
+    #[serde(deserialize_with = "deserialize_option_string_list")]
+    pub allowed_methods: Option<Vec<String>>,
+    /// Request headers allowed for cross-origin requests, any when not set.
+    #[serde(deserialize_with = "deserialize_option_string_list")]
+    pub allowed_headers: Option<Vec<String>>,
+    /// Number of seconds browsers may cache the result of a preflight request.
+    #[serde(deserialize_with = "deserialize_option_number_from_string")]
+    pub max_age: Option<usize>,
+    /// Allow any origin, method and header, only meant for development.
+    pub permissive: bool,
+}
+
+impl CorsSettings {
+    /// Check the settings for invalid or contradicting values.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.permissive {
+            if !self.allowed_origins.is_empty() {
+                return Err("CORS allowed origins cannot be combined with \
+                    permissive mode"
+                    .to_string());
+            }
+            return Ok(());
+        }
+        for origin in &self.allowed_origins {
+            let Ok(uri) = origin.parse::<Uri>() else {
+                return Err(format!("CORS origin {origin} is not a valid URI"));
+            };
+            if !matches!(uri.scheme_str(), Some("http" | "https"))
+                || uri.host().is_none()
+                || origin.ends_with('/')
+                || uri.path_and_query().is_some_and(|p| p.as_str() != "/")
+            {
+                return Err(format!(
+                    "CORS origin {origin} is not of the form \
+                    scheme://host[:port]"
+                ));
+            }
+        }
+        for method in self.allowed_methods.iter().flatten() {
+            if !CORS_METHODS.contains(&method.as_str()) {
+                return Err(format!(
+                    "CORS method {method} is not one of {}",
+                    CORS_METHODS.join(", ")
+                ));
+            }
+        }
+        for header in self.allowed_headers.iter().flatten() {
+            if HeaderName::from_bytes(header.as_bytes()).is_err() {
+                return Err(format!(
+                    "CORS header {header} is not a valid header name"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Settings for caching the validation of user tokens against Keystone.
+///
+/// A cached validation is used until the token expires or the maximum age
+/// is reached, whatever comes first. Keystone's revocation events are polled
+/// regularly to drop the validations of revoked tokens early.
+#[derive(Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct TokenCacheSettings {
+    /// Maximum age of a cached validation in seconds, 0 disables the cache.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_age: u64,
+    /// Maximum number of cached validations.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_entries: usize,
+    /// Interval in seconds in which Keystone is checked for revoked tokens.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub revocation_check_interval: u64,
+}
+
+impl Default for TokenCacheSettings {
+    fn default() -> Self {
+        Self {
+            max_age: 300,
+            max_entries: 10000,
+            revocation_check_interval: 30,
+        }
+    }
+}
+
+/// Schedules of the jobs run by the built-in scheduler.
+///
+/// Jobs without a schedule are not run automatically, but can still be
+/// triggered manually via the job run endpoint.
+#[derive(Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct JobsSettings {
+    pub server_state_import: Option<JobSettings>,
+    pub volume_state_import: Option<JobSettings>,
+    pub floating_ip_state_import: Option<JobSettings>,
+    pub flavor_import: Option<JobSettings>,
+    pub user_import: Option<JobSettings>,
+    pub user_sync: Option<JobSettings>,
+    pub user_budget_sync: Option<JobSettings>,
+}
+
+impl JobsSettings {
+    /// Get the schedule settings of the given job, if any.
+    pub fn get(&self, job: Job) -> Option<&JobSettings> {
+        match job {
+            Job::ServerStateImport => self.server_state_import.as_ref(),
+            Job::VolumeStateImport => self.volume_state_import.as_ref(),
+            Job::FloatingIpStateImport => {
+                self.floating_ip_state_import.as_ref()
+            }
+            Job::FlavorImport => self.flavor_import.as_ref(),
+            Job::UserImport => self.user_import.as_ref(),
+            Job::UserSync => self.user_sync.as_ref(),
+            Job::UserBudgetSync => self.user_budget_sync.as_ref(),
+        }
+    }
+
+    /// Check that each job is scheduled either by interval or cron expression.
+    pub fn validate(&self) -> Result<(), String> {
+        for job in Job::iter() {
+            let Some(settings) = self.get(job) else {
+                continue;
+            };
+            match (settings.interval, &settings.cron) {
+                (Some(0), None) => {
+                    return Err(format!(
+                        "Interval of job {job} has to be greater than 0"
+                    ));
+                }
+                (Some(_), None) => {}
+                (None, Some(cron)) => {
+                    if let Err(error) = cron::Schedule::from_str(cron) {
+                        return Err(format!(
+                            "Cron expression of job {job} is invalid: {error}"
+                        ));
+                    }
+                }
+                _ => {
+                    return Err(format!(
+                        "Job {job} needs either an interval or a cron \
+                        expression"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Schedule of a single job.
+///
+/// Exactly one of interval and cron expression has to be given. Cron
+/// expressions are evaluated in UTC and include seconds as first field,
+/// e.g., `0 0 * * * *` runs the job at the start of each hour.
+#[derive(Clone, serde::Deserialize)]
+pub struct JobSettings {
+    /// Number of seconds between the end of a run and the start of the next.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_option_number_from_string"
+    )]
+    pub interval: Option<u64>,
+    /// Cron expression the runs are scheduled by.
+    pub cron: Option<String>,
 }
 
 /// Deserialize a string containing a secret.
@@ -82,12 +325,22 @@ pub struct DatabaseSettings {
 }
 
 /// Settings needed for accessing the OpenStack services.
+///
+/// The admin account authenticates either with its username and password,
+/// or with an application credential, referenced by its ID or by its name
+/// along with the username, see [OpenStackSettings::validate].
 #[derive(Clone, serde::Deserialize)]
 pub struct OpenStackSettings {
     /// Username of the OpenStack admin account.
-    pub username: String,
+    pub username: Option<String>,
     /// Password of the OpenStack admin account.
-    pub password: String,
+    pub password: Option<SecretString>,
+    /// ID of the application credential of the OpenStack admin account.
+    pub application_credential_id: Option<String>,
+    /// Name of the application credential of the OpenStack admin account.
+    pub application_credential_name: Option<String>,
+    /// Secret of the application credential of the OpenStack admin account.
+    pub application_credential_secret: Option<SecretString>,
     /// Project name of the OpenStack admin account.
     pub project: String,
     /// Project ID of the of OpenStack admin account.
@@ -100,6 +353,72 @@ pub struct OpenStackSettings {
     pub keystone_endpoint: String,
     /// API URL of the Nova service.
     pub nova_endpoint: String,
+    /// API URL of the Cinder service.
+    pub cinder_endpoint: String,
+    /// API URL of the Neutron service.
+    pub neutron_endpoint: String,
+    /// UUID of the external network of the MWN floating IP pool.
+    pub mwn_network_id: String,
+    /// UUID of the external network of the Internet floating IP pool.
+    pub internet_network_id: String,
+    /// Seconds before its expiry at which the admin token is renewed.
+    #[serde(
+        default = "default_token_renewal_margin",
+        deserialize_with = "deserialize_number_from_string"
+    )]
+    pub token_renewal_margin: u64,
+    /// Aliases of the PCI devices passed through to servers that are GPUs.
+    ///
+    /// Flavors are charged the GPU price for these devices only, see
+    /// [crate::openstack::FlavorDetailed::gpus].
+    #[serde(default, deserialize_with = "deserialize_string_list")]
+    pub gpu_aliases: Vec<String>,
+}
+
+/// Default for [OpenStackSettings::token_renewal_margin].
+fn default_token_renewal_margin() -> u64 {
+    300
+}
+
+impl OpenStackSettings {
+    /// Check that a way of authentication is fully configured.
+    ///
+    /// An application credential takes precedence over the password, so that
+    /// it can be configured without unsetting a password given in the base
+    /// configuration.
+    pub fn validate(&self) -> Result<(), String> {
+        let application_credential = self.application_credential_id.is_some()
+            || self.application_credential_name.is_some()
+            || self.application_credential_secret.is_some();
+        if !application_credential {
+            if self.username.is_none() || self.password.is_none() {
+                return Err("OpenStack username and password, or an \
+                    application credential are required"
+                    .to_string());
+            }
+            return Ok(());
+        }
+        if self.application_credential_secret.is_none() {
+            return Err("OpenStack application credential secret is required"
+                .to_string());
+        }
+        match (
+            &self.application_credential_id,
+            &self.application_credential_name,
+        ) {
+            (Some(_), None) => Ok(()),
+            (None, Some(_)) if self.username.is_some() => Ok(()),
+            (None, Some(_)) => Err("OpenStack application credential name \
+                requires the username"
+                .to_string()),
+            (Some(_), Some(_)) => Err("OpenStack application credential ID \
+                and name cannot be combined"
+                .to_string()),
+            (None, None) => Err("Either OpenStack application credential ID \
+                or name is required"
+                .to_string()),
+        }
+    }
 }
 
 impl DatabaseSettings {
@@ -133,7 +452,8 @@ impl DatabaseSettings {
 ///
 /// The final settings are interpolated from the `base.yaml` and `local.yaml`
 /// or `production.yaml`, depending on the environment set in the `APP_ENVIRONMENT`
-/// environment variable, and environment variables with the prefix `APP`.
+/// environment variable, and environment variables with the prefix `APP`. Lists,
+/// e.g., the allowed CORS origins, are given as comma-separated values in the latter.
 pub fn get_configuration() -> Result<Settings, config::ConfigError> {
     let base_path = std::env::current_dir()
         .expect("Failed to determine current directory.");
@@ -158,7 +478,21 @@ pub fn get_configuration() -> Result<Settings, config::ConfigError> {
         )
         .build()?;
 
-    settings.try_deserialize::<Settings>()
+    let settings = settings.try_deserialize::<Settings>()?;
+    settings
+        .application
+        .cors
+        .validate()
+        .map_err(config::ConfigError::Message)?;
+    settings
+        .jobs
+        .validate()
+        .map_err(config::ConfigError::Message)?;
+    settings
+        .openstack
+        .validate()
+        .map_err(config::ConfigError::Message)?;
+    Ok(settings)
 }
 
 /// The potential environments the API backend may be executed in (local or production).
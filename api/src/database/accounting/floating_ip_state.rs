@@ -0,0 +1,515 @@
+//! Queries for floating IP states.
+
+use std::str::FromStr;
+
+use anyhow::Context;
+use avina_wire::accounting::{FloatingIpPool, FloatingIpState};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow, MySql, Transaction, mysql::MySqlRow};
+use uuid::Uuid;
+
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
+};
+
+/// Representation of a floating IP state specifically for communication with the database.
+///
+/// This uses types, that can be directly deserialized from SQL and is then converted
+/// to [FloatingIpState] afterwards.
+#[derive(FromRow)]
+pub struct FloatingIpStateRow {
+    /// ID for the floating IP state.
+    #[sqlx(try_from = "i32")]
+    pub id: u32,
+    /// Timestamp when the floating IP was first observed in this state.
+    pub begin: DateTime<Utc>,
+    /// Optional timestamp when the floating IP was first observed having left this state.
+    ///
+    /// This is optional, as the floating IP may still be in this state.
+    pub end: Option<DateTime<Utc>>,
+    /// UUID of the Neutron floating IP.
+    pub floating_ip_id: String,
+    /// Address of the floating IP.
+    pub address: String,
+    /// Pool the floating IP is allocated from.
+    pub pool: String,
+    /// ID of the user the floating IP belongs to.
+    #[sqlx(try_from = "i32")]
+    pub user: u32,
+    /// Name of the user the floating IP belongs to.
+    pub username: String,
+}
+
+impl TryFrom<FloatingIpStateRow> for FloatingIpState {
+    type Error = UnexpectedOnlyError;
+
+    fn try_from(row: FloatingIpStateRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            begin: row.begin.fixed_offset(),
+            end: row.end.map(|end| end.fixed_offset()),
+            floating_ip_id: Uuid::from_str(row.floating_ip_id.as_str())
+                .context("Could not parse floating IP id String")?,
+            address: row.address,
+            pool: FloatingIpPool::from_str(&row.pool)
+                .context("Failed to parse floating IP pool")?,
+            user: row.user,
+            username: row.username,
+        })
+    }
+}
+
+/// Convert the given rows into floating IP states.
+fn floating_ip_states_from_rows(
+    rows: Vec<MySqlRow>,
+) -> Result<Vec<FloatingIpState>, UnexpectedOnlyError> {
+    rows.into_iter()
+        .map(|r| FloatingIpStateRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to floating IP state row")?
+        .into_iter()
+        .map(FloatingIpState::try_from)
+        .collect()
+}
+
+/// Select a floating IP state by the given ID from the database, or return [None].
+#[tracing::instrument(
+    name = "select_maybe_floating_ip_state_from_db",
+    skip(transaction)
+)]
+pub async fn select_maybe_floating_ip_state_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    floating_ip_state_id: u64,
+) -> Result<Option<FloatingIpState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            fs.floating_ip_id as floating_ip_id,
+            fs.address as address,
+            fs.pool as pool,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_floatingipstate as fs,
+            user_user as u
+        WHERE
+            fs.user_id = u.id AND
+            fs.state_ptr_id = s.id AND
+            s.id = ?
+        "#,
+        floating_ip_state_id,
+    );
+    let row = transaction
+        .fetch_optional(query)
+        .await
+        .context("Failed to execute select query")?;
+    Ok(match row {
+        Some(row) => Some(
+            FloatingIpStateRow::from_row(&row)
+                .context("Failed to parse floating IP state row")?
+                .try_into()?,
+        ),
+        None => None,
+    })
+}
+
+/// Select a floating IP state with the given ID from the database, or a "not found" error.
+///
+/// This calls [select_maybe_floating_ip_state_from_db] and then turns a [None] response into a
+/// [NotFoundOrUnexpectedApiError::NotFoundError].
+#[tracing::instrument(
+    name = "select_floating_ip_state_from_db",
+    skip(transaction)
+)]
+pub async fn select_floating_ip_state_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    floating_ip_state_id: u64,
+) -> Result<FloatingIpState, NotFoundOrUnexpectedApiError> {
+    select_maybe_floating_ip_state_from_db(transaction, floating_ip_state_id)
+        .await?
+        .ok_or(NotFoundOrUnexpectedApiError::NotFoundError)
+}
+
+/// Select a list of all floating IP states from the database.
+#[tracing::instrument(
+    name = "select_all_floating_ip_states_from_db",
+    skip(transaction)
+)]
+pub async fn select_all_floating_ip_states_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<Vec<FloatingIpState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            fs.floating_ip_id as floating_ip_id,
+            fs.address as address,
+            fs.pool as pool,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_floatingipstate as fs,
+            user_user as u
+        WHERE
+            fs.user_id = u.id AND
+            fs.state_ptr_id = s.id
+        "#,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    floating_ip_states_from_rows(rows)
+}
+
+/// Select a list of floating IP states belonging to the project with the given ID from the database.
+#[tracing::instrument(
+    name = "select_floating_ip_states_by_project_from_db",
+    skip(transaction)
+)]
+pub async fn select_floating_ip_states_by_project_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    project_id: u64,
+) -> Result<Vec<FloatingIpState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            fs.floating_ip_id as floating_ip_id,
+            fs.address as address,
+            fs.pool as pool,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_floatingipstate as fs,
+            user_user as u
+        WHERE
+            fs.user_id = u.id AND
+            fs.state_ptr_id = s.id AND
+            u.project_id = ?
+        "#,
+        project_id,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    floating_ip_states_from_rows(rows)
+}
+
+/// Select a list of floating IP states belonging to the user with the given ID from the database.
+#[tracing::instrument(
+    name = "select_floating_ip_states_by_user_from_db",
+    skip(transaction)
+)]
+pub async fn select_floating_ip_states_by_user_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    user_id: u64,
+) -> Result<Vec<FloatingIpState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            fs.floating_ip_id as floating_ip_id,
+            fs.address as address,
+            fs.pool as pool,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_floatingipstate as fs,
+            user_user as u
+        WHERE
+            fs.user_id = u.id AND
+            fs.state_ptr_id = s.id AND
+            u.id = ?
+        "#,
+        user_id,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    floating_ip_states_from_rows(rows)
+}
+
+/// Select a list of floating IP states of the floating IP with the given UUID from the database.
+#[tracing::instrument(
+    name = "select_floating_ip_states_by_floating_ip_from_db",
+    skip(transaction)
+)]
+pub async fn select_floating_ip_states_by_floating_ip_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    floating_ip_id: Uuid,
+) -> Result<Vec<FloatingIpState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            fs.floating_ip_id as floating_ip_id,
+            fs.address as address,
+            fs.pool as pool,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_floatingipstate as fs,
+            user_user as u
+        WHERE
+            fs.user_id = u.id AND
+            fs.state_ptr_id = s.id AND
+            fs.floating_ip_id = ?
+        "#,
+        floating_ip_id.to_string(),
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    floating_ip_states_from_rows(rows)
+}
+
+/// Fields floating IP states can be ordered by via the `ordering` parameter.
+impl Orderable for FloatingIpState {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "s.id",
+            "begin" => "s.begin",
+            "end" => "s.end",
+            "floating_ip_id" => "fs.floating_ip_id",
+            "address" => "fs.address",
+            "pool" => "fs.pool",
+            "user" => "u.id",
+            "username" => "u.name",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the floating IP states selected by
+/// [select_floating_ip_state_list_from_db].
+#[derive(Debug, Default)]
+pub struct FloatingIpStateFilter {
+    /// ID of the project the floating IP states have to belong to.
+    pub project: Option<u64>,
+    /// ID of the user the floating IP states have to belong to.
+    pub user: Option<u64>,
+    /// UUID of the floating IP the states have to be of.
+    pub floating_ip: Option<Uuid>,
+    /// Pool the floating IP states have to be in.
+    pub pool: Option<FloatingIpPool>,
+}
+
+/// Select a list of floating IP states matching the given filter from the database, ordered
+/// and paged according to the given [ListQuery].
+///
+/// Returns the floating IP states together with the count of all floating IP states matching
+/// the filter.
+#[tracing::instrument(
+    name = "select_floating_ip_state_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_floating_ip_state_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &FloatingIpStateFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<FloatingIpState>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            fs.floating_ip_id as floating_ip_id,
+            fs.address as address,
+            fs.pool as pool,
+            u.id as user,
+            u.name as username
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM
+            accounting_state as s,
+            accounting_floatingipstate as fs,
+            user_user as u
+        WHERE
+            fs.user_id = u.id AND
+            fs.state_ptr_id = s.id
+        "#,
+            );
+            if let Some(project_id) = filter.project {
+                query.push(" AND u.project_id = ").push_bind(project_id);
+            }
+            if let Some(user_id) = filter.user {
+                query.push(" AND u.id = ").push_bind(user_id);
+            }
+            if let Some(floating_ip_id) = filter.floating_ip {
+                query
+                    .push(" AND fs.floating_ip_id = ")
+                    .push_bind(floating_ip_id.to_string());
+            }
+            if let Some(pool) = filter.pool {
+                let pool: &str = pool.into();
+                query.push(" AND fs.pool = ").push_bind(pool);
+            }
+        },
+        list_query,
+    )
+    .await?;
+    Ok((floating_ip_states_from_rows(rows)?, count))
+}
+
+/// Select a list of all floating IP states without end from the database.
+#[tracing::instrument(
+    name = "select_unfinished_floating_ip_states_from_db",
+    skip(transaction)
+)]
+pub async fn select_unfinished_floating_ip_states_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<Vec<FloatingIpState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            fs.floating_ip_id as floating_ip_id,
+            fs.address as address,
+            fs.pool as pool,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_floatingipstate as fs,
+            user_user as u
+        WHERE
+            fs.user_id = u.id AND
+            fs.state_ptr_id = s.id AND
+            s.end IS NULL
+        "#,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    floating_ip_states_from_rows(rows)
+}
+
+/// Simplified representation of data needed to create a new floating IP state.
+#[derive(Debug)]
+pub struct NewFloatingIpState {
+    /// Timestamp when the floating IP was first observed in this state.
+    pub begin: DateTime<Utc>,
+    /// Optional timestamp when the floating IP was first observed having left this state.
+    pub end: Option<DateTime<Utc>>,
+    /// UUID of the Neutron floating IP.
+    pub floating_ip_id: Uuid,
+    /// Address of the floating IP.
+    pub address: String,
+    /// Pool the floating IP is allocated from.
+    pub pool: FloatingIpPool,
+    /// ID of the user the floating IP belongs to.
+    pub user: u32,
+}
+
+/// Insert a new floating IP state based on the given [NewFloatingIpState] into the database.
+#[tracing::instrument(
+    name = "insert_floating_ip_state_into_db",
+    skip(new_floating_ip_state, transaction)
+)]
+pub async fn insert_floating_ip_state_into_db(
+    transaction: &mut Transaction<'_, MySql>,
+    new_floating_ip_state: &NewFloatingIpState,
+) -> Result<u64, MinimalApiError> {
+    let pool: &str = new_floating_ip_state.pool.into();
+    // TODO: MariaDB 10.5 introduced INSERT ... RETURNING
+    let query1 = sqlx::query!(
+        r#"
+        INSERT IGNORE INTO accounting_state (begin, end)
+        VALUES (?, ?)
+        "#,
+        new_floating_ip_state.begin,
+        new_floating_ip_state.end,
+    );
+    let result1 = transaction
+        .execute(query1)
+        .await
+        .context("Failed to execute insert query")?;
+    if result1.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to insert new state, a conflicting entry exists"
+                .to_string(),
+        ));
+    }
+    let id = result1.last_insert_id();
+    let query2 = sqlx::query!(
+        r#"
+        INSERT IGNORE INTO accounting_floatingipstate (
+            state_ptr_id, floating_ip_id, address, pool, user_id
+        )
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        id,
+        new_floating_ip_state.floating_ip_id.to_string(),
+        new_floating_ip_state.address,
+        pool,
+        new_floating_ip_state.user
+    );
+    let result2 = transaction
+        .execute(query2)
+        .await
+        .context("Failed to execute insert query")?;
+    if result2.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to insert new floating IP state, a conflicting entry exists"
+                .to_string(),
+        ));
+    }
+    Ok(id)
+}
+
+/// Set the end of the floating IP state with the given ID in the database.
+#[tracing::instrument(
+    name = "update_floating_ip_state_end_in_db",
+    skip(transaction)
+)]
+pub async fn update_floating_ip_state_end_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    floating_ip_state_id: u64,
+    end: DateTime<Utc>,
+) -> Result<(), UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE accounting_state
+        SET
+            end = ?
+        WHERE id = ?
+        "#,
+        end,
+        floating_ip_state_id,
+    );
+    transaction
+        .execute(query)
+        .await
+        .context("Failed to execute update query")?;
+    Ok(())
+}
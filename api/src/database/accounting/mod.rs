@@ -1,3 +1,6 @@
-//! Queries for the accounting module of the API, so the server states.
+//! Queries for the accounting module of the API, so the server, volume and
+//! floating IP states.
 
+pub mod floating_ip_state;
 pub mod server_state;
+pub mod volume_state;
@@ -19,6 +19,7 @@ use crate::{
     error::{
         MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
     },
+    pagination::{ListQuery, Orderable, select_list},
 };
 
 /// Representation of a server state specifically for communication with the database.
@@ -397,6 +398,119 @@ pub async fn select_server_states_by_server_from_db(
     Ok(rows)
 }
 
+/// Fields server states can be ordered by via the `ordering` parameter.
+impl Orderable for ServerState {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "s.id",
+            "begin" => "s.begin",
+            "end" => "s.end",
+            "instance_id" => "ss.instance_id",
+            "instance_name" => "ss.instance_name",
+            "flavor" => "f.id",
+            "flavor_name" => "f.name",
+            "status" => "ss.status",
+            "user" => "u.id",
+            "username" => "u.name",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the server states selected by [select_server_state_list_from_db].
+#[derive(Debug, Default)]
+pub struct ServerStateFilter {
+    /// ID of the project the server states have to belong to.
+    pub project: Option<u64>,
+    /// ID of the user the server states have to belong to.
+    pub user: Option<u64>,
+    /// UUID of the server the states have to be of.
+    pub server: Option<Uuid>,
+}
+
+/// Select a list of server states matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the server states together with the count of all server states matching the filter.
+#[tracing::instrument(
+    name = "select_server_state_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_server_state_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &ServerStateFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<ServerState>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            ss.instance_id as instance_id,
+            ss.instance_name as instance_name,
+            f.id as flavor,
+            f.name as flavor_name,
+            ss.status as status,
+            u.id as user,
+            u.name as username
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM
+            accounting_state as s,
+            accounting_serverstate as ss,
+            resources_flavor as f,
+            user_user as u
+        WHERE
+            ss.flavor_id = f.id AND
+            ss.user_id = u.id AND
+            ss.state_ptr_id = s.id
+        "#,
+            );
+            if let Some(project_id) = filter.project {
+                query.push(" AND u.project_id = ").push_bind(project_id);
+            }
+            if let Some(user_id) = filter.user {
+                query.push(" AND u.id = ").push_bind(user_id);
+            }
+            if let Some(server_id) = filter.server {
+                query
+                    .push(" AND ss.instance_id = ")
+                    .push_bind(server_id.to_string());
+            }
+        },
+        list_query,
+    )
+    .await?;
+    let server_states = rows
+        .into_iter()
+        .map(|r| ServerStateRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to server state")?
+        .into_iter()
+        .map(|r| {
+            Ok::<ServerState, UnexpectedOnlyError>(ServerState {
+                id: r.id,
+                begin: r.begin.fixed_offset(),
+                end: r.end.map(|end| end.fixed_offset()),
+                instance_id: Uuid::from_str(r.instance_id.as_str())
+                    .context("Could not parse instance id String")?,
+                instance_name: r.instance_name,
+                flavor: r.flavor,
+                flavor_name: r.flavor_name,
+                status: r.status,
+                user: r.user,
+                username: r.username,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert server state row to server state")?;
+    Ok((server_states, count))
+}
+
 /// Select the user class of the project the server with the given UUID belongs to from the
 /// database, or return [None].
 #[tracing::instrument(
@@ -444,6 +558,49 @@ pub async fn select_user_class_by_server_from_db(
     })
 }
 
+/// Select the ID of the project the server with the given UUID belongs to from the database, or
+/// return [None].
+#[tracing::instrument(
+    name = "select_project_id_by_server_from_db",
+    skip(transaction)
+)]
+pub async fn select_project_id_by_server_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    server_id: Uuid,
+) -> Result<Option<u32>, UnexpectedOnlyError> {
+    #[derive(FromRow)]
+    struct Row {
+        #[sqlx(try_from = "i32")]
+        project: u32,
+    }
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            u.project_id as project
+        FROM
+            accounting_serverstate as ss,
+            user_user as u
+        WHERE
+            ss.user_id = u.id AND
+            ss.instance_id = ?
+        LIMIT 1
+        "#,
+        server_id.to_string(),
+    );
+    let row = transaction
+        .fetch_optional(query)
+        .await
+        .context("Failed to execute select query")?;
+    Ok(match row {
+        Some(row) => Some(
+            Row::from_row(&row)
+                .context("Failed to parse project row")?
+                .project,
+        ),
+        None => None,
+    })
+}
+
 /// Select a list of server states belonging to the server with the given UUID, while it was part of
 /// the project with the given ID, from the database.
 ///
@@ -1094,6 +1251,33 @@ pub async fn select_unfinished_server_states_from_db(
     Ok(rows)
 }
 
+/// Set the end of the server state with the given ID in the database.
+#[tracing::instrument(
+    name = "update_server_state_end_in_db",
+    skip(transaction)
+)]
+pub async fn update_server_state_end_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    server_state_id: u64,
+    end: DateTime<Utc>,
+) -> Result<(), UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE accounting_state
+        SET
+            end = ?
+        WHERE id = ?
+        "#,
+        end,
+        server_state_id,
+    );
+    transaction
+        .execute(query)
+        .await
+        .context("Failed to execute update query")?;
+    Ok(())
+}
+
 /// Delete the server state with the given ID from the database.
 #[tracing::instrument(name = "delete_server_state_from_db", skip(transaction))]
 pub async fn delete_server_state_from_db(
@@ -0,0 +1,516 @@
+//! Queries for volume states.
+
+use std::str::FromStr;
+
+use anyhow::Context;
+use avina_wire::accounting::VolumeState;
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow, MySql, Transaction, mysql::MySqlRow};
+use uuid::Uuid;
+
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
+};
+
+/// Representation of a volume state specifically for communication with the database.
+///
+/// This uses types, that can be directly deserialized from SQL and is then converted
+/// to [VolumeState] afterwards.
+#[derive(FromRow)]
+pub struct VolumeStateRow {
+    /// ID for the volume state.
+    #[sqlx(try_from = "i32")]
+    pub id: u32,
+    /// Timestamp when the volume was first observed in this state.
+    pub begin: DateTime<Utc>,
+    /// Optional timestamp when the volume was first observed having left this state.
+    ///
+    /// This is optional, as the volume may still be in this state.
+    pub end: Option<DateTime<Utc>>,
+    /// UUID of the Cinder volume.
+    pub volume_id: String,
+    /// Name of the Cinder volume.
+    pub volume_name: String,
+    /// Status during this state (available, in-use, ...)
+    pub status: String,
+    /// Size of the volume in GiB during this state.
+    pub size: u32,
+    /// ID of the user the volume belongs to.
+    #[sqlx(try_from = "i32")]
+    pub user: u32,
+    /// Name of the user the volume belongs to.
+    pub username: String,
+}
+
+impl TryFrom<VolumeStateRow> for VolumeState {
+    type Error = UnexpectedOnlyError;
+
+    fn try_from(row: VolumeStateRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            begin: row.begin.fixed_offset(),
+            end: row.end.map(|end| end.fixed_offset()),
+            volume_id: Uuid::from_str(row.volume_id.as_str())
+                .context("Could not parse volume id String")?,
+            volume_name: row.volume_name,
+            status: row.status,
+            size: row.size,
+            user: row.user,
+            username: row.username,
+        })
+    }
+}
+
+/// Convert the given rows into volume states.
+fn volume_states_from_rows(
+    rows: Vec<MySqlRow>,
+) -> Result<Vec<VolumeState>, UnexpectedOnlyError> {
+    rows.into_iter()
+        .map(|r| VolumeStateRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to volume state row")?
+        .into_iter()
+        .map(VolumeState::try_from)
+        .collect()
+}
+
+/// Select a volume state by the given ID from the database, or return [None].
+#[tracing::instrument(
+    name = "select_maybe_volume_state_from_db",
+    skip(transaction)
+)]
+pub async fn select_maybe_volume_state_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    volume_state_id: u64,
+) -> Result<Option<VolumeState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            vs.volume_id as volume_id,
+            vs.volume_name as volume_name,
+            vs.status as status,
+            vs.size as size,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_volumestate as vs,
+            user_user as u
+        WHERE
+            vs.user_id = u.id AND
+            vs.state_ptr_id = s.id AND
+            s.id = ?
+        "#,
+        volume_state_id,
+    );
+    let row = transaction
+        .fetch_optional(query)
+        .await
+        .context("Failed to execute select query")?;
+    Ok(match row {
+        Some(row) => Some(
+            VolumeStateRow::from_row(&row)
+                .context("Failed to parse volume state row")?
+                .try_into()?,
+        ),
+        None => None,
+    })
+}
+
+/// Select a volume state with the given ID from the database, or a "not found" error.
+///
+/// This calls [select_maybe_volume_state_from_db] and then turns a [None] response into a
+/// [NotFoundOrUnexpectedApiError::NotFoundError].
+#[tracing::instrument(name = "select_volume_state_from_db", skip(transaction))]
+pub async fn select_volume_state_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    volume_state_id: u64,
+) -> Result<VolumeState, NotFoundOrUnexpectedApiError> {
+    select_maybe_volume_state_from_db(transaction, volume_state_id)
+        .await?
+        .ok_or(NotFoundOrUnexpectedApiError::NotFoundError)
+}
+
+/// Select a list of all volume states from the database.
+#[tracing::instrument(
+    name = "select_all_volume_states_from_db",
+    skip(transaction)
+)]
+pub async fn select_all_volume_states_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<Vec<VolumeState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            vs.volume_id as volume_id,
+            vs.volume_name as volume_name,
+            vs.status as status,
+            vs.size as size,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_volumestate as vs,
+            user_user as u
+        WHERE
+            vs.user_id = u.id AND
+            vs.state_ptr_id = s.id
+        "#,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    volume_states_from_rows(rows)
+}
+
+/// Select a list of volume states belonging to the project with the given ID from the database.
+#[tracing::instrument(
+    name = "select_volume_states_by_project_from_db",
+    skip(transaction)
+)]
+pub async fn select_volume_states_by_project_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    project_id: u64,
+) -> Result<Vec<VolumeState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            vs.volume_id as volume_id,
+            vs.volume_name as volume_name,
+            vs.status as status,
+            vs.size as size,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_volumestate as vs,
+            user_user as u
+        WHERE
+            vs.user_id = u.id AND
+            vs.state_ptr_id = s.id AND
+            u.project_id = ?
+        "#,
+        project_id,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    volume_states_from_rows(rows)
+}
+
+/// Select a list of volume states belonging to the user with the given ID from the database.
+#[tracing::instrument(
+    name = "select_volume_states_by_user_from_db",
+    skip(transaction)
+)]
+pub async fn select_volume_states_by_user_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    user_id: u64,
+) -> Result<Vec<VolumeState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            vs.volume_id as volume_id,
+            vs.volume_name as volume_name,
+            vs.status as status,
+            vs.size as size,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_volumestate as vs,
+            user_user as u
+        WHERE
+            vs.user_id = u.id AND
+            vs.state_ptr_id = s.id AND
+            u.id = ?
+        "#,
+        user_id,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    volume_states_from_rows(rows)
+}
+
+/// Select a list of volume states of the volume with the given UUID from the database.
+#[tracing::instrument(
+    name = "select_volume_states_by_volume_from_db",
+    skip(transaction)
+)]
+pub async fn select_volume_states_by_volume_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    volume_id: Uuid,
+) -> Result<Vec<VolumeState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            vs.volume_id as volume_id,
+            vs.volume_name as volume_name,
+            vs.status as status,
+            vs.size as size,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_volumestate as vs,
+            user_user as u
+        WHERE
+            vs.user_id = u.id AND
+            vs.state_ptr_id = s.id AND
+            vs.volume_id = ?
+        "#,
+        volume_id.to_string(),
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    volume_states_from_rows(rows)
+}
+
+/// Fields volume states can be ordered by via the `ordering` parameter.
+impl Orderable for VolumeState {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "s.id",
+            "begin" => "s.begin",
+            "end" => "s.end",
+            "volume_id" => "vs.volume_id",
+            "volume_name" => "vs.volume_name",
+            "status" => "vs.status",
+            "size" => "vs.size",
+            "user" => "u.id",
+            "username" => "u.name",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the volume states selected by [select_volume_state_list_from_db].
+#[derive(Debug, Default)]
+pub struct VolumeStateFilter {
+    /// ID of the project the volume states have to belong to.
+    pub project: Option<u64>,
+    /// ID of the user the volume states have to belong to.
+    pub user: Option<u64>,
+    /// UUID of the volume the states have to be of.
+    pub volume: Option<Uuid>,
+}
+
+/// Select a list of volume states matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the volume states together with the count of all volume states matching the filter.
+#[tracing::instrument(
+    name = "select_volume_state_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_volume_state_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &VolumeStateFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<VolumeState>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            vs.volume_id as volume_id,
+            vs.volume_name as volume_name,
+            vs.status as status,
+            vs.size as size,
+            u.id as user,
+            u.name as username
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM
+            accounting_state as s,
+            accounting_volumestate as vs,
+            user_user as u
+        WHERE
+            vs.user_id = u.id AND
+            vs.state_ptr_id = s.id
+        "#,
+            );
+            if let Some(project_id) = filter.project {
+                query.push(" AND u.project_id = ").push_bind(project_id);
+            }
+            if let Some(user_id) = filter.user {
+                query.push(" AND u.id = ").push_bind(user_id);
+            }
+            if let Some(volume_id) = filter.volume {
+                query
+                    .push(" AND vs.volume_id = ")
+                    .push_bind(volume_id.to_string());
+            }
+        },
+        list_query,
+    )
+    .await?;
+    Ok((volume_states_from_rows(rows)?, count))
+}
+
+/// Select a list of all volume states without end from the database.
+#[tracing::instrument(
+    name = "select_unfinished_volume_states_from_db",
+    skip(transaction)
+)]
+pub async fn select_unfinished_volume_states_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<Vec<VolumeState>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            s.id as id,
+            s.begin as begin,
+            s.end as end,
+            vs.volume_id as volume_id,
+            vs.volume_name as volume_name,
+            vs.status as status,
+            vs.size as size,
+            u.id as user,
+            u.name as username
+        FROM
+            accounting_state as s,
+            accounting_volumestate as vs,
+            user_user as u
+        WHERE
+            vs.user_id = u.id AND
+            vs.state_ptr_id = s.id AND
+            s.end IS NULL
+        "#,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    volume_states_from_rows(rows)
+}
+
+/// Simplified representation of data needed to create a new volume state.
+#[derive(Debug)]
+pub struct NewVolumeState {
+    /// Timestamp when the volume was first observed in this state.
+    pub begin: DateTime<Utc>,
+    /// Optional timestamp when the volume was first observed having left this state.
+    pub end: Option<DateTime<Utc>>,
+    /// UUID of the Cinder volume.
+    pub volume_id: Uuid,
+    /// Name of the Cinder volume.
+    pub volume_name: String,
+    /// Status during this state (available, in-use, ...)
+    pub status: String,
+    /// Size of the volume in GiB during this state.
+    pub size: u32,
+    /// ID of the user the volume belongs to.
+    pub user: u32,
+}
+
+/// Insert a new volume state based on the given [NewVolumeState] into the database.
+#[tracing::instrument(
+    name = "insert_volume_state_into_db",
+    skip(new_volume_state, transaction)
+)]
+pub async fn insert_volume_state_into_db(
+    transaction: &mut Transaction<'_, MySql>,
+    new_volume_state: &NewVolumeState,
+) -> Result<u64, MinimalApiError> {
+    // TODO: MariaDB 10.5 introduced INSERT ... RETURNING
+    let query1 = sqlx::query!(
+        r#"
+        INSERT IGNORE INTO accounting_state (begin, end)
+        VALUES (?, ?)
+        "#,
+        new_volume_state.begin,
+        new_volume_state.end,
+    );
+    let result1 = transaction
+        .execute(query1)
+        .await
+        .context("Failed to execute insert query")?;
+    if result1.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to insert new state, a conflicting entry exists"
+                .to_string(),
+        ));
+    }
+    let id = result1.last_insert_id();
+    let query2 = sqlx::query!(
+        r#"
+        INSERT IGNORE INTO accounting_volumestate (
+            state_ptr_id, volume_id, volume_name, status, size, user_id
+        )
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+        id,
+        new_volume_state.volume_id.to_string(),
+        new_volume_state.volume_name,
+        new_volume_state.status,
+        new_volume_state.size,
+        new_volume_state.user
+    );
+    let result2 = transaction
+        .execute(query2)
+        .await
+        .context("Failed to execute insert query")?;
+    if result2.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to insert new volume state, a conflicting entry exists"
+                .to_string(),
+        ));
+    }
+    Ok(id)
+}
+
+/// Set the end of the volume state with the given ID in the database.
+#[tracing::instrument(
+    name = "update_volume_state_end_in_db",
+    skip(transaction)
+)]
+pub async fn update_volume_state_end_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    volume_state_id: u64,
+    end: DateTime<Utc>,
+) -> Result<(), UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE accounting_state
+        SET
+            end = ?
+        WHERE id = ?
+        "#,
+        end,
+        volume_state_id,
+    );
+    transaction
+        .execute(query)
+        .await
+        .context("Failed to execute update query")?;
+    Ok(())
+}
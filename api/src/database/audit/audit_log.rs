@@ -0,0 +1,185 @@
+//! Queries for audit log entries.
+
+use std::str::FromStr;
+
+use anyhow::Context;
+use avina_wire::audit::{AuditLogEntry, AuditLogListParams, ObjectType};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{Executor, FromRow, MySql, Transaction};
+
+use crate::error::UnexpectedOnlyError;
+
+/// Representation of an audit log entry specifically for communication with the database.
+///
+/// This uses types, that can be directly deserialized from SQL and is then converted
+/// to [AuditLogEntry] afterwards.
+#[derive(FromRow)]
+pub struct AuditLogEntryRow {
+    /// ID of the audit log entry.
+    #[sqlx(try_from = "i32")]
+    pub id: u32,
+    /// Timestamp of the operation.
+    pub time: DateTime<Utc>,
+    /// ID of the user that actually performed the operation.
+    #[sqlx(try_from = "i32")]
+    pub user: u32,
+    /// ID of the user impersonated while performing the operation.
+    pub impersonated_user: Option<i32>,
+    /// HTTP method of the request.
+    pub method: String,
+    /// Route pattern of the request.
+    pub route: String,
+    /// Type of the object the operation targeted.
+    pub object_type: String,
+    /// ID of the object the operation targeted.
+    pub object_id: Option<i32>,
+    /// JSON snapshot of the object before the operation.
+    pub data_before: Option<String>,
+    /// JSON snapshot of the object after the operation.
+    pub data_after: Option<String>,
+}
+
+impl TryFrom<AuditLogEntryRow> for AuditLogEntry {
+    type Error = UnexpectedOnlyError;
+
+    fn try_from(row: AuditLogEntryRow) -> Result<Self, Self::Error> {
+        let parse = |data: Option<String>| {
+            data.map(|data| serde_json::from_str::<Value>(&data))
+                .transpose()
+                .context("Failed to parse audit log snapshot")
+        };
+        Ok(AuditLogEntry {
+            id: row.id,
+            time: row.time.fixed_offset(),
+            user: row.user,
+            impersonated_user: row.impersonated_user.map(|user| user as u32),
+            method: row.method,
+            route: row.route,
+            object_type: ObjectType::from_str(&row.object_type)
+                .context("Failed to parse object type")?,
+            object_id: row.object_id.map(|id| id as u32),
+            before: parse(row.data_before)?,
+            after: parse(row.data_after)?,
+        })
+    }
+}
+
+/// Data of a new audit log entry, to be inserted into the database.
+#[derive(Debug)]
+pub struct NewAuditLogEntry {
+    /// ID of the user that actually performed the operation.
+    pub user_id: u32,
+    /// ID of the user impersonated while performing the operation.
+    pub impersonated_user_id: Option<u32>,
+    /// HTTP method of the request.
+    pub method: String,
+    /// Route pattern of the request.
+    pub route: String,
+    /// Type of the object the operation targeted.
+    pub object_type: ObjectType,
+    /// ID of the object the operation targeted.
+    pub object_id: Option<u32>,
+    /// JSON snapshot of the object before the operation.
+    pub before: Option<Value>,
+    /// JSON snapshot of the object after the operation.
+    pub after: Option<Value>,
+}
+
+/// Insert a new entry into the audit log.
+#[tracing::instrument(
+    name = "insert_audit_log_entry_into_db",
+    skip(transaction)
+)]
+pub async fn insert_audit_log_entry_into_db(
+    transaction: &mut Transaction<'_, MySql>,
+    entry: &NewAuditLogEntry,
+) -> Result<u64, UnexpectedOnlyError> {
+    let object_type: &str = entry.object_type.into();
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO audit_log (
+            time, user_id, impersonated_user_id, method, route,
+            object_type, object_id, data_before, data_after
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        Utc::now(),
+        entry.user_id,
+        entry.impersonated_user_id,
+        entry.method,
+        entry.route,
+        object_type,
+        entry.object_id,
+        entry.before.as_ref().map(|before| before.to_string()),
+        entry.after.as_ref().map(|after| after.to_string()),
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute insert query")?;
+    Ok(result.last_insert_id())
+}
+
+/// Select the most recent audit log entries matching the given filters from the database.
+///
+/// The user filter matches both the acting and the impersonated user, the time range
+/// includes its begin but excludes its end.
+#[tracing::instrument(
+    name = "select_audit_log_entries_from_db",
+    skip(transaction)
+)]
+pub async fn select_audit_log_entries_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    params: &AuditLogListParams,
+    limit: u32,
+) -> Result<Vec<AuditLogEntry>, UnexpectedOnlyError> {
+    let object_type: Option<&str> = params.object_type.map(|t| t.into());
+    let begin = params.begin.map(|begin| begin.to_utc());
+    let end = params.end.map(|end| end.to_utc());
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            id,
+            time,
+            user_id as user,
+            impersonated_user_id as impersonated_user,
+            method,
+            route,
+            object_type,
+            object_id,
+            data_before,
+            data_after
+        FROM audit_log
+        WHERE
+            (? IS NULL OR object_type = ?) AND
+            (? IS NULL OR user_id = ? OR impersonated_user_id = ?) AND
+            (? IS NULL OR time >= ?) AND
+            (? IS NULL OR time < ?)
+        ORDER BY time DESC, id DESC
+        LIMIT ?
+        "#,
+        object_type,
+        object_type,
+        params.user,
+        params.user,
+        params.user,
+        begin,
+        begin,
+        end,
+        end,
+        limit
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?
+        .into_iter()
+        .map(|r| AuditLogEntryRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to audit log entry row")?
+        .into_iter()
+        .map(AuditLogEntry::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
@@ -0,0 +1,3 @@
+//! Queries for the audit module of the API, so the audit log.
+
+pub mod audit_log;
@@ -7,8 +7,11 @@ use avina_wire::budgeting::{
 use chrono::{Datelike, Utc};
 use sqlx::{Executor, FromRow, MySql, Transaction};
 
-use crate::error::{
-    MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
 };
 
 /// Select a project budget by the given ID from the database, or return [None].
@@ -239,6 +242,81 @@ pub async fn select_project_budgets_by_year_from_db(
     Ok(rows)
 }
 
+/// Fields project budgets can be ordered by via the `ordering` parameter.
+impl Orderable for ProjectBudget {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "b.id",
+            "project" => "p.id",
+            "project_name" => "p.name",
+            "year" => "b.year",
+            "amount" => "b.amount",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the project budgets selected by [select_project_budget_list_from_db].
+#[derive(Debug, Default)]
+pub struct ProjectBudgetFilter {
+    /// ID of the project the project budgets have to belong to.
+    pub project: Option<u64>,
+    /// ID of a user of the project the project budgets have to belong to.
+    pub user: Option<u64>,
+    /// Year the project budgets have to be for.
+    pub year: Option<u32>,
+}
+
+/// Select a list of project budgets matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the project budgets together with the count of all project budgets matching the
+/// filter.
+#[tracing::instrument(
+    name = "select_project_budget_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_project_budget_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &ProjectBudgetFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<ProjectBudget>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT b.id, p.id as project, p.name as project_name, b.year, b.amount
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM budgeting_projectbudget as b, user_project as p
+        WHERE b.project_id = p.id
+        "#,
+            );
+            if let Some(project_id) = filter.project {
+                query.push(" AND p.id = ").push_bind(project_id);
+            }
+            if let Some(user_id) = filter.user {
+                query
+                    .push(" AND p.id IN (SELECT project_id FROM user_user WHERE id = ")
+                    .push_bind(user_id)
+                    .push(")");
+            }
+            if let Some(year) = filter.year {
+                query.push(" AND b.year = ").push_bind(year);
+            }
+        },
+        list_query,
+    )
+    .await?;
+    let project_budgets = rows
+        .into_iter()
+        .map(|r| ProjectBudget::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to project budget")?;
+    Ok((project_budgets, count))
+}
+
 /// Simplified representation of data needed to create a new project budget.
 pub struct NewProjectBudget {
     /// Project ID the budget belongs to.
@@ -7,8 +7,11 @@ use avina_wire::budgeting::{
 use chrono::{Datelike, Utc};
 use sqlx::{Executor, FromRow, MySql, Transaction};
 
-use crate::error::{
-    MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
 };
 
 /// Select a user budget by the given ID from the database, or return [None].
@@ -268,6 +271,77 @@ pub async fn select_user_budgets_by_year_from_db(
     Ok(rows)
 }
 
+/// Fields user budgets can be ordered by via the `ordering` parameter.
+impl Orderable for UserBudget {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "b.id",
+            "user" => "u.id",
+            "username" => "u.name",
+            "year" => "b.year",
+            "amount" => "b.amount",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the user budgets selected by [select_user_budget_list_from_db].
+#[derive(Debug, Default)]
+pub struct UserBudgetFilter {
+    /// ID of the project the user budgets have to belong to.
+    pub project: Option<u64>,
+    /// ID of the user the user budgets have to belong to.
+    pub user: Option<u64>,
+    /// Year the user budgets have to be for.
+    pub year: Option<u32>,
+}
+
+/// Select a list of user budgets matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the user budgets together with the count of all user budgets matching the filter.
+#[tracing::instrument(
+    name = "select_user_budget_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_user_budget_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &UserBudgetFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<UserBudget>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT b.id, u.id as user, u.name as username, b.year, b.amount
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM budgeting_userbudget as b, user_user as u
+        WHERE b.user_id = u.id
+        "#,
+            );
+            if let Some(project_id) = filter.project {
+                query.push(" AND u.project_id = ").push_bind(project_id);
+            }
+            if let Some(user_id) = filter.user {
+                query.push(" AND u.id = ").push_bind(user_id);
+            }
+            if let Some(year) = filter.year {
+                query.push(" AND b.year = ").push_bind(year);
+            }
+        },
+        list_query,
+    )
+    .await?;
+    let user_budgets = rows
+        .into_iter()
+        .map(|r| UserBudget::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to user budget")?;
+    Ok((user_budgets, count))
+}
+
 /// Simplified representation of data needed to create a new project budget.
 pub struct NewUserBudget {
     /// User ID the budget belongs to.
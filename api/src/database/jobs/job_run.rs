@@ -0,0 +1,260 @@
+//! Queries for job runs.
+
+use std::str::FromStr;
+
+use anyhow::Context;
+use avina_wire::jobs::{Job, JobRun};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow, MySql, MySqlConnection, Transaction};
+
+use crate::error::{NotFoundOrUnexpectedApiError, UnexpectedOnlyError};
+
+/// Representation of a job run specifically for communication with the database.
+///
+/// This uses types, that can be directly deserialized from SQL and is then converted
+/// to [JobRun] afterwards.
+#[derive(FromRow)]
+pub struct JobRunRow {
+    /// ID of the job run.
+    #[sqlx(try_from = "i32")]
+    pub id: u32,
+    /// Name of the job.
+    pub job: String,
+    /// Whether the job was triggered manually instead of by the scheduler.
+    pub manual: bool,
+    /// ID of the user that triggered the job manually.
+    pub user: Option<i32>,
+    /// Timestamp the job run started at.
+    pub started_at: DateTime<Utc>,
+    /// Timestamp the job run finished at.
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Result of the job run as JSON.
+    pub result: Option<String>,
+    /// Error message of a failed job run.
+    pub error: Option<String>,
+}
+
+impl TryFrom<JobRunRow> for JobRun {
+    type Error = UnexpectedOnlyError;
+
+    fn try_from(row: JobRunRow) -> Result<Self, Self::Error> {
+        Ok(JobRun {
+            id: row.id,
+            job: Job::from_str(&row.job).context("Failed to parse job")?,
+            manual: row.manual,
+            user: row.user.map(|user| user as u32),
+            started_at: row.started_at.fixed_offset(),
+            finished_at: row.finished_at.map(|f| f.fixed_offset()),
+            result: row.result,
+            error: row.error,
+        })
+    }
+}
+
+/// Select a job run by the given ID from the database, or return [None].
+#[tracing::instrument(name = "select_maybe_job_run_from_db", skip(transaction))]
+pub async fn select_maybe_job_run_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    job_run_id: u64,
+) -> Result<Option<JobRun>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            id,
+            job,
+            manual,
+            user_id as user,
+            started_at,
+            finished_at,
+            result,
+            error
+        FROM jobs_jobrun
+        WHERE id = ?
+        "#,
+        job_run_id
+    );
+    let row = transaction
+        .fetch_optional(query)
+        .await
+        .context("Failed to execute select query")?;
+    Ok(match row {
+        Some(row) => Some(
+            JobRunRow::from_row(&row)
+                .context("Failed to parse job run row")?
+                .try_into()?,
+        ),
+        None => None,
+    })
+}
+
+/// Select a job run with the given ID from the database, or a "not found" error.
+///
+/// This calls [select_maybe_job_run_from_db] and then turns a [None] response into a
+/// [NotFoundOrUnexpectedApiError::NotFoundError].
+#[tracing::instrument(name = "select_job_run_from_db", skip(transaction))]
+pub async fn select_job_run_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    job_run_id: u64,
+) -> Result<JobRun, NotFoundOrUnexpectedApiError> {
+    select_maybe_job_run_from_db(transaction, job_run_id)
+        .await?
+        .ok_or(NotFoundOrUnexpectedApiError::NotFoundError)
+}
+
+/// Select a list of the most recent job runs from the database.
+#[tracing::instrument(name = "select_job_runs_from_db", skip(transaction))]
+pub async fn select_job_runs_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    limit: u32,
+) -> Result<Vec<JobRun>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            id,
+            job,
+            manual,
+            user_id as user,
+            started_at,
+            finished_at,
+            result,
+            error
+        FROM jobs_jobrun
+        ORDER BY started_at DESC, id DESC
+        LIMIT ?
+        "#,
+        limit
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?
+        .into_iter()
+        .map(|r| JobRunRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to job run row")?
+        .into_iter()
+        .map(JobRun::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// Select a list of the most recent runs of the given job from the database.
+#[tracing::instrument(
+    name = "select_job_runs_by_job_from_db",
+    skip(transaction)
+)]
+pub async fn select_job_runs_by_job_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    job: Job,
+    limit: u32,
+) -> Result<Vec<JobRun>, UnexpectedOnlyError> {
+    let job: &str = job.into();
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            id,
+            job,
+            manual,
+            user_id as user,
+            started_at,
+            finished_at,
+            result,
+            error
+        FROM jobs_jobrun
+        WHERE job = ?
+        ORDER BY started_at DESC, id DESC
+        LIMIT ?
+        "#,
+        job,
+        limit
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?
+        .into_iter()
+        .map(|r| JobRunRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to job run row")?
+        .into_iter()
+        .map(JobRun::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// Insert a new, unfinished run of the given job into the database.
+#[tracing::instrument(name = "insert_job_run_into_db", skip(transaction))]
+pub async fn insert_job_run_into_db(
+    transaction: &mut Transaction<'_, MySql>,
+    job: Job,
+    user_id: Option<u32>,
+    started_at: DateTime<Utc>,
+) -> Result<u64, UnexpectedOnlyError> {
+    let job: &str = job.into();
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO jobs_jobrun (job, manual, started_at, user_id)
+        VALUES (?, ?, ?, ?)
+        "#,
+        job,
+        user_id.is_some(),
+        started_at,
+        user_id,
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute insert query")?;
+    Ok(result.last_insert_id())
+}
+
+/// Mark the job run with the given ID as finished, with either a result or an error.
+#[tracing::instrument(name = "finish_job_run_in_db", skip(transaction))]
+pub async fn finish_job_run_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    job_run_id: u64,
+    finished_at: DateTime<Utc>,
+    result: Option<String>,
+    error: Option<String>,
+) -> Result<(), UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE jobs_jobrun
+        SET finished_at = ?, result = ?, error = ?
+        WHERE id = ?
+        "#,
+        finished_at,
+        result,
+        error,
+        job_run_id,
+    );
+    transaction
+        .execute(query)
+        .await
+        .context("Failed to execute update query")?;
+    Ok(())
+}
+
+/// Try to acquire the database lock of the given job, without waiting.
+///
+/// The lock is named after the job and the database, and held by the given
+/// connection until it is closed, so that a job runs at most once at a time
+/// across all instances of the API sharing the database. Returns whether the
+/// lock was acquired.
+#[tracing::instrument(name = "lock_job_in_db", skip(connection))]
+pub async fn lock_job_in_db(
+    connection: &mut MySqlConnection,
+    job: Job,
+) -> Result<bool, UnexpectedOnlyError> {
+    let job: &str = job.into();
+    let row = sqlx::query!(
+        r#"
+        SELECT GET_LOCK(CONCAT('avina_job_', ?, '_', LEFT(MD5(DATABASE()), 16)), 0) AS locked
+        "#,
+        job,
+    )
+    .fetch_one(connection)
+    .await
+    .context("Failed to execute lock query")?;
+    Ok(row.locked == Some(1))
+}
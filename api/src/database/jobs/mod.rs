@@ -0,0 +1,3 @@
+//! Queries for the jobs module of the API, so the job runs.
+
+pub mod job_run;
@@ -19,7 +19,9 @@
 
 // TODO: maybe this module could become a separate crate (avina-db) at some point.
 pub mod accounting;
+pub mod audit;
 pub mod budgeting;
+pub mod jobs;
 pub mod pricing;
 pub mod quota;
 pub mod resources;
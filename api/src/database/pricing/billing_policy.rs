@@ -0,0 +1,390 @@
+//! Queries for billing policies.
+
+use anyhow::Context;
+use avina_wire::{
+    pricing::{BillingPolicy, BillingPolicyCreateData},
+    user::UserClass,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow, MySql, Transaction, mysql::MySqlRow};
+
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
+};
+
+/// Representation of a billing policy specifically for communication with the database.
+///
+/// This uses types, that can be directly deserialized from SQL and is then converted
+/// to [BillingPolicy] afterwards.
+#[derive(FromRow)]
+pub struct BillingPolicyRow {
+    /// ID of the billing policy.
+    #[sqlx(try_from = "i32")]
+    pub id: u32,
+    /// Server status of the policy.
+    pub status: String,
+    /// User class of the policy, or [None] for all user classes.
+    pub user_class: Option<u32>,
+    /// Factor by which the runtime in the status is billed.
+    pub factor: f64,
+    /// Timestamp from when the policy is valid.
+    pub start_time: DateTime<Utc>,
+    /// Timestamp until when the policy is valid, or [None] if indefinitely.
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<BillingPolicyRow> for BillingPolicy {
+    type Error = UnexpectedOnlyError;
+
+    fn try_from(row: BillingPolicyRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            status: row.status,
+            user_class: row
+                .user_class
+                .map(UserClass::try_from)
+                .transpose()
+                .context("Failed to parse user class")?,
+            factor: row.factor,
+            start_time: row.start_time.fixed_offset(),
+            end_time: row.end_time.map(|end_time| end_time.fixed_offset()),
+        })
+    }
+}
+
+/// Convert the given rows into billing policies.
+fn billing_policies_from_rows(
+    rows: Vec<MySqlRow>,
+) -> Result<Vec<BillingPolicy>, UnexpectedOnlyError> {
+    rows.into_iter()
+        .map(|r| BillingPolicyRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to billing policy row")?
+        .into_iter()
+        .map(BillingPolicy::try_from)
+        .collect()
+}
+
+/// Select a billing policy by the given ID from the database, or return [None].
+#[tracing::instrument(
+    name = "select_maybe_billing_policy_from_db",
+    skip(transaction)
+)]
+pub async fn select_maybe_billing_policy_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    billing_policy_id: u64,
+) -> Result<Option<BillingPolicy>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, status, user_class, factor, start_time, end_time
+        FROM pricing_billingpolicy
+        WHERE id = ?
+        "#,
+        billing_policy_id
+    );
+    let row = transaction
+        .fetch_optional(query)
+        .await
+        .context("Failed to execute select query")?;
+    Ok(match row {
+        Some(row) => Some(
+            BillingPolicyRow::from_row(&row)
+                .context("Failed to parse billing policy row")?
+                .try_into()?,
+        ),
+        None => None,
+    })
+}
+
+/// Select a billing policy with the given ID from the database, or a "not found" error.
+///
+/// This calls [select_maybe_billing_policy_from_db] and then turns a [None] response into a
+/// [NotFoundOrUnexpectedApiError::NotFoundError].
+#[tracing::instrument(
+    name = "select_billing_policy_from_db",
+    skip(transaction)
+)]
+pub async fn select_billing_policy_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    billing_policy_id: u64,
+) -> Result<BillingPolicy, NotFoundOrUnexpectedApiError> {
+    select_maybe_billing_policy_from_db(transaction, billing_policy_id)
+        .await?
+        .ok_or(NotFoundOrUnexpectedApiError::NotFoundError)
+}
+
+/// Select a list of all billing policies from the database.
+#[tracing::instrument(
+    name = "select_all_billing_policies_from_db",
+    skip(transaction)
+)]
+pub async fn select_all_billing_policies_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<Vec<BillingPolicy>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, status, user_class, factor, start_time, end_time
+        FROM pricing_billingpolicy
+        "#,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    billing_policies_from_rows(rows)
+}
+
+/// Select a list of billing policies that are valid at some point during the
+/// period given by begin and end timestamp.
+#[tracing::instrument(
+    name = "select_billing_policies_for_period_from_db",
+    skip(transaction)
+)]
+pub async fn select_billing_policies_for_period_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<BillingPolicy>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, status, user_class, factor, start_time, end_time
+        FROM pricing_billingpolicy
+        WHERE
+            start_time <= ? AND
+            (end_time IS NULL OR end_time > ?)
+        "#,
+        end,
+        begin,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    billing_policies_from_rows(rows)
+}
+
+/// Fields billing policies can be ordered by via the `ordering` parameter.
+impl Orderable for BillingPolicy {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "id",
+            "status" => "status",
+            "user_class" => "user_class",
+            "factor" => "factor",
+            "start_time" => "start_time",
+            "end_time" => "end_time",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the billing policies selected by [select_billing_policy_list_from_db].
+#[derive(Debug, Default)]
+pub struct BillingPolicyFilter {
+    /// Server status the billing policies have to be for.
+    pub status: Option<String>,
+    /// User class the billing policies have to apply to, including those for all user classes.
+    pub user_class: Option<UserClass>,
+    /// Time the billing policies have to be valid at.
+    pub valid_at: Option<DateTime<Utc>>,
+}
+
+/// Select a list of billing policies matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the billing policies together with the count of all billing policies matching the
+/// filter.
+#[tracing::instrument(
+    name = "select_billing_policy_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_billing_policy_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &BillingPolicyFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<BillingPolicy>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT id, status, user_class, factor, start_time, end_time
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM pricing_billingpolicy
+        WHERE TRUE
+        "#,
+            );
+            if let Some(status) = &filter.status {
+                query.push(" AND status = ").push_bind(status.clone());
+            }
+            if let Some(user_class) = filter.user_class {
+                query
+                    .push(" AND (user_class IS NULL OR user_class = ")
+                    .push_bind(user_class as u32)
+                    .push(")");
+            }
+            if let Some(valid_at) = filter.valid_at {
+                query
+                    .push(" AND start_time <= ")
+                    .push_bind(valid_at)
+                    .push(" AND (end_time IS NULL OR end_time > ")
+                    .push_bind(valid_at)
+                    .push(")");
+            }
+        },
+        list_query,
+    )
+    .await?;
+    Ok((billing_policies_from_rows(rows)?, count))
+}
+
+/// Check the fields of a billing policy, returning a message for invalid ones.
+pub fn validate_billing_policy_fields(
+    status: &str,
+    factor: f64,
+    start_time: DateTime<Utc>,
+    end_time: Option<DateTime<Utc>>,
+) -> Result<(), String> {
+    if status.is_empty() || status.len() > 18 {
+        return Err("Status must have between 1 and 18 characters".to_string());
+    }
+    if !factor.is_finite() || factor < 0. {
+        return Err("Factor must be a non-negative number".to_string());
+    }
+    if let Some(end_time) = end_time
+        && end_time <= start_time
+    {
+        return Err("End time must be after start time".to_string());
+    }
+    Ok(())
+}
+
+/// Simplified representation of data needed to create a new billing policy.
+pub struct NewBillingPolicy {
+    /// Server status of the policy.
+    pub status: String,
+    /// User class of the policy, or [None] for all user classes.
+    pub user_class: Option<UserClass>,
+    /// Factor by which the runtime in the status is billed.
+    pub factor: f64,
+    /// Timestamp from when the policy is valid.
+    pub start_time: DateTime<Utc>,
+    /// Timestamp until when the policy is valid, or [None] if indefinitely.
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<BillingPolicyCreateData> for NewBillingPolicy {
+    type Error = String;
+
+    /// Transform a [BillingPolicyCreateData] into a [NewBillingPolicy].
+    ///
+    /// More specifically this also replaces not inputted data by defaults, e.g.,
+    /// now in UTC for the start time, and validates the fields with
+    /// [validate_billing_policy_fields].
+    fn try_from(data: BillingPolicyCreateData) -> Result<Self, Self::Error> {
+        let start_time =
+            data.start_time.map(|d| d.to_utc()).unwrap_or(Utc::now());
+        let end_time = data.end_time.map(|d| d.to_utc());
+        validate_billing_policy_fields(
+            &data.status,
+            data.factor,
+            start_time,
+            end_time,
+        )?;
+        Ok(Self {
+            status: data.status,
+            user_class: data.user_class,
+            factor: data.factor,
+            start_time,
+            end_time,
+        })
+    }
+}
+
+/// Insert a new billing policy based on the given [NewBillingPolicy] into the database.
+#[tracing::instrument(
+    name = "insert_billing_policy_into_db",
+    skip(new_billing_policy, transaction)
+)]
+pub async fn insert_billing_policy_into_db(
+    transaction: &mut Transaction<'_, MySql>,
+    new_billing_policy: &NewBillingPolicy,
+) -> Result<u64, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO pricing_billingpolicy (status, user_class, factor, start_time, end_time)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        new_billing_policy.status,
+        new_billing_policy.user_class.map(|c| c as u32),
+        new_billing_policy.factor,
+        new_billing_policy.start_time,
+        new_billing_policy.end_time,
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute insert query")?;
+    Ok(result.last_insert_id())
+}
+
+/// Delete the billing policy with the given ID from the database.
+#[tracing::instrument(
+    name = "delete_billing_policy_from_db",
+    skip(transaction)
+)]
+pub async fn delete_billing_policy_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    billing_policy_id: u64,
+) -> Result<(), MinimalApiError> {
+    let query = sqlx::query!(
+        r#"
+        DELETE IGNORE FROM pricing_billingpolicy
+        WHERE id = ?
+        "#,
+        billing_policy_id
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute delete query")?;
+    if result.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to delete billing policy.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Update the billing policy in the database to the given one.
+#[tracing::instrument(
+    name = "update_billing_policy_in_db",
+    skip(billing_policy, transaction)
+)]
+pub async fn update_billing_policy_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    billing_policy: &BillingPolicy,
+) -> Result<(), UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE pricing_billingpolicy
+        SET status = ?, user_class = ?, factor = ?, start_time = ?, end_time = ?
+        WHERE id = ?
+        "#,
+        billing_policy.status,
+        billing_policy.user_class.map(|c| c as u32),
+        billing_policy.factor,
+        billing_policy.start_time.to_utc(),
+        billing_policy.end_time.map(|d| d.to_utc()),
+        billing_policy.id,
+    );
+    transaction
+        .execute(query)
+        .await
+        .context("Failed to execute update query")?;
+    Ok(())
+}
@@ -15,6 +15,7 @@ use crate::{
     error::{
         MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
     },
+    pagination::{ListQuery, Orderable, select_list},
 };
 
 /// Representation of a flavor price specifically for communication with the database.
@@ -360,6 +361,119 @@ pub async fn select_flavor_prices_for_userclass_and_period_from_db(
     Ok(prices)
 }
 
+/// Fields flavor prices can be ordered by via the `ordering` parameter.
+impl Orderable for FlavorPrice {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "p.id",
+            "flavor" => "p.flavor_id",
+            "flavor_name" => "f.name",
+            "user_class" => "p.user_class",
+            "unit_price" => "p.unit_price",
+            "start_time" => "p.start_time",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the flavor prices selected by [select_flavor_price_list_from_db].
+#[derive(Debug, Default)]
+pub struct FlavorPriceFilter {
+    /// User class the flavor prices have to be for.
+    pub user_class: Option<UserClass>,
+    /// Time the flavor prices have to be valid at, i.e., being the last one of their flavor
+    /// and user class starting before it.
+    pub valid_at: Option<DateTime<Utc>>,
+}
+
+/// Select a list of flavor prices matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the flavor prices together with the count of all flavor prices matching the filter.
+#[tracing::instrument(
+    name = "select_flavor_price_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_flavor_price_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &FlavorPriceFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<FlavorPrice>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT
+            p.id,
+            p.flavor_id as flavor,
+            f.name as flavor_name,
+            p.user_class as user_class,
+            p.unit_price as unit_price,
+            p.start_time as start_time
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM
+            pricing_flavorprice as p,
+            resources_flavor as f
+        WHERE
+            p.flavor_id = f.id
+        "#,
+            );
+            if let Some(user_class) = filter.user_class {
+                query
+                    .push(" AND p.user_class = ")
+                    .push_bind(user_class as u32);
+            }
+            if let Some(valid_at) = filter.valid_at {
+                query
+                    .push(
+                        r#"
+        AND p.id = (
+            SELECT q.id
+            FROM pricing_flavorprice AS q
+            WHERE
+                q.flavor_id = p.flavor_id AND
+                q.user_class = p.user_class AND
+                q.start_time <= "#,
+                    )
+                    .push_bind(valid_at)
+                    .push(
+                        r#"
+            ORDER BY q.start_time DESC
+            LIMIT 1
+        )
+        "#,
+                    );
+            }
+        },
+        list_query,
+    )
+    .await?;
+    let flavor_prices = rows
+        .into_iter()
+        .map(|r| FlavorPriceRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to flavor price row")?
+        .into_iter()
+        .map(|row| {
+            Ok::<FlavorPrice, UnexpectedOnlyError>(FlavorPrice {
+                id: row.id,
+                flavor: row.flavor,
+                flavor_name: row.flavor_name,
+                user_class: row
+                    .user_class
+                    .try_into()
+                    .context("Failed to parse user class")?,
+                unit_price: row.unit_price,
+                start_time: row.start_time.fixed_offset(),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to flavor price")?;
+    Ok((flavor_prices, count))
+}
+
 /// Simplified representation of data needed to create a new flavor price.
 pub struct NewFlavorPrice {
     /// ID of the flavor.
@@ -0,0 +1,405 @@
+//! Queries for floating IP prices.
+
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::Context;
+use avina_wire::{
+    accounting::FloatingIpPool,
+    pricing::{
+        FloatingIpPrice, FloatingIpPriceCreateData, FloatingIpPriceModifyData,
+    },
+    user::UserClass,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow, MySql, Transaction, mysql::MySqlRow};
+
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
+};
+
+/// Representation of a floating IP price specifically for communication with the database.
+///
+/// This uses types, that can be directly deserialized from SQL and is then converted
+/// to [FloatingIpPrice] afterwards.
+#[derive(FromRow)]
+pub struct FloatingIpPriceRow {
+    /// ID of the floating IP price.
+    #[sqlx(try_from = "i32")]
+    pub id: u32,
+    /// User class of the price.
+    pub user_class: u32,
+    /// Pool of the floating IPs of the price.
+    pub pool: String,
+    /// Actual price per unit (full year allocation of a floating IP).
+    pub unit_price: f64,
+    /// Timestamp from when the price is valid.
+    ///
+    /// It is valid until the next price for the same user class and pool takes over.
+    pub start_time: DateTime<Utc>,
+}
+
+impl TryFrom<FloatingIpPriceRow> for FloatingIpPrice {
+    type Error = UnexpectedOnlyError;
+
+    fn try_from(row: FloatingIpPriceRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            user_class: row
+                .user_class
+                .try_into()
+                .context("Failed to parse user class")?,
+            pool: FloatingIpPool::from_str(&row.pool)
+                .context("Failed to parse floating IP pool")?,
+            unit_price: row.unit_price,
+            start_time: row.start_time.fixed_offset(),
+        })
+    }
+}
+
+/// Convert the given rows into floating IP prices.
+fn floating_ip_prices_from_rows(
+    rows: Vec<MySqlRow>,
+) -> Result<Vec<FloatingIpPrice>, UnexpectedOnlyError> {
+    rows.into_iter()
+        .map(|r| FloatingIpPriceRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to floating IP price row")?
+        .into_iter()
+        .map(FloatingIpPrice::try_from)
+        .collect()
+}
+
+/// Select a floating IP price by the given ID from the database, or return [None].
+#[tracing::instrument(
+    name = "select_maybe_floating_ip_price_from_db",
+    skip(transaction)
+)]
+pub async fn select_maybe_floating_ip_price_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    floating_ip_price_id: u64,
+) -> Result<Option<FloatingIpPrice>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, user_class, pool, unit_price, start_time
+        FROM pricing_floatingipprice
+        WHERE id = ?
+        "#,
+        floating_ip_price_id
+    );
+    let row = transaction
+        .fetch_optional(query)
+        .await
+        .context("Failed to execute select query")?;
+    Ok(match row {
+        Some(row) => Some(
+            FloatingIpPriceRow::from_row(&row)
+                .context("Failed to parse floating IP price row")?
+                .try_into()?,
+        ),
+        None => None,
+    })
+}
+
+/// Select a floating IP price with the given ID from the database, or a "not found" error.
+///
+/// This calls [select_maybe_floating_ip_price_from_db] and then turns a [None] response into a
+/// [NotFoundOrUnexpectedApiError::NotFoundError].
+#[tracing::instrument(
+    name = "select_floating_ip_price_from_db",
+    skip(transaction)
+)]
+pub async fn select_floating_ip_price_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    floating_ip_price_id: u64,
+) -> Result<FloatingIpPrice, NotFoundOrUnexpectedApiError> {
+    select_maybe_floating_ip_price_from_db(transaction, floating_ip_price_id)
+        .await?
+        .ok_or(NotFoundOrUnexpectedApiError::NotFoundError)
+}
+
+/// Select a list of all floating IP prices from the database.
+#[tracing::instrument(
+    name = "select_all_floating_ip_prices_from_db",
+    skip(transaction)
+)]
+pub async fn select_all_floating_ip_prices_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<Vec<FloatingIpPrice>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, user_class, pool, unit_price, start_time
+        FROM pricing_floatingipprice
+        "#,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    floating_ip_prices_from_rows(rows)
+}
+
+/// Select a list of floating IP prices, that are valid during the period given by begin and
+/// end timestamp, ordered by their start time from newest to oldest.
+///
+/// For each user class and pool, this includes the prices starting within the period and the
+/// last one starting before it.
+#[tracing::instrument(
+    name = "select_floating_ip_prices_for_period_from_db",
+    skip(transaction)
+)]
+pub async fn select_floating_ip_prices_for_period_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<FloatingIpPrice>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, user_class, pool, unit_price, start_time
+        FROM pricing_floatingipprice
+        WHERE start_time <= ?
+        ORDER BY start_time DESC
+        "#,
+        end,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    let mut prices = Vec::new();
+    let mut done = HashSet::new();
+    for price in floating_ip_prices_from_rows(rows)? {
+        if done.contains(&(price.user_class, price.pool)) {
+            continue;
+        }
+        if price.start_time <= begin {
+            done.insert((price.user_class, price.pool));
+        }
+        prices.push(price);
+    }
+    Ok(prices)
+}
+
+/// Fields floating IP prices can be ordered by via the `ordering` parameter.
+impl Orderable for FloatingIpPrice {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "p.id",
+            "user_class" => "p.user_class",
+            "pool" => "p.pool",
+            "unit_price" => "p.unit_price",
+            "start_time" => "p.start_time",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the floating IP prices selected by [select_floating_ip_price_list_from_db].
+#[derive(Debug, Default)]
+pub struct FloatingIpPriceFilter {
+    /// User class the floating IP prices have to be for.
+    pub user_class: Option<UserClass>,
+    /// Pool the floating IP prices have to be for.
+    pub pool: Option<FloatingIpPool>,
+    /// Time the floating IP prices have to be valid at, i.e., being the last one of their
+    /// user class and pool starting before it.
+    pub valid_at: Option<DateTime<Utc>>,
+}
+
+/// Select a list of floating IP prices matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the floating IP prices together with the count of all floating IP prices matching the filter.
+#[tracing::instrument(
+    name = "select_floating_ip_price_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_floating_ip_price_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &FloatingIpPriceFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<FloatingIpPrice>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT p.id, p.user_class, p.pool, p.unit_price, p.start_time
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM pricing_floatingipprice AS p
+        WHERE TRUE
+        "#,
+            );
+            if let Some(user_class) = filter.user_class {
+                query
+                    .push(" AND p.user_class = ")
+                    .push_bind(user_class as u32);
+            }
+            if let Some(pool) = filter.pool {
+                let pool: &str = pool.into();
+                query.push(" AND p.pool = ").push_bind(pool);
+            }
+            if let Some(valid_at) = filter.valid_at {
+                query
+                    .push(
+                        r#"
+        AND p.id = (
+            SELECT q.id
+            FROM pricing_floatingipprice AS q
+            WHERE q.user_class = p.user_class AND q.pool = p.pool AND q.start_time <= "#,
+                    )
+                    .push_bind(valid_at)
+                    .push(
+                        r#"
+            ORDER BY q.start_time DESC
+            LIMIT 1
+        )
+        "#,
+                    );
+            }
+        },
+        list_query,
+    )
+    .await?;
+    Ok((floating_ip_prices_from_rows(rows)?, count))
+}
+
+/// Simplified representation of data needed to create a new floating IP price.
+pub struct NewFloatingIpPrice {
+    /// User class for the price.
+    pub user_class: UserClass,
+    /// Pool of the floating IPs for the price.
+    pub pool: FloatingIpPool,
+    /// Actual price per unit (full year allocation of a floating IP).
+    pub unit_price: f64,
+    /// Timestamp from when the price is valid.
+    ///
+    /// It is valid until the next price for the same user class and pool takes over.
+    pub start_time: DateTime<Utc>,
+}
+
+impl TryFrom<FloatingIpPriceCreateData> for NewFloatingIpPrice {
+    type Error = String;
+
+    /// Transform a [FloatingIpPriceCreateData] into a [NewFloatingIpPrice].
+    ///
+    /// More specifically this also replaces not inputted data by defaults, e.g.,
+    /// 0. for the unit price, and now in UTC for the start time.
+    fn try_from(data: FloatingIpPriceCreateData) -> Result<Self, Self::Error> {
+        let unit_price = data.price.unwrap_or(0.);
+        if !unit_price.is_finite() || unit_price < 0. {
+            return Err("Price must be a non-negative number".to_string());
+        }
+        Ok(Self {
+            user_class: data.user_class,
+            pool: data.pool,
+            unit_price,
+            start_time: data
+                .start_time
+                .map(|d| d.to_utc())
+                .unwrap_or(Utc::now()),
+        })
+    }
+}
+
+/// Insert a new floating IP price based on the given [NewFloatingIpPrice] into the database.
+#[tracing::instrument(
+    name = "insert_floating_ip_price_into_db",
+    skip(new_floating_ip_price, transaction)
+)]
+pub async fn insert_floating_ip_price_into_db(
+    transaction: &mut Transaction<'_, MySql>,
+    new_floating_ip_price: &NewFloatingIpPrice,
+) -> Result<u64, MinimalApiError> {
+    let pool: &str = new_floating_ip_price.pool.into();
+    let query = sqlx::query!(
+        r#"
+        INSERT IGNORE INTO pricing_floatingipprice (user_class, pool, unit_price, start_time)
+        VALUES (?, ?, ?, ?)
+        "#,
+        new_floating_ip_price.user_class as u32,
+        pool,
+        new_floating_ip_price.unit_price,
+        new_floating_ip_price.start_time,
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute insert query")?;
+    if result.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to insert new floating IP price, a conflicting entry exists"
+                .to_string(),
+        ));
+    }
+    Ok(result.last_insert_id())
+}
+
+/// Delete the floating IP price with the given ID from the database.
+#[tracing::instrument(
+    name = "delete_floating_ip_price_from_db",
+    skip(transaction)
+)]
+pub async fn delete_floating_ip_price_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    floating_ip_price_id: u64,
+) -> Result<(), MinimalApiError> {
+    let query = sqlx::query!(
+        r#"
+        DELETE IGNORE FROM pricing_floatingipprice
+        WHERE id = ?
+        "#,
+        floating_ip_price_id
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute delete query")?;
+    if result.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to delete floating IP price.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Update the floating IP price with the given [FloatingIpPriceModifyData] in the database.
+#[tracing::instrument(
+    name = "update_floating_ip_price_in_db",
+    skip(data, transaction)
+)]
+pub async fn update_floating_ip_price_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    data: &FloatingIpPriceModifyData,
+) -> Result<FloatingIpPrice, NotFoundOrUnexpectedApiError> {
+    let row =
+        select_floating_ip_price_from_db(transaction, data.id as u64).await?;
+    let price = FloatingIpPrice {
+        id: data.id,
+        user_class: data.user_class.unwrap_or(row.user_class),
+        pool: data.pool.unwrap_or(row.pool),
+        unit_price: data.unit_price.unwrap_or(row.unit_price),
+        start_time: data.start_time.unwrap_or(row.start_time),
+    };
+    let pool: &str = price.pool.into();
+    let query = sqlx::query!(
+        r#"
+        UPDATE pricing_floatingipprice
+        SET user_class = ?, pool = ?, unit_price = ?, start_time = ?
+        WHERE id = ?
+        "#,
+        price.user_class as u32,
+        pool,
+        price.unit_price,
+        price.start_time.to_utc(),
+        price.id,
+    );
+    transaction
+        .execute(query)
+        .await
+        .context("Failed to execute update query")?;
+    Ok(price)
+}
@@ -1,3 +1,9 @@
-//! Queries for the pricing module of the API, so the flavor prices.
+//! Queries for the pricing module of the API, so the flavor, volume, floating
+//! IP and resource prices, billing policies and price overrides.
 
+pub mod billing_policy;
 pub mod flavor_price;
+pub mod floating_ip_price;
+pub mod price_override;
+pub mod resource_price;
+pub mod volume_price;
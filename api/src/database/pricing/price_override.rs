@@ -0,0 +1,472 @@
+//! Queries for price overrides.
+
+use anyhow::Context;
+use avina_wire::pricing::{PriceOverride, PriceOverrideCreateData};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow, MySql, Transaction, mysql::MySqlRow};
+
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
+};
+
+/// Representation of a price override specifically for communication with the database.
+///
+/// This uses types, that can be directly deserialized from SQL and is then converted
+/// to [PriceOverride] afterwards.
+#[derive(FromRow)]
+pub struct PriceOverrideRow {
+    /// ID of the price override.
+    #[sqlx(try_from = "i32")]
+    pub id: u32,
+    /// ID of the project of the override.
+    #[sqlx(try_from = "i32")]
+    pub project: u32,
+    /// Name of the project of the override.
+    pub project_name: String,
+    /// ID of the flavor of the override, or [None] for all flavors.
+    pub flavor: Option<i32>,
+    /// Name of the flavor of the override, or [None] for all flavors.
+    pub flavor_name: Option<String>,
+    /// Discount in percent on the price of the user class.
+    pub discount: Option<f64>,
+    /// Price per unit (full year usage of the flavor) replacing that of the
+    /// user class.
+    pub unit_price: Option<f64>,
+    /// Timestamp from when the override is valid.
+    pub start_time: DateTime<Utc>,
+    /// Timestamp until when the override is valid, or [None] if indefinitely.
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<PriceOverrideRow> for PriceOverride {
+    type Error = UnexpectedOnlyError;
+
+    fn try_from(row: PriceOverrideRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            project: row.project,
+            project_name: row.project_name,
+            flavor: row
+                .flavor
+                .map(u32::try_from)
+                .transpose()
+                .context("Failed to parse flavor ID")?,
+            flavor_name: row.flavor_name,
+            discount: row.discount,
+            unit_price: row.unit_price,
+            start_time: row.start_time.fixed_offset(),
+            end_time: row.end_time.map(|end_time| end_time.fixed_offset()),
+        })
+    }
+}
+
+/// Convert the given rows into price overrides.
+fn price_overrides_from_rows(
+    rows: Vec<MySqlRow>,
+) -> Result<Vec<PriceOverride>, UnexpectedOnlyError> {
+    rows.into_iter()
+        .map(|r| PriceOverrideRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to price override row")?
+        .into_iter()
+        .map(PriceOverride::try_from)
+        .collect()
+}
+
+/// Select a price override by the given ID from the database, or return [None].
+#[tracing::instrument(
+    name = "select_maybe_price_override_from_db",
+    skip(transaction)
+)]
+pub async fn select_maybe_price_override_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    price_override_id: u64,
+) -> Result<Option<PriceOverride>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            o.id,
+            o.project_id as project,
+            p.name as project_name,
+            o.flavor_id as flavor,
+            f.name as flavor_name,
+            o.discount,
+            o.unit_price,
+            o.start_time,
+            o.end_time
+        FROM
+            pricing_priceoverride as o
+            JOIN user_project as p ON o.project_id = p.id
+            LEFT JOIN resources_flavor as f ON o.flavor_id = f.id
+        WHERE o.id = ?
+        "#,
+        price_override_id
+    );
+    let row = transaction
+        .fetch_optional(query)
+        .await
+        .context("Failed to execute select query")?;
+    Ok(match row {
+        Some(row) => Some(
+            PriceOverrideRow::from_row(&row)
+                .context("Failed to parse price override row")?
+                .try_into()?,
+        ),
+        None => None,
+    })
+}
+
+/// Select a price override with the given ID from the database, or a "not found" error.
+///
+/// This calls [select_maybe_price_override_from_db] and then turns a [None] response into a
+/// [NotFoundOrUnexpectedApiError::NotFoundError].
+#[tracing::instrument(
+    name = "select_price_override_from_db",
+    skip(transaction)
+)]
+pub async fn select_price_override_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    price_override_id: u64,
+) -> Result<PriceOverride, NotFoundOrUnexpectedApiError> {
+    select_maybe_price_override_from_db(transaction, price_override_id)
+        .await?
+        .ok_or(NotFoundOrUnexpectedApiError::NotFoundError)
+}
+
+/// Select a list of all price overrides from the database.
+#[tracing::instrument(
+    name = "select_all_price_overrides_from_db",
+    skip(transaction)
+)]
+pub async fn select_all_price_overrides_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<Vec<PriceOverride>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            o.id,
+            o.project_id as project,
+            p.name as project_name,
+            o.flavor_id as flavor,
+            f.name as flavor_name,
+            o.discount,
+            o.unit_price,
+            o.start_time,
+            o.end_time
+        FROM
+            pricing_priceoverride as o
+            JOIN user_project as p ON o.project_id = p.id
+            LEFT JOIN resources_flavor as f ON o.flavor_id = f.id
+        "#,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    price_overrides_from_rows(rows)
+}
+
+/// Select a list of price overrides that are valid at some point during the
+/// period given by begin and end timestamp.
+#[tracing::instrument(
+    name = "select_price_overrides_for_period_from_db",
+    skip(transaction)
+)]
+pub async fn select_price_overrides_for_period_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<PriceOverride>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT
+            o.id,
+            o.project_id as project,
+            p.name as project_name,
+            o.flavor_id as flavor,
+            f.name as flavor_name,
+            o.discount,
+            o.unit_price,
+            o.start_time,
+            o.end_time
+        FROM
+            pricing_priceoverride as o
+            JOIN user_project as p ON o.project_id = p.id
+            LEFT JOIN resources_flavor as f ON o.flavor_id = f.id
+        WHERE
+            o.start_time <= ? AND
+            (o.end_time IS NULL OR o.end_time > ?)
+        "#,
+        end,
+        begin,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    price_overrides_from_rows(rows)
+}
+
+/// Fields price overrides can be ordered by via the `ordering` parameter.
+impl Orderable for PriceOverride {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "o.id",
+            "project" => "o.project_id",
+            "project_name" => "p.name",
+            "flavor" => "o.flavor_id",
+            "flavor_name" => "f.name",
+            "start_time" => "o.start_time",
+            "end_time" => "o.end_time",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the price overrides selected by [select_price_override_list_from_db].
+#[derive(Debug, Default)]
+pub struct PriceOverrideFilter {
+    /// ID of the project the price overrides have to be for.
+    pub project: Option<u64>,
+    /// ID of the flavor the price overrides have to apply to, including those for all flavors.
+    pub flavor: Option<u64>,
+    /// Time the price overrides have to be valid at.
+    pub valid_at: Option<DateTime<Utc>>,
+}
+
+/// Select a list of price overrides matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the price overrides together with the count of all price overrides matching the
+/// filter.
+#[tracing::instrument(
+    name = "select_price_override_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_price_override_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &PriceOverrideFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<PriceOverride>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT
+            o.id,
+            o.project_id as project,
+            p.name as project_name,
+            o.flavor_id as flavor,
+            f.name as flavor_name,
+            o.discount,
+            o.unit_price,
+            o.start_time,
+            o.end_time
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM
+            pricing_priceoverride as o
+            JOIN user_project as p ON o.project_id = p.id
+            LEFT JOIN resources_flavor as f ON o.flavor_id = f.id
+        WHERE TRUE
+        "#,
+            );
+            if let Some(project_id) = filter.project {
+                query.push(" AND o.project_id = ").push_bind(project_id);
+            }
+            if let Some(flavor_id) = filter.flavor {
+                query
+                    .push(" AND (o.flavor_id IS NULL OR o.flavor_id = ")
+                    .push_bind(flavor_id)
+                    .push(")");
+            }
+            if let Some(valid_at) = filter.valid_at {
+                query
+                    .push(" AND o.start_time <= ")
+                    .push_bind(valid_at)
+                    .push(" AND (o.end_time IS NULL OR o.end_time > ")
+                    .push_bind(valid_at)
+                    .push(")");
+            }
+        },
+        list_query,
+    )
+    .await?;
+    Ok((price_overrides_from_rows(rows)?, count))
+}
+
+/// Check the fields of a price override, returning a message for invalid ones.
+pub fn validate_price_override_fields(
+    flavor: Option<u32>,
+    discount: Option<f64>,
+    unit_price: Option<f64>,
+    start_time: DateTime<Utc>,
+    end_time: Option<DateTime<Utc>>,
+) -> Result<(), String> {
+    match (discount, unit_price) {
+        (Some(discount), None) => {
+            if !discount.is_finite() || !(0. ..=100.).contains(&discount) {
+                return Err(
+                    "Discount must be a number between 0 and 100".to_string()
+                );
+            }
+        }
+        (None, Some(unit_price)) => {
+            if flavor.is_none() {
+                return Err("Unit price requires a flavor".to_string());
+            }
+            if !unit_price.is_finite() || unit_price < 0. {
+                return Err(
+                    "Unit price must be a non-negative number".to_string()
+                );
+            }
+        }
+        _ => {
+            return Err("Exactly one of discount and unit price must be set"
+                .to_string());
+        }
+    }
+    if let Some(end_time) = end_time
+        && end_time <= start_time
+    {
+        return Err("End time must be after start time".to_string());
+    }
+    Ok(())
+}
+
+/// Simplified representation of data needed to create a new price override.
+pub struct NewPriceOverride {
+    /// ID of the project of the override.
+    pub project_id: u64,
+    /// ID of the flavor of the override, or [None] for all flavors.
+    pub flavor_id: Option<u64>,
+    /// Discount in percent on the price of the user class.
+    pub discount: Option<f64>,
+    /// Price per unit (full year usage of the flavor) replacing that of the
+    /// user class.
+    pub unit_price: Option<f64>,
+    /// Timestamp from when the override is valid.
+    pub start_time: DateTime<Utc>,
+    /// Timestamp until when the override is valid, or [None] if indefinitely.
+    pub end_time: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<PriceOverrideCreateData> for NewPriceOverride {
+    type Error = String;
+
+    /// Transform a [PriceOverrideCreateData] into a [NewPriceOverride].
+    ///
+    /// More specifically this also replaces not inputted data by defaults, e.g.,
+    /// now in UTC for the start time, and validates the fields with
+    /// [validate_price_override_fields].
+    fn try_from(data: PriceOverrideCreateData) -> Result<Self, Self::Error> {
+        let start_time =
+            data.start_time.map(|d| d.to_utc()).unwrap_or(Utc::now());
+        let end_time = data.end_time.map(|d| d.to_utc());
+        validate_price_override_fields(
+            data.flavor,
+            data.discount,
+            data.unit_price,
+            start_time,
+            end_time,
+        )?;
+        Ok(Self {
+            project_id: data.project as u64,
+            flavor_id: data.flavor.map(|flavor| flavor as u64),
+            discount: data.discount,
+            unit_price: data.unit_price,
+            start_time,
+            end_time,
+        })
+    }
+}
+
+/// Insert a new price override based on the given [NewPriceOverride] into the database.
+#[tracing::instrument(
+    name = "insert_price_override_into_db",
+    skip(new_price_override, transaction)
+)]
+pub async fn insert_price_override_into_db(
+    transaction: &mut Transaction<'_, MySql>,
+    new_price_override: &NewPriceOverride,
+) -> Result<u64, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        INSERT INTO pricing_priceoverride (project_id, flavor_id, discount, unit_price, start_time, end_time)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+        new_price_override.project_id,
+        new_price_override.flavor_id,
+        new_price_override.discount,
+        new_price_override.unit_price,
+        new_price_override.start_time,
+        new_price_override.end_time,
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute insert query")?;
+    Ok(result.last_insert_id())
+}
+
+/// Delete the price override with the given ID from the database.
+#[tracing::instrument(
+    name = "delete_price_override_from_db",
+    skip(transaction)
+)]
+pub async fn delete_price_override_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    price_override_id: u64,
+) -> Result<(), MinimalApiError> {
+    let query = sqlx::query!(
+        r#"
+        DELETE IGNORE FROM pricing_priceoverride
+        WHERE id = ?
+        "#,
+        price_override_id
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute delete query")?;
+    if result.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to delete price override.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Update the price override in the database to the given one.
+///
+/// Only the discount, unit price, start and end time can be changed.
+#[tracing::instrument(
+    name = "update_price_override_in_db",
+    skip(price_override, transaction)
+)]
+pub async fn update_price_override_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    price_override: &PriceOverride,
+) -> Result<(), UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        UPDATE pricing_priceoverride
+        SET discount = ?, unit_price = ?, start_time = ?, end_time = ?
+        WHERE id = ?
+        "#,
+        price_override.discount,
+        price_override.unit_price,
+        price_override.start_time.to_utc(),
+        price_override.end_time.map(|d| d.to_utc()),
+        price_override.id,
+    );
+    transaction
+        .execute(query)
+        .await
+        .context("Failed to execute update query")?;
+    Ok(())
+}
@@ -0,0 +1,410 @@
+//! Queries for resource prices.
+
+use std::collections::HashSet;
+
+use anyhow::Context;
+use avina_wire::{
+    pricing::{
+        ResourcePrice, ResourcePriceCreateData, ResourcePriceModifyData,
+    },
+    user::UserClass,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow, MySql, Transaction, mysql::MySqlRow};
+
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
+};
+
+/// Representation of a resource price specifically for communication with the database.
+///
+/// This uses types, that can be directly deserialized from SQL and is then converted
+/// to [ResourcePrice] afterwards.
+#[derive(FromRow)]
+pub struct ResourcePriceRow {
+    /// ID of the resource price.
+    #[sqlx(try_from = "i32")]
+    pub id: u32,
+    /// User class of the price.
+    pub user_class: u32,
+    /// Price per vCPU (full year usage of a vCPU).
+    pub vcpu_price: f64,
+    /// Price per GiB of RAM (full year usage of a GiB).
+    pub ram_price: f64,
+    /// Price per GPU (full year usage of a GPU).
+    pub gpu_price: f64,
+    /// Timestamp from when the price is valid.
+    ///
+    /// It is valid until the next price for the same user class takes over.
+    pub start_time: DateTime<Utc>,
+}
+
+impl TryFrom<ResourcePriceRow> for ResourcePrice {
+    type Error = UnexpectedOnlyError;
+
+    fn try_from(row: ResourcePriceRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            user_class: row
+                .user_class
+                .try_into()
+                .context("Failed to parse user class")?,
+            vcpu_price: row.vcpu_price,
+            ram_price: row.ram_price,
+            gpu_price: row.gpu_price,
+            start_time: row.start_time.fixed_offset(),
+        })
+    }
+}
+
+/// Convert the given rows into resource prices.
+fn resource_prices_from_rows(
+    rows: Vec<MySqlRow>,
+) -> Result<Vec<ResourcePrice>, UnexpectedOnlyError> {
+    rows.into_iter()
+        .map(|r| ResourcePriceRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to resource price row")?
+        .into_iter()
+        .map(ResourcePrice::try_from)
+        .collect()
+}
+
+/// Select a resource price by the given ID from the database, or return [None].
+#[tracing::instrument(
+    name = "select_maybe_resource_price_from_db",
+    skip(transaction)
+)]
+pub async fn select_maybe_resource_price_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    resource_price_id: u64,
+) -> Result<Option<ResourcePrice>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, user_class, vcpu_price, ram_price, gpu_price, start_time
+        FROM pricing_resourceprice
+        WHERE id = ?
+        "#,
+        resource_price_id
+    );
+    let row = transaction
+        .fetch_optional(query)
+        .await
+        .context("Failed to execute select query")?;
+    Ok(match row {
+        Some(row) => Some(
+            ResourcePriceRow::from_row(&row)
+                .context("Failed to parse resource price row")?
+                .try_into()?,
+        ),
+        None => None,
+    })
+}
+
+/// Select a resource price with the given ID from the database, or a "not found" error.
+///
+/// This calls [select_maybe_resource_price_from_db] and then turns a [None] response into a
+/// [NotFoundOrUnexpectedApiError::NotFoundError].
+#[tracing::instrument(
+    name = "select_resource_price_from_db",
+    skip(transaction)
+)]
+pub async fn select_resource_price_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    resource_price_id: u64,
+) -> Result<ResourcePrice, NotFoundOrUnexpectedApiError> {
+    select_maybe_resource_price_from_db(transaction, resource_price_id)
+        .await?
+        .ok_or(NotFoundOrUnexpectedApiError::NotFoundError)
+}
+
+/// Select a list of all resource prices from the database.
+#[tracing::instrument(
+    name = "select_all_resource_prices_from_db",
+    skip(transaction)
+)]
+pub async fn select_all_resource_prices_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<Vec<ResourcePrice>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, user_class, vcpu_price, ram_price, gpu_price, start_time
+        FROM pricing_resourceprice
+        "#,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    resource_prices_from_rows(rows)
+}
+
+/// Select a list of resource prices, that are valid during the period given by begin and end
+/// timestamp, ordered by their start time from newest to oldest.
+///
+/// For each user class, this includes the prices starting within the period and the last one
+/// starting before it.
+#[tracing::instrument(
+    name = "select_resource_prices_for_period_from_db",
+    skip(transaction)
+)]
+pub async fn select_resource_prices_for_period_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<ResourcePrice>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, user_class, vcpu_price, ram_price, gpu_price, start_time
+        FROM pricing_resourceprice
+        WHERE start_time <= ?
+        ORDER BY start_time DESC
+        "#,
+        end,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    let mut prices = Vec::new();
+    let mut done = HashSet::new();
+    for price in resource_prices_from_rows(rows)? {
+        if done.contains(&price.user_class) {
+            continue;
+        }
+        if price.start_time <= begin {
+            done.insert(price.user_class);
+        }
+        prices.push(price);
+    }
+    Ok(prices)
+}
+
+/// Fields resource prices can be ordered by via the `ordering` parameter.
+impl Orderable for ResourcePrice {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "p.id",
+            "user_class" => "p.user_class",
+            "vcpu_price" => "p.vcpu_price",
+            "ram_price" => "p.ram_price",
+            "gpu_price" => "p.gpu_price",
+            "start_time" => "p.start_time",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the resource prices selected by [select_resource_price_list_from_db].
+#[derive(Debug, Default)]
+pub struct ResourcePriceFilter {
+    /// User class the resource prices have to be for.
+    pub user_class: Option<UserClass>,
+    /// Time the resource prices have to be valid at, i.e., being the last one of their
+    /// user class starting before it.
+    pub valid_at: Option<DateTime<Utc>>,
+}
+
+/// Select a list of resource prices matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the resource prices together with the count of all resource prices matching the filter.
+#[tracing::instrument(
+    name = "select_resource_price_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_resource_price_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &ResourcePriceFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<ResourcePrice>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT p.id, p.user_class, p.vcpu_price, p.ram_price, p.gpu_price, p.start_time
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM pricing_resourceprice AS p
+        WHERE TRUE
+        "#,
+            );
+            if let Some(user_class) = filter.user_class {
+                query
+                    .push(" AND p.user_class = ")
+                    .push_bind(user_class as u32);
+            }
+            if let Some(valid_at) = filter.valid_at {
+                query
+                    .push(
+                        r#"
+        AND p.id = (
+            SELECT q.id
+            FROM pricing_resourceprice AS q
+            WHERE q.user_class = p.user_class AND q.start_time <= "#,
+                    )
+                    .push_bind(valid_at)
+                    .push(
+                        r#"
+            ORDER BY q.start_time DESC
+            LIMIT 1
+        )
+        "#,
+                    );
+            }
+        },
+        list_query,
+    )
+    .await?;
+    Ok((resource_prices_from_rows(rows)?, count))
+}
+
+/// Simplified representation of data needed to create a new resource price.
+pub struct NewResourcePrice {
+    /// User class for the price.
+    pub user_class: UserClass,
+    /// Price per vCPU (full year usage of a vCPU).
+    pub vcpu_price: f64,
+    /// Price per GiB of RAM (full year usage of a GiB).
+    pub ram_price: f64,
+    /// Price per GPU (full year usage of a GPU).
+    pub gpu_price: f64,
+    /// Timestamp from when the price is valid.
+    ///
+    /// It is valid until the next price for the same user class takes over.
+    pub start_time: DateTime<Utc>,
+}
+
+impl TryFrom<ResourcePriceCreateData> for NewResourcePrice {
+    type Error = String;
+
+    /// Transform a [ResourcePriceCreateData] into a [NewResourcePrice].
+    ///
+    /// More specifically this also replaces not inputted data by defaults, e.g.,
+    /// 0. for the prices, and now in UTC for the start time.
+    fn try_from(data: ResourcePriceCreateData) -> Result<Self, Self::Error> {
+        let vcpu_price = data.vcpu_price.unwrap_or(0.);
+        let ram_price = data.ram_price.unwrap_or(0.);
+        let gpu_price = data.gpu_price.unwrap_or(0.);
+        if [vcpu_price, ram_price, gpu_price]
+            .iter()
+            .any(|price| !price.is_finite() || *price < 0.)
+        {
+            return Err("Prices must be non-negative numbers".to_string());
+        }
+        Ok(Self {
+            user_class: data.user_class,
+            vcpu_price,
+            ram_price,
+            gpu_price,
+            start_time: data
+                .start_time
+                .map(|d| d.to_utc())
+                .unwrap_or(Utc::now()),
+        })
+    }
+}
+
+/// Insert a new resource price based on the given [NewResourcePrice] into the database.
+#[tracing::instrument(
+    name = "insert_resource_price_into_db",
+    skip(new_resource_price, transaction)
+)]
+pub async fn insert_resource_price_into_db(
+    transaction: &mut Transaction<'_, MySql>,
+    new_resource_price: &NewResourcePrice,
+) -> Result<u64, MinimalApiError> {
+    let query = sqlx::query!(
+        r#"
+        INSERT IGNORE INTO pricing_resourceprice (user_class, vcpu_price, ram_price, gpu_price, start_time)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        new_resource_price.user_class as u32,
+        new_resource_price.vcpu_price,
+        new_resource_price.ram_price,
+        new_resource_price.gpu_price,
+        new_resource_price.start_time,
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute insert query")?;
+    if result.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to insert new resource price, a conflicting entry exists"
+                .to_string(),
+        ));
+    }
+    Ok(result.last_insert_id())
+}
+
+/// Delete the resource price with the given ID from the database.
+#[tracing::instrument(
+    name = "delete_resource_price_from_db",
+    skip(transaction)
+)]
+pub async fn delete_resource_price_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    resource_price_id: u64,
+) -> Result<(), MinimalApiError> {
+    let query = sqlx::query!(
+        r#"
+        DELETE IGNORE FROM pricing_resourceprice
+        WHERE id = ?
+        "#,
+        resource_price_id
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute delete query")?;
+    if result.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to delete resource price.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Update the resource price with the given [ResourcePriceModifyData] in the database.
+#[tracing::instrument(
+    name = "update_resource_price_in_db",
+    skip(data, transaction)
+)]
+pub async fn update_resource_price_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    data: &ResourcePriceModifyData,
+) -> Result<ResourcePrice, NotFoundOrUnexpectedApiError> {
+    let row =
+        select_resource_price_from_db(transaction, data.id as u64).await?;
+    let price = ResourcePrice {
+        id: data.id,
+        user_class: data.user_class.unwrap_or(row.user_class),
+        vcpu_price: data.vcpu_price.unwrap_or(row.vcpu_price),
+        ram_price: data.ram_price.unwrap_or(row.ram_price),
+        gpu_price: data.gpu_price.unwrap_or(row.gpu_price),
+        start_time: data.start_time.unwrap_or(row.start_time),
+    };
+    let query = sqlx::query!(
+        r#"
+        UPDATE pricing_resourceprice
+        SET user_class = ?, vcpu_price = ?, ram_price = ?, gpu_price = ?, start_time = ?
+        WHERE id = ?
+        "#,
+        price.user_class as u32,
+        price.vcpu_price,
+        price.ram_price,
+        price.gpu_price,
+        price.start_time.to_utc(),
+        price.id,
+    );
+    transaction
+        .execute(query)
+        .await
+        .context("Failed to execute update query")?;
+    Ok(price)
+}
@@ -0,0 +1,376 @@
+//! Queries for volume prices.
+
+use std::collections::HashSet;
+
+use anyhow::Context;
+use avina_wire::{
+    pricing::{VolumePrice, VolumePriceCreateData, VolumePriceModifyData},
+    user::UserClass,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{Executor, FromRow, MySql, Transaction, mysql::MySqlRow};
+
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
+};
+
+/// Representation of a volume price specifically for communication with the database.
+///
+/// This uses types, that can be directly deserialized from SQL and is then converted
+/// to [VolumePrice] afterwards.
+#[derive(FromRow)]
+pub struct VolumePriceRow {
+    /// ID of the volume price.
+    #[sqlx(try_from = "i32")]
+    pub id: u32,
+    /// User class of the price.
+    pub user_class: u32,
+    /// Actual price per unit (full year usage of a GiB).
+    pub unit_price: f64,
+    /// Timestamp from when the price is valid.
+    ///
+    /// It is valid until the next price for the same user class takes over.
+    pub start_time: DateTime<Utc>,
+}
+
+impl TryFrom<VolumePriceRow> for VolumePrice {
+    type Error = UnexpectedOnlyError;
+
+    fn try_from(row: VolumePriceRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            user_class: row
+                .user_class
+                .try_into()
+                .context("Failed to parse user class")?,
+            unit_price: row.unit_price,
+            start_time: row.start_time.fixed_offset(),
+        })
+    }
+}
+
+/// Convert the given rows into volume prices.
+fn volume_prices_from_rows(
+    rows: Vec<MySqlRow>,
+) -> Result<Vec<VolumePrice>, UnexpectedOnlyError> {
+    rows.into_iter()
+        .map(|r| VolumePriceRow::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to volume price row")?
+        .into_iter()
+        .map(VolumePrice::try_from)
+        .collect()
+}
+
+/// Select a volume price by the given ID from the database, or return [None].
+#[tracing::instrument(
+    name = "select_maybe_volume_price_from_db",
+    skip(transaction)
+)]
+pub async fn select_maybe_volume_price_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    volume_price_id: u64,
+) -> Result<Option<VolumePrice>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, user_class, unit_price, start_time
+        FROM pricing_volumeprice
+        WHERE id = ?
+        "#,
+        volume_price_id
+    );
+    let row = transaction
+        .fetch_optional(query)
+        .await
+        .context("Failed to execute select query")?;
+    Ok(match row {
+        Some(row) => Some(
+            VolumePriceRow::from_row(&row)
+                .context("Failed to parse volume price row")?
+                .try_into()?,
+        ),
+        None => None,
+    })
+}
+
+/// Select a volume price with the given ID from the database, or a "not found" error.
+///
+/// This calls [select_maybe_volume_price_from_db] and then turns a [None] response into a
+/// [NotFoundOrUnexpectedApiError::NotFoundError].
+#[tracing::instrument(name = "select_volume_price_from_db", skip(transaction))]
+pub async fn select_volume_price_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    volume_price_id: u64,
+) -> Result<VolumePrice, NotFoundOrUnexpectedApiError> {
+    select_maybe_volume_price_from_db(transaction, volume_price_id)
+        .await?
+        .ok_or(NotFoundOrUnexpectedApiError::NotFoundError)
+}
+
+/// Select a list of all volume prices from the database.
+#[tracing::instrument(
+    name = "select_all_volume_prices_from_db",
+    skip(transaction)
+)]
+pub async fn select_all_volume_prices_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<Vec<VolumePrice>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, user_class, unit_price, start_time
+        FROM pricing_volumeprice
+        "#,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    volume_prices_from_rows(rows)
+}
+
+/// Select a list of volume prices, that are valid during the period given by begin and end
+/// timestamp, ordered by their start time from newest to oldest.
+///
+/// For each user class, this includes the prices starting within the period and the last one
+/// starting before it.
+#[tracing::instrument(
+    name = "select_volume_prices_for_period_from_db",
+    skip(transaction)
+)]
+pub async fn select_volume_prices_for_period_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<VolumePrice>, UnexpectedOnlyError> {
+    let query = sqlx::query!(
+        r#"
+        SELECT id, user_class, unit_price, start_time
+        FROM pricing_volumeprice
+        WHERE start_time <= ?
+        ORDER BY start_time DESC
+        "#,
+        end,
+    );
+    let rows = transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?;
+    let mut prices = Vec::new();
+    let mut done = HashSet::new();
+    for price in volume_prices_from_rows(rows)? {
+        if done.contains(&price.user_class) {
+            continue;
+        }
+        if price.start_time <= begin {
+            done.insert(price.user_class);
+        }
+        prices.push(price);
+    }
+    Ok(prices)
+}
+
+/// Fields volume prices can be ordered by via the `ordering` parameter.
+impl Orderable for VolumePrice {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "p.id",
+            "user_class" => "p.user_class",
+            "unit_price" => "p.unit_price",
+            "start_time" => "p.start_time",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the volume prices selected by [select_volume_price_list_from_db].
+#[derive(Debug, Default)]
+pub struct VolumePriceFilter {
+    /// User class the volume prices have to be for.
+    pub user_class: Option<UserClass>,
+    /// Time the volume prices have to be valid at, i.e., being the last one of their
+    /// user class starting before it.
+    pub valid_at: Option<DateTime<Utc>>,
+}
+
+/// Select a list of volume prices matching the given filter from the database, ordered and
+/// paged according to the given [ListQuery].
+///
+/// Returns the volume prices together with the count of all volume prices matching the filter.
+#[tracing::instrument(
+    name = "select_volume_price_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_volume_price_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &VolumePriceFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<VolumePrice>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT p.id, p.user_class, p.unit_price, p.start_time
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM pricing_volumeprice AS p
+        WHERE TRUE
+        "#,
+            );
+            if let Some(user_class) = filter.user_class {
+                query
+                    .push(" AND p.user_class = ")
+                    .push_bind(user_class as u32);
+            }
+            if let Some(valid_at) = filter.valid_at {
+                query
+                    .push(
+                        r#"
+        AND p.id = (
+            SELECT q.id
+            FROM pricing_volumeprice AS q
+            WHERE q.user_class = p.user_class AND q.start_time <= "#,
+                    )
+                    .push_bind(valid_at)
+                    .push(
+                        r#"
+            ORDER BY q.start_time DESC
+            LIMIT 1
+        )
+        "#,
+                    );
+            }
+        },
+        list_query,
+    )
+    .await?;
+    Ok((volume_prices_from_rows(rows)?, count))
+}
+
+/// Simplified representation of data needed to create a new volume price.
+pub struct NewVolumePrice {
+    /// User class for the price.
+    pub user_class: UserClass,
+    /// Actual price per unit (full year usage of a GiB).
+    pub unit_price: f64,
+    /// Timestamp from when the price is valid.
+    ///
+    /// It is valid until the next price for the same user class takes over.
+    pub start_time: DateTime<Utc>,
+}
+
+impl TryFrom<VolumePriceCreateData> for NewVolumePrice {
+    type Error = String;
+
+    /// Transform a [VolumePriceCreateData] into a [NewVolumePrice].
+    ///
+    /// More specifically this also replaces not inputted data by defaults, e.g.,
+    /// 0. for the unit price, and now in UTC for the start time.
+    fn try_from(data: VolumePriceCreateData) -> Result<Self, Self::Error> {
+        let unit_price = data.price.unwrap_or(0.);
+        if !unit_price.is_finite() || unit_price < 0. {
+            return Err("Price must be a non-negative number".to_string());
+        }
+        Ok(Self {
+            user_class: data.user_class,
+            unit_price,
+            start_time: data
+                .start_time
+                .map(|d| d.to_utc())
+                .unwrap_or(Utc::now()),
+        })
+    }
+}
+
+/// Insert a new volume price based on the given [NewVolumePrice] into the database.
+#[tracing::instrument(
+    name = "insert_volume_price_into_db",
+    skip(new_volume_price, transaction)
+)]
+pub async fn insert_volume_price_into_db(
+    transaction: &mut Transaction<'_, MySql>,
+    new_volume_price: &NewVolumePrice,
+) -> Result<u64, MinimalApiError> {
+    let query = sqlx::query!(
+        r#"
+        INSERT IGNORE INTO pricing_volumeprice (user_class, unit_price, start_time)
+        VALUES (?, ?, ?)
+        "#,
+        new_volume_price.user_class as u32,
+        new_volume_price.unit_price,
+        new_volume_price.start_time,
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute insert query")?;
+    if result.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to insert new volume price, a conflicting entry exists"
+                .to_string(),
+        ));
+    }
+    Ok(result.last_insert_id())
+}
+
+/// Delete the volume price with the given ID from the database.
+#[tracing::instrument(name = "delete_volume_price_from_db", skip(transaction))]
+pub async fn delete_volume_price_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    volume_price_id: u64,
+) -> Result<(), MinimalApiError> {
+    let query = sqlx::query!(
+        r#"
+        DELETE IGNORE FROM pricing_volumeprice
+        WHERE id = ?
+        "#,
+        volume_price_id
+    );
+    let result = transaction
+        .execute(query)
+        .await
+        .context("Failed to execute delete query")?;
+    if result.rows_affected() == 0 {
+        return Err(MinimalApiError::ValidationError(
+            "Failed to delete volume price.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Update the volume price with the given [VolumePriceModifyData] in the database.
+#[tracing::instrument(
+    name = "update_volume_price_in_db",
+    skip(data, transaction)
+)]
+pub async fn update_volume_price_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    data: &VolumePriceModifyData,
+) -> Result<VolumePrice, NotFoundOrUnexpectedApiError> {
+    let row = select_volume_price_from_db(transaction, data.id as u64).await?;
+    let price = VolumePrice {
+        id: data.id,
+        user_class: data.user_class.unwrap_or(row.user_class),
+        unit_price: data.unit_price.unwrap_or(row.unit_price),
+        start_time: data.start_time.unwrap_or(row.start_time),
+    };
+    let query = sqlx::query!(
+        r#"
+        UPDATE pricing_volumeprice
+        SET user_class = ?, unit_price = ?, start_time = ?
+        WHERE id = ?
+        "#,
+        price.user_class as u32,
+        price.unit_price,
+        price.start_time.to_utc(),
+        price.id,
+    );
+    transaction
+        .execute(query)
+        .await
+        .context("Failed to execute update query")?;
+    Ok(price)
+}
@@ -6,8 +6,11 @@ use avina_wire::user::{
 };
 use sqlx::{Executor, FromRow, MySql, Transaction};
 
-use crate::error::{
-    MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+use crate::{
+    error::{
+        MinimalApiError, NotFoundOrUnexpectedApiError, UnexpectedOnlyError,
+    },
+    pagination::{ListQuery, Orderable, select_list},
 };
 
 /// Select the name of the user with the given ID from the database, or return [None].
@@ -168,6 +171,84 @@ pub async fn select_users_by_id_from_db(
     Ok(rows)
 }
 
+/// Fields users can be ordered by via the `ordering` parameter.
+impl Orderable for User {
+    fn column(field: &str) -> Option<&'static str> {
+        Some(match field {
+            "id" => "user.id",
+            "name" => "user.name",
+            "openstack_id" => "user.openstack_id",
+            "project" => "project.id",
+            "project_name" => "project.name",
+            "role" => "user.role",
+            "is_staff" => "user.is_staff",
+            "is_active" => "user.is_active",
+            _ => return None,
+        })
+    }
+}
+
+/// Filter for the users selected by [select_user_list_from_db].
+#[derive(Debug, Default)]
+pub struct UserFilter {
+    /// ID of the project the users have to belong to.
+    pub project: Option<u64>,
+    /// ID the user has to have.
+    pub id: Option<u64>,
+}
+
+/// Select a list of users matching the given filter from the database, ordered and paged
+/// according to the given [ListQuery].
+///
+/// Returns the users together with the count of all users matching the filter.
+#[tracing::instrument(
+    name = "select_user_list_from_db",
+    skip(transaction, list_query)
+)]
+pub async fn select_user_list_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+    filter: &UserFilter,
+    list_query: &ListQuery,
+) -> Result<(Vec<User>, u32), UnexpectedOnlyError> {
+    let (rows, count) = select_list(
+        transaction,
+        r#"
+        SELECT
+            user.id AS id,
+            user.name AS name,
+            user.openstack_id AS openstack_id,
+            user.role AS role,
+            project.id as project,
+            project.name AS project_name,
+            user.is_staff AS is_staff,
+            user.is_active AS is_active
+        "#,
+        |query| {
+            query.push(
+                r#"
+        FROM user_user AS user, user_project AS project
+        WHERE
+            user.project_id = project.id
+        "#,
+            );
+            if let Some(project_id) = filter.project {
+                query.push(" AND user.project_id = ").push_bind(project_id);
+            }
+            if let Some(user_id) = filter.id {
+                query.push(" AND user.id = ").push_bind(user_id);
+            }
+        },
+        list_query,
+    )
+    .await?;
+    let users = rows
+        .into_iter()
+        .map(|r| User::from_row(&r))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to convert row to user")?;
+    Ok((users, count))
+}
+
 /// Select the detailed representation of the user with the given ID, or [None].
 #[tracing::instrument(
     name = "select_maybe_user_detail_from_db",
@@ -0,0 +1,49 @@
+//! Committing or, in dry runs, rolling back the changes of imports and syncs.
+//!
+//! Endpoints supporting dry runs take [DryRunParams], record the changes of
+//! their logic in a list of [Change]s, and then respond with [finish], which
+//! either commits the transaction and returns the plain summary, or rolls it
+//! back and returns the summary together with the changes as [DryRun].
+
+use actix_web::HttpResponse;
+use anyhow::Context;
+use avina_wire::dry_run::{Change, DryRun, DryRunParams};
+use serde::Serialize;
+use sqlx::{MySql, Transaction};
+use utoipa::ToSchema;
+
+use crate::error::UnexpectedOnlyError;
+
+/// Response of an endpoint supporting dry runs, see [finish].
+#[derive(Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum DryRunnable<T> {
+    Summary(T),
+    DryRun(DryRun<T>),
+}
+
+/// Commit the transaction and respond with the summary, or in a dry run roll
+/// the transaction back and respond with both summary and changes.
+pub async fn finish<T: Serialize>(
+    transaction: Transaction<'_, MySql>,
+    params: &DryRunParams,
+    summary: T,
+    changes: Vec<Change>,
+) -> Result<HttpResponse, UnexpectedOnlyError> {
+    let response = if params.dry_run.unwrap_or(false) {
+        transaction
+            .rollback()
+            .await
+            .context("Failed to roll back transaction")?;
+        DryRunnable::DryRun(DryRun { summary, changes })
+    } else {
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit transaction")?;
+        DryRunnable::Summary(summary)
+    };
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(response))
+}
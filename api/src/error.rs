@@ -7,6 +7,8 @@
 //!
 //! For special cases, where an [actix_web::Error] needs to be returned, there are also
 //! a few helper functions for various kinds of HTTP errors.
+//!
+//! Every error response is counted in the metrics by its error type and variant.
 
 use actix_web::{
     HttpResponse, ResponseError,
@@ -18,6 +20,9 @@ use actix_web::{
     },
 };
 use avina_wire::error::{ErrorResponse, error_chain_fmt};
+use strum::IntoStaticStr;
+
+use crate::metrics::record_api_error;
 
 /// Wrap given message in an HTTP Unauthorized Error.
 pub fn unauthorized_error(message: &str) -> actix_web::Error {
@@ -30,6 +35,17 @@ pub fn unauthorized_error(message: &str) -> actix_web::Error {
     .into()
 }
 
+/// Wrap given message in an HTTP Forbidden Error.
+pub fn forbidden_error(message: &str) -> actix_web::Error {
+    InternalError::from_response(
+        anyhow::anyhow!(message.to_string()),
+        HttpResponse::Forbidden().json(ErrorResponse {
+            detail: message.to_string(),
+        }),
+    )
+    .into()
+}
+
 /// Wrap the given message in an HTTP Internal Server Error.
 pub fn internal_server_error(message: &str) -> actix_web::Error {
     InternalError::from_response(
@@ -69,7 +85,7 @@ pub async fn not_found() -> Result<HttpResponse, actix_web::Error> {
 }
 
 /// Like [NormalApiError] but with an additional not-found variant.
-#[derive(thiserror::Error)]
+#[derive(thiserror::Error, IntoStaticStr)]
 pub enum OptionApiError {
     /// Validation of the user input failed. This causes an HTTP 400 BAD REQUEST status code
     /// with the contained error message.
@@ -100,6 +116,7 @@ impl std::fmt::Debug for OptionApiError {
 
 impl ResponseError for OptionApiError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
+        record_api_error("OptionApiError", self.into());
         let (status_code, message) = match self {
             OptionApiError::ValidationError(message) => {
                 (StatusCode::BAD_REQUEST, message.clone())
@@ -213,7 +230,7 @@ impl From<UnexpectedOnlyError> for MinimalApiError {
 ///
 /// Either the validation of the user input failed, the user is not authorized
 /// for the specified action, or something unexpected happened.
-#[derive(thiserror::Error)]
+#[derive(thiserror::Error, IntoStaticStr)]
 pub enum NormalApiError {
     /// Validation of the user input failed. This causes an HTTP 400 BAD REQUEST status code
     /// with the contained error message.
@@ -238,6 +255,7 @@ impl std::fmt::Debug for NormalApiError {
 
 impl ResponseError for NormalApiError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
+        record_api_error("NormalApiError", self.into());
         let (status_code, message) = match self {
             NormalApiError::ValidationError(message) => {
                 (StatusCode::BAD_REQUEST, message.clone())
@@ -298,7 +316,7 @@ impl std::fmt::Debug for MinimalApiError {
 }
 
 /// The request can fail either when the resource does not exist, or unexpectedly.
-#[derive(thiserror::Error)]
+#[derive(thiserror::Error, IntoStaticStr)]
 pub enum NotFoundOrUnexpectedApiError {
     /// The requested resource was not found. This causes an HTTP 404 NOT FOUND status code
     /// with the message "Resource not found".
@@ -321,6 +339,7 @@ impl std::fmt::Debug for NotFoundOrUnexpectedApiError {
 
 impl ResponseError for NotFoundOrUnexpectedApiError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
+        record_api_error("NotFoundOrUnexpectedApiError", self.into());
         let (status_code, message) = match self {
             NotFoundOrUnexpectedApiError::NotFoundError => {
                 (StatusCode::NOT_FOUND, self.to_string())
@@ -345,7 +364,7 @@ impl ResponseError for NotFoundOrUnexpectedApiError {
 }
 
 /// The request can only fail due to an authorization error.
-#[derive(thiserror::Error)]
+#[derive(thiserror::Error, IntoStaticStr)]
 pub enum AuthOnlyError {
     /// The user is unauthorized to perform the request. This causes an HTTP 403 FORBIDDEN status
     /// code with the contained error message.
@@ -361,6 +380,7 @@ impl std::fmt::Debug for AuthOnlyError {
 
 impl ResponseError for AuthOnlyError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
+        record_api_error("AuthOnlyError", self.into());
         let (status_code, message) = match self {
             AuthOnlyError::AuthorizationError(message) => {
                 (StatusCode::FORBIDDEN, message.clone())
@@ -390,7 +410,7 @@ impl From<AuthOnlyError> for NormalApiError {
 }
 
 /// The request can only fail when the resource does not exist.
-#[derive(thiserror::Error)]
+#[derive(thiserror::Error, IntoStaticStr)]
 pub enum NotFoundOnlyError {
     /// The requested resource was not found. This causes an HTTP 404 NOT FOUND status code
     /// with the message "Resource not found".
@@ -408,6 +428,7 @@ impl std::fmt::Debug for NotFoundOnlyError {
 
 impl ResponseError for NotFoundOnlyError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
+        record_api_error("NotFoundOnlyError", self.into());
         let (status_code, message) = match self {
             NotFoundOnlyError::NotFoundError => {
                 (StatusCode::NOT_FOUND, self.to_string())
@@ -435,7 +456,7 @@ impl From<NotFoundOnlyError> for OptionApiError {
 }
 
 /// The request can only fail unexpectedly.
-#[derive(thiserror::Error)]
+#[derive(thiserror::Error, IntoStaticStr)]
 pub enum UnexpectedOnlyError {
     /// An unexpected error occurred. This causes an HTTP 500 INTERNAL SERVER ERROR status code
     /// with the message "Internal server error, contact admin or check logs". The contained
@@ -452,6 +473,7 @@ impl std::fmt::Debug for UnexpectedOnlyError {
 
 impl ResponseError for UnexpectedOnlyError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
+        record_api_error("UnexpectedOnlyError", self.into());
         let (status_code, message) = match self {
             UnexpectedOnlyError::UnexpectedError(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
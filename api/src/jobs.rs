@@ -0,0 +1,310 @@
+//! Built-in scheduler for recurring jobs, e.g., the imports from OpenStack.
+//!
+//! The [JobRunner] runs a single [Job] and records the run in the database,
+//! be it triggered manually via the job run endpoint or by the scheduler.
+//! The scheduler spawns a task per job with a schedule in the [JobsSettings],
+//! that sleeps until the next scheduled time and then runs the job.
+
+use std::{str::FromStr, time::Duration};
+
+use actix_web::web::Data;
+use anyhow::Context;
+use avina_wire::{
+    budgeting::UserBudgetSync,
+    jobs::{Job, JobRun},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{Connection, MySqlPool};
+use strum::IntoEnumIterator;
+
+use crate::{
+    configuration::{JobSettings, JobsSettings},
+    database::{
+        budgeting::user_budget::sync_user_budgets_in_db,
+        jobs::job_run::{
+            finish_job_run_in_db, insert_job_run_into_db, lock_job_in_db,
+            select_job_run_from_db,
+        },
+    },
+    error::OptionApiError,
+    openstack::OpenStack,
+    routes::{
+        accounting::{
+            floating_ip_state::import::import_floating_ip_states,
+            server_state::import::import_server_states,
+            volume_state::import::import_volume_states,
+        },
+        resources::flavor::import::import_flavors,
+        user::{import::import_users, sync::sync_users},
+    },
+    startup::AvinaLdapConfig,
+};
+
+/// Runner executing jobs and recording their runs in the database.
+///
+/// Each job runs at most once at a time, also across multiple instances of
+/// the API sharing the database, a run overlapping with a running one of the
+/// same job is rejected.
+pub struct JobRunner {
+    /// Database connection pool.
+    db_pool: Data<MySqlPool>,
+    /// OpenStack abstraction used by the imports.
+    openstack: Data<OpenStack>,
+    /// Configuration for avina-ldap access used by the user jobs.
+    avina_ldap_config: Data<AvinaLdapConfig>,
+}
+
+impl JobRunner {
+    /// Create a new runner from the shared state objects.
+    pub fn new(
+        db_pool: Data<MySqlPool>,
+        openstack: Data<OpenStack>,
+        avina_ldap_config: Data<AvinaLdapConfig>,
+    ) -> Self {
+        Self {
+            db_pool,
+            openstack,
+            avina_ldap_config,
+        }
+    }
+
+    /// Run the given job and return the finished run.
+    ///
+    /// The run is recorded in the database before the job starts, and
+    /// afterwards updated with either the result or the error of the job.
+    /// A failing job is rolled back as a whole and does not cause an error
+    /// here, but is reported in the returned run. The user ID is given for
+    /// manually triggered runs only. If the job is already running, an
+    /// [OptionApiError::ValidationError] is returned.
+    ///
+    /// The job is locked via [lock_job_in_db] on a connection detached from
+    /// the pool, which is closed afterwards, releasing the lock also when the
+    /// run is aborted.
+    #[tracing::instrument(name = "job_runner_run", skip(self))]
+    pub async fn run(
+        &self,
+        job: Job,
+        user_id: Option<u32>,
+    ) -> Result<JobRun, OptionApiError> {
+        let mut lock_connection = self
+            .db_pool
+            .acquire()
+            .await
+            .context("Failed to acquire connection")?
+            .detach();
+        if !lock_job_in_db(&mut lock_connection, job).await? {
+            return Err(OptionApiError::ValidationError(format!(
+                "Job {job} is already running"
+            )));
+        }
+        let job_run = self.run_locked(job, user_id).await;
+        if let Err(error) = lock_connection.close().await {
+            tracing::warn!("Failed to release lock of job {job}: {error:?}");
+        }
+        job_run
+    }
+
+    /// Run the given job while holding its lock, see [JobRunner::run].
+    async fn run_locked(
+        &self,
+        job: Job,
+        user_id: Option<u32>,
+    ) -> Result<JobRun, OptionApiError> {
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("Failed to begin transaction")?;
+        let job_run_id =
+            insert_job_run_into_db(&mut transaction, job, user_id, Utc::now())
+                .await?;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit transaction")?;
+
+        let (result, error) = match self.execute(job).await {
+            Ok(result) => (Some(result), None),
+            Err(error) => {
+                tracing::error!("Job {job} failed: {error:?}");
+                (None, Some(format!("{error:#}")))
+            }
+        };
+
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("Failed to begin transaction")?;
+        finish_job_run_in_db(
+            &mut transaction,
+            job_run_id,
+            Utc::now(),
+            result,
+            error,
+        )
+        .await?;
+        let job_run = select_job_run_from_db(&mut transaction, job_run_id)
+            .await
+            .context("Failed to select finished job run")?;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit transaction")?;
+        Ok(job_run)
+    }
+
+    /// Execute the given job in a transaction and return its result as JSON.
+    async fn execute(&self, job: Job) -> Result<String, anyhow::Error> {
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .context("Failed to begin transaction")?;
+        let result = match job {
+            Job::ServerStateImport => serde_json::to_string(
+                &import_server_states(
+                    &mut transaction,
+                    &self.openstack,
+                    &mut Vec::new(),
+                )
+                .await?,
+            ),
+            Job::VolumeStateImport => serde_json::to_string(
+                &import_volume_states(
+                    &mut transaction,
+                    &self.openstack,
+                    &mut Vec::new(),
+                )
+                .await?,
+            ),
+            Job::FloatingIpStateImport => serde_json::to_string(
+                &import_floating_ip_states(
+                    &mut transaction,
+                    &self.openstack,
+                    &mut Vec::new(),
+                )
+                .await?,
+            ),
+            Job::FlavorImport => serde_json::to_string(
+                &import_flavors(
+                    &mut transaction,
+                    &self.openstack,
+                    None,
+                    &mut Vec::new(),
+                )
+                .await?,
+            ),
+            Job::UserImport => serde_json::to_string(
+                &import_users(
+                    &mut transaction,
+                    &self.openstack,
+                    &self.avina_ldap_config,
+                    &mut Vec::new(),
+                )
+                .await?,
+            ),
+            Job::UserSync => serde_json::to_string(
+                &sync_users(
+                    &mut transaction,
+                    &self.avina_ldap_config,
+                    &mut Vec::new(),
+                )
+                .await?,
+            ),
+            Job::UserBudgetSync => serde_json::to_string(&UserBudgetSync {
+                updated_budget_count: sync_user_budgets_in_db(&mut transaction)
+                    .await? as u32,
+            }),
+        }
+        .context("Failed to serialize job result")?;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit transaction")?;
+        Ok(result)
+    }
+}
+
+/// Schedule of a job, parsed from its [JobSettings].
+enum JobSchedule {
+    /// Fixed interval between the end of a run and the start of the next.
+    Interval(Duration),
+    /// Cron expression, evaluated in UTC.
+    Cron(Box<cron::Schedule>),
+}
+
+impl JobSchedule {
+    /// Parse the schedule from the settings.
+    ///
+    /// The settings are expected to be validated already, see
+    /// [JobsSettings::validate].
+    fn from_settings(settings: &JobSettings) -> Result<Self, anyhow::Error> {
+        match (settings.interval, &settings.cron) {
+            (Some(interval), None) => {
+                Ok(Self::Interval(Duration::from_secs(interval)))
+            }
+            (None, Some(cron)) => Ok(Self::Cron(Box::new(
+                cron::Schedule::from_str(cron)
+                    .context("Failed to parse cron expression")?,
+            ))),
+            _ => Err(anyhow::anyhow!(
+                "Either an interval or a cron expression is required"
+            )),
+        }
+    }
+
+    /// Get the next time the job is scheduled at after the given time.
+    fn next_after(&self, time: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Interval(interval) => Some(time + *interval),
+            Self::Cron(schedule) => schedule.after(&time).next(),
+        }
+    }
+}
+
+/// Start the scheduler for all jobs with a schedule in the settings.
+pub fn start_scheduler(
+    runner: Data<JobRunner>,
+    settings: &JobsSettings,
+) -> Result<(), anyhow::Error> {
+    for job in Job::iter() {
+        let Some(settings) = settings.get(job) else {
+            continue;
+        };
+        let schedule = JobSchedule::from_settings(settings)
+            .context(format!("Invalid schedule of job {job}"))?;
+        tracing::info!("Scheduling job {job}");
+        tokio::spawn(schedule_job(runner.clone(), job, schedule));
+    }
+    Ok(())
+}
+
+/// Run the given job according to its schedule, forever.
+///
+/// Scheduled times that pass while the job is still running are skipped.
+async fn schedule_job(
+    runner: Data<JobRunner>,
+    job: Job,
+    schedule: JobSchedule,
+) {
+    loop {
+        let now = Utc::now();
+        let Some(next) = schedule.next_after(now) else {
+            tracing::warn!("Job {job} has no upcoming scheduled time");
+            return;
+        };
+        tokio::time::sleep((next - now).to_std().unwrap_or_default()).await;
+        match runner.run(job, None).await {
+            Ok(job_run) => {
+                tracing::info!("Scheduled run of job {job} finished: {job_run}")
+            }
+            Err(OptionApiError::ValidationError(message)) => {
+                tracing::info!("Skipping scheduled run: {message}")
+            }
+            Err(error) => {
+                tracing::error!("Scheduled run of job {job} failed: {error:?}")
+            }
+        }
+    }
+}
@@ -180,3 +180,26 @@ impl AvinaLdap {
         None
     }
 }
+
+/// Check that avina-ldap at the given URL is reachable and accepts the token.
+///
+/// Unlike [AvinaLdap::new], this does not parse the response, it only
+/// requires an HTTP 200 OK.
+pub async fn check_avina_ldap(
+    url: &str,
+    token: &str,
+) -> Result<(), anyhow::Error> {
+    let response = Client::new()
+        .get(url)
+        .header("Authorization", token)
+        .send()
+        .await
+        .context("Could not reach avina-ldap")?;
+    if response.status().as_u16() != StatusCode::OK {
+        return Err(anyhow!(
+            "avina-ldap returned code {}",
+            response.status().as_u16()
+        ));
+    }
+    Ok(())
+}
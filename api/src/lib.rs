@@ -4,13 +4,18 @@
 //! as a library, so that the unpublished `avina-test` crate may
 //! import and programmatically start it as well.
 
+pub mod audit;
 pub mod authentication;
 pub mod authorization;
 pub mod configuration;
 pub mod database;
+pub mod dry_run;
 pub mod error;
+pub mod jobs;
 pub mod ldap;
+pub mod metrics;
 pub mod openstack;
+pub mod pagination;
 pub mod routes;
 pub mod startup;
 pub mod telemetry;
@@ -0,0 +1,232 @@
+//! Prometheus metrics of avina-api.
+//!
+//! All metrics are registered in a process-wide [Registry], so that they can
+//! be recorded from anywhere, e.g., in the error types or the OpenStack
+//! abstraction, without having to hand around application state. They are
+//! exposed in the Prometheus text format by the `/metrics` endpoint.
+
+use std::{sync::LazyLock, time::Instant};
+
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    middleware::Next,
+};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+use sqlx::MySqlPool;
+
+/// Collection of all metrics of avina-api.
+struct Metrics {
+    /// Registry all metrics are registered in.
+    registry: Registry,
+    /// Number of HTTP requests by method, route and status code.
+    http_requests: IntCounterVec,
+    /// Duration of HTTP requests by method and route.
+    http_request_duration: HistogramVec,
+    /// Number of API errors by error type and variant.
+    api_errors: IntCounterVec,
+    /// Number of OpenStack calls by method and outcome.
+    openstack_calls: IntCounterVec,
+    /// Duration of OpenStack calls by method.
+    openstack_call_duration: HistogramVec,
+    /// Number of cache lookups by cache and result.
+    cache_lookups: IntCounterVec,
+    /// Number of database connections by state.
+    database_connections: IntGaugeVec,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "Number of HTTP requests"),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Duration of HTTP requests",
+            ),
+            &["method", "route"],
+        )
+        .unwrap();
+        let api_errors = IntCounterVec::new(
+            Opts::new("api_errors_total", "Number of API errors"),
+            &["error", "variant"],
+        )
+        .unwrap();
+        let openstack_calls = IntCounterVec::new(
+            Opts::new("openstack_calls_total", "Number of OpenStack calls"),
+            &["method", "outcome"],
+        )
+        .unwrap();
+        let openstack_call_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "openstack_call_duration_seconds",
+                "Duration of OpenStack calls",
+            ),
+            &["method"],
+        )
+        .unwrap();
+        let cache_lookups = IntCounterVec::new(
+            Opts::new("cache_lookups_total", "Number of cache lookups"),
+            &["cache", "result"],
+        )
+        .unwrap();
+        let database_connections = IntGaugeVec::new(
+            Opts::new(
+                "database_connections",
+                "Number of database connections in the pool",
+            ),
+            &["state"],
+        )
+        .unwrap();
+
+        let registry = Registry::new_custom(Some("avina".to_string()), None)
+            .expect("Failed to create metrics registry");
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .unwrap();
+        registry.register(Box::new(api_errors.clone())).unwrap();
+        registry
+            .register(Box::new(openstack_calls.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(openstack_call_duration.clone()))
+            .unwrap();
+        registry.register(Box::new(cache_lookups.clone())).unwrap();
+        registry
+            .register(Box::new(database_connections.clone()))
+            .unwrap();
+
+        Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            api_errors,
+            openstack_calls,
+            openstack_call_duration,
+            cache_lookups,
+            database_connections,
+        }
+    }
+}
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+/// Record an API error of the given error type and variant.
+pub fn record_api_error(error: &str, variant: &str) {
+    METRICS
+        .api_errors
+        .with_label_values(&[error, variant])
+        .inc();
+}
+
+/// Record a lookup in the given cache, and whether it was a hit.
+pub fn record_cache_lookup(cache: &str, hit: bool) {
+    let result = if hit { "hit" } else { "miss" };
+    METRICS
+        .cache_lookups
+        .with_label_values(&[cache, result])
+        .inc();
+}
+
+/// Guard recording count, outcome and duration of an OpenStack call.
+///
+/// The call is recorded when the guard is dropped, as failed unless
+/// [OpenStackCall::succeeded] was called before. This way, early returns
+/// in the OpenStack functions are accounted for as well.
+pub struct OpenStackCall {
+    method: &'static str,
+    start: Instant,
+    succeeded: bool,
+}
+
+impl OpenStackCall {
+    /// Start recording a call of the given method.
+    pub fn start(method: &'static str) -> Self {
+        Self {
+            method,
+            start: Instant::now(),
+            succeeded: false,
+        }
+    }
+
+    /// Mark the call as succeeded.
+    pub fn succeeded(&mut self) {
+        self.succeeded = true;
+    }
+}
+
+impl Drop for OpenStackCall {
+    fn drop(&mut self) {
+        let outcome = if self.succeeded { "success" } else { "error" };
+        METRICS
+            .openstack_calls
+            .with_label_values(&[self.method, outcome])
+            .inc();
+        METRICS
+            .openstack_call_duration
+            .with_label_values(&[self.method])
+            .observe(self.start.elapsed().as_secs_f64());
+    }
+}
+
+/// Middleware function for recording count and duration of HTTP requests.
+///
+/// Requests are labelled by their route pattern instead of their path, e.g.,
+/// `/api/user/users/{user_id}`, to keep the number of time series bounded.
+pub async fn track_requests(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let start = Instant::now();
+    let method = req.method().to_string();
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| "unmatched".to_string());
+    let result = next.call(req).await;
+    let status = match &result {
+        Ok(response) => response.status(),
+        Err(error) => error.as_response_error().status_code(),
+    };
+    METRICS
+        .http_requests
+        .with_label_values(&[method.as_str(), route.as_str(), status.as_str()])
+        .inc();
+    METRICS
+        .http_request_duration
+        .with_label_values(&[method.as_str(), route.as_str()])
+        .observe(start.elapsed().as_secs_f64());
+    result
+}
+
+/// Render all metrics in the Prometheus text format.
+///
+/// The database pool metrics are sampled right before, as they are gauges
+/// of the current state.
+pub fn render_metrics(db_pool: &MySqlPool) -> Result<String, anyhow::Error> {
+    let size = db_pool.size() as i64;
+    let idle = db_pool.num_idle() as i64;
+    let max = db_pool.options().get_max_connections() as i64;
+    METRICS
+        .database_connections
+        .with_label_values(&["active"])
+        .set(size - idle);
+    METRICS
+        .database_connections
+        .with_label_values(&["idle"])
+        .set(idle);
+    METRICS
+        .database_connections
+        .with_label_values(&["max"])
+        .set(max);
+
+    let mut buffer = Vec::new();
+    TextEncoder::new().encode(&METRICS.registry.gather(), &mut buffer)?;
+    Ok(String::from_utf8(buffer)?)
+}
@@ -10,6 +10,8 @@
 //!
 //! For development, these and other the OpenStack API specifications might be useful:
 //! * [Nova Compute API](https://docs.openstack.org/api-ref/compute/)
+//! * [Cinder Block Storage API](https://docs.openstack.org/api-ref/block-storage/v3/)
+//! * [Neutron Networking API](https://docs.openstack.org/api-ref/network/v2/)
 //! * [Keystone Identity API](https://docs.openstack.org/api-ref/compute/)
 //!
 //! Note, that except from most other modules, in this one "user" and "project"
@@ -17,18 +19,62 @@
 //! project and projects, where an "OpenStack user/project" is roughly equivalent to an
 //! "LRZ user", and an "OpenStack domain" is roughly equivalent to an "LRZ project".
 
-use std::{collections::HashMap, time::Instant};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
 use anyhow::Context;
-use jzon::object;
+use avina_wire::accounting::FloatingIpPool;
+use chrono::{DateTime, SecondsFormat, TimeDelta, Utc};
+use jzon::{JsonValue, object};
 use reqwest::{
     ClientBuilder,
     header::{CONTENT_TYPE, HeaderMap, HeaderValue},
 };
+use secrecy::ExposeSecret;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
-use crate::configuration::OpenStackSettings;
+use crate::{configuration::OpenStackSettings, metrics::OpenStackCall};
+
+/// Number of resources requested per page of Nova lists.
+///
+/// Nova caps this to its configured `max_limit`, in which case the lists are
+/// still followed to the end via their next links.
+const NOVA_PAGE_SIZE: u32 = 1000;
+
+/// Nova microversion, from which detailed flavors include their extra specs.
+const NOVA_EXTRA_SPECS_MICROVERSION: &str = "2.61";
+
+/// Number of resources requested per page of Cinder lists.
+///
+/// Like Nova, Cinder caps this to its configured `osapi_max_limit`.
+const CINDER_PAGE_SIZE: u32 = 1000;
+
+/// Number of resources requested per page of Neutron lists.
+///
+/// Neutron caps this to its configured `pagination_max_limit`.
+const NEUTRON_PAGE_SIZE: u32 = 1000;
+
+/// Validity assumed for admin tokens, when Keystone does not tell their expiry.
+const DEFAULT_TOKEN_VALIDITY: TimeDelta = TimeDelta::hours(1);
+
+/// Number of attempts to issue a new admin token before renewal fails.
+const TOKEN_RENEWAL_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry to issue a new admin token.
+///
+/// The delay is doubled for every further retry.
+const TOKEN_RENEWAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Error returned when the OpenStack admin token could not be renewed.
+///
+/// This is kept as distinct type, so that it can be told apart from other
+/// errors of the OpenStack functions, e.g., by the authentication middleware.
+#[derive(thiserror::Error, Debug)]
+#[error("Failed to renew OpenStack admin token")]
+pub struct TokenRenewalError(#[source] anyhow::Error);
 
 /// Wrapper of an OpenStack Keystone token.
 ///
@@ -38,39 +84,64 @@ struct Token {
     settings: OpenStackSettings,
     /// The actual OpenStack Keystone token.
     token: String,
-    /// Timestamp of the last renewal of the token.
-    renewed_at: Instant,
+    /// Time at which the token expires.
+    expires_at: DateTime<Utc>,
 }
 
 impl Token {
     /// Issue and wrap a new Keystone token for the configured [OpenStackSettings].
     ///
-    /// This already issues a new token with [issue_token] and initializes the timestamp for renewal
-    /// to the current time.
+    /// This already issues a new token with [issue_token].
     async fn new(settings: &OpenStackSettings) -> Result<Self, anyhow::Error> {
+        let (token, expires_at) = issue_token(settings).await?;
         Ok(Self {
             settings: settings.clone(),
-            token: issue_token(settings).await?,
-            renewed_at: Instant::now(),
+            token,
+            expires_at,
         })
     }
 
     /// Renew the wrapped Keystone token.
     ///
-    /// This reuses [issue_token] and updates the timestamp.
+    /// This reuses [issue_token] and retries it up to [TOKEN_RENEWAL_ATTEMPTS]
+    /// times with exponential backoff starting at [TOKEN_RENEWAL_BACKOFF],
+    /// so that short Keystone outages are bridged.
     async fn renew(&mut self) -> Result<(), anyhow::Error> {
-        self.token = issue_token(&self.settings).await?;
-        self.renewed_at = Instant::now();
-        Ok(())
+        let mut delay = TOKEN_RENEWAL_BACKOFF;
+        let mut attempt = 1;
+        loop {
+            match issue_token(&self.settings).await {
+                Ok((token, expires_at)) => {
+                    self.token = token;
+                    self.expires_at = expires_at;
+                    return Ok(());
+                }
+                Err(error) if attempt < TOKEN_RENEWAL_ATTEMPTS => {
+                    tracing::warn!(
+                        "Failed to renew admin token in attempt {attempt}, \
+                        retrying in {delay:?}: {error:#}"
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                    attempt += 1;
+                }
+                Err(error) => {
+                    return Err(error.context(format!(
+                        "Failed to renew admin token in {attempt} attempts"
+                    )));
+                }
+            }
+        }
     }
 
     /// Check if the token is expired.
     ///
-    /// By default, Keystone issues tokens with a 24 hour validity. To ensure the
-    /// token is always fresh, however, this function considers tokens older than
-    /// an hour expired.
+    /// To ensure the token is always fresh, this already considers tokens
+    /// expired, that expire within the configured renewal margin.
     fn is_expired(&self) -> bool {
-        self.renewed_at.elapsed().as_secs() > 3600
+        let margin =
+            TimeDelta::seconds(self.settings.token_renewal_margin as i64);
+        Utc::now() + margin >= self.expires_at
     }
 
     /// Get the wrapped Keystone token as cloned [String]
@@ -95,12 +166,21 @@ impl TokenHandler {
 
     /// Get the current token as [String].
     ///
-    /// This automatically renews the wrapped [Token] when expired.
-    async fn get(&self) -> String {
-        if self.token.read().await.is_expired() {
-            self.token.write().await.renew().await.unwrap();
+    /// This automatically renews the wrapped [Token] when expired. Concurrent
+    /// calls wait for a single renewal instead of renewing the token each.
+    async fn get(&self) -> Result<String, TokenRenewalError> {
+        {
+            let token = self.token.read().await;
+            if !token.is_expired() {
+                return Ok(token.get());
+            }
+        }
+        let mut token = self.token.write().await;
+        // another call may have renewed the token while waiting for the lock
+        if token.is_expired() {
+            token.renew().await.map_err(TokenRenewalError)?;
         }
-        self.token.read().await.get()
+        Ok(token.get())
     }
 }
 
@@ -125,6 +205,58 @@ pub struct ProjectMinimal {
     pub name: String,
 }
 
+/// Minimal OpenStack API representation of a user.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct UserMinimal {
+    /// OpenStack user UUID (without dashes).
+    pub id: String,
+    /// OpenStack user name.
+    pub name: String,
+}
+
+/// Information on a validated user token as returned by Keystone.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct ValidatedToken {
+    /// The project the token is scoped to.
+    pub project: ProjectMinimal,
+    /// The user the token was issued to.
+    pub user: Option<UserMinimal>,
+    /// Timestamp the token was issued at.
+    pub issued_at: Option<DateTime<Utc>>,
+    /// Timestamp the token expires at.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Audit IDs of the token, the first one identifying the token itself
+    /// and the optional second one the chain of tokens it was rescoped from.
+    #[serde(default)]
+    pub audit_ids: Vec<String>,
+}
+
+/// Keystone revocation event, revoking all tokens matching its attributes.
+///
+/// Attributes not set in the event match any token. Only the attributes
+/// needed to match the tokens cached by `avina-api` are deserialized, any
+/// other attribute is thus handled like a wildcard.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct RevocationEvent {
+    /// Audit ID of the revoked token.
+    pub audit_id: Option<String>,
+    /// Audit ID of the chain of revoked tokens.
+    pub audit_chain_id: Option<String>,
+    /// OpenStack UUID of the user whose tokens are revoked.
+    pub user_id: Option<String>,
+    /// OpenStack UUID of the project whose tokens are revoked.
+    pub project_id: Option<String>,
+    /// Only tokens issued before this timestamp are revoked.
+    pub issued_before: DateTime<Utc>,
+}
+
+/// A list of revocation events in the OpenStack API.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct RevocationEventList {
+    /// The list of revocation events.
+    events: Vec<RevocationEvent>,
+}
+
 /// OpenStack API representation of a link (URL) to some API resource.
 #[derive(Clone, Debug, serde::Deserialize)]
 pub struct Link {
@@ -134,6 +266,64 @@ pub struct Link {
     pub rel: String,
 }
 
+/// Links of a collection in the Keystone API.
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+pub struct CollectionLinks {
+    /// API URL to the next page of the collection, if there is one.
+    pub next: Option<String>,
+}
+
+/// A single page of a list of resources in the OpenStack API.
+///
+/// This is used by [OpenStack::get_list] to follow the pagination of Nova
+/// and Keystone lists to their end.
+trait ListPage: serde::de::DeserializeOwned {
+    /// Type of the listed resources.
+    type Item;
+
+    /// Nova microversion to request the list in, the default if not set.
+    const NOVA_MICROVERSION: Option<&str> = None;
+
+    /// Get the URL of the next page, if any, given the URL of the first one.
+    fn next_url(&self, url: &str) -> Result<Option<String>, anyhow::Error>;
+
+    /// Get the resources on this page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// Get the URL of the next page of a Nova list.
+///
+/// Nova only links a next page when it limited the page, in which case the
+/// next page starts after the marker, i.e., the last resource of this page.
+/// Cinder and Neutron paginate their lists the same way.
+fn nova_next_url(
+    url: &str,
+    links: &[Link],
+    marker: Option<String>,
+) -> Result<Option<String>, anyhow::Error> {
+    if !links.iter().any(|link| link.rel == "next") {
+        return Ok(None);
+    }
+    let Some(marker) = marker else {
+        return Err(anyhow::anyhow!("Nova linked a next page to an empty one"));
+    };
+    Ok(Some(format!("{url}&marker={marker}")))
+}
+
+/// Get the URL of the next page of a Keystone list.
+///
+/// Keystone cuts lists longer than its list limit, only telling so by the
+/// truncated flag, which is treated as error instead of missing resources.
+fn keystone_next_url(
+    links: &CollectionLinks,
+    truncated: bool,
+) -> Result<Option<String>, anyhow::Error> {
+    if truncated {
+        return Err(anyhow::anyhow!("Keystone truncated the list"));
+    }
+    Ok(links.next.clone())
+}
+
 /// Detailed representation of a flavor in the OpenStack API.
 #[derive(Clone, Debug, serde::Deserialize)]
 #[allow(unused)]
@@ -165,8 +355,47 @@ pub struct FlavorDetailed {
     pub rxtx_factor: f32,
     /// Description of the flavor.
     pub description: Option<String>,
-    // TODO: this is a more complicated field.
-    // "extra_specs": {}
+    /// Extra specs of the flavor, e.g., the requested PCI devices.
+    ///
+    /// These are only included from [NOVA_EXTRA_SPECS_MICROVERSION] on.
+    #[serde(default)]
+    pub extra_specs: HashMap<String, String>,
+}
+
+impl FlavorDetailed {
+    /// Get the amount of memory in GiB, as Nova gives it in MiB.
+    pub fn ram_gib(&self) -> f64 {
+        self.ram as f64 / 1024.
+    }
+
+    /// Get the number of GPUs from the extra specs.
+    ///
+    /// This counts virtual and physical GPUs requested as `resources:VGPU`
+    /// and `resources:PGPU`, and devices passed through via
+    /// `pci_passthrough:alias`, whose value is a comma-separated list of
+    /// `<alias>:<count>`, if their alias is one of the given GPU aliases.
+    pub fn gpus(&self, gpu_aliases: &[String]) -> u32 {
+        let resources = ["resources:VGPU", "resources:PGPU"]
+            .iter()
+            .filter_map(|key| self.extra_specs.get(*key))
+            .filter_map(|count| count.trim().parse::<u32>().ok())
+            .sum::<u32>();
+        let passed_through = self
+            .extra_specs
+            .get("pci_passthrough:alias")
+            .map(|aliases| {
+                aliases
+                    .split(',')
+                    .filter_map(|alias| alias.rsplit_once(':'))
+                    .filter(|(alias, _)| {
+                        gpu_aliases.iter().any(|gpu| gpu == alias.trim())
+                    })
+                    .filter_map(|(_, count)| count.trim().parse::<u32>().ok())
+                    .sum()
+            })
+            .unwrap_or(0);
+        resources + passed_through
+    }
 }
 
 /// A list of detailed flavors in the OpenStack API.
@@ -174,6 +403,9 @@ pub struct FlavorDetailed {
 pub struct FlavorDetailedList {
     /// The list of flavors.
     flavors: Vec<FlavorDetailed>,
+    /// Links to further pages of the list.
+    #[serde(default)]
+    flavors_links: Vec<Link>,
 }
 
 /// A flavor in a [ServerDetailed] struct in the OpenStack API.
@@ -324,6 +556,80 @@ pub struct ServerDetailed {
 pub struct ServerDetailedList {
     /// The list of servers.
     servers: Vec<ServerDetailed>,
+    /// Links to further pages of the list.
+    #[serde(default)]
+    servers_links: Vec<Link>,
+}
+
+/// A detailed volume representation in the OpenStack API.
+// TODO: there are many missing fields here.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[allow(unused)]
+pub struct VolumeDetailed {
+    /// UUID of the volume.
+    pub id: Uuid,
+    /// Name of the volume, which is optional in Cinder.
+    pub name: Option<String>,
+    /// Description of the volume.
+    pub description: Option<String>,
+    /// Status of the volume (available, in-use, ...).
+    pub status: String,
+    /// Size of the volume in GiB.
+    pub size: u32,
+    /// UUID of the project owning the volume.
+    #[serde(rename = "os-vol-tenant-attr:tenant_id")]
+    pub tenant_id: String,
+    /// UUID of the user that created the volume.
+    pub user_id: String,
+    /// Name of the volume type.
+    pub volume_type: Option<String>,
+    /// Whether the volume is bootable, as string.
+    pub bootable: Option<String>,
+    /// Timestamp of creation of the volume.
+    // TODO: this is actually a datetime
+    pub created_at: String,
+}
+
+/// A detailed list of volumes in the OpenStack API.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct VolumeDetailedList {
+    /// The list of volumes.
+    volumes: Vec<VolumeDetailed>,
+    /// Links to further pages of the list.
+    #[serde(default)]
+    volumes_links: Vec<Link>,
+}
+
+/// A floating IP representation in the OpenStack API.
+#[derive(Clone, Debug, serde::Deserialize)]
+#[allow(unused)]
+pub struct FloatingIp {
+    /// UUID of the floating IP.
+    pub id: Uuid,
+    /// The floating IP address.
+    pub floating_ip_address: String,
+    /// UUID of the external network the floating IP is allocated from.
+    pub floating_network_id: String,
+    /// UUID of the project owning the floating IP.
+    pub project_id: String,
+    /// Status of the floating IP (ACTIVE, DOWN, ...).
+    pub status: String,
+    /// UUID of the port the floating IP is associated with, if any.
+    pub port_id: Option<String>,
+    /// Fixed IP address the floating IP is associated with, if any.
+    pub fixed_ip_address: Option<String>,
+    /// Description of the floating IP.
+    pub description: Option<String>,
+}
+
+/// A list of floating IPs in the OpenStack API.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct FloatingIpList {
+    /// The list of floating IPs.
+    floatingips: Vec<FloatingIp>,
+    /// Links to further pages of the list.
+    #[serde(default)]
+    floatingips_links: Vec<Link>,
 }
 
 /// Representation of a project domain in the OpenStack API.
@@ -345,6 +651,12 @@ pub struct Domain {
 pub struct DomainList {
     /// The list of domains.
     domains: Vec<Domain>,
+    /// Links to other pages of the list.
+    #[serde(default)]
+    links: CollectionLinks,
+    /// Whether the list was truncated to Keystone's list limit.
+    #[serde(default)]
+    truncated: bool,
 }
 
 /// Representation of a project in the OpenStack API.
@@ -375,6 +687,93 @@ pub struct Project {
 pub struct ProjectList {
     /// The project list.
     projects: Vec<Project>,
+    /// Links to other pages of the list.
+    #[serde(default)]
+    links: CollectionLinks,
+    /// Whether the list was truncated to Keystone's list limit.
+    #[serde(default)]
+    truncated: bool,
+}
+
+impl ListPage for FlavorDetailedList {
+    type Item = FlavorDetailed;
+
+    const NOVA_MICROVERSION: Option<&str> = Some(NOVA_EXTRA_SPECS_MICROVERSION);
+
+    fn next_url(&self, url: &str) -> Result<Option<String>, anyhow::Error> {
+        let marker = self.flavors.last().map(|flavor| flavor.id.clone());
+        nova_next_url(url, &self.flavors_links, marker)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.flavors
+    }
+}
+
+impl ListPage for ServerDetailedList {
+    type Item = ServerDetailed;
+
+    fn next_url(&self, url: &str) -> Result<Option<String>, anyhow::Error> {
+        let marker = self.servers.last().map(|server| server.id.to_string());
+        nova_next_url(url, &self.servers_links, marker)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.servers
+    }
+}
+
+impl ListPage for VolumeDetailedList {
+    type Item = VolumeDetailed;
+
+    fn next_url(&self, url: &str) -> Result<Option<String>, anyhow::Error> {
+        let marker = self.volumes.last().map(|volume| volume.id.to_string());
+        nova_next_url(url, &self.volumes_links, marker)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.volumes
+    }
+}
+
+impl ListPage for FloatingIpList {
+    type Item = FloatingIp;
+
+    fn next_url(&self, url: &str) -> Result<Option<String>, anyhow::Error> {
+        let marker = self
+            .floatingips
+            .last()
+            .map(|floating_ip| floating_ip.id.to_string());
+        nova_next_url(url, &self.floatingips_links, marker)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.floatingips
+    }
+}
+
+impl ListPage for DomainList {
+    type Item = Domain;
+
+    fn next_url(&self, _url: &str) -> Result<Option<String>, anyhow::Error> {
+        keystone_next_url(&self.links, self.truncated)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.domains
+    }
+}
+
+impl ListPage for ProjectList {
+    type Item = Project;
+
+    fn next_url(&self, _url: &str) -> Result<Option<String>, anyhow::Error> {
+        keystone_next_url(&self.links, self.truncated)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.projects
+    }
 }
 
 impl OpenStack {
@@ -391,6 +790,11 @@ impl OpenStack {
         })
     }
 
+    /// Get the aliases of the passed through PCI devices that are GPUs.
+    pub fn gpu_aliases(&self) -> &[String] {
+        &self.settings.gpu_aliases
+    }
+
     /// Setup a HTTP client with the token in the default headers.
     async fn client(&self) -> Result<reqwest::Client, anyhow::Error> {
         let mut headers = HeaderMap::new();
@@ -398,7 +802,7 @@ impl OpenStack {
             .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         headers.insert(
             "X-Auth-Token",
-            HeaderValue::from_str(self.token.get().await.as_str())
+            HeaderValue::from_str(self.token.get().await?.as_str())
                 .context("Could not create token header")?,
         );
         ClientBuilder::new()
@@ -411,20 +815,18 @@ impl OpenStack {
     ///
     /// The user token is the one to be validated and given to Keystone as "subject token" also via
     /// a header, and needs to be distinguished from the admin token authenticating the validation
-    /// request. When the validation succeeds the project the token belongs to is returned.
+    /// request. When the validation succeeds the information on the token, most importantly the
+    /// project it belongs to, is returned.
     pub async fn validate_user_token(
         &self,
         token: &str,
-    ) -> Result<ProjectMinimal, anyhow::Error> {
-        #[derive(Debug, serde::Deserialize)]
-        struct ValidateResponseToken {
-            project: ProjectMinimal,
-        }
+    ) -> Result<ValidatedToken, anyhow::Error> {
         #[derive(Debug, serde::Deserialize)]
         struct ValidateResponse {
-            token: ValidateResponseToken,
+            token: ValidatedToken,
         }
 
+        let mut call = OpenStackCall::start("validate_user_token");
         let client = self.client().await?;
         let url = format!("{}/auth/tokens/", self.settings.keystone_endpoint);
         let response = client
@@ -439,7 +841,7 @@ impl OpenStack {
                 response.status().as_u16()
             ));
         }
-        let project: ValidateResponse = serde_json::from_str(
+        let validated: ValidateResponse = serde_json::from_str(
             response
                 .text()
                 .await
@@ -447,93 +849,81 @@ impl OpenStack {
                 .as_str(),
         )
         .context("Could not parse response")?;
-        Ok(project.token.project)
+        call.succeeded();
+        Ok(validated.token)
     }
 
-    /// Get a list of all flavors in detailed representation.
-    pub async fn get_flavors(
-        &self,
-    ) -> Result<Vec<FlavorDetailed>, anyhow::Error> {
+    /// Check that Keystone is reachable and accepts the admin token.
+    ///
+    /// This validates the admin token against Keystone, which requires both.
+    pub async fn check_keystone(&self) -> Result<(), anyhow::Error> {
+        let mut call = OpenStackCall::start("check_keystone");
         let client = self.client().await?;
-        let url = format!(
-            "{}/v2.1/flavors/detail?is_public=False",
-            self.settings.nova_endpoint
-        );
+        let url = format!("{}/auth/tokens/", self.settings.keystone_endpoint);
         let response = client
             .get(url.as_str())
+            .header("X-Subject-Token", self.token.get().await?)
             .send()
             .await
-            .context("Could not retrieve flavor list")?;
+            .context("Could not reach Keystone")?;
         if !response.status().is_success() {
             return Err(anyhow::anyhow!(
-                "Failed to validate user token, returned code {}",
+                "Failed to validate admin token, returned code {}",
                 response.status().as_u16()
             ));
         }
-        let flavors: FlavorDetailedList = serde_json::from_str(
-            response
-                .text()
-                .await
-                .context("Could not read response text")?
-                .as_str(),
-        )
-        .context("Could not parse response")?;
-        Ok(flavors.flavors)
+        call.succeeded();
+        Ok(())
     }
 
-    /// Get a list of all servers in detailed representation.
-    pub async fn get_servers(
-        &self,
-    ) -> Result<Vec<ServerDetailed>, anyhow::Error> {
+    /// Check that Nova is reachable and accepts the admin token.
+    ///
+    /// This lists a single flavor, which is about the cheapest authenticated
+    /// request Nova offers.
+    pub async fn check_nova(&self) -> Result<(), anyhow::Error> {
+        let mut call = OpenStackCall::start("check_nova");
         let client = self.client().await?;
-        let url = format!(
-            "{}/v2.1/servers/detail?all_tenants=True",
-            self.settings.nova_endpoint
-        );
+        let url =
+            format!("{}/v2.1/flavors?limit=1", self.settings.nova_endpoint);
         let response = client
             .get(url.as_str())
             .send()
             .await
-            .context("Could not retrieve server list")?;
+            .context("Could not reach Nova")?;
         if !response.status().is_success() {
             return Err(anyhow::anyhow!(
-                "Failed to validate user token, returned code {}",
+                "Failed to list flavors, returned code {}",
                 response.status().as_u16()
             ));
         }
-        let servers: ServerDetailedList = serde_json::from_str(
-            response
-                .text()
-                .await
-                .context("Could not read response text")?
-                .as_str(),
-        )
-        .context("Could not parse response")?;
-        Ok(servers.servers)
+        call.succeeded();
+        Ok(())
     }
 
-    /// Get a list of servers in detailed representation for the given project.
-    pub async fn get_servers_of_project(
+    /// Get a list of all token revocation events since the given timestamp.
+    pub async fn get_revocation_events(
         &self,
-        project_id: String,
-    ) -> Result<Vec<ServerDetailed>, anyhow::Error> {
+        since: DateTime<Utc>,
+    ) -> Result<Vec<RevocationEvent>, anyhow::Error> {
+        let mut call = OpenStackCall::start("get_revocation_events");
         let client = self.client().await?;
         let url = format!(
-            "{}/v2.1/servers/detail?all_tenants=True&tenant_id={}",
-            self.settings.nova_endpoint, project_id,
+            "{}/OS-REVOKE/events?since={}",
+            self.settings.keystone_endpoint,
+            since.to_rfc3339_opts(SecondsFormat::Secs, true)
         );
         let response = client
             .get(url.as_str())
             .send()
             .await
-            .context("Could not retrieve server list")?;
+            .context("Could not retrieve revocation event list")?;
         if !response.status().is_success() {
             return Err(anyhow::anyhow!(
-                "Failed to validate user token, returned code {}",
+                "Failed to retrieve revocation events, returned code {}",
                 response.status().as_u16()
             ));
         }
-        let servers: ServerDetailedList = serde_json::from_str(
+        let events: RevocationEventList = serde_json::from_str(
             response
                 .text()
                 .await
@@ -541,86 +931,227 @@ impl OpenStack {
                 .as_str(),
         )
         .context("Could not parse response")?;
-        Ok(servers.servers)
+        call.succeeded();
+        Ok(events.events)
+    }
+
+    /// Get all resources of a list, following its pagination to the end.
+    ///
+    /// Any page failing to be retrieved fails the whole list, as callers
+    /// like the imports must not mistake a partial list for the full one.
+    async fn get_list<P: ListPage>(
+        &self,
+        url: String,
+        name: &str,
+    ) -> Result<Vec<P::Item>, anyhow::Error> {
+        let client = self.client().await?;
+        let mut items = Vec::new();
+        let mut visited = HashSet::new();
+        let mut next = Some(url.clone());
+        while let Some(page_url) = next {
+            if !visited.insert(page_url.clone()) {
+                return Err(anyhow::anyhow!(
+                    "Pagination of {name} list does not advance"
+                ));
+            }
+            let mut request = client.get(page_url.as_str());
+            if let Some(microversion) = P::NOVA_MICROVERSION {
+                request = request
+                    .header("X-OpenStack-Nova-API-Version", microversion);
+            }
+            let response = request
+                .send()
+                .await
+                .context(format!("Could not retrieve {name} list"))?;
+            if !response.status().is_success() {
+                return Err(anyhow::anyhow!(
+                    "Failed to retrieve {name} list, returned code {}",
+                    response.status().as_u16()
+                ));
+            }
+            let page: P = serde_json::from_str(
+                response
+                    .text()
+                    .await
+                    .context("Could not read response text")?
+                    .as_str(),
+            )
+            .context("Could not parse response")?;
+            next = page
+                .next_url(url.as_str())
+                .context(format!("Could not paginate {name} list"))?;
+            items.extend(page.into_items());
+        }
+        Ok(items)
+    }
+
+    /// Get a list of all flavors in detailed representation.
+    pub async fn get_flavors(
+        &self,
+    ) -> Result<Vec<FlavorDetailed>, anyhow::Error> {
+        let mut call = OpenStackCall::start("get_flavors");
+        let url = format!(
+            "{}/v2.1/flavors/detail?is_public=False&limit={NOVA_PAGE_SIZE}",
+            self.settings.nova_endpoint
+        );
+        let flavors =
+            self.get_list::<FlavorDetailedList>(url, "flavor").await?;
+        call.succeeded();
+        Ok(flavors)
+    }
+
+    /// Get a list of all servers in detailed representation.
+    pub async fn get_servers(
+        &self,
+    ) -> Result<Vec<ServerDetailed>, anyhow::Error> {
+        let mut call = OpenStackCall::start("get_servers");
+        let url = format!(
+            "{}/v2.1/servers/detail?all_tenants=True&limit={NOVA_PAGE_SIZE}",
+            self.settings.nova_endpoint
+        );
+        let servers =
+            self.get_list::<ServerDetailedList>(url, "server").await?;
+        call.succeeded();
+        Ok(servers)
+    }
+
+    /// Get a list of servers in detailed representation for the given project.
+    pub async fn get_servers_of_project(
+        &self,
+        project_id: String,
+    ) -> Result<Vec<ServerDetailed>, anyhow::Error> {
+        let mut call = OpenStackCall::start("get_servers_of_project");
+        let url = format!(
+            "{}/v2.1/servers/detail?all_tenants=True&tenant_id={}&limit={}",
+            self.settings.nova_endpoint, project_id, NOVA_PAGE_SIZE,
+        );
+        let servers =
+            self.get_list::<ServerDetailedList>(url, "server").await?;
+        call.succeeded();
+        Ok(servers)
+    }
+
+    /// Get a list of all volumes of all projects in detailed representation.
+    pub async fn get_volumes(
+        &self,
+    ) -> Result<Vec<VolumeDetailed>, anyhow::Error> {
+        let mut call = OpenStackCall::start("get_volumes");
+        let url = format!(
+            "{}/v3/{}/volumes/detail?all_tenants=True&limit={CINDER_PAGE_SIZE}",
+            self.settings.cinder_endpoint, self.settings.project_id
+        );
+        let volumes =
+            self.get_list::<VolumeDetailedList>(url, "volume").await?;
+        call.succeeded();
+        Ok(volumes)
+    }
+
+    /// Get a list of all floating IPs of all projects.
+    pub async fn get_floating_ips(
+        &self,
+    ) -> Result<Vec<FloatingIp>, anyhow::Error> {
+        let mut call = OpenStackCall::start("get_floating_ips");
+        let url = format!(
+            "{}/v2.0/floatingips?limit={NEUTRON_PAGE_SIZE}",
+            self.settings.neutron_endpoint
+        );
+        let floating_ips =
+            self.get_list::<FloatingIpList>(url, "floating IP").await?;
+        call.succeeded();
+        Ok(floating_ips)
+    }
+
+    /// Get the pool the given floating IP is allocated from, or [None] if its
+    /// network is neither the configured MWN nor Internet network.
+    pub fn floating_ip_pool(
+        &self,
+        floating_ip: &FloatingIp,
+    ) -> Option<FloatingIpPool> {
+        if floating_ip.floating_network_id == self.settings.mwn_network_id {
+            Some(FloatingIpPool::Mwn)
+        } else if floating_ip.floating_network_id
+            == self.settings.internet_network_id
+        {
+            Some(FloatingIpPool::Internet)
+        } else {
+            None
+        }
     }
 
     /// Get a list of all domains (e.g., LRZ projects.)
     pub async fn get_domains(&self) -> Result<Vec<Domain>, anyhow::Error> {
-        let client = self.client().await?;
+        let mut call = OpenStackCall::start("get_domains");
         let url = format!("{}/domains", self.settings.keystone_endpoint);
-        let response = client
-            .get(url.as_str())
-            .send()
-            .await
-            .context("Could not retrieve domain list")?;
-        if !response.status().is_success() {
-            return Err(anyhow::anyhow!(
-                "Failed to validate user token, returned code {}",
-                response.status().as_u16()
-            ));
-        }
-        let domains: DomainList = serde_json::from_str(
-            response
-                .text()
-                .await
-                .context("Could not read response text")?
-                .as_str(),
-        )
-        .context("Could not parse response")?;
-        Ok(domains.domains)
+        let domains = self.get_list::<DomainList>(url, "domain").await?;
+        call.succeeded();
+        Ok(domains)
     }
 
     /// Get a list of all projects (e.g., LRZ users).
     pub async fn get_projects(&self) -> Result<Vec<Project>, anyhow::Error> {
-        let client = self.client().await?;
+        let mut call = OpenStackCall::start("get_projects");
         let url = format!("{}/projects", self.settings.keystone_endpoint);
-        let response = client
-            .get(url.as_str())
-            .send()
-            .await
-            .context("Could not retrieve project list")?;
-        if !response.status().is_success() {
-            return Err(anyhow::anyhow!(
-                "Failed to validate user token, returned code {}",
-                response.status().as_u16()
-            ));
-        }
-        let projects: ProjectList = serde_json::from_str(
-            response
-                .text()
-                .await
-                .context("Could not read response text")?
-                .as_str(),
-        )
-        .context("Could not parse response")?;
-        Ok(projects.projects)
+        let projects = self.get_list::<ProjectList>(url, "project").await?;
+        call.succeeded();
+        Ok(projects)
     }
 }
 
-/// Issue a new authentication token from the given [OpenStackSettings].
+/// Build the data of a token request from the given [OpenStackSettings].
 ///
-/// This function is kept separate from the [OpenStack] implementation, as it
-/// is necessary for its creation.
-#[tracing::instrument(name = "Issue an OpenStack token", skip(settings))]
-pub async fn issue_token(
-    settings: &OpenStackSettings,
-) -> Result<String, anyhow::Error> {
-    let mut headers = HeaderMap::new();
-    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-    let client = ClientBuilder::new()
-        .default_headers(headers)
-        .build()
-        .unwrap();
-    let url = format!("{}/auth/tokens/", settings.keystone_endpoint);
-    let data = object! {
+/// When an application credential is configured, it is used instead of the
+/// password. Tokens issued for application credentials are always scoped to
+/// the project the credential belongs to, so no scope is given for them.
+fn auth_data(settings: &OpenStackSettings) -> Result<JsonValue, anyhow::Error> {
+    if let Some(secret) = &settings.application_credential_secret {
+        let mut credential = object! {
+            "secret": secret.expose_secret(),
+        };
+        match (
+            &settings.application_credential_id,
+            &settings.application_credential_name,
+            &settings.username,
+        ) {
+            (Some(id), _, _) => credential["id"] = id.as_str().into(),
+            (None, Some(name), Some(username)) => {
+                credential["name"] = name.as_str().into();
+                credential["user"] = object! {
+                    "name": username.as_str(),
+                    "domain": {"name": settings.domain.clone()},
+                };
+            }
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Application credential requires either ID, or name and \
+                    username"
+                ));
+            }
+        }
+        return Ok(object! {
+            "auth": {
+                "identity": {
+                    "methods": ["application_credential"],
+                    "application_credential": credential,
+                }
+            }
+        });
+    }
+    let (Some(username), Some(password)) =
+        (&settings.username, &settings.password)
+    else {
+        return Err(anyhow::anyhow!(
+            "Password authentication requires username and password"
+        ));
+    };
+    Ok(object! {
         "auth": {
             "identity": {
                 "methods": ["password"],
                 "password": {
                     "user": {
-                        "name": settings.username.clone(),
+                        "name": username.as_str(),
                         "domain": {"name": settings.domain.clone()},
-                        "password": settings.password.clone(),
+                        "password": password.expose_secret(),
                     }
                 }
             },
@@ -631,7 +1162,37 @@ pub async fn issue_token(
                 }
             }
         }
-    };
+    })
+}
+
+/// Issue a new authentication token from the given [OpenStackSettings].
+///
+/// This function is kept separate from the [OpenStack] implementation, as it
+/// is necessary for its creation. Along with the token, it returns the time
+/// the token expires at, or assumes [DEFAULT_TOKEN_VALIDITY] if Keystone's
+/// response does not tell.
+#[tracing::instrument(name = "Issue an OpenStack token", skip(settings))]
+pub async fn issue_token(
+    settings: &OpenStackSettings,
+) -> Result<(String, DateTime<Utc>), anyhow::Error> {
+    #[derive(Debug, serde::Deserialize)]
+    struct IssuedToken {
+        expires_at: DateTime<Utc>,
+    }
+    #[derive(Debug, serde::Deserialize)]
+    struct IssueResponse {
+        token: IssuedToken,
+    }
+
+    let mut call = OpenStackCall::start("issue_token");
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    let client = ClientBuilder::new()
+        .default_headers(headers)
+        .build()
+        .context("Could not create client")?;
+    let url = format!("{}/auth/tokens/", settings.keystone_endpoint);
+    let data = auth_data(settings)?;
     let response = match client
         .post(url.as_str())
         .body(data.to_string())
@@ -654,7 +1215,10 @@ pub async fn issue_token(
         ));
     }
     let token = match response.headers().get("X-Subject-Token") {
-        Some(token) => token.to_str().unwrap().to_string(),
+        Some(token) => token
+            .to_str()
+            .context("Token in authentication response header is invalid")?
+            .to_string(),
         None => {
             return Err(anyhow::anyhow!(
                 "No token in authentication response header"
@@ -663,5 +1227,16 @@ pub async fn issue_token(
     }
     .trim()
     .to_string();
-    Ok(token)
+    let expires_at = match response.json::<IssueResponse>().await {
+        Ok(issued) => issued.token.expires_at,
+        Err(error) => {
+            tracing::warn!(
+                "Assuming default validity, as token expiry could not be \
+                parsed: {error}"
+            );
+            Utc::now() + DEFAULT_TOKEN_VALIDITY
+        }
+    };
+    call.succeeded();
+    Ok((token, expires_at))
 }
@@ -0,0 +1,196 @@
+//! Ordering and pagination of the responses of list endpoints.
+//!
+//! List endpoints parse their `ordering`, `limit` and `offset` parameters
+//! into a [ListQuery], which their select functions apply in SQL via
+//! [select_list]. The selected objects are then returned with [paginate],
+//! which responds with either the plain list or, when a `limit` is given, a
+//! single [Page] of all matching objects.
+
+use actix_web::{HttpRequest, HttpResponse};
+use anyhow::Context;
+use avina_wire::pagination::Page;
+use serde::Serialize;
+use sqlx::{Executor, MySql, QueryBuilder, Row, Transaction, mysql::MySqlRow};
+use utoipa::ToSchema;
+
+use crate::error::{MinimalApiError, UnexpectedOnlyError};
+
+/// Objects that can be ordered by the fields given in an `ordering`.
+pub trait Orderable {
+    /// Get the SQL column to order the objects by for the given field, if the
+    /// objects can be ordered by it.
+    ///
+    /// This acts as whitelist, only the returned columns end up in queries.
+    fn column(field: &str) -> Option<&'static str>;
+}
+
+/// Response of a list endpoint, see [paginate].
+#[derive(Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum Paginated<T> {
+    List(Vec<T>),
+    Page(Page<T>),
+}
+
+/// Ordering and page requested from a list endpoint.
+pub struct ListQuery {
+    /// Columns to order by, each with whether to order descendingly by it.
+    order_by: Vec<(&'static str, bool)>,
+    /// Maximum number of objects to select, all if [None].
+    limit: Option<u32>,
+    /// Number of objects to skip.
+    offset: u32,
+}
+
+impl ListQuery {
+    /// Parse the ordering, limit and offset parameters of a list endpoint.
+    ///
+    /// Each of the comma-separated fields of the ordering may be prefixed
+    /// with `-` to order descendingly by it. Later fields break ties of
+    /// earlier ones, and the objects are finally ordered by their ID, so that
+    /// pages are stable. An unknown field, an offset without a limit or a
+    /// zero limit result in a [MinimalApiError::ValidationError].
+    pub fn new<T: Orderable>(
+        ordering: Option<&str>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Self, MinimalApiError> {
+        match (limit, offset) {
+            (None, Some(_)) => {
+                return Err(MinimalApiError::ValidationError(
+                    "Offset requires a limit".to_string(),
+                ));
+            }
+            (Some(0), _) => {
+                return Err(MinimalApiError::ValidationError(
+                    "Limit must be greater than zero".to_string(),
+                ));
+            }
+            _ => {}
+        }
+        let mut order_by = Vec::new();
+        for field in ordering.into_iter().flat_map(|o| o.split(',')) {
+            let field = field.trim();
+            let (name, descending) = match field.strip_prefix('-') {
+                Some(name) => (name, true),
+                None => (field, false),
+            };
+            let Some(column) = T::column(name) else {
+                return Err(MinimalApiError::ValidationError(format!(
+                    "Cannot order by unknown field \"{name}\""
+                )));
+            };
+            order_by.push((column, descending));
+        }
+        if let Some(id) = T::column("id")
+            && !order_by.iter().any(|(column, _)| *column == id)
+        {
+            order_by.push((id, false));
+        }
+        Ok(Self {
+            order_by,
+            limit,
+            offset: offset.unwrap_or(0),
+        })
+    }
+
+    /// Append the ORDER BY, LIMIT and OFFSET clauses to the given query.
+    fn push_order_by_and_page(&self, query: &mut QueryBuilder<'_, MySql>) {
+        for (i, (column, descending)) in self.order_by.iter().enumerate() {
+            query.push(if i == 0 { " ORDER BY " } else { ", " });
+            query.push(column);
+            if *descending {
+                query.push(" DESC");
+            }
+        }
+        if let Some(limit) = self.limit {
+            query
+                .push(" LIMIT ")
+                .push_bind(limit)
+                .push(" OFFSET ")
+                .push_bind(self.offset);
+        }
+    }
+}
+
+/// Select the objects of a list endpoint, ordered and paged as requested.
+///
+/// The query consists of the given SELECT clause followed by the FROM and
+/// WHERE clauses appended by the given function. When a page is requested,
+/// the latter are reused to count all objects matching the filters. Returns
+/// the selected rows and this count.
+pub async fn select_list(
+    transaction: &mut Transaction<'_, MySql>,
+    select: &str,
+    push_from_where: impl Fn(&mut QueryBuilder<'_, MySql>),
+    list_query: &ListQuery,
+) -> Result<(Vec<MySqlRow>, u32), UnexpectedOnlyError> {
+    let mut query = QueryBuilder::new(select);
+    push_from_where(&mut query);
+    list_query.push_order_by_and_page(&mut query);
+    let rows = transaction
+        .fetch_all(query.build())
+        .await
+        .context("Failed to execute select query")?;
+    let count = if list_query.limit.is_some() {
+        let mut query = QueryBuilder::new("SELECT COUNT(*)");
+        push_from_where(&mut query);
+        let row = transaction
+            .fetch_one(query.build())
+            .await
+            .context("Failed to execute count query")?;
+        row.try_get::<i64, _>(0).context("Failed to parse count")? as u32
+    } else {
+        rows.len() as u32
+    };
+    Ok((rows, count))
+}
+
+/// Respond with the given objects, paginated if a limit was requested.
+///
+/// Without a limit, the objects are returned as a plain list, like list
+/// endpoints did before pagination was introduced. Otherwise, a [Page] with
+/// the objects selected for it and the count of all matching objects is
+/// returned, whose `next` and `previous` URLs are derived from the URL of
+/// the request.
+pub fn paginate<T: Serialize>(
+    request: &HttpRequest,
+    objects: Vec<T>,
+    count: u32,
+    list_query: &ListQuery,
+) -> HttpResponse {
+    let response = match list_query.limit {
+        None => Paginated::List(objects),
+        Some(limit) => {
+            let offset = list_query.offset;
+            let next = (offset.saturating_add(limit) < count)
+                .then(|| page_url(request, offset + limit));
+            let previous = (offset > 0)
+                .then(|| page_url(request, offset.saturating_sub(limit)));
+            Paginated::Page(Page {
+                count,
+                next,
+                previous,
+                results: objects,
+            })
+        }
+    };
+    HttpResponse::Ok()
+        .content_type("application/json")
+        .json(response)
+}
+
+/// Get the URL of the request with its offset replaced by the given one.
+fn page_url(request: &HttpRequest, offset: u32) -> String {
+    let mut url = request.full_url();
+    let pairs = url
+        .query_pairs()
+        .filter(|(key, _)| key != "offset")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect::<Vec<_>>();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair("offset", &offset.to_string());
+    url.to_string()
+}
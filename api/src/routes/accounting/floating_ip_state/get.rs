@@ -0,0 +1,64 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    accounting::FloatingIpState, error::ErrorResponse, user::User,
+};
+use sqlx::MySqlPool;
+
+use super::FloatingIpStateIdParam;
+use crate::{
+    authorization::require_master_user_or_return_not_found,
+    database::{
+        accounting::floating_ip_state::select_floating_ip_state_from_db,
+        user::user::select_user_from_db,
+    },
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    get,
+    path = "/{floating_ip_state_id}",
+    tag = "accounting",
+    params(FloatingIpStateIdParam),
+    responses(
+        (status = 200, description = "Requested floating IP state", body = FloatingIpState),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "floating_ip_state_get")]
+pub async fn floating_ip_state_get(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Path<FloatingIpStateIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let floating_ip_state = select_floating_ip_state_from_db(
+        &mut transaction,
+        params.floating_ip_state_id as u64,
+    )
+    .await?;
+    let floating_ip_state_user =
+        select_user_from_db(&mut transaction, floating_ip_state.user as u64)
+            .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    if floating_ip_state.user != user.id {
+        require_master_user_or_return_not_found(
+            &user,
+            floating_ip_state_user.project,
+        )?;
+    }
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(floating_ip_state))
+}
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use actix_web::{
+    HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::{Context, anyhow};
+use avina_wire::{
+    accounting::{FloatingIpPool, FloatingIpStateImport},
+    dry_run::{Change, ChangeAction, ChangeObject, DryRunParams},
+    error::ErrorResponse,
+    user::User,
+};
+use chrono::Utc;
+use sqlx::{MySql, MySqlPool, Transaction};
+use uuid::Uuid;
+
+use crate::{
+    authorization::require_admin_user,
+    database::accounting::floating_ip_state::{
+        NewFloatingIpState, insert_floating_ip_state_into_db,
+        select_unfinished_floating_ip_states_from_db,
+        update_floating_ip_state_end_in_db,
+    },
+    dry_run::{DryRunnable, finish},
+    error::OptionApiError,
+    openstack::{FloatingIp, OpenStack},
+    routes::accounting::server_state::import::{
+        select_users_by_openstack_id_from_db, union_hash_zip,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/import/",
+    tag = "accounting",
+    params(DryRunParams),
+    responses(
+        (status = 200, description = "Summary of the import, with the changes in dry runs", body = DryRunnable<FloatingIpStateImport>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "floating_ip_state_import", skip(openstack))]
+pub async fn floating_ip_state_import(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    openstack: Data<OpenStack>,
+    params: Query<DryRunParams>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let mut changes = Vec::new();
+    let import =
+        import_floating_ip_states(&mut transaction, &openstack, &mut changes)
+            .await?;
+    Ok(finish(transaction, &params, import, changes).await?)
+}
+
+/// Import the current floating IP allocations from Neutron into the database.
+///
+/// This works like the import of server states: new floating IP states are
+/// created for floating IPs without an unfinished state or whose address,
+/// pool or owning user changed, and the unfinished states of floating IPs that
+/// changed or were released are ended. Floating IPs of unknown users, or of
+/// networks that are neither the configured MWN nor Internet network, are
+/// skipped. Every opened and ended state is recorded in the given changes.
+#[tracing::instrument(
+    name = "import_floating_ip_states",
+    skip(transaction, openstack)
+)]
+pub async fn import_floating_ip_states(
+    transaction: &mut Transaction<'_, MySql>,
+    openstack: &OpenStack,
+    changes: &mut Vec<Change>,
+) -> Result<FloatingIpStateImport, OptionApiError> {
+    let floating_ips = openstack
+        .get_floating_ips()
+        .await?
+        .into_iter()
+        .filter_map(|f| match openstack.floating_ip_pool(&f) {
+            Some(pool) => Some((f.id, (f, pool))),
+            None => {
+                tracing::warn!(
+                    "Network {} of floating IP {} is not a known pool, skipping it.",
+                    f.floating_network_id,
+                    f.id
+                );
+                None
+            }
+        })
+        .collect::<HashMap<_, _>>();
+    let states = select_unfinished_floating_ip_states_from_db(transaction)
+        .await?
+        .into_iter()
+        .map(|s| (s.floating_ip_id, s))
+        .collect::<HashMap<_, _>>();
+    let users = select_users_by_openstack_id_from_db(transaction).await?;
+
+    let mut new_state_count = 0;
+    let mut end_state_count = 0;
+
+    for floating_ip_and_state in union_hash_zip(floating_ips, states).values() {
+        match floating_ip_and_state {
+            (Some((floating_ip, pool)), Some(state)) => {
+                let user =
+                    users.get(&floating_ip.project_id).map(|(id, _)| *id);
+                let mut reasons = Vec::new();
+                if floating_ip.floating_ip_address != state.address {
+                    reasons.push(format!(
+                        "address changed from {} to {}",
+                        state.address, floating_ip.floating_ip_address
+                    ));
+                }
+                if *pool != state.pool {
+                    reasons.push(format!(
+                        "pool changed from {} to {}",
+                        state.pool, pool
+                    ));
+                }
+                if user != Some(state.user) {
+                    reasons.push(format!(
+                        "user changed from {} to {}",
+                        state.username, floating_ip.project_id
+                    ));
+                }
+                if !reasons.is_empty() {
+                    let reason = reasons.join(", ");
+                    update_floating_ip_state_end_in_db(
+                        transaction,
+                        state.id as u64,
+                        Utc::now(),
+                    )
+                    .await?;
+                    end_state_count += 1;
+                    changes.push(floating_ip_state_change(
+                        ChangeAction::End,
+                        floating_ip.id,
+                        &reason,
+                    ));
+                    let count = create_floating_ip_state_in_db(
+                        transaction,
+                        floating_ip,
+                        *pool,
+                        user,
+                    )
+                    .await?;
+                    if count > 0 {
+                        changes.push(floating_ip_state_change(
+                            ChangeAction::Create,
+                            floating_ip.id,
+                            &reason,
+                        ));
+                    }
+                    new_state_count += count;
+                }
+            }
+            (Some((floating_ip, pool)), None) => {
+                let user =
+                    users.get(&floating_ip.project_id).map(|(id, _)| *id);
+                let count = create_floating_ip_state_in_db(
+                    transaction,
+                    floating_ip,
+                    *pool,
+                    user,
+                )
+                .await?;
+                if count > 0 {
+                    changes.push(floating_ip_state_change(
+                        ChangeAction::Create,
+                        floating_ip.id,
+                        "floating IP has no unfinished state",
+                    ));
+                }
+                new_state_count += count;
+            }
+            (None, Some(state)) => {
+                update_floating_ip_state_end_in_db(
+                    transaction,
+                    state.id as u64,
+                    Utc::now(),
+                )
+                .await?;
+                end_state_count += 1;
+                changes.push(floating_ip_state_change(
+                    ChangeAction::End,
+                    state.floating_ip_id,
+                    "floating IP was released",
+                ));
+            }
+            (None, None) => {
+                return Err(anyhow!(
+                    "Floating IP state hash map contains invalid none-none pair."
+                )
+                .into());
+            }
+        }
+    }
+
+    Ok(FloatingIpStateImport {
+        new_state_count,
+        end_state_count,
+    })
+}
+
+fn floating_ip_state_change(
+    action: ChangeAction,
+    floating_ip_id: Uuid,
+    reason: &str,
+) -> Change {
+    Change {
+        action,
+        object: ChangeObject::FloatingIpState,
+        name: floating_ip_id.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Create a new floating IP state for the floating IP owned by the given user,
+/// or skip it when the user is unknown, returning the number of created states.
+#[tracing::instrument(
+    name = "create_floating_ip_state_in_db",
+    skip(transaction)
+)]
+async fn create_floating_ip_state_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    floating_ip: &FloatingIp,
+    pool: FloatingIpPool,
+    user: Option<u32>,
+) -> Result<u32, OptionApiError> {
+    let Some(user) = user else {
+        tracing::warn!(
+            "User {} not found, skipping floating IP state creation.",
+            floating_ip.project_id
+        );
+        return Ok(0);
+    };
+    let floating_ip_state = NewFloatingIpState {
+        begin: Utc::now(),
+        end: None,
+        floating_ip_id: floating_ip.id,
+        address: floating_ip.floating_ip_address.clone(),
+        pool,
+        user,
+    };
+    let _ = insert_floating_ip_state_into_db(transaction, &floating_ip_state)
+        .await?;
+    Ok(1)
+}
@@ -0,0 +1,130 @@
+use actix_web::{
+    HttpRequest, HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    accounting::{FloatingIpState, FloatingIpStateListParams},
+    error::ErrorResponse,
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use crate::{
+    authorization::{
+        require_admin_user, require_master_user_or_return_not_found,
+        require_user_or_project_master_or_not_found,
+    },
+    database::{
+        accounting::floating_ip_state::{
+            FloatingIpStateFilter, select_floating_ip_state_list_from_db,
+            select_floating_ip_states_by_floating_ip_from_db,
+        },
+        user::user::select_user_from_db,
+    },
+    error::OptionApiError,
+    pagination::{ListQuery, Paginated, paginate},
+};
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "accounting",
+    params(FloatingIpStateListParams),
+    responses(
+        (status = 200, description = "List of floating IP states", body = Paginated<FloatingIpState>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "floating_ip_state_list")]
+pub async fn floating_ip_state_list(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<FloatingIpStateListParams>,
+    req: HttpRequest,
+) -> Result<HttpResponse, OptionApiError> {
+    let list_query = ListQuery::new::<FloatingIpState>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let filter = if params.all.unwrap_or(false) {
+        require_admin_user(&user)?;
+        FloatingIpStateFilter {
+            floating_ip: params.floating_ip,
+            pool: params.pool,
+            ..Default::default()
+        }
+    } else if let Some(project_id) = params.project {
+        require_master_user_or_return_not_found(&user, project_id)?;
+        FloatingIpStateFilter {
+            project: Some(project_id as u64),
+            floating_ip: params.floating_ip,
+            pool: params.pool,
+            ..Default::default()
+        }
+    } else if let Some(user_id) = params.user {
+        let user1 = select_user_from_db(&mut transaction, user_id as u64)
+            .await
+            .context("Failed to select user")?;
+        require_user_or_project_master_or_not_found(
+            &user,
+            user1.id,
+            user1.project,
+        )?;
+        FloatingIpStateFilter {
+            user: Some(user1.id as u64),
+            floating_ip: params.floating_ip,
+            pool: params.pool,
+            ..Default::default()
+        }
+    } else if let Some(floating_ip_id) = params.floating_ip {
+        let floating_ip_states =
+            select_floating_ip_states_by_floating_ip_from_db(
+                &mut transaction,
+                floating_ip_id,
+            )
+            .await?;
+        let Some(floating_ip_state) = floating_ip_states.first() else {
+            return Err(OptionApiError::NotFoundError);
+        };
+        let floating_ip_state_user = select_user_from_db(
+            &mut transaction,
+            floating_ip_state.user as u64,
+        )
+        .await?;
+        require_user_or_project_master_or_not_found(
+            &user,
+            floating_ip_state_user.id,
+            floating_ip_state_user.project,
+        )?;
+        FloatingIpStateFilter {
+            floating_ip: Some(floating_ip_id),
+            pool: params.pool,
+            ..Default::default()
+        }
+    } else {
+        FloatingIpStateFilter {
+            user: Some(user.id as u64),
+            pool: params.pool,
+            ..Default::default()
+        }
+    };
+    let (floating_ip_states, count) = select_floating_ip_state_list_from_db(
+        &mut transaction,
+        &filter,
+        &list_query,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(paginate(&req, floating_ip_states, count, &list_query))
+}
@@ -0,0 +1,47 @@
+//! Endpoints for floating IP states.
+
+use actix_web::{
+    Scope,
+    web::{get, scope},
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
+
+pub mod list;
+use list::floating_ip_state_list;
+pub mod get;
+use get::floating_ip_state_get;
+pub mod import;
+use import::floating_ip_state_import;
+
+/// Routes to floating IP state endpoints.
+///
+///   - `GET /api/accounting/floatingipstates` => [floating_ip_state_list] endpoint
+///   - `GET /api/accounting/floatingipstates/{id}` => [floating_ip_state_get] endpoint
+///   - `GET /api/accounting/floatingipstates/import/` => [floating_ip_state_import] endpoint
+pub fn floating_ip_states_scope() -> Scope {
+    scope("/floatingipstates")
+        .route("", get().to(floating_ip_state_list))
+        .route("/{floating_ip_state_id}", get().to(floating_ip_state_get))
+        .route("/import/", get().to(floating_ip_state_import))
+}
+
+/// OpenAPI documentation of the floating IP state endpoints
+/// routed by [floating_ip_states_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    list::floating_ip_state_list,
+    get::floating_ip_state_get,
+    import::floating_ip_state_import
+))]
+pub struct FloatingIpStatesApiDoc;
+
+/// Wrapper type for the floating IP state ID parameter to user endpoints.
+///
+/// As this is handed to endpoints as [actix_web::web::Path], it should to have a distinguishable type.
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct FloatingIpStateIdParam {
+    /// The wrapped floating IP state ID.
+    floating_ip_state_id: u32,
+}
@@ -1,6 +1,7 @@
-//! Endpoints for server status, consumption and cost.
+//! Endpoints for server, volume and floating IP status, consumption and cost.
 
 use actix_web::{Scope, web::scope};
+use utoipa::OpenApi;
 
 pub mod server_state;
 use server_state::server_states_scope;
@@ -8,6 +9,10 @@ pub mod server_consumption;
 use server_consumption::server_consumption_scope;
 pub mod server_cost;
 use server_cost::server_cost_scope;
+pub mod volume_state;
+use volume_state::volume_states_scope;
+pub mod floating_ip_state;
+use floating_ip_state::floating_ip_states_scope;
 
 /// Main scope for routes server state, consumption and cost endpoints.
 ///
@@ -15,9 +20,28 @@ use server_cost::server_cost_scope;
 ///   - `/api/accounting/serverconsumption` => [server_consumption_scope], see
 ///     [server_consumption] submodule
 ///   - `/api/accounting/servercost` => [server_cost_scope], see [server_cost] submodule
+///   - `/api/accounting/volumestates` => [volume_states_scope], see [volume_state] submodule
+///   - `/api/accounting/floatingipstates` => [floating_ip_states_scope], see
+///     [floating_ip_state] submodule
 pub fn accounting_scope() -> Scope {
     scope("/accounting")
         .service(server_states_scope())
         .service(server_consumption_scope())
         .service(server_cost_scope())
+        .service(volume_states_scope())
+        .service(floating_ip_states_scope())
 }
+
+/// OpenAPI documentation of the accounting endpoints
+/// routed by [accounting_scope].
+#[derive(OpenApi)]
+#[openapi(
+    nest(
+        (path = "/serverstates", api = server_state::ServerStatesApiDoc),
+        (path = "/serverconsumption", api = server_consumption::ServerConsumptionApiDoc),
+        (path = "/servercost", api = server_cost::ServerCostApiDoc),
+        (path = "/volumestates", api = volume_state::VolumeStatesApiDoc),
+        (path = "/floatingipstates", api = floating_ip_state::FloatingIpStatesApiDoc)
+    )
+)]
+pub struct AccountingApiDoc;
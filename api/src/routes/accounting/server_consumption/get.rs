@@ -11,11 +11,14 @@ use avina_wire::{
         ServerConsumptionParams, ServerConsumptionProject,
         ServerConsumptionServer, ServerConsumptionUser, ServerState,
     },
-    user::User,
+    error::ErrorResponse,
+    pricing::BillingPolicy,
+    user::{User, UserClass},
 };
-use chrono::{DateTime, Datelike, TimeZone, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
 use serde::Serialize;
 use sqlx::{MySql, MySqlPool, Transaction};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::{
@@ -28,32 +31,118 @@ use crate::{
             select_ordered_server_states_by_server_begin_and_end_from_db,
             select_ordered_server_states_by_user_begin_and_end_from_db,
             select_server_states_by_server_from_db,
+            select_user_class_by_server_from_db,
+        },
+        pricing::billing_policy::{
+            select_all_billing_policies_from_db,
+            select_billing_policies_for_period_from_db,
         },
         user::{
             project::select_all_projects_from_db,
-            user::{select_user_from_db, select_users_by_project_from_db},
+            user::{
+                select_user_class_by_user_from_db, select_user_from_db,
+                select_users_by_project_from_db,
+            },
         },
     },
     error::{OptionApiError, UnexpectedOnlyError},
 };
 
-const CONSUMING_STATES: [&str; 15] = [
-    "ACTIVE",
-    "BUILD",
-    "HARD_REBOOT",
-    "MIGRATING",
-    "PASSWORD",
-    "PAUSED",
-    "REBOOT",
-    "REBUILD",
-    "RESCUE",
-    "RESIZE",
-    "REVERT_RESIZE",
-    "SHUTOFF",
-    "SUSPENDED",
-    "UNKNOWN",
-    "VERIFY_RESIZE",
-];
+/// Billing policies applying to the servers of a user class.
+///
+/// The runtime of a server state is billable in the periods in which a policy
+/// for its status is valid, weighted by the factor of that policy, see
+/// [BillingRates::billable_seconds]. Policies for the user class take
+/// precedence over those for all user classes.
+pub struct BillingRates {
+    policies: Vec<BillingPolicy>,
+}
+
+impl BillingRates {
+    /// Select the policies applying to the given user class.
+    pub fn new(
+        policies: Vec<BillingPolicy>,
+        user_class: Option<UserClass>,
+    ) -> Self {
+        let policies = policies
+            .into_iter()
+            .filter(|policy| {
+                policy.user_class.is_none() || policy.user_class == user_class
+            })
+            .collect();
+        Self { policies }
+    }
+
+    /// Load the policies applying to the given user class during the period.
+    pub async fn load(
+        transaction: &mut Transaction<'_, MySql>,
+        user_class: Option<UserClass>,
+        begin: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Self, UnexpectedOnlyError> {
+        let policies = match (begin, end) {
+            (Some(begin), Some(end)) => {
+                select_billing_policies_for_period_from_db(
+                    transaction,
+                    begin,
+                    end,
+                )
+                .await?
+            }
+            _ => select_all_billing_policies_from_db(transaction).await?,
+        };
+        Ok(Self::new(policies, user_class))
+    }
+
+    /// Get the factor of the status at the given time, 0. if not billable.
+    fn factor_at(&self, status: &str, time: DateTime<FixedOffset>) -> f64 {
+        self.policies
+            .iter()
+            .filter(|policy| {
+                policy.status == status
+                    && policy.start_time <= time
+                    && policy.end_time.is_none_or(|end_time| end_time > time)
+            })
+            .max_by_key(|policy| policy.user_class.is_some())
+            .map_or(0., |policy| policy.factor)
+    }
+
+    /// Get the times at which any of the policies starts or ends.
+    pub fn change_times(&self) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        self.policies.iter().flat_map(|policy| {
+            std::iter::once(policy.start_time)
+                .chain(policy.end_time)
+                .map(|time| time.to_utc())
+        })
+    }
+
+    /// Get the billable seconds of a server in the status during the period.
+    ///
+    /// The period is split where policies for the status start or end, and
+    /// the seconds of each part are weighted by the factor valid in it.
+    pub fn billable_seconds(
+        &self,
+        status: &str,
+        begin: DateTime<FixedOffset>,
+        end: DateTime<FixedOffset>,
+    ) -> f64 {
+        let mut times = vec![begin, end];
+        for policy in self.policies.iter().filter(|p| p.status == status) {
+            times.push(policy.start_time);
+            times.extend(policy.end_time);
+        }
+        times.retain(|time| *time >= begin && *time <= end);
+        times.sort();
+        times.dedup();
+        times
+            .windows(2)
+            .map(|pair| {
+                (pair[1] - pair[0]).num_seconds() as f64
+                    * self.factor_at(status, pair[0])
+            })
+            .sum()
+    }
+}
 
 pub async fn calculate_server_consumption_for_server(
     transaction: &mut Transaction<'_, MySql>,
@@ -61,6 +150,7 @@ pub async fn calculate_server_consumption_for_server(
     begin: Option<DateTime<Utc>>,
     end: Option<DateTime<Utc>>,
     states: Option<Vec<ServerState>>,
+    rates: Option<&BillingRates>,
 ) -> Result<ServerConsumptionServer, UnexpectedOnlyError> {
     let mut states = match states {
         Some(states) => states,
@@ -95,21 +185,30 @@ pub async fn calculate_server_consumption_for_server(
     {
         last.end = Some(end.fixed_offset());
     }
-    for state in states {
-        let entry = consumption.entry(state.flavor_name).or_default();
-        if !CONSUMING_STATES.contains(&state.status.as_str()) {
-            continue;
+    let loaded_rates;
+    let rates = match rates {
+        Some(rates) => rates,
+        None => {
+            let user_class =
+                select_user_class_by_server_from_db(transaction, server_uuid)
+                    .await?;
+            loaded_rates =
+                BillingRates::load(transaction, user_class, begin, end).await?;
+            &loaded_rates
         }
-        *entry += (state.end.unwrap() - state.begin).num_seconds() as f64;
+    };
+    for state in states {
+        *consumption.entry(state.flavor_name).or_default() += rates
+            .billable_seconds(&state.status, state.begin, state.end.unwrap());
     }
     // TODO:
     Ok(consumption)
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ServerConsumptionForUser {
-    Normal(ServerConsumptionFlavors),
+    Normal(#[schema(inline)] ServerConsumptionFlavors),
     Detail(ServerConsumptionUser),
 }
 
@@ -136,6 +235,10 @@ pub async fn calculate_server_consumption_for_user(
             .push(state);
     }
 
+    let user_class =
+        select_user_class_by_user_from_db(transaction, user_id).await?;
+    let rates = BillingRates::load(transaction, user_class, begin, end).await?;
+
     let mut consumption = ServerConsumptionUser::default();
     for (server_uuid, server_states) in server_state_map {
         consumption.servers.insert(
@@ -146,6 +249,7 @@ pub async fn calculate_server_consumption_for_user(
                 begin,
                 end,
                 Some(server_states),
+                Some(&rates),
             )
             .await?,
         );
@@ -164,10 +268,10 @@ pub async fn calculate_server_consumption_for_user(
     })
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ServerConsumptionForProject {
-    Normal(ServerConsumptionFlavors),
+    Normal(#[schema(inline)] ServerConsumptionFlavors),
     Detail(ServerConsumptionProject),
 }
 
@@ -212,10 +316,10 @@ pub async fn calculate_server_consumption_for_project(
     })
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ServerConsumptionForAll {
-    Normal(ServerConsumptionFlavors),
+    Normal(#[schema(inline)] ServerConsumptionFlavors),
     Detail(ServerConsumptionAll),
 }
 
@@ -259,15 +363,27 @@ pub async fn calculate_server_consumption_for_all(
     })
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ServerConsumption {
-    Server(ServerConsumptionServer),
+    Server(#[schema(inline)] ServerConsumptionServer),
     User(ServerConsumptionForUser),
     Project(ServerConsumptionForProject),
     All(ServerConsumptionForAll),
 }
 
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "accounting",
+    params(ServerConsumptionParams),
+    responses(
+        (status = 200, description = "Consumption, in detail if requested", body = ServerConsumption),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "server_consumption")]
 pub async fn server_consumption(
     user: ReqData<User>,
@@ -348,6 +464,7 @@ pub async fn server_consumption(
                 Some(begin.into()),
                 Some(end.into()),
                 None,
+                None,
             )
             .await?,
         )
@@ -2,6 +2,7 @@ use actix_web::{
     Scope,
     web::{get, scope},
 };
+use utoipa::OpenApi;
 
 pub(crate) mod get;
 use get::server_consumption;
@@ -9,3 +10,9 @@ use get::server_consumption;
 pub fn server_consumption_scope() -> Scope {
     scope("/serverconsumption").route("/", get().to(server_consumption))
 }
+
+/// OpenAPI documentation of the server consumption endpoints
+/// routed by [server_consumption_scope].
+#[derive(OpenApi)]
+#[openapi(paths(get::server_consumption))]
+pub struct ServerConsumptionApiDoc;
@@ -0,0 +1,157 @@
+//! Cost of the floating IPs, which is added to the server cost of users,
+//! projects and all projects.
+
+use std::collections::HashMap;
+
+use avina_wire::{
+    accounting::{FloatingIpPool, FloatingIpState},
+    pricing::FloatingIpPrice,
+    user::UserClass,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{MySql, Transaction};
+
+use crate::{
+    database::{
+        accounting::floating_ip_state::{
+            select_floating_ip_states_by_project_from_db,
+            select_floating_ip_states_by_user_from_db,
+        },
+        pricing::floating_ip_price::select_floating_ip_prices_for_period_from_db,
+        user::project::select_all_projects_from_db,
+    },
+    error::UnexpectedOnlyError,
+};
+
+/// Floating IP prices valid during a period, by user class and pool.
+pub struct FloatingIpPrices {
+    /// Prices per user class and pool, ordered by their start time.
+    prices: HashMap<(UserClass, FloatingIpPool), Vec<FloatingIpPrice>>,
+}
+
+impl FloatingIpPrices {
+    /// Load the floating IP prices valid during the given period.
+    pub async fn load(
+        transaction: &mut Transaction<'_, MySql>,
+        begin: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Self, UnexpectedOnlyError> {
+        let mut prices = HashMap::<_, Vec<_>>::new();
+        for price in select_floating_ip_prices_for_period_from_db(
+            transaction,
+            begin,
+            end,
+        )
+        .await?
+        .into_iter()
+        .rev()
+        {
+            prices
+                .entry((price.user_class, price.pool))
+                .or_default()
+                .push(price);
+        }
+        Ok(Self { prices })
+    }
+
+    /// Calculate the cost of the floating IP state within the given period.
+    ///
+    /// Each price of the pool valid during the overlap of state and period is
+    /// billed proportionally to the time it was valid. Before the first price
+    /// of the user class and pool, floating IPs are free.
+    pub fn state_cost(
+        &self,
+        state: &FloatingIpState,
+        user_class: UserClass,
+        begin: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> f64 {
+        let Some(prices) = self.prices.get(&(user_class, state.pool)) else {
+            return 0.0;
+        };
+        let state_begin = state.begin.to_utc().max(begin);
+        let state_end = state.end.map_or(end, |e| e.to_utc()).min(end);
+        let mut cost = 0.0;
+        for (i, price) in prices.iter().enumerate() {
+            let price_end = prices
+                .get(i + 1)
+                .map_or(state_end, |next| next.start_time.to_utc());
+            let period_begin = price.start_time.to_utc().max(state_begin);
+            let period_end = price_end.min(state_end);
+            if period_end <= period_begin {
+                continue;
+            }
+            let seconds = (period_end - period_begin).num_seconds() as f64;
+            cost += price.unit_price * seconds / ((365 * 24 * 60 * 60) as f64);
+        }
+        cost
+    }
+}
+
+/// Calculate the floating IP cost of the user with the given ID and user
+/// class.
+pub async fn calculate_floating_ip_cost_for_user(
+    transaction: &mut Transaction<'_, MySql>,
+    user_id: u64,
+    user_class: UserClass,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<f64, UnexpectedOnlyError> {
+    let prices = FloatingIpPrices::load(transaction, begin, end).await?;
+    Ok(
+        select_floating_ip_states_by_user_from_db(transaction, user_id)
+            .await?
+            .iter()
+            .map(|state| prices.state_cost(state, user_class, begin, end))
+            .sum(),
+    )
+}
+
+/// Calculate the floating IP cost of each user of the project with the given
+/// ID and user class, by user name.
+pub async fn calculate_floating_ip_cost_for_project(
+    transaction: &mut Transaction<'_, MySql>,
+    project_id: u64,
+    user_class: UserClass,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<HashMap<String, f64>, UnexpectedOnlyError> {
+    let prices = FloatingIpPrices::load(transaction, begin, end).await?;
+    let mut cost = HashMap::new();
+    for state in
+        select_floating_ip_states_by_project_from_db(transaction, project_id)
+            .await?
+    {
+        *cost.entry(state.username.clone()).or_default() +=
+            prices.state_cost(&state, user_class, begin, end);
+    }
+    Ok(cost)
+}
+
+/// Calculate the floating IP cost of each user of all projects, by project
+/// name and user name.
+pub async fn calculate_floating_ip_cost_for_all(
+    transaction: &mut Transaction<'_, MySql>,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<HashMap<String, HashMap<String, f64>>, UnexpectedOnlyError> {
+    let prices = FloatingIpPrices::load(transaction, begin, end).await?;
+    let mut cost = HashMap::new();
+    for project in select_all_projects_from_db(transaction).await? {
+        let states = select_floating_ip_states_by_project_from_db(
+            transaction,
+            project.id as u64,
+        )
+        .await?;
+        if states.is_empty() {
+            continue;
+        }
+        let project_cost: &mut HashMap<String, f64> =
+            cost.entry(project.name.clone()).or_default();
+        for state in states {
+            *project_cost.entry(state.username.clone()).or_default() +=
+                prices.state_cost(&state, project.user_class, begin, end);
+        }
+    }
+    Ok(cost)
+}
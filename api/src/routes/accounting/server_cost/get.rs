@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, iter};
 
 use actix_web::{
     HttpResponse,
@@ -10,7 +10,8 @@ use avina_wire::{
         ServerCostAll, ServerCostParams, ServerCostProject, ServerCostServer,
         ServerCostSimple, ServerCostUser,
     },
-    pricing::FlavorPrice,
+    error::ErrorResponse,
+    pricing::{FlavorPrice, PriceOverride},
     user::{User, UserClass},
 };
 use chrono::{DateTime, Datelike, TimeZone, Utc};
@@ -18,8 +19,20 @@ use indexmap::IndexMap;
 use serde::Serialize;
 use sqlx::{MySql, MySqlPool, Transaction};
 use strum::IntoEnumIterator;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::{
+    floating_ip::{
+        calculate_floating_ip_cost_for_all,
+        calculate_floating_ip_cost_for_project,
+        calculate_floating_ip_cost_for_user,
+    },
+    volume::{
+        calculate_volume_cost_for_all, calculate_volume_cost_for_project,
+        calculate_volume_cost_for_user,
+    },
+};
 use crate::{
     authorization::{
         require_admin_user, require_master_user_or_return_not_found,
@@ -27,17 +40,24 @@ use crate::{
     },
     database::{
         accounting::server_state::{
+            select_project_id_by_server_from_db,
             select_server_states_by_server_from_db,
             select_user_class_by_server_from_db,
         },
-        pricing::flavor_price::select_flavor_prices_for_period_from_db,
+        pricing::{
+            flavor_price::select_flavor_prices_for_period_from_db,
+            price_override::select_price_overrides_for_period_from_db,
+        },
         resources::flavor::select_all_flavors_from_db,
         user::{
             project::{
                 select_all_projects_from_db,
                 select_user_class_by_project_from_db,
             },
-            user::{select_user_class_by_user_from_db, select_user_from_db},
+            user::{
+                select_maybe_user_from_db, select_user_class_by_user_from_db,
+                select_user_from_db,
+            },
         },
     },
     error::{OptionApiError, UnexpectedOnlyError},
@@ -99,19 +119,71 @@ async fn get_flavor_prices_for_period(
     Ok(prices)
 }
 
-type Prices = HashMap<UserClass, HashMap<String, f64>>;
-type PricePeriods = IndexMap<DateTime<Utc>, Prices>;
+/// Flavor prices valid during a price period.
+#[derive(Clone, Default)]
+pub(crate) struct Prices {
+    /// List prices per user class and flavor name.
+    list: HashMap<UserClass, HashMap<String, f64>>,
+    /// Price overrides valid during the whole period per project ID.
+    overrides: HashMap<u32, Vec<PriceOverride>>,
+}
+
+impl Prices {
+    /// Get the list price of the flavor for the user class.
+    fn list_price(&self, user_class: UserClass, flavor: &str) -> Option<f64> {
+        self.list.get(&user_class)?.get(flavor).copied()
+    }
+
+    /// Get the price of the flavor the project actually pays.
+    ///
+    /// An absolute price for the flavor takes precedence over a discount for
+    /// the flavor, which in turn takes precedence over a discount for all
+    /// flavors. Without any override, the list price of the user class is
+    /// returned.
+    fn price(
+        &self,
+        project: u32,
+        user_class: UserClass,
+        flavor: &str,
+    ) -> Option<f64> {
+        let overrides = self
+            .overrides
+            .get(&project)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let flavor_override = overrides
+            .iter()
+            .find(|o| o.flavor_name.as_deref() == Some(flavor));
+        if let Some(unit_price) = flavor_override.and_then(|o| o.unit_price) {
+            return Some(unit_price);
+        }
+        let list_price = self.list_price(user_class, flavor)?;
+        let discount = flavor_override
+            .or_else(|| overrides.iter().find(|o| o.flavor.is_none()))
+            .and_then(|o| o.discount)
+            .unwrap_or(0.);
+        Some(list_price * (1. - discount / 100.))
+    }
+}
+
+pub(crate) type PricePeriods = IndexMap<DateTime<Utc>, Prices>;
 
-async fn get_flavor_price_periods(
+/// Get the flavor prices for the period, split into the periods in which
+/// they are constant.
+///
+/// The periods start at the given begin and whenever a flavor price changes
+/// or a price override starts or ends.
+pub(crate) async fn get_flavor_price_periods(
     transaction: &mut Transaction<'_, MySql>,
     begin: DateTime<Utc>,
     end: DateTime<Utc>,
 ) -> Result<PricePeriods, UnexpectedOnlyError> {
     let flavors = select_all_flavors_from_db(transaction).await?;
-    let mut current_prices = Prices::new();
+    let mut current_prices = Prices::default();
     for user_class in UserClass::iter() {
         for flavor in flavors.clone() {
             current_prices
+                .list
                 .entry(user_class)
                 .or_default()
                 .entry(flavor.name.clone())
@@ -128,6 +200,7 @@ async fn get_flavor_price_periods(
             break;
         }
         *current_prices
+            .list
             .get_mut(&price.user_class)
             .unwrap()
             .entry(price.flavor_name.clone())
@@ -140,44 +213,157 @@ async fn get_flavor_price_periods(
     let mut current_time = begin;
     periods.insert(current_time, current_prices.clone());
 
-    if i == prices.len() {
-        return Ok(periods);
-    }
-
-    current_time = prices.get(i).unwrap().start_time.to_utc();
-    while i < prices.len() {
-        let price = prices.get(i).unwrap();
-        if price.start_time.to_utc() == current_time {
-            *current_prices
-                .get_mut(&price.user_class)
-                .unwrap()
-                .entry(price.flavor_name.clone())
-                .or_insert(0.0) = price.unit_price;
-            i += 1;
-        } else {
-            periods.insert(current_time, current_prices.clone());
-            current_time = price.start_time.to_utc();
+    if i < prices.len() {
+        current_time = prices.get(i).unwrap().start_time.to_utc();
+        while i < prices.len() {
+            let price = prices.get(i).unwrap();
+            if price.start_time.to_utc() == current_time {
+                *current_prices
+                    .list
+                    .get_mut(&price.user_class)
+                    .unwrap()
+                    .entry(price.flavor_name.clone())
+                    .or_insert(0.0) = price.unit_price;
+                i += 1;
+            } else {
+                periods.insert(current_time, current_prices.clone());
+                current_time = price.start_time.to_utc();
+            }
         }
+        periods.insert(current_time, current_prices.clone());
     }
-    periods.insert(current_time, current_prices.clone());
+
+    add_price_overrides_to_periods(transaction, &mut periods, begin, end)
+        .await?;
 
     Ok(periods)
 }
 
-fn calculate_flavor_consumption_cost(
+/// Add the price overrides valid between begin and end to the price periods.
+///
+/// The periods are split wherever an override starts or ends, so that the
+/// overrides of each period are valid during all of it.
+async fn add_price_overrides_to_periods(
+    transaction: &mut Transaction<'_, MySql>,
+    periods: &mut PricePeriods,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<(), UnexpectedOnlyError> {
+    let overrides =
+        select_price_overrides_for_period_from_db(transaction, begin, end)
+            .await?;
+    let changes = overrides
+        .iter()
+        .flat_map(|o| iter::once(o.start_time).chain(o.end_time))
+        .map(|time| time.to_utc())
+        .filter(|time| *time > begin && *time < end)
+        .collect::<Vec<_>>();
+    for time in changes {
+        if periods.contains_key(&time) {
+            continue;
+        }
+        let prices = periods
+            .iter()
+            .rev()
+            .find(|(start_time, _)| **start_time < time)
+            .map(|(_, prices)| prices.clone())
+            .unwrap_or_default();
+        periods.insert(time, prices);
+        periods.sort_keys();
+    }
+    for (start_time, prices) in periods.iter_mut() {
+        prices.overrides.clear();
+        for price_override in overrides.iter().filter(|o| {
+            o.start_time <= *start_time
+                && o.end_time.is_none_or(|end_time| end_time > *start_time)
+        }) {
+            prices
+                .overrides
+                .entry(price_override.project)
+                .or_default()
+                .push(price_override.clone());
+        }
+    }
+    Ok(())
+}
+
+/// Calculate the cost of the flavor consumption at the prices the project
+/// pays, see [Prices::price].
+pub(crate) fn calculate_flavor_consumption_cost(
     flavor_consumption: f64,
     prices: &Prices,
+    project: u32,
     user_class: UserClass,
     flavor: &str,
 ) -> f64 {
     let mut cost = 0.0;
-    if let Some(price) = prices.get(&user_class).unwrap().get(flavor) {
+    if let Some(price) = prices.price(project, user_class, flavor) {
         cost = (flavor_consumption * price) / ((365 * 24 * 60 * 60) as f64);
     }
     cost
 }
 
-#[derive(Serialize)]
+/// Calculate the discount of the project on the cost of the flavor
+/// consumption, i.e. the cost at list prices minus the actual one.
+pub(crate) fn calculate_flavor_consumption_discount(
+    flavor_consumption: f64,
+    prices: &Prices,
+    project: u32,
+    user_class: UserClass,
+    flavor: &str,
+) -> f64 {
+    let mut list_cost = 0.0;
+    if let Some(price) = prices.list_price(user_class, flavor) {
+        list_cost =
+            (flavor_consumption * price) / ((365 * 24 * 60 * 60) as f64);
+    }
+    list_cost
+        - calculate_flavor_consumption_cost(
+            flavor_consumption,
+            prices,
+            project,
+            user_class,
+            flavor,
+        )
+}
+
+/// Add the volume cost of the user to their entry in the given user costs.
+fn add_volume_cost_to_user(
+    users: &mut HashMap<String, ServerCostUser>,
+    user_name: String,
+    volume_cost: f64,
+) {
+    let user_cost = users.entry(user_name).or_insert(ServerCostUser {
+        total: 0.0,
+        discount: 0.0,
+        flavors: HashMap::new(),
+        volumes: 0.0,
+        floating_ips: 0.0,
+        servers: HashMap::new(),
+    });
+    user_cost.volumes += volume_cost;
+    user_cost.total += volume_cost;
+}
+
+/// Add the floating IP cost of the user to their entry in the given user costs.
+fn add_floating_ip_cost_to_user(
+    users: &mut HashMap<String, ServerCostUser>,
+    user_name: String,
+    floating_ip_cost: f64,
+) {
+    let user_cost = users.entry(user_name).or_insert(ServerCostUser {
+        total: 0.0,
+        discount: 0.0,
+        flavors: HashMap::new(),
+        volumes: 0.0,
+        floating_ips: 0.0,
+        servers: HashMap::new(),
+    });
+    user_cost.floating_ips += floating_ip_cost;
+    user_cost.total += floating_ip_cost;
+}
+
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ServerCostForServer {
     Normal(ServerCostSimple),
@@ -190,12 +376,20 @@ pub async fn calculate_server_cost_for_server_normal(
     begin: DateTime<Utc>,
     end: DateTime<Utc>,
 ) -> Result<ServerCostSimple, UnexpectedOnlyError> {
-    let mut cost = ServerCostSimple { total: 0.0 };
+    let mut cost = ServerCostSimple {
+        total: 0.0,
+        discount: 0.0,
+    };
     let Some(user_class) =
         select_user_class_by_server_from_db(transaction, server_uuid).await?
     else {
         return Ok(cost);
     };
+    let Some(project) =
+        select_project_id_by_server_from_db(transaction, server_uuid).await?
+    else {
+        return Ok(cost);
+    };
     let price_periods =
         get_flavor_price_periods(transaction, begin, end).await?;
 
@@ -211,6 +405,7 @@ pub async fn calculate_server_cost_for_server_normal(
             Some(*start_time),
             Some(end_time),
             None,
+            None,
         )
         .await?;
         for (flavor_name, flavor_consumption) in consumption {
@@ -220,9 +415,18 @@ pub async fn calculate_server_cost_for_server_normal(
             let flavor_cost = calculate_flavor_consumption_cost(
                 flavor_consumption,
                 prices,
+                project,
+                user_class,
+                &flavor_name,
+            );
+            let flavor_discount = calculate_flavor_consumption_discount(
+                flavor_consumption,
+                prices,
+                project,
                 user_class,
                 &flavor_name,
             );
+            cost.discount += flavor_discount;
             if flavor_cost <= 0. {
                 continue;
             }
@@ -242,6 +446,7 @@ pub async fn calculate_server_cost_for_server_detail(
 ) -> Result<ServerCostServer, UnexpectedOnlyError> {
     let mut cost = ServerCostServer {
         total: 0.0,
+        discount: 0.0,
         flavors: HashMap::new(),
     };
     let Some(user_class) =
@@ -249,6 +454,11 @@ pub async fn calculate_server_cost_for_server_detail(
     else {
         return Ok(cost);
     };
+    let Some(project) =
+        select_project_id_by_server_from_db(transaction, server_uuid).await?
+    else {
+        return Ok(cost);
+    };
     let price_periods =
         get_flavor_price_periods(transaction, begin, end).await?;
 
@@ -264,16 +474,26 @@ pub async fn calculate_server_cost_for_server_detail(
             Some(*start_time),
             Some(end_time),
             None,
+            None,
         )
         .await?;
         for (flavor_name, flavor_consumption) in consumption {
             let flavor_cost = calculate_flavor_consumption_cost(
                 flavor_consumption,
                 prices,
+                project,
+                user_class,
+                &flavor_name,
+            );
+            let flavor_discount = calculate_flavor_consumption_discount(
+                flavor_consumption,
+                prices,
+                project,
                 user_class,
                 &flavor_name,
             );
             *cost.flavors.entry(flavor_name).or_default() += flavor_cost;
+            cost.discount += flavor_discount;
             if flavor_cost <= 0. {
                 continue;
             }
@@ -313,7 +533,7 @@ pub async fn calculate_server_cost_for_server(
     })
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ServerCostForUser {
     Normal(ServerCostSimple),
@@ -327,12 +547,21 @@ pub async fn calculate_server_cost_for_user_normal(
     begin: DateTime<Utc>,
     end: DateTime<Utc>,
 ) -> Result<ServerCostSimple, UnexpectedOnlyError> {
-    let mut cost = ServerCostSimple { total: 0.0 };
+    let mut cost = ServerCostSimple {
+        total: 0.0,
+        discount: 0.0,
+    };
     let Some(user_class) =
         select_user_class_by_user_from_db(transaction, user_id).await?
     else {
         return Ok(cost);
     };
+    let Some(project) = select_maybe_user_from_db(transaction, user_id)
+        .await?
+        .map(|user| user.project)
+    else {
+        return Ok(cost);
+    };
     let price_periods =
         get_flavor_price_periods(transaction, begin, end).await?;
 
@@ -363,13 +592,39 @@ pub async fn calculate_server_cost_for_user_normal(
             let flavor_cost = calculate_flavor_consumption_cost(
                 flavor_consumption,
                 prices,
+                project,
+                user_class,
+                &flavor_name,
+            );
+            let flavor_discount = calculate_flavor_consumption_discount(
+                flavor_consumption,
+                prices,
+                project,
                 user_class,
                 &flavor_name,
             );
+            cost.discount += flavor_discount;
             cost.total += flavor_cost;
         }
     }
 
+    cost.total += calculate_volume_cost_for_user(
+        transaction,
+        user_id,
+        user_class,
+        begin,
+        end,
+    )
+    .await?;
+    cost.total += calculate_floating_ip_cost_for_user(
+        transaction,
+        user_id,
+        user_class,
+        begin,
+        end,
+    )
+    .await?;
+
     Ok(cost)
 }
 
@@ -382,7 +637,10 @@ pub async fn calculate_server_cost_for_user_detail(
 ) -> Result<ServerCostUser, UnexpectedOnlyError> {
     let mut cost = ServerCostUser {
         total: 0.0,
+        discount: 0.0,
         flavors: HashMap::new(),
+        volumes: 0.0,
+        floating_ips: 0.0,
         servers: HashMap::new(),
     };
     let Some(user_class) =
@@ -390,6 +648,12 @@ pub async fn calculate_server_cost_for_user_detail(
     else {
         return Ok(cost);
     };
+    let Some(project) = select_maybe_user_from_db(transaction, user_id)
+        .await?
+        .map(|user| user.project)
+    else {
+        return Ok(cost);
+    };
     let price_periods =
         get_flavor_price_periods(transaction, begin, end).await?;
 
@@ -418,18 +682,29 @@ pub async fn calculate_server_cost_for_user_detail(
             let server_cost =
                 cost.servers.entry(server_uuid).or_insert(ServerCostServer {
                     total: 0.0,
+                    discount: 0.0,
                     flavors: HashMap::new(),
                 });
             for (flavor_name, flavor_consumption) in server_consumption {
                 let flavor_cost = calculate_flavor_consumption_cost(
                     flavor_consumption,
                     prices,
+                    project,
+                    user_class,
+                    &flavor_name,
+                );
+                let flavor_discount = calculate_flavor_consumption_discount(
+                    flavor_consumption,
+                    prices,
+                    project,
                     user_class,
                     &flavor_name,
                 );
                 *server_cost.flavors.entry(flavor_name.clone()).or_default() +=
                     flavor_cost;
                 *cost.flavors.entry(flavor_name).or_default() += flavor_cost;
+                server_cost.discount += flavor_discount;
+                cost.discount += flavor_discount;
                 if flavor_cost <= 0. {
                     continue;
                 }
@@ -439,6 +714,25 @@ pub async fn calculate_server_cost_for_user_detail(
         }
     }
 
+    cost.volumes = calculate_volume_cost_for_user(
+        transaction,
+        user_id,
+        user_class,
+        begin,
+        end,
+    )
+    .await?;
+    cost.total += cost.volumes;
+    cost.floating_ips = calculate_floating_ip_cost_for_user(
+        transaction,
+        user_id,
+        user_class,
+        begin,
+        end,
+    )
+    .await?;
+    cost.total += cost.floating_ips;
+
     Ok(cost)
 }
 
@@ -471,7 +765,7 @@ pub async fn calculate_server_cost_for_user(
     })
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ServerCostForProject {
     Normal(ServerCostSimple),
@@ -484,7 +778,10 @@ pub async fn calculate_server_cost_for_project_normal(
     begin: DateTime<Utc>,
     end: DateTime<Utc>,
 ) -> Result<ServerCostSimple, UnexpectedOnlyError> {
-    let mut cost = ServerCostSimple { total: 0.0 };
+    let mut cost = ServerCostSimple {
+        total: 0.0,
+        discount: 0.0,
+    };
     let Some(user_class) =
         select_user_class_by_project_from_db(transaction, project_id).await?
     else {
@@ -521,9 +818,18 @@ pub async fn calculate_server_cost_for_project_normal(
             let flavor_cost = calculate_flavor_consumption_cost(
                 flavor_consumption,
                 prices,
+                project_id as u32,
+                user_class,
+                &flavor_name,
+            );
+            let flavor_discount = calculate_flavor_consumption_discount(
+                flavor_consumption,
+                prices,
+                project_id as u32,
                 user_class,
                 &flavor_name,
             );
+            cost.discount += flavor_discount;
             if flavor_cost <= 0. {
                 continue;
             }
@@ -531,6 +837,27 @@ pub async fn calculate_server_cost_for_project_normal(
         }
     }
 
+    cost.total += calculate_volume_cost_for_project(
+        transaction,
+        project_id,
+        user_class,
+        begin,
+        end,
+    )
+    .await?
+    .values()
+    .sum::<f64>();
+    cost.total += calculate_floating_ip_cost_for_project(
+        transaction,
+        project_id,
+        user_class,
+        begin,
+        end,
+    )
+    .await?
+    .values()
+    .sum::<f64>();
+
     Ok(cost)
 }
 
@@ -543,7 +870,10 @@ pub async fn calculate_server_cost_for_project_detail(
 ) -> Result<ServerCostProject, UnexpectedOnlyError> {
     let mut cost = ServerCostProject {
         total: 0.0,
+        discount: 0.0,
         flavors: HashMap::new(),
+        volumes: 0.0,
+        floating_ips: 0.0,
         users: HashMap::new(),
     };
     let Some(user_class) =
@@ -581,7 +911,10 @@ pub async fn calculate_server_cost_for_project_detail(
                     .entry(user_name.clone())
                     .or_insert(ServerCostUser {
                         total: 0.0,
+                        discount: 0.0,
                         flavors: HashMap::new(),
+                        volumes: 0.0,
+                        floating_ips: 0.0,
                         servers: HashMap::new(),
                     });
             for (server_uuid, server_consumption) in user_consumption.servers {
@@ -590,12 +923,21 @@ pub async fn calculate_server_cost_for_project_detail(
                     .entry(server_uuid)
                     .or_insert(ServerCostServer {
                         total: 0.0,
+                        discount: 0.0,
                         flavors: HashMap::new(),
                     });
                 for (flavor_name, flavor_consumption) in server_consumption {
                     let flavor_cost = calculate_flavor_consumption_cost(
                         flavor_consumption,
                         prices,
+                        project_id as u32,
+                        user_class,
+                        &flavor_name,
+                    );
+                    let flavor_discount = calculate_flavor_consumption_discount(
+                        flavor_consumption,
+                        prices,
+                        project_id as u32,
                         user_class,
                         &flavor_name,
                     );
@@ -609,6 +951,9 @@ pub async fn calculate_server_cost_for_project_detail(
                         .or_default() += flavor_cost;
                     *cost.flavors.entry(flavor_name).or_default() +=
                         flavor_cost;
+                    server_cost.discount += flavor_discount;
+                    user_cost.discount += flavor_discount;
+                    cost.discount += flavor_discount;
                     if flavor_cost <= 0. {
                         continue;
                     }
@@ -620,6 +965,37 @@ pub async fn calculate_server_cost_for_project_detail(
         }
     }
 
+    for (user_name, volume_cost) in calculate_volume_cost_for_project(
+        transaction,
+        project_id,
+        user_class,
+        begin,
+        end,
+    )
+    .await?
+    {
+        add_volume_cost_to_user(&mut cost.users, user_name, volume_cost);
+        cost.volumes += volume_cost;
+        cost.total += volume_cost;
+    }
+    for (user_name, floating_ip_cost) in calculate_floating_ip_cost_for_project(
+        transaction,
+        project_id,
+        user_class,
+        begin,
+        end,
+    )
+    .await?
+    {
+        add_floating_ip_cost_to_user(
+            &mut cost.users,
+            user_name,
+            floating_ip_cost,
+        );
+        cost.floating_ips += floating_ip_cost;
+        cost.total += floating_ip_cost;
+    }
+
     Ok(cost)
 }
 
@@ -652,7 +1028,7 @@ pub async fn calculate_server_cost_for_project(
     })
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ServerCostForAll {
     Normal(ServerCostSimple),
@@ -665,7 +1041,10 @@ pub async fn calculate_server_cost_for_all_normal(
     begin: DateTime<Utc>,
     end: DateTime<Utc>,
 ) -> Result<ServerCostSimple, UnexpectedOnlyError> {
-    let mut cost = ServerCostSimple { total: 0.0 };
+    let mut cost = ServerCostSimple {
+        total: 0.0,
+        discount: 0.0,
+    };
     let price_periods =
         get_flavor_price_periods(transaction, begin, end).await?;
 
@@ -706,9 +1085,18 @@ pub async fn calculate_server_cost_for_all_normal(
                 let flavor_cost = calculate_flavor_consumption_cost(
                     flavor_consumption,
                     prices,
+                    project.id,
+                    project.user_class,
+                    &flavor_name,
+                );
+                let flavor_discount = calculate_flavor_consumption_discount(
+                    flavor_consumption,
+                    prices,
+                    project.id,
                     project.user_class,
                     &flavor_name,
                 );
+                cost.discount += flavor_discount;
                 if flavor_cost <= 0. {
                     continue;
                 }
@@ -717,6 +1105,17 @@ pub async fn calculate_server_cost_for_all_normal(
         }
     }
 
+    cost.total += calculate_volume_cost_for_all(transaction, begin, end)
+        .await?
+        .values()
+        .flat_map(|project_cost| project_cost.values())
+        .sum::<f64>();
+    cost.total += calculate_floating_ip_cost_for_all(transaction, begin, end)
+        .await?
+        .values()
+        .flat_map(|project_cost| project_cost.values())
+        .sum::<f64>();
+
     Ok(cost)
 }
 
@@ -728,7 +1127,10 @@ pub async fn calculate_server_cost_for_all_detail(
 ) -> Result<ServerCostAll, UnexpectedOnlyError> {
     let mut cost = ServerCostAll {
         total: 0.0,
+        discount: 0.0,
         flavors: HashMap::new(),
+        volumes: 0.0,
+        floating_ips: 0.0,
         projects: HashMap::new(),
     };
     let price_periods =
@@ -768,7 +1170,10 @@ pub async fn calculate_server_cost_for_all_detail(
                 .entry(project_name.clone())
                 .or_insert(ServerCostProject {
                     total: 0.0,
+                    discount: 0.0,
                     flavors: HashMap::new(),
+                    volumes: 0.0,
+                    floating_ips: 0.0,
                     users: HashMap::new(),
                 });
 
@@ -778,7 +1183,10 @@ pub async fn calculate_server_cost_for_all_detail(
                     .entry(user_name.clone())
                     .or_insert(ServerCostUser {
                         total: 0.0,
+                        discount: 0.0,
                         flavors: HashMap::new(),
+                        volumes: 0.0,
+                        floating_ips: 0.0,
                         servers: HashMap::new(),
                     });
                 for (server_uuid, server_consumption) in
@@ -789,6 +1197,7 @@ pub async fn calculate_server_cost_for_all_detail(
                         .entry(server_uuid)
                         .or_insert(ServerCostServer {
                             total: 0.0,
+                            discount: 0.0,
                             flavors: HashMap::new(),
                         });
                     for (flavor_name, flavor_consumption) in server_consumption
@@ -796,9 +1205,18 @@ pub async fn calculate_server_cost_for_all_detail(
                         let flavor_cost = calculate_flavor_consumption_cost(
                             flavor_consumption,
                             prices,
+                            project.id,
                             project.user_class,
                             &flavor_name,
                         );
+                        let flavor_discount =
+                            calculate_flavor_consumption_discount(
+                                flavor_consumption,
+                                prices,
+                                project.id,
+                                project.user_class,
+                                &flavor_name,
+                            );
                         *server_cost
                             .flavors
                             .entry(flavor_name.clone())
@@ -813,6 +1231,10 @@ pub async fn calculate_server_cost_for_all_detail(
                             .or_default() += flavor_cost;
                         *cost.flavors.entry(flavor_name).or_default() +=
                             flavor_cost;
+                        server_cost.discount += flavor_discount;
+                        user_cost.discount += flavor_discount;
+                        project_cost.discount += flavor_discount;
+                        cost.discount += flavor_discount;
                         if flavor_cost <= 0. {
                             continue;
                         }
@@ -826,6 +1248,60 @@ pub async fn calculate_server_cost_for_all_detail(
         }
     }
 
+    for (project_name, project_volume_cost) in
+        calculate_volume_cost_for_all(transaction, begin, end).await?
+    {
+        let project_cost =
+            cost.projects
+                .entry(project_name)
+                .or_insert(ServerCostProject {
+                    total: 0.0,
+                    discount: 0.0,
+                    flavors: HashMap::new(),
+                    volumes: 0.0,
+                    floating_ips: 0.0,
+                    users: HashMap::new(),
+                });
+        for (user_name, volume_cost) in project_volume_cost {
+            add_volume_cost_to_user(
+                &mut project_cost.users,
+                user_name,
+                volume_cost,
+            );
+            project_cost.volumes += volume_cost;
+            project_cost.total += volume_cost;
+            cost.volumes += volume_cost;
+            cost.total += volume_cost;
+        }
+    }
+
+    for (project_name, project_floating_ip_cost) in
+        calculate_floating_ip_cost_for_all(transaction, begin, end).await?
+    {
+        let project_cost =
+            cost.projects
+                .entry(project_name)
+                .or_insert(ServerCostProject {
+                    total: 0.0,
+                    discount: 0.0,
+                    flavors: HashMap::new(),
+                    volumes: 0.0,
+                    floating_ips: 0.0,
+                    users: HashMap::new(),
+                });
+        for (user_name, floating_ip_cost) in project_floating_ip_cost {
+            add_floating_ip_cost_to_user(
+                &mut project_cost.users,
+                user_name,
+                floating_ip_cost,
+            );
+            project_cost.floating_ips += floating_ip_cost;
+            project_cost.total += floating_ip_cost;
+            cost.floating_ips += floating_ip_cost;
+            cost.total += floating_ip_cost;
+        }
+    }
+
     Ok(cost)
 }
 
@@ -847,7 +1323,7 @@ pub async fn calculate_server_cost_for_all(
     })
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ServerCost {
     Server(ServerCostForServer),
@@ -856,6 +1332,18 @@ pub enum ServerCost {
     All(ServerCostForAll),
 }
 
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "accounting",
+    params(ServerCostParams),
+    responses(
+        (status = 200, description = "Cost, in detail if requested", body = ServerCost),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "server_cost")]
 pub async fn server_cost(
     user: ReqData<User>,
@@ -2,10 +2,23 @@ use actix_web::{
     Scope,
     web::{get, scope},
 };
+use utoipa::OpenApi;
 
 pub(crate) mod get;
 use get::server_cost;
+pub(crate) mod series;
+use series::server_cost_series;
+pub(crate) mod floating_ip;
+pub(crate) mod volume;
 
 pub fn server_cost_scope() -> Scope {
-    scope("/servercost").route("/", get().to(server_cost))
+    scope("/servercost")
+        .route("/", get().to(server_cost))
+        .route("/series/", get().to(server_cost_series))
 }
+
+/// OpenAPI documentation of the server cost endpoints
+/// routed by [server_cost_scope].
+#[derive(OpenApi)]
+#[openapi(paths(get::server_cost, series::server_cost_series))]
+pub struct ServerCostApiDoc;
@@ -0,0 +1,348 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::{Context, anyhow};
+use avina_wire::{
+    accounting::{
+        ServerConsumptionFlavors, ServerCostBucket, ServerCostInterval,
+        ServerCostSeriesParams,
+    },
+    error::ErrorResponse,
+    user::User,
+};
+use chrono::{DateTime, Datelike, Days, NaiveDate, TimeZone, Utc};
+use sqlx::{MySql, MySqlPool, Transaction};
+use uuid::Uuid;
+
+use super::get::{
+    calculate_server_cost_for_all_normal,
+    calculate_server_cost_for_project_normal,
+    calculate_server_cost_for_server_normal,
+    calculate_server_cost_for_user_normal,
+};
+use crate::{
+    authorization::{
+        require_admin_user, require_master_user_or_return_not_found,
+        require_user_or_project_master_or_not_found,
+    },
+    database::{
+        accounting::server_state::select_server_states_by_server_from_db,
+        user::user::select_user_from_db,
+    },
+    error::{OptionApiError, UnexpectedOnlyError},
+    routes::accounting::server_consumption::get::{
+        ServerConsumptionForAll, ServerConsumptionForProject,
+        ServerConsumptionForUser, calculate_server_consumption_for_all,
+        calculate_server_consumption_for_project,
+        calculate_server_consumption_for_server,
+        calculate_server_consumption_for_user,
+    },
+};
+
+/// Object the series of consumption and cost is calculated for.
+#[derive(Clone, Copy, Debug)]
+enum SeriesScope {
+    Server(Uuid),
+    User(u64),
+    Project(u64),
+    All,
+}
+
+/// Get the first bucket boundary after the given time.
+fn next_bucket_boundary(
+    time: DateTime<Utc>,
+    interval: ServerCostInterval,
+) -> DateTime<Utc> {
+    let date = time.date_naive();
+    let next = match interval {
+        ServerCostInterval::Day => date + Days::new(1),
+        ServerCostInterval::Week => {
+            date + Days::new(7 - date.weekday().num_days_from_monday() as u64)
+        }
+        ServerCostInterval::Month => {
+            let (year, month) = match date.month() {
+                12 => (date.year() + 1, 1),
+                month => (date.year(), month + 1),
+            };
+            NaiveDate::from_ymd_opt(year, month, 1).unwrap()
+        }
+    };
+    Utc.from_utc_datetime(&next.and_hms_opt(0, 0, 0).unwrap())
+}
+
+/// Maximum number of buckets a series may be split into.
+///
+/// Each bucket costs a consumption and a cost calculation, so long periods
+/// have to be requested with a coarser interval.
+const MAX_BUCKET_COUNT: usize = 1000;
+
+/// Begin and end of a bucket of a series.
+type Bucket = (DateTime<Utc>, DateTime<Utc>);
+
+/// Split the period into buckets of the given interval.
+///
+/// The buckets are aligned to the calendar in UTC, so the first and the last
+/// bucket are cut to the period and may be shorter than the others. Periods
+/// with more than [MAX_BUCKET_COUNT] buckets are rejected.
+pub fn split_into_buckets(
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+    interval: ServerCostInterval,
+) -> Result<Vec<Bucket>, OptionApiError> {
+    let mut buckets = Vec::new();
+    let mut bucket_begin = begin;
+    while bucket_begin < end {
+        if buckets.len() == MAX_BUCKET_COUNT {
+            return Err(OptionApiError::ValidationError(format!(
+                "Period must not span more than {MAX_BUCKET_COUNT} buckets"
+            )));
+        }
+        let bucket_end = next_bucket_boundary(bucket_begin, interval).min(end);
+        buckets.push((bucket_begin, bucket_end));
+        bucket_begin = bucket_end;
+    }
+    Ok(buckets)
+}
+
+async fn calculate_consumption_for_bucket(
+    transaction: &mut Transaction<'_, MySql>,
+    scope: SeriesScope,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<ServerConsumptionFlavors, UnexpectedOnlyError> {
+    Ok(match scope {
+        SeriesScope::Server(server_uuid) => {
+            calculate_server_consumption_for_server(
+                transaction,
+                server_uuid,
+                Some(begin),
+                Some(end),
+                None,
+                None,
+            )
+            .await?
+        }
+        SeriesScope::User(user_id) => {
+            let ServerConsumptionForUser::Normal(consumption) =
+                calculate_server_consumption_for_user(
+                    transaction,
+                    user_id,
+                    Some(begin),
+                    Some(end),
+                    None,
+                )
+                .await?
+            else {
+                return Err(anyhow!(
+                    "Unexpected ServerConsumptionForUser variant"
+                )
+                .into());
+            };
+            consumption
+        }
+        SeriesScope::Project(project_id) => {
+            let ServerConsumptionForProject::Normal(consumption) =
+                calculate_server_consumption_for_project(
+                    transaction,
+                    project_id,
+                    Some(begin),
+                    Some(end),
+                    None,
+                )
+                .await?
+            else {
+                return Err(anyhow!(
+                    "Unexpected ServerConsumptionForProject variant"
+                )
+                .into());
+            };
+            consumption
+        }
+        SeriesScope::All => {
+            let ServerConsumptionForAll::Normal(consumption) =
+                calculate_server_consumption_for_all(
+                    transaction,
+                    Some(begin),
+                    Some(end),
+                    None,
+                )
+                .await?
+            else {
+                return Err(anyhow!(
+                    "Unexpected ServerConsumptionForAll variant"
+                )
+                .into());
+            };
+            consumption
+        }
+    })
+}
+
+async fn calculate_cost_for_bucket(
+    transaction: &mut Transaction<'_, MySql>,
+    scope: SeriesScope,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<f64, UnexpectedOnlyError> {
+    let cost = match scope {
+        SeriesScope::Server(server_uuid) => {
+            calculate_server_cost_for_server_normal(
+                transaction,
+                server_uuid,
+                begin,
+                end,
+            )
+            .await?
+        }
+        SeriesScope::User(user_id) => {
+            calculate_server_cost_for_user_normal(
+                transaction,
+                user_id,
+                begin,
+                end,
+            )
+            .await?
+        }
+        SeriesScope::Project(project_id) => {
+            calculate_server_cost_for_project_normal(
+                transaction,
+                project_id,
+                begin,
+                end,
+            )
+            .await?
+        }
+        SeriesScope::All => {
+            calculate_server_cost_for_all_normal(transaction, begin, end)
+                .await?
+        }
+    };
+    Ok(cost.total)
+}
+
+/// Calculate consumption and cost for each of the given buckets.
+///
+/// Each bucket is calculated like the totals of the server consumption and
+/// cost endpoints, so price changes in the middle of a bucket are accounted
+/// for by the price periods of that bucket.
+async fn calculate_server_cost_series(
+    transaction: &mut Transaction<'_, MySql>,
+    scope: SeriesScope,
+    buckets: Vec<Bucket>,
+) -> Result<Vec<ServerCostBucket>, UnexpectedOnlyError> {
+    let mut series = Vec::new();
+    for (bucket_begin, bucket_end) in buckets {
+        let consumption = calculate_consumption_for_bucket(
+            transaction,
+            scope,
+            bucket_begin,
+            bucket_end,
+        )
+        .await?;
+        let cost = calculate_cost_for_bucket(
+            transaction,
+            scope,
+            bucket_begin,
+            bucket_end,
+        )
+        .await?;
+        series.push(ServerCostBucket {
+            begin: bucket_begin.fixed_offset(),
+            end: bucket_end.fixed_offset(),
+            consumption: consumption.values().sum(),
+            cost,
+        });
+    }
+    Ok(series)
+}
+
+/// Endpoint for the consumption and cost per day, week or month.
+///
+/// The object is selected like for the server cost endpoint and requires the
+/// same privileges, the buckets are split by [split_into_buckets].
+#[utoipa::path(
+    get,
+    path = "/series/",
+    tag = "accounting",
+    params(ServerCostSeriesParams),
+    responses(
+        (status = 200, description = "Consumption and cost per bucket", body = Vec<ServerCostBucket>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "server_cost_series")]
+pub async fn server_cost_series(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<ServerCostSeriesParams>,
+) -> Result<HttpResponse, OptionApiError> {
+    let end = params.end.unwrap_or(Utc::now().fixed_offset());
+    let begin = params.begin.unwrap_or(
+        Utc.with_ymd_and_hms(Utc::now().year(), 1, 1, 1, 0, 0)
+            .unwrap()
+            .fixed_offset(),
+    );
+    if begin > end {
+        return Err(OptionApiError::ValidationError(
+            "Begin must not be after end".to_string(),
+        ));
+    }
+    let buckets = split_into_buckets(
+        begin.into(),
+        end.into(),
+        params.interval.unwrap_or_default(),
+    )?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let scope = if params.all.unwrap_or(false) {
+        require_admin_user(&user)?;
+        SeriesScope::All
+    } else if let Some(project_id) = params.project {
+        require_master_user_or_return_not_found(&user, project_id)?;
+        SeriesScope::Project(project_id as u64)
+    } else if let Some(user_id) = params.user {
+        let user_queried =
+            select_user_from_db(&mut transaction, user_id as u64).await?;
+        require_user_or_project_master_or_not_found(
+            &user,
+            user_id,
+            user_queried.project,
+        )?;
+        SeriesScope::User(user_id as u64)
+    } else if let Some(server_id) = params.server {
+        let server_states = select_server_states_by_server_from_db(
+            &mut transaction,
+            server_id,
+            true,
+        )
+        .await?;
+        let Some(server_state) = server_states.first() else {
+            return Err(OptionApiError::NotFoundError);
+        };
+        let server_state_user =
+            select_user_from_db(&mut transaction, server_state.user as u64)
+                .await?;
+        require_user_or_project_master_or_not_found(
+            &user,
+            server_state_user.id,
+            server_state_user.project,
+        )?;
+        SeriesScope::Server(server_id)
+    } else {
+        SeriesScope::User(user.id as u64)
+    };
+    let series =
+        calculate_server_cost_series(&mut transaction, scope, buckets).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(series))
+}
@@ -0,0 +1,152 @@
+//! Cost of the Cinder volumes, which is added to the server cost of users,
+//! projects and all projects.
+
+use std::collections::HashMap;
+
+use avina_wire::{
+    accounting::VolumeState, pricing::VolumePrice, user::UserClass,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{MySql, Transaction};
+
+use crate::{
+    database::{
+        accounting::volume_state::{
+            select_volume_states_by_project_from_db,
+            select_volume_states_by_user_from_db,
+        },
+        pricing::volume_price::select_volume_prices_for_period_from_db,
+        user::project::select_all_projects_from_db,
+    },
+    error::UnexpectedOnlyError,
+};
+
+/// Statuses of volumes that do not occupy any storage and are not billed.
+const NON_BILLABLE_VOLUME_STATUSES: [&str; 2] = ["error", "error_deleting"];
+
+/// Volume prices valid during a period, by user class.
+pub struct VolumePrices {
+    /// Prices per user class, ordered by their start time.
+    prices: HashMap<UserClass, Vec<VolumePrice>>,
+}
+
+impl VolumePrices {
+    /// Load the volume prices valid during the given period.
+    pub async fn load(
+        transaction: &mut Transaction<'_, MySql>,
+        begin: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Self, UnexpectedOnlyError> {
+        let mut prices = HashMap::<_, Vec<_>>::new();
+        for price in
+            select_volume_prices_for_period_from_db(transaction, begin, end)
+                .await?
+                .into_iter()
+                .rev()
+        {
+            prices.entry(price.user_class).or_default().push(price);
+        }
+        Ok(Self { prices })
+    }
+
+    /// Calculate the cost of the volume state within the given period.
+    ///
+    /// The size of the state is billed for each price valid during the
+    /// overlap of state and period, proportionally to the time the price was
+    /// valid. Before the first price of the user class, volumes are free.
+    pub fn state_cost(
+        &self,
+        state: &VolumeState,
+        user_class: UserClass,
+        begin: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> f64 {
+        if NON_BILLABLE_VOLUME_STATUSES.contains(&state.status.as_str()) {
+            return 0.0;
+        }
+        let Some(prices) = self.prices.get(&user_class) else {
+            return 0.0;
+        };
+        let state_begin = state.begin.to_utc().max(begin);
+        let state_end = state.end.map_or(end, |e| e.to_utc()).min(end);
+        let mut cost = 0.0;
+        for (i, price) in prices.iter().enumerate() {
+            let price_end = prices
+                .get(i + 1)
+                .map_or(state_end, |next| next.start_time.to_utc());
+            let period_begin = price.start_time.to_utc().max(state_begin);
+            let period_end = price_end.min(state_end);
+            if period_end <= period_begin {
+                continue;
+            }
+            let seconds = (period_end - period_begin).num_seconds() as f64;
+            cost += state.size as f64 * price.unit_price * seconds
+                / ((365 * 24 * 60 * 60) as f64);
+        }
+        cost
+    }
+}
+
+/// Calculate the volume cost of the user with the given ID and user class.
+pub async fn calculate_volume_cost_for_user(
+    transaction: &mut Transaction<'_, MySql>,
+    user_id: u64,
+    user_class: UserClass,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<f64, UnexpectedOnlyError> {
+    let prices = VolumePrices::load(transaction, begin, end).await?;
+    Ok(select_volume_states_by_user_from_db(transaction, user_id)
+        .await?
+        .iter()
+        .map(|state| prices.state_cost(state, user_class, begin, end))
+        .sum())
+}
+
+/// Calculate the volume cost of each user of the project with the given ID
+/// and user class, by user name.
+pub async fn calculate_volume_cost_for_project(
+    transaction: &mut Transaction<'_, MySql>,
+    project_id: u64,
+    user_class: UserClass,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<HashMap<String, f64>, UnexpectedOnlyError> {
+    let prices = VolumePrices::load(transaction, begin, end).await?;
+    let mut cost = HashMap::new();
+    for state in
+        select_volume_states_by_project_from_db(transaction, project_id).await?
+    {
+        *cost.entry(state.username.clone()).or_default() +=
+            prices.state_cost(&state, user_class, begin, end);
+    }
+    Ok(cost)
+}
+
+/// Calculate the volume cost of each user of all projects, by project name
+/// and user name.
+pub async fn calculate_volume_cost_for_all(
+    transaction: &mut Transaction<'_, MySql>,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<HashMap<String, HashMap<String, f64>>, UnexpectedOnlyError> {
+    let prices = VolumePrices::load(transaction, begin, end).await?;
+    let mut cost = HashMap::new();
+    for project in select_all_projects_from_db(transaction).await? {
+        let states = select_volume_states_by_project_from_db(
+            transaction,
+            project.id as u64,
+        )
+        .await?;
+        if states.is_empty() {
+            continue;
+        }
+        let project_cost: &mut HashMap<String, f64> =
+            cost.entry(project.name.clone()).or_default();
+        for state in states {
+            *project_cost.entry(state.username.clone()).or_default() +=
+                prices.state_cost(&state, project.user_class, begin, end);
+        }
+    }
+    Ok(cost)
+}
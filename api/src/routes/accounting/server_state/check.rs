@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+
+use actix_web::{
+    HttpResponse,
+    web::{Data, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    accounting::{ServerState, ServerStateAnomaly, ServerStateAnomalyKind},
+    error::ErrorResponse,
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use crate::{
+    authorization::require_admin_user,
+    database::accounting::server_state::select_all_server_states_from_db,
+    error::OptionApiError,
+};
+
+/// Endpoint for checking the consistency of all server states.
+///
+/// This reports the anomalies found by [find_server_state_anomalies] for all
+/// servers. Only admins can call this endpoint, otherwise a
+/// [OptionApiError::AuthorizationError] is returned.
+#[utoipa::path(
+    get,
+    path = "/check/",
+    tag = "accounting",
+    responses(
+        (status = 200, description = "Anomalies of the server states", body = Vec<ServerStateAnomaly>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "server_state_check")]
+pub async fn server_state_check(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let server_states =
+        select_all_server_states_from_db(&mut transaction).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    let anomalies = find_server_state_anomalies(server_states);
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(anomalies))
+}
+
+/// Find the anomalies in the histories of the given server states.
+///
+/// The states of each server are ordered by their begin and checked for:
+///
+/// - states ending before they begin, which are not considered any further,
+/// - unfinished states followed by another unfinished state, which are
+///   repairable by ending them at the begin of the following state,
+/// - states overlapping the following state, which are repairable by ending
+///   them at the begin of the following state, unless they are unfinished or
+///   outlast the following state,
+/// - gaps between a state and the following state, which are not repairable.
+///
+/// The anomalies are grouped by server. Each repairable anomaly concerns a
+/// different server state, and its other server state is the following one.
+pub fn find_server_state_anomalies(
+    server_states: Vec<ServerState>,
+) -> Vec<ServerStateAnomaly> {
+    let mut servers = BTreeMap::<_, Vec<ServerState>>::new();
+    for server_state in server_states {
+        servers
+            .entry(server_state.instance_id)
+            .or_default()
+            .push(server_state);
+    }
+    let mut anomalies = Vec::new();
+    for mut states in servers.into_values() {
+        states.sort_by_key(|state| (state.begin, state.id));
+        let (valid, invalid): (Vec<_>, Vec<_>) =
+            states.into_iter().partition(|state| match state.end {
+                Some(end) => end >= state.begin,
+                None => true,
+            });
+        for state in invalid.iter() {
+            anomalies.push(anomaly(
+                ServerStateAnomalyKind::EndBeforeBegin,
+                state,
+                None,
+                false,
+            ));
+        }
+        for (i, pair) in valid.windows(2).enumerate() {
+            let (state, next) = (&pair[0], &pair[1]);
+            let Some(end) = state.end else {
+                let later_unfinished =
+                    valid[i + 1..].iter().any(|later| later.end.is_none());
+                let kind = if later_unfinished {
+                    ServerStateAnomalyKind::MultipleUnfinished
+                } else {
+                    ServerStateAnomalyKind::Overlap
+                };
+                anomalies.push(anomaly(
+                    kind,
+                    state,
+                    Some(next),
+                    later_unfinished,
+                ));
+                continue;
+            };
+            if end > next.begin {
+                let repairable = match next.end {
+                    Some(next_end) => end <= next_end,
+                    None => true,
+                };
+                anomalies.push(anomaly(
+                    ServerStateAnomalyKind::Overlap,
+                    state,
+                    Some(next),
+                    repairable,
+                ));
+            } else if end < next.begin {
+                anomalies.push(anomaly(
+                    ServerStateAnomalyKind::Gap,
+                    state,
+                    Some(next),
+                    false,
+                ));
+            }
+        }
+    }
+    anomalies
+}
+
+/// Create an anomaly of the given state, describing it by its kind.
+fn anomaly(
+    kind: ServerStateAnomalyKind,
+    state: &ServerState,
+    next: Option<&ServerState>,
+    repairable: bool,
+) -> ServerStateAnomaly {
+    let next_id = next.map(|next| next.id);
+    let description = match (kind, next_id) {
+        (ServerStateAnomalyKind::MultipleUnfinished, Some(id)) => {
+            format!("Server state is unfinished, like later state {id}")
+        }
+        (ServerStateAnomalyKind::Overlap, Some(id)) => {
+            format!("Server state overlaps following state {id}")
+        }
+        (ServerStateAnomalyKind::Gap, Some(id)) => {
+            format!("Server state is followed by state {id} after a gap")
+        }
+        _ => "Server state ends before it begins".to_string(),
+    };
+    ServerStateAnomaly {
+        instance_id: state.instance_id,
+        kind,
+        server_state: state.id,
+        other_server_state: next_id,
+        description,
+        repairable,
+    }
+}
@@ -5,11 +5,14 @@ use actix_web::{
 use anyhow::Context;
 use avina_wire::{
     accounting::{ServerState, ServerStateCreateData},
+    audit::ObjectType,
+    error::ErrorResponse,
     user::User,
 };
 use sqlx::MySqlPool;
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
     database::{
         accounting::server_state::{
@@ -21,10 +24,23 @@ use crate::{
     error::{NormalApiError, OptionApiError},
 };
 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "accounting",
+    request_body = ServerStateCreateData,
+    responses(
+        (status = 201, description = "Created server state", body = ServerState),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "server_state_create")]
 pub async fn server_state_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<ServerStateCreateData>,
 ) -> Result<HttpResponse, OptionApiError> {
     require_admin_user(&user)?;
@@ -48,10 +64,6 @@ pub async fn server_state_create(
     .await?;
     let id = insert_server_state_into_db(&mut transaction, &new_server_state)
         .await?;
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
     let server_state_created = ServerState {
         id: id as u32,
         begin: data.begin,
@@ -64,6 +76,18 @@ pub async fn server_state_create(
         user: new_server_state.user,
         username,
     };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::ServerState,
+            server_state_created.id,
+            &server_state_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
     Ok(HttpResponse::Created()
         .content_type("application/json")
         .json(server_state_created))
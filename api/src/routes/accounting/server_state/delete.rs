@@ -3,20 +3,35 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::ServerStateIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::accounting::server_state::delete_server_state_from_db,
+    database::accounting::server_state::{
+        delete_server_state_from_db, select_maybe_server_state_from_db,
+    },
     error::NormalApiError,
 };
 
+#[utoipa::path(
+    delete,
+    path = "/{server_state_id}/",
+    tag = "accounting",
+    params(ServerStateIdParam),
+    responses(
+        (status = 204, description = "Server state deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "server_state_delete")]
 pub async fn server_state_delete(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     params: Path<ServerStateIdParam>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -24,11 +39,24 @@ pub async fn server_state_delete(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let server_state_before = select_maybe_server_state_from_db(
+        &mut transaction,
+        params.server_state_id as u64,
+    )
+    .await?;
     delete_server_state_from_db(
         &mut transaction,
         params.server_state_id as u64,
     )
     .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::ServerState,
+            params.server_state_id,
+            &server_state_before,
+        )
+        .await?;
     transaction
         .commit()
         .await
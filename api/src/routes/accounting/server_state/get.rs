@@ -3,7 +3,7 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{accounting::ServerState, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::ServerStateIdParam;
@@ -16,6 +16,18 @@ use crate::{
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "/{server_state_id}",
+    tag = "accounting",
+    params(ServerStateIdParam),
+    responses(
+        (status = 200, description = "Requested server state", body = ServerState),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "server_state_get")]
 pub async fn server_state_get(
     user: ReqData<User>,
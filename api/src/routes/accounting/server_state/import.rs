@@ -2,12 +2,18 @@ use std::{collections::HashMap, hash::Hash};
 
 use actix_web::{
     HttpResponse,
-    web::{Data, ReqData},
+    web::{Data, Query, ReqData},
 };
 use anyhow::{Context, anyhow};
-use avina_wire::{accounting::ServerStateImport, user::User};
+use avina_wire::{
+    accounting::ServerStateImport,
+    dry_run::{Change, ChangeAction, ChangeObject, DryRunParams},
+    error::ErrorResponse,
+    user::User,
+};
 use chrono::Utc;
 use sqlx::{Executor, FromRow, MySql, MySqlPool, Transaction};
+use uuid::Uuid;
 
 use crate::{
     authorization::require_admin_user,
@@ -15,6 +21,7 @@ use crate::{
         NewServerState, insert_server_state_into_db,
         select_unfinished_server_states_from_db,
     },
+    dry_run::{DryRunnable, finish},
     error::{
         NotFoundOrUnexpectedApiError, OptionApiError, UnexpectedOnlyError,
     },
@@ -22,7 +29,7 @@ use crate::{
 };
 
 // WARN: the hashmap cannot contain (None, None).
-fn union_hash_zip<K, V, W>(
+pub(crate) fn union_hash_zip<K, V, W>(
     hm1: HashMap<K, V>,
     hm2: HashMap<K, W>,
 ) -> HashMap<K, (Option<V>, Option<W>)>
@@ -43,11 +50,24 @@ where
     hm3
 }
 
+#[utoipa::path(
+    get,
+    path = "/import/",
+    tag = "accounting",
+    params(DryRunParams),
+    responses(
+        (status = 200, description = "Summary of the import, with the changes in dry runs", body = DryRunnable<ServerStateImport>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "server_state_import", skip(openstack))]
 pub async fn server_state_import(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
     openstack: Data<OpenStack>,
+    params: Query<DryRunParams>,
     // TODO: is the NormalApiError::ValidationError used?
     // Maybe we need a AuthOrUnexpectedError type.
 ) -> Result<HttpResponse, OptionApiError> {
@@ -56,7 +76,31 @@ pub async fn server_state_import(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let mut changes = Vec::new();
+    let import =
+        import_server_states(&mut transaction, &openstack, &mut changes)
+            .await?;
+    Ok(finish(transaction, &params, import, changes).await?)
+}
 
+/// Import the current server states from OpenStack into the database.
+///
+/// New server states are created for servers without an unfinished state or whose
+/// status, name, flavor or owning user changed, and the unfinished states of
+/// servers that changed or vanished are ended. Flavors and users are compared
+/// by mapping their OpenStack IDs to the ones in the database. The unfinished
+/// state of a server whose flavor or user is unknown is kept open, as no new
+/// state could be created for it. Every opened and ended state is recorded in
+/// the given changes.
+#[tracing::instrument(
+    name = "import_server_states",
+    skip(transaction, openstack)
+)]
+pub async fn import_server_states(
+    transaction: &mut Transaction<'_, MySql>,
+    openstack: &OpenStack,
+    changes: &mut Vec<Change>,
+) -> Result<ServerStateImport, OptionApiError> {
     // TODO: should we add additional context to the error here?
     let servers = openstack
         .get_servers()
@@ -65,13 +109,16 @@ pub async fn server_state_import(
         .cloned()
         .map(|s| (s.id, s))
         .collect::<HashMap<_, _>>();
-    let states = select_unfinished_server_states_from_db(&mut transaction)
+    let states = select_unfinished_server_states_from_db(transaction)
         .await?
         .iter()
         .cloned()
         .map(|s| (s.instance_id, s))
         .collect::<HashMap<_, _>>();
 
+    let flavors = select_flavors_by_openstack_id_from_db(transaction).await?;
+    let users = select_users_by_openstack_id_from_db(transaction).await?;
+
     let servers_and_states = union_hash_zip(servers, states);
 
     let mut new_state_count = 0;
@@ -80,23 +127,85 @@ pub async fn server_state_import(
     for server_and_state in servers_and_states.values() {
         match server_and_state {
             (Some(server), Some(state)) => {
+                let (Some((flavor, flavor_name)), Some((user, username))) = (
+                    flavors.get(&server.flavor.id),
+                    users.get(&server.tenant_id),
+                ) else {
+                    tracing::warn!(
+                        "Flavor {} or user {} not found, keeping state of server {} open.",
+                        server.flavor.id,
+                        server.tenant_id,
+                        server.id
+                    );
+                    continue;
+                };
+                let mut reasons = Vec::new();
                 if server.status != state.status {
-                    end_server_state_in_db(&mut transaction, state.id as u64)
+                    reasons.push(format!(
+                        "status changed from {} to {}",
+                        state.status, server.status
+                    ));
+                }
+                if server.name != state.instance_name {
+                    reasons.push(format!(
+                        "name changed from {} to {}",
+                        state.instance_name, server.name
+                    ));
+                }
+                if *flavor != state.flavor {
+                    reasons.push(format!(
+                        "flavor changed from {} to {}",
+                        state.flavor_name, flavor_name
+                    ));
+                }
+                if *user != state.user {
+                    reasons.push(format!(
+                        "user changed from {} to {}",
+                        state.username, username
+                    ));
+                }
+                if !reasons.is_empty() {
+                    let reason = reasons.join(", ");
+                    end_server_state_in_db(transaction, state.id as u64)
                         .await?;
                     end_state_count += 1;
-                    new_state_count +=
-                        create_server_state_in_db(&mut transaction, server)
-                            .await?;
+                    changes.push(server_state_change(
+                        ChangeAction::End,
+                        server.id,
+                        &reason,
+                    ));
+                    let count =
+                        create_server_state_in_db(transaction, server).await?;
+                    if count > 0 {
+                        changes.push(server_state_change(
+                            ChangeAction::Create,
+                            server.id,
+                            &reason,
+                        ));
+                    }
+                    new_state_count += count;
                 }
             }
             (Some(server), None) => {
-                new_state_count +=
-                    create_server_state_in_db(&mut transaction, server).await?;
+                let count =
+                    create_server_state_in_db(transaction, server).await?;
+                if count > 0 {
+                    changes.push(server_state_change(
+                        ChangeAction::Create,
+                        server.id,
+                        "server has no unfinished state",
+                    ));
+                }
+                new_state_count += count;
             }
             (None, Some(state)) => {
-                end_server_state_in_db(&mut transaction, state.id as u64)
-                    .await?;
+                end_server_state_in_db(transaction, state.id as u64).await?;
                 end_state_count += 1;
+                changes.push(server_state_change(
+                    ChangeAction::End,
+                    state.instance_id,
+                    "server no longer exists",
+                ));
             }
             (None, None) => {
                 return Err(anyhow!(
@@ -107,16 +216,23 @@ pub async fn server_state_import(
         }
     }
 
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
-    Ok(HttpResponse::Ok().content_type("application/json").json(
-        ServerStateImport {
-            new_state_count,
-            end_state_count,
-        },
-    ))
+    Ok(ServerStateImport {
+        new_state_count,
+        end_state_count,
+    })
+}
+
+fn server_state_change(
+    action: ChangeAction,
+    instance_id: Uuid,
+    reason: &str,
+) -> Change {
+    Change {
+        action,
+        object: ChangeObject::ServerState,
+        name: instance_id.to_string(),
+        reason: reason.to_string(),
+    }
 }
 
 #[tracing::instrument(name = "end_server_state_in_db", skip(transaction))]
@@ -280,3 +396,69 @@ pub async fn select_user_id_by_openstack_id_from_db(
         .await?
         .ok_or(NotFoundOrUnexpectedApiError::NotFoundError)
 }
+
+#[tracing::instrument(
+    name = "select_flavors_by_openstack_id_from_db",
+    skip(transaction)
+)]
+pub async fn select_flavors_by_openstack_id_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<HashMap<String, (u32, String)>, UnexpectedOnlyError> {
+    #[derive(FromRow)]
+    struct Row {
+        #[sqlx(try_from = "i64")]
+        id: u32,
+        openstack_id: String,
+        name: String,
+    }
+    let query = sqlx::query!(
+        r#"
+        SELECT id, openstack_id, name
+        FROM resources_flavor
+        "#,
+    );
+    Ok(transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?
+        .into_iter()
+        .map(|row| Row::from_row(&row))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to parse flavor row")?
+        .into_iter()
+        .map(|row| (row.openstack_id, (row.id, row.name)))
+        .collect())
+}
+
+#[tracing::instrument(
+    name = "select_users_by_openstack_id_from_db",
+    skip(transaction)
+)]
+pub async fn select_users_by_openstack_id_from_db(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<HashMap<String, (u32, String)>, UnexpectedOnlyError> {
+    #[derive(FromRow)]
+    struct Row {
+        #[sqlx(try_from = "i32")]
+        id: u32,
+        openstack_id: String,
+        name: String,
+    }
+    let query = sqlx::query!(
+        r#"
+        SELECT id, openstack_id, name
+        FROM user_user
+        "#,
+    );
+    Ok(transaction
+        .fetch_all(query)
+        .await
+        .context("Failed to execute select query")?
+        .into_iter()
+        .map(|row| Row::from_row(&row))
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to parse user row")?
+        .into_iter()
+        .map(|row| (row.openstack_id, (row.id, row.name)))
+        .collect())
+}
@@ -1,10 +1,11 @@
 use actix_web::{
-    HttpResponse,
+    HttpRequest, HttpResponse,
     web::{Data, Query, ReqData},
 };
 use anyhow::Context;
 use avina_wire::{
-    accounting::ServerStateListParams,
+    accounting::{ServerState, ServerStateListParams},
+    error::ErrorResponse,
     user::{Project, User},
 };
 use sqlx::MySqlPool;
@@ -16,47 +17,53 @@ use crate::{
     },
     database::{
         accounting::server_state::{
-            select_all_server_states_from_db,
-            select_server_states_by_project_from_db,
-            select_server_states_by_server_and_project_from_db,
-            select_server_states_by_server_and_user_from_db,
+            ServerStateFilter, select_server_state_list_from_db,
             select_server_states_by_server_from_db,
-            select_server_states_by_user_from_db,
         },
         user::user::select_user_from_db,
     },
     error::OptionApiError,
+    pagination::{ListQuery, Paginated, paginate},
 };
 
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "accounting",
+    params(ServerStateListParams),
+    responses(
+        (status = 200, description = "List of server states", body = Paginated<ServerState>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "server_state_list")]
 pub async fn server_state_list(
     user: ReqData<User>,
     project: ReqData<Project>,
     db_pool: Data<MySqlPool>,
     params: Query<ServerStateListParams>,
+    req: HttpRequest,
 ) -> Result<HttpResponse, OptionApiError> {
+    let list_query = ListQuery::new::<ServerState>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
     let mut transaction = db_pool
         .begin()
         .await
         .context("Failed to begin transaction")?;
-    let server_states = if params.all.unwrap_or(false) {
+    let filter = if params.all.unwrap_or(false) {
         require_admin_user(&user)?;
-        select_all_server_states_from_db(&mut transaction).await?
+        ServerStateFilter::default()
     } else if let Some(project_id) = params.project {
         require_master_user_or_return_not_found(&user, project_id)?;
-        if let Some(server_id) = params.server {
-            select_server_states_by_server_and_project_from_db(
-                &mut transaction,
-                server_id,
-                project_id as u64,
-            )
-            .await?
-        } else {
-            select_server_states_by_project_from_db(
-                &mut transaction,
-                project_id as u64,
-            )
-            .await?
+        ServerStateFilter {
+            project: Some(project_id as u64),
+            server: params.server,
+            ..Default::default()
         }
     } else if let Some(user_id) = params.user {
         let user1 = select_user_from_db(&mut transaction, user_id as u64)
@@ -67,19 +74,10 @@ pub async fn server_state_list(
             user1.id,
             user1.project,
         )?;
-        if let Some(server_id) = params.server {
-            select_server_states_by_server_and_user_from_db(
-                &mut transaction,
-                server_id,
-                user1.id as u64,
-            )
-            .await?
-        } else {
-            select_server_states_by_user_from_db(
-                &mut transaction,
-                user1.id as u64,
-            )
-            .await?
+        ServerStateFilter {
+            user: Some(user1.id as u64),
+            server: params.server,
+            ..Default::default()
         }
     } else if let Some(server_id) = params.server {
         let server_states = select_server_states_by_server_from_db(
@@ -88,26 +86,36 @@ pub async fn server_state_list(
             false,
         )
         .await?;
+        let Some(server_state) = server_states.first() else {
+            return Err(OptionApiError::NotFoundError);
+        };
         let server_state_user =
-            select_user_from_db(&mut transaction, server_states[0].user as u64)
+            select_user_from_db(&mut transaction, server_state.user as u64)
                 .await?;
-        match require_user_or_project_master_or_not_found(
+        require_user_or_project_master_or_not_found(
             &user,
             server_state_user.id,
             server_state_user.project,
-        ) {
-            Ok(_) => server_states,
-            Err(e) => return Err(e.into()),
+        )?;
+        ServerStateFilter {
+            server: Some(server_id),
+            ..Default::default()
         }
     } else {
-        select_server_states_by_user_from_db(&mut transaction, user.id as u64)
-            .await?
+        ServerStateFilter {
+            user: Some(user.id as u64),
+            ..Default::default()
+        }
     };
+    let (server_states, count) = select_server_state_list_from_db(
+        &mut transaction,
+        &filter,
+        &list_query,
+    )
+    .await?;
     transaction
         .commit()
         .await
         .context("Failed to commit transaction")?;
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .json(server_states))
+    Ok(paginate(&req, server_states, count, &list_query))
 }
@@ -0,0 +1,337 @@
+use std::{collections::HashMap, fmt::Display};
+
+use actix_web::{
+    HttpMessage, HttpRequest, HttpResponse,
+    web::{Data, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    accounting::{ServerState, ServerStateLoad, ServerStateLoadData},
+    audit::ObjectType,
+    error::ErrorResponse,
+    user::User,
+};
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+use sqlx::{MySql, MySqlPool, Transaction};
+use uuid::Uuid;
+
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::{
+        accounting::server_state::{
+            NewServerState, insert_server_state_into_db,
+            select_all_server_states_from_db,
+        },
+        resources::flavor::select_all_flavors_from_db,
+        user::user::select_all_users_from_db,
+    },
+    error::OptionApiError,
+};
+
+/// Maximum size of a request body of the [server_state_load] endpoint.
+///
+/// This is larger than actix' default, as a load might contain the history
+/// of all servers of the cloud.
+pub(super) const SERVER_STATE_LOAD_LIMIT: usize = 32 * 1024 * 1024;
+
+/// Endpoint for creating many, e.g., historical server states at once.
+///
+/// The body is either a JSON array of [ServerStateLoadData] or, with the
+/// content type `text/csv`, a CSV file with a header line naming the fields.
+/// The whole batch is checked by [resolve_server_state_load] and only created
+/// if no entry is invalid, all in a single transaction. Only admins can call
+/// this endpoint, otherwise a [OptionApiError::AuthorizationError] is
+/// returned.
+#[utoipa::path(
+    post,
+    path = "/load/",
+    tag = "accounting",
+    request_body(content((Vec<ServerStateLoadData> = "application/json"), (String = "text/csv"))),
+    responses(
+        (status = 201, description = "Summary of the created server states", body = ServerStateLoad),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "server_state_load", skip(body))]
+pub async fn server_state_load(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    req: HttpRequest,
+    body: String,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let entries = if req.content_type() == "text/csv" {
+        server_state_load_data_from_csv(&body)
+    } else {
+        serde_json::from_str::<Vec<ServerStateLoadData>>(&body)
+            .map_err(|e| format!("Failed to parse server states: {e}"))
+    }
+    .map_err(OptionApiError::ValidationError)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let resolved = resolve_server_state_load(&mut transaction, entries).await?;
+    let new_state_count = resolved.len() as u32;
+    for (new_server_state, flavor_name, username) in resolved {
+        let id =
+            insert_server_state_into_db(&mut transaction, &new_server_state)
+                .await?;
+        let server_state_created = ServerState {
+            id: id as u32,
+            begin: new_server_state.begin.fixed_offset(),
+            end: new_server_state.end.map(|end| end.fixed_offset()),
+            instance_id: new_server_state.instance_id,
+            instance_name: new_server_state.instance_name,
+            flavor: new_server_state.flavor,
+            flavor_name,
+            status: new_server_state.status,
+            user: new_server_state.user,
+            username,
+        };
+        audit
+            .created(
+                &mut transaction,
+                ObjectType::ServerState,
+                server_state_created.id,
+                &server_state_created,
+            )
+            .await?;
+    }
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Created()
+        .content_type("application/json")
+        .json(ServerStateLoad { new_state_count }))
+}
+
+/// Server state to create, with the names of its flavor and user.
+type ResolvedServerState = (NewServerState, String, String);
+
+/// Origin of a period in the overlap check of [resolve_server_state_load].
+#[derive(Clone, Copy)]
+enum PeriodSource {
+    /// Entry of the load with the given number.
+    Entry(usize),
+    /// Existing server state with the given ID.
+    State(u32),
+}
+
+impl Display for PeriodSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PeriodSource::Entry(number) => write!(f, "entry {number}"),
+            PeriodSource::State(id) => write!(f, "server state {id}"),
+        }
+    }
+}
+
+/// Period of a server, from begin to end or indefinitely if unfinished.
+struct Period {
+    begin: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+    source: PeriodSource,
+}
+
+/// Find the overlaps of the given periods of a server involving an entry.
+///
+/// Each overlap is reported for the entry, or for the later one if both
+/// periods are entries, naming the other period.
+fn find_overlaps(mut periods: Vec<Period>, errors: &mut Vec<String>) {
+    periods.sort_by_key(|period| period.begin);
+    let mut latest: Option<&Period> = None;
+    for period in periods.iter() {
+        if let Some(previous) = latest {
+            if previous.end.is_none_or(|end| end > period.begin)
+                && let (other, PeriodSource::Entry(number))
+                | (PeriodSource::Entry(number), other) =
+                    (previous.source, period.source)
+            {
+                errors.push(format!("Entry {number}: overlaps {other}"));
+            }
+            let ends_later = match (previous.end, period.end) {
+                (None, _) => false,
+                (Some(_), None) => true,
+                (Some(previous_end), Some(end)) => end > previous_end,
+            };
+            if !ends_later {
+                continue;
+            }
+        }
+        latest = Some(period);
+    }
+}
+
+/// Resolve flavors and users of the given entries and check them as a batch.
+///
+/// Flavors and users are looked up by their name, ID, or OpenStack ID. An
+/// entry is invalid if its flavor or user is unknown, if it does not end
+/// after it begins, or if it overlaps another entry or an existing state of
+/// the same server. If any entry is invalid, a
+/// [OptionApiError::ValidationError] lists all of them, numbered from 1.
+pub async fn resolve_server_state_load(
+    transaction: &mut Transaction<'_, MySql>,
+    entries: Vec<ServerStateLoadData>,
+) -> Result<Vec<ResolvedServerState>, OptionApiError> {
+    let mut flavors = HashMap::new();
+    for flavor in select_all_flavors_from_db(transaction).await? {
+        flavors.insert(flavor.id.to_string(), (flavor.id, flavor.name.clone()));
+        flavors.insert(flavor.openstack_id, (flavor.id, flavor.name.clone()));
+        flavors.insert(flavor.name.clone(), (flavor.id, flavor.name));
+    }
+    let mut users = HashMap::new();
+    for user in select_all_users_from_db(transaction).await? {
+        users.insert(user.id.to_string(), (user.id, user.name.clone()));
+        users.insert(user.openstack_id, (user.id, user.name.clone()));
+        users.insert(user.name.clone(), (user.id, user.name));
+    }
+
+    let mut errors = Vec::new();
+    let mut resolved = Vec::new();
+    let mut numbers = Vec::new();
+    for (i, entry) in entries.into_iter().enumerate() {
+        let number = i + 1;
+        let flavor = flavors.get(&entry.flavor);
+        if flavor.is_none() {
+            errors.push(format!(
+                "Entry {number}: unknown flavor {}",
+                entry.flavor
+            ));
+        }
+        let user = users.get(&entry.user);
+        if user.is_none() {
+            errors.push(format!("Entry {number}: unknown user {}", entry.user));
+        }
+        if entry.end.is_some_and(|end| end <= entry.begin) {
+            errors.push(format!("Entry {number}: end is not after begin"));
+            continue;
+        }
+        let (Some((flavor, flavor_name)), Some((user, username))) =
+            (flavor, user)
+        else {
+            continue;
+        };
+        resolved.push((
+            NewServerState {
+                begin: entry.begin.to_utc(),
+                end: entry.end.map(|end| end.to_utc()),
+                instance_id: entry.instance_id,
+                instance_name: entry.instance_name,
+                flavor: *flavor,
+                status: entry.status,
+                user: *user,
+            },
+            flavor_name.clone(),
+            username.clone(),
+        ));
+        numbers.push(number);
+    }
+
+    let mut servers = HashMap::<Uuid, Vec<_>>::new();
+    for ((state, _, _), number) in resolved.iter().zip(numbers) {
+        servers.entry(state.instance_id).or_default().push(Period {
+            begin: state.begin,
+            end: state.end,
+            source: PeriodSource::Entry(number),
+        });
+    }
+    for state in select_all_server_states_from_db(transaction).await? {
+        if let Some(periods) = servers.get_mut(&state.instance_id) {
+            periods.push(Period {
+                begin: state.begin.to_utc(),
+                end: state.end.map(|end| end.to_utc()),
+                source: PeriodSource::State(state.id),
+            });
+        }
+    }
+    for periods in servers.into_values() {
+        find_overlaps(periods, &mut errors);
+    }
+
+    if !errors.is_empty() {
+        return Err(OptionApiError::ValidationError(errors.join("; ")));
+    }
+    Ok(resolved)
+}
+
+/// Parse the server states of a CSV file with a header line.
+///
+/// The header names the fields of [ServerStateLoadData], in any order, and
+/// empty values are treated as missing, e.g., for unfinished states.
+pub fn server_state_load_data_from_csv(
+    text: &str,
+) -> Result<Vec<ServerStateLoadData>, String> {
+    let mut records = parse_csv(text)?.into_iter();
+    let Some(header) = records.next() else {
+        return Ok(Vec::new());
+    };
+    records
+        .enumerate()
+        .map(|(i, record)| {
+            if record.len() != header.len() {
+                return Err(format!(
+                    "Entry {}: expected {} fields, found {}",
+                    i + 1,
+                    header.len(),
+                    record.len()
+                ));
+            }
+            let object = header
+                .iter()
+                .cloned()
+                .zip(record)
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(key, value)| (key, Value::String(value)))
+                .collect::<Map<_, _>>();
+            serde_json::from_value(Value::Object(object))
+                .map_err(|e| format!("Entry {}: {e}", i + 1))
+        })
+        .collect()
+}
+
+/// Split the given CSV text into records of fields, skipping empty lines.
+///
+/// Fields may be enclosed in double quotes to contain commas and line breaks,
+/// double quotes within them are escaped by doubling them.
+fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => quoted = false,
+            ('"', false) if field.is_empty() => quoted = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                if record.len() > 1 || !record[0].is_empty() {
+                    records.push(std::mem::take(&mut record));
+                } else {
+                    record.clear();
+                }
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("Unterminated quoted field in CSV".to_string());
+    }
+    record.push(field);
+    if record.len() > 1 || !record[0].is_empty() {
+        records.push(record);
+    }
+    Ok(records)
+}
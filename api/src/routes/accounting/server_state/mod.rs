@@ -2,9 +2,10 @@
 
 use actix_web::{
     Scope,
-    web::{delete, get, patch, post, scope},
+    web::{PayloadConfig, delete, get, patch, post, resource, scope},
 };
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
 pub mod create;
 use create::server_state_create;
@@ -18,6 +19,12 @@ pub mod delete;
 use delete::server_state_delete;
 pub mod import;
 use import::server_state_import;
+pub mod check;
+use check::server_state_check;
+pub mod repair;
+use repair::server_state_repair;
+pub mod load;
+use load::{SERVER_STATE_LOAD_LIMIT, server_state_load};
 
 /// Routes to server state endpoints.
 ///
@@ -27,6 +34,9 @@ use import::server_state_import;
 ///   - `PATCH /api/accounting/serverstates/{id}/` => [server_state_modify] endpoint
 ///   - `GET /api/accounting/serverstates/{id}/` => [server_state_delete] endpoint
 ///   - `GET /api/accounting/serverstates/import/` => [server_state_import] endpoint
+///   - `GET /api/accounting/serverstates/check/` => [server_state_check] endpoint
+///   - `POST /api/accounting/serverstates/repair/` => [server_state_repair] endpoint
+///   - `POST /api/accounting/serverstates/load/` => [server_state_load] endpoint
 pub fn server_states_scope() -> Scope {
     scope("/serverstates")
         .route("/", post().to(server_state_create))
@@ -35,12 +45,36 @@ pub fn server_states_scope() -> Scope {
         .route("/{server_state_id}/", patch().to(server_state_modify))
         .route("/{server_state_id}/", delete().to(server_state_delete))
         .route("/import/", get().to(server_state_import))
+        .route("/check/", get().to(server_state_check))
+        .route("/repair/", post().to(server_state_repair))
+        .service(
+            resource("/load/")
+                .app_data(PayloadConfig::new(SERVER_STATE_LOAD_LIMIT))
+                .route(post().to(server_state_load)),
+        )
 }
 
+/// OpenAPI documentation of the server state endpoints
+/// routed by [server_states_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::server_state_create,
+    list::server_state_list,
+    get::server_state_get,
+    modify::server_state_modify,
+    delete::server_state_delete,
+    import::server_state_import,
+    check::server_state_check,
+    repair::server_state_repair,
+    load::server_state_load
+))]
+pub struct ServerStatesApiDoc;
+
 /// Wrapper type for the server state ID parameter to user endpoints.
 ///
 /// As this is handed to endpoints as [actix_web::web::Path], it should to have a distinguishable type.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
 pub struct ServerStateIdParam {
     /// The wrapped server state ID.
     server_state_id: u32,
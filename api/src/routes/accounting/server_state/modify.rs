@@ -3,20 +3,42 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::{accounting::ServerStateModifyData, user::User};
+use avina_wire::{
+    accounting::{ServerState, ServerStateModifyData},
+    audit::ObjectType,
+    error::ErrorResponse,
+    user::User,
+};
 use sqlx::MySqlPool;
 
 use super::ServerStateIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::accounting::server_state::update_server_state_in_db,
+    database::accounting::server_state::{
+        select_maybe_server_state_from_db, update_server_state_in_db,
+    },
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    patch,
+    path = "/{server_state_id}/",
+    tag = "accounting",
+    params(ServerStateIdParam),
+    request_body = ServerStateModifyData,
+    responses(
+        (status = 200, description = "Modified server state", body = ServerState),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "server_state_modify")]
 pub async fn server_state_modify(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<ServerStateModifyData>,
     params: Path<ServerStateIdParam>,
 ) -> Result<HttpResponse, OptionApiError> {
@@ -31,8 +53,22 @@ pub async fn server_state_modify(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let server_state_before = select_maybe_server_state_from_db(
+        &mut transaction,
+        params.server_state_id as u64,
+    )
+    .await?;
     let server_state =
         update_server_state_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::ServerState,
+            params.server_state_id,
+            &server_state_before,
+            &server_state,
+        )
+        .await?;
     transaction
         .commit()
         .await
@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use actix_web::{
+    HttpResponse,
+    web::{Data, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    accounting::ServerStateAnomaly, audit::ObjectType, error::ErrorResponse,
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use super::check::find_server_state_anomalies;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::accounting::server_state::{
+        select_all_server_states_from_db, update_server_state_end_in_db,
+    },
+    error::OptionApiError,
+};
+
+/// Endpoint for repairing the unambiguous anomalies of all server states.
+///
+/// This ends each server state with a repairable anomaly, as found by
+/// [find_server_state_anomalies], at the begin of the following state, all in
+/// a single transaction. The repaired anomalies are returned, the others have
+/// to be resolved manually. Only admins can call this endpoint, otherwise a
+/// [OptionApiError::AuthorizationError] is returned.
+#[utoipa::path(
+    post,
+    path = "/repair/",
+    tag = "accounting",
+    responses(
+        (status = 200, description = "Repaired anomalies of the server states", body = Vec<ServerStateAnomaly>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "server_state_repair")]
+pub async fn server_state_repair(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let server_states =
+        select_all_server_states_from_db(&mut transaction).await?;
+    let server_states_by_id = server_states
+        .iter()
+        .cloned()
+        .map(|state| (state.id, state))
+        .collect::<HashMap<_, _>>();
+    let repaired = find_server_state_anomalies(server_states)
+        .into_iter()
+        .filter(|anomaly| anomaly.repairable)
+        .collect::<Vec<_>>();
+    for anomaly in repaired.iter() {
+        let (Some(before), Some(next)) = (
+            server_states_by_id.get(&anomaly.server_state),
+            anomaly
+                .other_server_state
+                .and_then(|id| server_states_by_id.get(&id)),
+        ) else {
+            return Err(anyhow::anyhow!(
+                "Repairable anomaly without following server state"
+            )
+            .into());
+        };
+        update_server_state_end_in_db(
+            &mut transaction,
+            before.id as u64,
+            next.begin.to_utc(),
+        )
+        .await?;
+        let mut after = before.clone();
+        after.end = Some(next.begin);
+        audit
+            .modified(
+                &mut transaction,
+                ObjectType::ServerState,
+                before.id,
+                before,
+                &after,
+            )
+            .await?;
+    }
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(repaired))
+}
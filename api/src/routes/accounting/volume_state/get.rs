@@ -0,0 +1,61 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{accounting::VolumeState, error::ErrorResponse, user::User};
+use sqlx::MySqlPool;
+
+use super::VolumeStateIdParam;
+use crate::{
+    authorization::require_master_user_or_return_not_found,
+    database::{
+        accounting::volume_state::select_volume_state_from_db,
+        user::user::select_user_from_db,
+    },
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    get,
+    path = "/{volume_state_id}",
+    tag = "accounting",
+    params(VolumeStateIdParam),
+    responses(
+        (status = 200, description = "Requested volume state", body = VolumeState),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "volume_state_get")]
+pub async fn volume_state_get(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Path<VolumeStateIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let volume_state = select_volume_state_from_db(
+        &mut transaction,
+        params.volume_state_id as u64,
+    )
+    .await?;
+    let volume_state_user =
+        select_user_from_db(&mut transaction, volume_state.user as u64).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    if volume_state.user != user.id {
+        require_master_user_or_return_not_found(
+            &user,
+            volume_state_user.project,
+        )?;
+    }
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(volume_state))
+}
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use actix_web::{
+    HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::{Context, anyhow};
+use avina_wire::{
+    accounting::VolumeStateImport,
+    dry_run::{Change, ChangeAction, ChangeObject, DryRunParams},
+    error::ErrorResponse,
+    user::User,
+};
+use chrono::Utc;
+use sqlx::{MySql, MySqlPool, Transaction};
+use uuid::Uuid;
+
+use crate::{
+    authorization::require_admin_user,
+    database::accounting::volume_state::{
+        NewVolumeState, insert_volume_state_into_db,
+        select_unfinished_volume_states_from_db, update_volume_state_end_in_db,
+    },
+    dry_run::{DryRunnable, finish},
+    error::OptionApiError,
+    openstack::{OpenStack, VolumeDetailed},
+    routes::accounting::server_state::import::{
+        select_users_by_openstack_id_from_db, union_hash_zip,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/import/",
+    tag = "accounting",
+    params(DryRunParams),
+    responses(
+        (status = 200, description = "Summary of the import, with the changes in dry runs", body = DryRunnable<VolumeStateImport>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "volume_state_import", skip(openstack))]
+pub async fn volume_state_import(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    openstack: Data<OpenStack>,
+    params: Query<DryRunParams>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let mut changes = Vec::new();
+    let import =
+        import_volume_states(&mut transaction, &openstack, &mut changes)
+            .await?;
+    Ok(finish(transaction, &params, import, changes).await?)
+}
+
+/// Import the current volume states from Cinder into the database.
+///
+/// This works like the import of server states: new volume states are created
+/// for volumes without an unfinished state or whose status, name, size or
+/// owning user changed, and the unfinished states of volumes that changed or
+/// vanished are ended. Volumes of unknown users are skipped. Every opened and
+/// ended state is recorded in the given changes.
+#[tracing::instrument(
+    name = "import_volume_states",
+    skip(transaction, openstack)
+)]
+pub async fn import_volume_states(
+    transaction: &mut Transaction<'_, MySql>,
+    openstack: &OpenStack,
+    changes: &mut Vec<Change>,
+) -> Result<VolumeStateImport, OptionApiError> {
+    let volumes = openstack
+        .get_volumes()
+        .await?
+        .into_iter()
+        .map(|v| (v.id, v))
+        .collect::<HashMap<_, _>>();
+    let states = select_unfinished_volume_states_from_db(transaction)
+        .await?
+        .into_iter()
+        .map(|s| (s.volume_id, s))
+        .collect::<HashMap<_, _>>();
+    let users = select_users_by_openstack_id_from_db(transaction).await?;
+
+    let mut new_state_count = 0;
+    let mut end_state_count = 0;
+
+    for volume_and_state in union_hash_zip(volumes, states).values() {
+        match volume_and_state {
+            (Some(volume), Some(state)) => {
+                let user = users.get(&volume.tenant_id).map(|(id, _)| *id);
+                let name = volume.name.clone().unwrap_or_default();
+                let mut reasons = Vec::new();
+                if volume.status != state.status {
+                    reasons.push(format!(
+                        "status changed from {} to {}",
+                        state.status, volume.status
+                    ));
+                }
+                if name != state.volume_name {
+                    reasons.push(format!(
+                        "name changed from {} to {}",
+                        state.volume_name, name
+                    ));
+                }
+                if volume.size != state.size {
+                    reasons.push(format!(
+                        "size changed from {} to {}",
+                        state.size, volume.size
+                    ));
+                }
+                if user != Some(state.user) {
+                    reasons.push(format!(
+                        "user changed from {} to {}",
+                        state.username, volume.tenant_id
+                    ));
+                }
+                if !reasons.is_empty() {
+                    let reason = reasons.join(", ");
+                    update_volume_state_end_in_db(
+                        transaction,
+                        state.id as u64,
+                        Utc::now(),
+                    )
+                    .await?;
+                    end_state_count += 1;
+                    changes.push(volume_state_change(
+                        ChangeAction::End,
+                        volume.id,
+                        &reason,
+                    ));
+                    let count =
+                        create_volume_state_in_db(transaction, volume, user)
+                            .await?;
+                    if count > 0 {
+                        changes.push(volume_state_change(
+                            ChangeAction::Create,
+                            volume.id,
+                            &reason,
+                        ));
+                    }
+                    new_state_count += count;
+                }
+            }
+            (Some(volume), None) => {
+                let user = users.get(&volume.tenant_id).map(|(id, _)| *id);
+                let count =
+                    create_volume_state_in_db(transaction, volume, user)
+                        .await?;
+                if count > 0 {
+                    changes.push(volume_state_change(
+                        ChangeAction::Create,
+                        volume.id,
+                        "volume has no unfinished state",
+                    ));
+                }
+                new_state_count += count;
+            }
+            (None, Some(state)) => {
+                update_volume_state_end_in_db(
+                    transaction,
+                    state.id as u64,
+                    Utc::now(),
+                )
+                .await?;
+                end_state_count += 1;
+                changes.push(volume_state_change(
+                    ChangeAction::End,
+                    state.volume_id,
+                    "volume no longer exists",
+                ));
+            }
+            (None, None) => {
+                return Err(anyhow!(
+                    "Volume state hash map contains invalid none-none pair."
+                )
+                .into());
+            }
+        }
+    }
+
+    Ok(VolumeStateImport {
+        new_state_count,
+        end_state_count,
+    })
+}
+
+fn volume_state_change(
+    action: ChangeAction,
+    volume_id: Uuid,
+    reason: &str,
+) -> Change {
+    Change {
+        action,
+        object: ChangeObject::VolumeState,
+        name: volume_id.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Create a new volume state for the volume owned by the given user, or skip
+/// it when the user is unknown, returning the number of created states.
+#[tracing::instrument(name = "create_volume_state_in_db", skip(transaction))]
+async fn create_volume_state_in_db(
+    transaction: &mut Transaction<'_, MySql>,
+    volume: &VolumeDetailed,
+    user: Option<u32>,
+) -> Result<u32, OptionApiError> {
+    let Some(user) = user else {
+        tracing::warn!(
+            "User {} not found, skipping volume state creation.",
+            volume.tenant_id
+        );
+        return Ok(0);
+    };
+    let volume_state = NewVolumeState {
+        begin: Utc::now(),
+        end: None,
+        volume_id: volume.id,
+        volume_name: volume.name.clone().unwrap_or_default(),
+        status: volume.status.clone(),
+        size: volume.size,
+        user,
+    };
+    let _ = insert_volume_state_into_db(transaction, &volume_state).await?;
+    Ok(1)
+}
@@ -0,0 +1,120 @@
+use actix_web::{
+    HttpRequest, HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    accounting::{VolumeState, VolumeStateListParams},
+    error::ErrorResponse,
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use crate::{
+    authorization::{
+        require_admin_user, require_master_user_or_return_not_found,
+        require_user_or_project_master_or_not_found,
+    },
+    database::{
+        accounting::volume_state::{
+            VolumeStateFilter, select_volume_state_list_from_db,
+            select_volume_states_by_volume_from_db,
+        },
+        user::user::select_user_from_db,
+    },
+    error::OptionApiError,
+    pagination::{ListQuery, Paginated, paginate},
+};
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "accounting",
+    params(VolumeStateListParams),
+    responses(
+        (status = 200, description = "List of volume states", body = Paginated<VolumeState>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "volume_state_list")]
+pub async fn volume_state_list(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<VolumeStateListParams>,
+    req: HttpRequest,
+) -> Result<HttpResponse, OptionApiError> {
+    let list_query = ListQuery::new::<VolumeState>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let filter = if params.all.unwrap_or(false) {
+        require_admin_user(&user)?;
+        VolumeStateFilter {
+            volume: params.volume,
+            ..Default::default()
+        }
+    } else if let Some(project_id) = params.project {
+        require_master_user_or_return_not_found(&user, project_id)?;
+        VolumeStateFilter {
+            project: Some(project_id as u64),
+            volume: params.volume,
+            ..Default::default()
+        }
+    } else if let Some(user_id) = params.user {
+        let user1 = select_user_from_db(&mut transaction, user_id as u64)
+            .await
+            .context("Failed to select user")?;
+        require_user_or_project_master_or_not_found(
+            &user,
+            user1.id,
+            user1.project,
+        )?;
+        VolumeStateFilter {
+            user: Some(user1.id as u64),
+            volume: params.volume,
+            ..Default::default()
+        }
+    } else if let Some(volume_id) = params.volume {
+        let volume_states =
+            select_volume_states_by_volume_from_db(&mut transaction, volume_id)
+                .await?;
+        let Some(volume_state) = volume_states.first() else {
+            return Err(OptionApiError::NotFoundError);
+        };
+        let volume_state_user =
+            select_user_from_db(&mut transaction, volume_state.user as u64)
+                .await?;
+        require_user_or_project_master_or_not_found(
+            &user,
+            volume_state_user.id,
+            volume_state_user.project,
+        )?;
+        VolumeStateFilter {
+            volume: Some(volume_id),
+            ..Default::default()
+        }
+    } else {
+        VolumeStateFilter {
+            user: Some(user.id as u64),
+            ..Default::default()
+        }
+    };
+    let (volume_states, count) = select_volume_state_list_from_db(
+        &mut transaction,
+        &filter,
+        &list_query,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(paginate(&req, volume_states, count, &list_query))
+}
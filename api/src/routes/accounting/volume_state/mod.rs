@@ -0,0 +1,47 @@
+//! Endpoints for volume states.
+
+use actix_web::{
+    Scope,
+    web::{get, scope},
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
+
+pub mod list;
+use list::volume_state_list;
+pub mod get;
+use get::volume_state_get;
+pub mod import;
+use import::volume_state_import;
+
+/// Routes to volume state endpoints.
+///
+///   - `GET /api/accounting/volumestates` => [volume_state_list] endpoint
+///   - `GET /api/accounting/volumestates/{id}` => [volume_state_get] endpoint
+///   - `GET /api/accounting/volumestates/import/` => [volume_state_import] endpoint
+pub fn volume_states_scope() -> Scope {
+    scope("/volumestates")
+        .route("", get().to(volume_state_list))
+        .route("/{volume_state_id}", get().to(volume_state_get))
+        .route("/import/", get().to(volume_state_import))
+}
+
+/// OpenAPI documentation of the volume state endpoints
+/// routed by [volume_states_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    list::volume_state_list,
+    get::volume_state_get,
+    import::volume_state_import
+))]
+pub struct VolumeStatesApiDoc;
+
+/// Wrapper type for the volume state ID parameter to user endpoints.
+///
+/// As this is handed to endpoints as [actix_web::web::Path], it should to have a distinguishable type.
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct VolumeStateIdParam {
+    /// The wrapped volume state ID.
+    volume_state_id: u32,
+}
@@ -0,0 +1,72 @@
+//! Implementation of the audit-log-list endpoint.
+
+use actix_web::{
+    HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::{AuditLogEntry, AuditLogListParams},
+    error::ErrorResponse,
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use crate::{
+    authorization::require_admin_user,
+    database::audit::audit_log::select_audit_log_entries_from_db,
+    error::NormalApiError,
+};
+
+/// Number of audit log entries returned when no limit is given.
+const DEFAULT_LIMIT: u32 = 100;
+
+/// List the most recent audit log entries matching the given [AuditLogListParams].
+///
+/// Entries can be filtered by object type, by user, matching both the acting and the
+/// impersonated user, and by a time range.
+///
+/// Only admins can call this endpoint, otherwise a [NormalApiError::AuthorizationError] is
+/// returned. If the begin of the time range is not before its end, a
+/// [NormalApiError::ValidationError] is returned.
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "audit",
+    params(AuditLogListParams),
+    responses(
+        (status = 200, description = "List of recent audit log entries", body = Vec<AuditLogEntry>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "audit_log_list")]
+pub async fn audit_log_list(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<AuditLogListParams>,
+) -> Result<HttpResponse, NormalApiError> {
+    require_admin_user(&user)?;
+    if let (Some(begin), Some(end)) = (params.begin, params.end)
+        && begin >= end
+    {
+        return Err(NormalApiError::ValidationError(
+            "Begin must be before end".to_string(),
+        ));
+    }
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT);
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let entries =
+        select_audit_log_entries_from_db(&mut transaction, &params, limit)
+            .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(entries))
+}
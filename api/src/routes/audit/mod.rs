@@ -0,0 +1,22 @@
+//! Endpoints for the audit log.
+
+use actix_web::{
+    Scope,
+    web::{get, scope},
+};
+use utoipa::OpenApi;
+
+pub mod list;
+use list::audit_log_list;
+
+/// Routes to audit log endpoints.
+///
+///   - `GET /api/audit/` => [audit_log_list] endpoint
+pub fn audit_scope() -> Scope {
+    scope("/audit").route("/", get().to(audit_log_list))
+}
+
+/// OpenAPI documentation of the audit log endpoints routed by [audit_scope].
+#[derive(OpenApi)]
+#[openapi(paths(list::audit_log_list))]
+pub struct AuditApiDoc;
@@ -4,12 +4,16 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
+    audit::ObjectType,
     budgeting::{BudgetBulkCreate, BudgetBulkCreateData},
+    error::ErrorResponse,
     user::User,
 };
+use serde_json::json;
 use sqlx::{MySql, MySqlPool, Transaction};
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
     database::{
         budgeting::{
@@ -89,10 +93,22 @@ async fn bulk_create_project_budgets(
     Ok(count)
 }
 
+#[utoipa::path(
+    post,
+    path = "/budgetbulkcreate/",
+    tag = "budgeting",
+    request_body = BudgetBulkCreateData,
+    responses(
+        (status = 200, description = "Number of created budgets", body = BudgetBulkCreate),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "budget_bulk_create")]
 pub async fn budget_bulk_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<BudgetBulkCreateData>,
     // TODO: is the ValidationError variant ever used?
 ) -> Result<HttpResponse, NormalApiError> {
@@ -105,6 +121,24 @@ pub async fn budget_bulk_create(
         bulk_create_user_budgets(&mut transaction, data.year as u32).await?;
     let new_project_budget_count =
         bulk_create_project_budgets(&mut transaction, data.year as u32).await?;
+    audit
+        .record(
+            &mut transaction,
+            ObjectType::UserBudget,
+            None,
+            None,
+            Some(json!({"year": data.year, "count": new_user_budget_count})),
+        )
+        .await?;
+    audit
+        .record(
+            &mut transaction,
+            ObjectType::ProjectBudget,
+            None,
+            None,
+            Some(json!({"year": data.year, "count": new_project_budget_count})),
+        )
+        .await?;
     transaction
         .commit()
         .await
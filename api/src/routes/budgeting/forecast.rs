@@ -0,0 +1,329 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    accounting::ServerState,
+    budgeting::{BudgetForecast, BudgetForecastParams},
+    error::ErrorResponse,
+    user::{Project, User},
+};
+use chrono::{DateTime, Datelike, TimeDelta, Utc};
+use sqlx::{MySql, MySqlPool, Transaction};
+
+use crate::{
+    authorization::{
+        require_master_user_or_return_not_found,
+        require_user_or_project_master_or_not_found,
+    },
+    database::{
+        accounting::server_state::{
+            select_server_states_by_project_from_db,
+            select_server_states_by_user_from_db,
+        },
+        budgeting::{
+            project_budget::select_maybe_project_budget_by_project_and_year_from_db,
+            user_budget::select_maybe_user_budget_by_user_and_year_from_db,
+        },
+        user::{project::select_project_from_db, user::select_user_from_db},
+    },
+    error::{
+        NotFoundOrUnexpectedApiError, OptionApiError, UnexpectedOnlyError,
+    },
+    routes::accounting::{
+        server_consumption::get::BillingRates,
+        server_cost::get::{
+            PricePeriods, Prices, calculate_flavor_consumption_cost,
+            calculate_server_cost_for_project_normal,
+            calculate_server_cost_for_user_normal, get_flavor_price_periods,
+        },
+    },
+    utils::start_of_the_year,
+};
+
+/// Get the boundaries of the steps of the forecast from begin to end.
+///
+/// The steps end at every change of prices or billing policies, so the cost of
+/// the running servers grows linearly within each step.
+fn forecast_steps(
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+    changes: impl Iterator<Item = DateTime<Utc>>,
+) -> Vec<DateTime<Utc>> {
+    let mut times = vec![begin, end];
+    times.extend(changes.filter(|time| *time > begin && *time < end));
+    times.sort();
+    times.dedup();
+    times
+}
+
+/// Calculate the cost of the given running servers of the project during the
+/// period.
+fn calculate_running_server_cost(
+    servers: &[ServerState],
+    rates: &BillingRates,
+    prices: &Prices,
+    project: &Project,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> f64 {
+    servers
+        .iter()
+        .map(|server| {
+            let seconds = rates.billable_seconds(
+                &server.status,
+                begin.fixed_offset(),
+                end.fixed_offset(),
+            );
+            calculate_flavor_consumption_cost(
+                seconds,
+                prices,
+                project.id,
+                project.user_class,
+                &server.flavor_name,
+            )
+        })
+        .sum()
+}
+
+/// Project the cost of the running servers over the given steps.
+///
+/// Returns the projected total, starting from the given cost, and the time at
+/// which the budget is exceeded, which is interpolated linearly within the
+/// step it happens in, see [forecast_steps].
+/// A budget already exceeded by the given cost is not reported.
+fn project_running_server_cost(
+    servers: &[ServerState],
+    rates: &BillingRates,
+    price_periods: &PricePeriods,
+    project: &Project,
+    steps: &[DateTime<Utc>],
+    cost: f64,
+    budget: Option<u32>,
+) -> (f64, Option<DateTime<Utc>>) {
+    let mut total = cost;
+    let mut exceeded_at = None;
+    let mut period = 0;
+    for step in steps.windows(2) {
+        let (step_begin, step_end) = (step[0], step[1]);
+        while price_periods
+            .get_index(period + 1)
+            .is_some_and(|(start_time, _)| *start_time <= step_begin)
+        {
+            period += 1;
+        }
+        let Some((_, prices)) = price_periods.get_index(period) else {
+            break;
+        };
+        let step_cost = calculate_running_server_cost(
+            servers, rates, prices, project, step_begin, step_end,
+        );
+        if let Some(budget) = budget
+            && exceeded_at.is_none()
+            && total < budget as f64
+            && total + step_cost >= budget as f64
+        {
+            let fraction = (budget as f64 - total) / step_cost;
+            let seconds = (step_end - step_begin).num_seconds() as f64;
+            exceeded_at = Some(
+                step_begin + TimeDelta::seconds((seconds * fraction) as i64),
+            );
+        }
+        total += step_cost;
+    }
+    (total, exceeded_at)
+}
+
+/// Forecast the cost of the given servers on top of the cost so far.
+///
+/// Only the servers without an end, i.e. the currently running ones, are
+/// projected, the cost of volumes and floating IPs is not.
+async fn forecast_running_servers(
+    transaction: &mut Transaction<'_, MySql>,
+    mut servers: Vec<ServerState>,
+    project: &Project,
+    cost: f64,
+    budget: Option<(u32, u32)>,
+    now: DateTime<Utc>,
+) -> Result<BudgetForecast, UnexpectedOnlyError> {
+    servers.retain(|server| server.end.is_none());
+    let end = start_of_the_year(now.year() as u32 + 1);
+    let price_periods = get_flavor_price_periods(transaction, now, end).await?;
+    let rates = BillingRates::load(
+        transaction,
+        Some(project.user_class),
+        Some(now),
+        Some(end),
+    )
+    .await?;
+    let hourly_cost = match price_periods.first() {
+        Some((_, prices)) => calculate_running_server_cost(
+            &servers,
+            &rates,
+            prices,
+            project,
+            now,
+            now + TimeDelta::hours(1),
+        ),
+        None => 0.,
+    };
+    let steps = forecast_steps(
+        now,
+        end,
+        price_periods.keys().cloned().chain(rates.change_times()),
+    );
+    let amount = budget.map(|(_, amount)| amount);
+    let (forecast, projected_exceeded_at) = project_running_server_cost(
+        &servers,
+        &rates,
+        &price_periods,
+        project,
+        &steps,
+        cost,
+        amount,
+    );
+    let exceeded_at = amount
+        .filter(|amount| cost >= *amount as f64)
+        .map(|_| now)
+        .or(projected_exceeded_at);
+    Ok(BudgetForecast {
+        budget_id: budget.map(|(id, _)| id),
+        budget: amount,
+        cost,
+        hourly_cost,
+        forecast,
+        end: end.fixed_offset(),
+        exceeded_at: exceeded_at.map(|time| time.fixed_offset()),
+    })
+}
+
+pub async fn calculate_budget_forecast_for_user(
+    transaction: &mut Transaction<'_, MySql>,
+    user_id: u64,
+    now: DateTime<Utc>,
+) -> Result<BudgetForecast, NotFoundOrUnexpectedApiError> {
+    let user = select_user_from_db(transaction, user_id).await?;
+    let project =
+        select_project_from_db(transaction, user.project as u64).await?;
+    let year = now.year() as u32;
+    let budget = select_maybe_user_budget_by_user_and_year_from_db(
+        transaction,
+        user_id,
+        year,
+    )
+    .await?;
+    let cost = calculate_server_cost_for_user_normal(
+        transaction,
+        user_id,
+        start_of_the_year(year),
+        now,
+    )
+    .await?;
+    let servers =
+        select_server_states_by_user_from_db(transaction, user_id).await?;
+    Ok(forecast_running_servers(
+        transaction,
+        servers,
+        &project,
+        cost.total,
+        budget.map(|budget| (budget.id, budget.amount)),
+        now,
+    )
+    .await?)
+}
+
+pub async fn calculate_budget_forecast_for_project(
+    transaction: &mut Transaction<'_, MySql>,
+    project_id: u64,
+    now: DateTime<Utc>,
+) -> Result<BudgetForecast, NotFoundOrUnexpectedApiError> {
+    let project = select_project_from_db(transaction, project_id).await?;
+    let year = now.year() as u32;
+    let budget = select_maybe_project_budget_by_project_and_year_from_db(
+        transaction,
+        project_id,
+        year,
+    )
+    .await?;
+    let cost = calculate_server_cost_for_project_normal(
+        transaction,
+        project_id,
+        start_of_the_year(year),
+        now,
+    )
+    .await?;
+    let servers =
+        select_server_states_by_project_from_db(transaction, project_id)
+            .await?;
+    Ok(forecast_running_servers(
+        transaction,
+        servers,
+        &project,
+        cost.total,
+        budget.map(|budget| (budget.id, budget.amount)),
+        now,
+    )
+    .await?)
+}
+
+/// Endpoint forecasting the cost of a user or project to the end of the year.
+///
+/// Defaults to the requesting user, a project's forecast requires being its
+/// master, and another user's being the user itself or their project's
+/// master. The cost so far is projected by the currently running servers.
+#[utoipa::path(
+    get,
+    path = "/budgetforecast/",
+    tag = "budgeting",
+    params(BudgetForecastParams),
+    responses(
+        (status = 200, description = "Forecast of the cost and budget", body = BudgetForecast),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "budget_forecast")]
+pub async fn budget_forecast(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<BudgetForecastParams>,
+) -> Result<HttpResponse, OptionApiError> {
+    let now = Utc::now();
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let forecast = if let Some(project_id) = params.project {
+        require_master_user_or_return_not_found(&user, project_id)?;
+        calculate_budget_forecast_for_project(
+            &mut transaction,
+            project_id as u64,
+            now,
+        )
+        .await?
+    } else {
+        let user_id = params.user.unwrap_or(user.id);
+        let user_queried =
+            select_user_from_db(&mut transaction, user_id as u64).await?;
+        require_user_or_project_master_or_not_found(
+            &user,
+            user_id,
+            user_queried.project,
+        )?;
+        calculate_budget_forecast_for_user(
+            &mut transaction,
+            user_id as u64,
+            now,
+        )
+        .await?
+    };
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(forecast))
+}
@@ -2,6 +2,7 @@ use actix_web::{
     Scope,
     web::{get, post, scope},
 };
+use utoipa::OpenApi;
 
 mod project_budget;
 use project_budget::project_budgets_scope;
@@ -11,6 +12,8 @@ mod bulk_create;
 use bulk_create::budget_bulk_create;
 mod over_tree;
 use over_tree::budget_over_tree;
+mod forecast;
+use forecast::budget_forecast;
 
 pub fn budgeting_scope() -> Scope {
     scope("/budgeting")
@@ -18,4 +21,21 @@ pub fn budgeting_scope() -> Scope {
         .service(user_budgets_scope())
         .route("/budgetbulkcreate/", post().to(budget_bulk_create))
         .route("/budgetovertree/", get().to(budget_over_tree))
+        .route("/budgetforecast/", get().to(budget_forecast))
 }
+
+/// OpenAPI documentation of the budgeting endpoints
+/// routed by [budgeting_scope].
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        bulk_create::budget_bulk_create,
+        over_tree::budget_over_tree,
+        forecast::budget_forecast
+    ),
+    nest(
+        (path = "/projectbudgets", api = project_budget::ProjectBudgetsApiDoc),
+        (path = "/userbudgets", api = user_budget::UserBudgetsApiDoc)
+    )
+)]
+pub struct BudgetingApiDoc;
@@ -10,6 +10,7 @@ use avina_wire::{
         BudgetOverTree, BudgetOverTreeParams, BudgetOverTreeProject,
         BudgetOverTreeServer, BudgetOverTreeUser,
     },
+    error::ErrorResponse,
     user::User,
 };
 use chrono::{DateTime, Datelike, Utc};
@@ -55,6 +56,7 @@ async fn budget_over_tree_for_user(
 ) -> Result<BudgetOverTree, NotFoundOrUnexpectedApiError> {
     let mut tree = BudgetOverTree {
         cost: None,
+        discount: None,
         projects: HashMap::new(),
         flavors: None,
     };
@@ -83,10 +85,12 @@ async fn budget_over_tree_for_user(
     .await?;
 
     tree.cost = Some(project_cost.total);
+    tree.discount = Some(project_cost.discount);
     tree.projects.insert(
         user.project_name.clone(),
         BudgetOverTreeProject {
             cost: project_cost.total,
+            discount: project_cost.discount,
             budget_id: None,
             budget: None,
             over: false,
@@ -111,6 +115,7 @@ async fn budget_over_tree_for_user(
             user.name.clone(),
             BudgetOverTreeUser {
                 cost: user_cost.total,
+                discount: user_cost.discount,
                 budget_id: None,
                 budget: None,
                 over: false,
@@ -131,6 +136,7 @@ async fn budget_over_tree_for_user(
                 server_uuid,
                 BudgetOverTreeServer {
                     total: server_cost.total,
+                    discount: server_cost.discount,
                     flavors: server_cost.flavors,
                 },
             );
@@ -151,6 +157,7 @@ async fn budget_over_tree_for_project(
 ) -> Result<BudgetOverTree, UnexpectedOnlyError> {
     let mut tree = BudgetOverTree {
         cost: None,
+        discount: None,
         projects: HashMap::new(),
         flavors: None,
     };
@@ -185,10 +192,12 @@ async fn budget_over_tree_for_project(
     .await?;
 
     tree.cost = Some(project_cost.total);
+    tree.discount = Some(project_cost.discount);
     tree.projects.insert(
         project.name.clone(),
         BudgetOverTreeProject {
             cost: project_cost.total,
+            discount: project_cost.discount,
             budget_id: None,
             budget: None,
             over: false,
@@ -209,6 +218,7 @@ async fn budget_over_tree_for_project(
             username.clone(),
             BudgetOverTreeUser {
                 cost: user_cost.total,
+                discount: user_cost.discount,
                 budget_id: None,
                 budget: None,
                 over: false,
@@ -229,6 +239,7 @@ async fn budget_over_tree_for_project(
                 server_uuid,
                 BudgetOverTreeServer {
                     total: server_cost.total,
+                    discount: server_cost.discount,
                     flavors: server_cost.flavors,
                 },
             );
@@ -262,6 +273,7 @@ async fn budget_over_tree_for_all(
         calculate_server_cost_for_all_detail(transaction, begin, end).await?;
     let mut tree = BudgetOverTree {
         cost: Some(all_cost.total),
+        discount: Some(all_cost.discount),
         projects: HashMap::new(),
         flavors: Some(all_cost.flavors),
     };
@@ -271,6 +283,7 @@ async fn budget_over_tree_for_all(
             project_name.clone(),
             BudgetOverTreeProject {
                 cost: project_cost.total,
+                discount: project_cost.discount,
                 budget_id: None,
                 budget: None,
                 over: false,
@@ -292,6 +305,7 @@ async fn budget_over_tree_for_all(
                 username.clone(),
                 BudgetOverTreeUser {
                     cost: user_cost.total,
+                    discount: user_cost.discount,
                     budget_id: None,
                     budget: None,
                     over: false,
@@ -312,6 +326,7 @@ async fn budget_over_tree_for_all(
                     server_uuid,
                     BudgetOverTreeServer {
                         total: server_cost.total,
+                        discount: server_cost.discount,
                         flavors: server_cost.flavors,
                     },
                 );
@@ -322,6 +337,18 @@ async fn budget_over_tree_for_all(
     Ok(tree)
 }
 
+#[utoipa::path(
+    get,
+    path = "/budgetovertree/",
+    tag = "budgeting",
+    params(BudgetOverTreeParams),
+    responses(
+        (status = 200, description = "Tree of budgets and their costs", body = BudgetOverTree),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "budget_over_tree")]
 pub async fn budget_over_tree(
     user: ReqData<User>,
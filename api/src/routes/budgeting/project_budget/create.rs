@@ -4,12 +4,15 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
+    audit::ObjectType,
     budgeting::{ProjectBudget, ProjectBudgetCreateData},
+    error::ErrorResponse,
     user::User,
 };
 use sqlx::MySqlPool;
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
     database::{
         budgeting::project_budget::{
@@ -20,10 +23,23 @@ use crate::{
     error::{NormalApiError, OptionApiError},
 };
 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "budgeting",
+    request_body = ProjectBudgetCreateData,
+    responses(
+        (status = 201, description = "Created project budget", body = ProjectBudget),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_budget_create")]
 pub async fn project_budget_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<ProjectBudgetCreateData>,
 ) -> Result<HttpResponse, OptionApiError> {
     require_admin_user(&user)?;
@@ -41,10 +57,6 @@ pub async fn project_budget_create(
     let id =
         insert_project_budget_into_db(&mut transaction, &new_project_budget)
             .await?;
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
     let project_budget_created = ProjectBudget {
         id: id as u32,
         project: new_project_budget.project_id as u32,
@@ -52,6 +64,18 @@ pub async fn project_budget_create(
         year: new_project_budget.year,
         amount: new_project_budget.amount as u32,
     };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::ProjectBudget,
+            project_budget_created.id,
+            &project_budget_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
     Ok(HttpResponse::Created()
         .content_type("application/json")
         .json(project_budget_created))
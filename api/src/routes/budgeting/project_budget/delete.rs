@@ -3,20 +3,35 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::ProjectBudgetIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::budgeting::project_budget::delete_project_budget_from_db,
+    database::budgeting::project_budget::{
+        delete_project_budget_from_db, select_maybe_project_budget_from_db,
+    },
     error::NormalApiError,
 };
 
+#[utoipa::path(
+    delete,
+    path = "/{project_budget_id}/",
+    tag = "budgeting",
+    params(ProjectBudgetIdParam),
+    responses(
+        (status = 204, description = "Project budget deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_budget_delete")]
 pub async fn project_budget_delete(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     params: Path<ProjectBudgetIdParam>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -24,11 +39,24 @@ pub async fn project_budget_delete(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let project_budget_before = select_maybe_project_budget_from_db(
+        &mut transaction,
+        params.project_budget_id as u64,
+    )
+    .await?;
     delete_project_budget_from_db(
         &mut transaction,
         params.project_budget_id as u64,
     )
     .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::ProjectBudget,
+            params.project_budget_id,
+            &project_budget_before,
+        )
+        .await?;
     transaction
         .commit()
         .await
@@ -3,7 +3,7 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{budgeting::ProjectBudget, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::ProjectBudgetIdParam;
@@ -13,6 +13,18 @@ use crate::{
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "/{project_budget_id}",
+    tag = "budgeting",
+    params(ProjectBudgetIdParam),
+    responses(
+        (status = 200, description = "Requested project budget", body = ProjectBudget),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_budget_get")]
 pub async fn project_budget_get(
     user: ReqData<User>,
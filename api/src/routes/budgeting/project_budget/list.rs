@@ -1,10 +1,11 @@
 use actix_web::{
-    HttpResponse,
+    HttpRequest, HttpResponse,
     web::{Data, Query, ReqData},
 };
 use anyhow::Context;
 use avina_wire::{
-    budgeting::ProjectBudgetListParams,
+    budgeting::{ProjectBudget, ProjectBudgetListParams},
+    error::ErrorResponse,
     user::{Project, User},
 };
 use sqlx::MySqlPool;
@@ -15,37 +16,51 @@ use crate::{
     },
     database::{
         budgeting::project_budget::{
-            select_all_project_budgets_from_db,
-            select_project_budgets_by_project_from_db,
-            select_project_budgets_by_user_from_db,
-            select_project_budgets_by_year_from_db,
+            ProjectBudgetFilter, select_project_budget_list_from_db,
         },
         user::user::select_user_from_db,
     },
     error::NormalApiError,
+    pagination::{ListQuery, Paginated, paginate},
 };
 
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "budgeting",
+    params(ProjectBudgetListParams),
+    responses(
+        (status = 200, description = "List of project budgets", body = Paginated<ProjectBudget>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_budget_list")]
 pub async fn project_budget_list(
     user: ReqData<User>,
     project: ReqData<Project>,
     db_pool: Data<MySqlPool>,
     params: Query<ProjectBudgetListParams>,
+    req: HttpRequest,
 ) -> Result<HttpResponse, NormalApiError> {
+    let list_query = ListQuery::new::<ProjectBudget>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
     let mut transaction = db_pool
         .begin()
         .await
         .context("Failed to begin transaction")?;
-    let project_budgets = if params.all.unwrap_or(false) {
+    let filter = if params.all.unwrap_or(false) {
         require_admin_user(&user)?;
-        select_all_project_budgets_from_db(&mut transaction).await?
+        ProjectBudgetFilter::default()
     } else if let Some(project_id) = params.project {
         require_project_user(&user, project_id)?;
-        select_project_budgets_by_project_from_db(
-            &mut transaction,
-            project_id as u64,
-        )
-        .await?
+        ProjectBudgetFilter {
+            project: Some(project_id as u64),
+            ..Default::default()
+        }
     } else if let Some(user_id) = params.user {
         // TODO: this can be optimized when the user is the current user
         let queried_user =
@@ -53,25 +68,31 @@ pub async fn project_budget_list(
                 .await
                 .context("Failed to select user")?;
         require_master_user(&user, queried_user.project)?;
-        // TODO: since we already query the user we have the project id
-        //       and can thus use select_project_budgets_by_project_from_db
-        select_project_budgets_by_user_from_db(
-            &mut transaction,
-            queried_user.id as u64,
-        )
-        .await?
+        ProjectBudgetFilter {
+            project: Some(queried_user.project as u64),
+            ..Default::default()
+        }
     } else if let Some(year) = params.year {
         require_admin_user(&user)?;
-        select_project_budgets_by_year_from_db(&mut transaction, year).await?
+        ProjectBudgetFilter {
+            year: Some(year),
+            ..Default::default()
+        }
     } else {
-        select_project_budgets_by_user_from_db(&mut transaction, user.id as u64)
-            .await?
+        ProjectBudgetFilter {
+            user: Some(user.id as u64),
+            ..Default::default()
+        }
     };
+    let (project_budgets, count) = select_project_budget_list_from_db(
+        &mut transaction,
+        &filter,
+        &list_query,
+    )
+    .await?;
     transaction
         .commit()
         .await
         .context("Failed to commit transaction")?;
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .json(project_budgets))
+    Ok(paginate(&req, project_budgets, count, &list_query))
 }
@@ -3,6 +3,7 @@ use actix_web::{
     web::{delete, get, patch, post, scope},
 };
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
 mod create;
 use create::project_budget_create;
@@ -27,7 +28,21 @@ pub fn project_budgets_scope() -> Scope {
         .route("/over/", get().to(project_budget_over))
 }
 
-#[derive(Deserialize, Debug)]
+/// OpenAPI documentation of the project budget endpoints
+/// routed by [project_budgets_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::project_budget_create,
+    list::project_budget_list,
+    get::project_budget_get,
+    modify::project_budget_modify,
+    delete::project_budget_delete,
+    over::project_budget_over
+))]
+pub struct ProjectBudgetsApiDoc;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
 struct ProjectBudgetIdParam {
     project_budget_id: u32,
 }
@@ -3,12 +3,18 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
 };
 use anyhow::{Context, anyhow};
-use avina_wire::{budgeting::ProjectBudgetModifyData, user::User};
+use avina_wire::{
+    audit::ObjectType,
+    budgeting::{ProjectBudget, ProjectBudgetModifyData},
+    error::ErrorResponse,
+    user::User,
+};
 use chrono::{Datelike, Utc};
 use sqlx::MySqlPool;
 
 use super::ProjectBudgetIdParam;
 use crate::{
+    audit::Audit,
     authorization::{
         require_admin_user, require_master_user_or_return_not_found,
     },
@@ -23,10 +29,24 @@ use crate::{
     utils::start_of_the_year,
 };
 
+#[utoipa::path(
+    patch,
+    path = "/{project_budget_id}/",
+    tag = "budgeting",
+    params(ProjectBudgetIdParam),
+    request_body = ProjectBudgetModifyData,
+    responses(
+        (status = 200, description = "Modified project budget", body = ProjectBudget),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_budget_modify")]
 pub async fn project_budget_modify(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<ProjectBudgetModifyData>,
     params: Path<ProjectBudgetIdParam>,
 ) -> Result<HttpResponse, OptionApiError> {
@@ -75,13 +95,22 @@ pub async fn project_budget_modify(
         )));
     }
 
-    let project_budget =
+    let project_budget_modified =
         update_project_budget_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::ProjectBudget,
+            project_budget.id,
+            &project_budget,
+            &project_budget_modified,
+        )
+        .await?;
     transaction
         .commit()
         .await
         .context("Failed to commit transaction")?;
     Ok(HttpResponse::Ok()
         .content_type("application/json")
-        .json(project_budget))
+        .json(project_budget_modified))
 }
@@ -8,11 +8,13 @@ use avina_wire::{
         ProjectBudgetOverDetail, ProjectBudgetOverParams,
         ProjectBudgetOverSimple,
     },
+    error::ErrorResponse,
     user::User,
 };
 use chrono::{DateTime, Datelike, Utc};
 use serde::Serialize;
 use sqlx::{MySql, MySqlPool, Transaction};
+use utoipa::ToSchema;
 
 use crate::{
     authorization::{
@@ -30,7 +32,7 @@ use crate::{
     utils::start_of_the_year,
 };
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ProjectBudgetOver {
     Normal(Vec<ProjectBudgetOverSimple>),
@@ -108,6 +110,7 @@ pub async fn calculate_project_budget_over_for_budget_detail(
         project_name: budget.project_name,
         over: cost.total >= budget.amount as f64,
         cost: cost.total,
+        discount: cost.discount,
         budget: budget.amount,
     };
     overs.push(over);
@@ -212,6 +215,7 @@ pub async fn calculate_project_budget_over_for_project_detail(
         project_name: budget.project_name,
         over: cost.total >= budget.amount as f64,
         cost: cost.total,
+        discount: cost.discount,
         budget: budget.amount,
     };
     overs.push(over);
@@ -309,6 +313,7 @@ pub async fn calculate_project_budget_over_for_all_detail(
             project_name: budget.project_name,
             over: cost.total >= budget.amount as f64,
             cost: cost.total,
+            discount: cost.discount,
             budget: budget.amount,
         };
         overs.push(over);
@@ -333,6 +338,18 @@ pub async fn calculate_project_budget_over_for_all(
     })
 }
 
+#[utoipa::path(
+    get,
+    path = "/over/",
+    tag = "budgeting",
+    params(ProjectBudgetOverParams),
+    responses(
+        (status = 200, description = "Whether the budgets are exceeded, in detail if requested", body = ProjectBudgetOver),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_budget_over")]
 pub async fn project_budget_over(
     user: ReqData<User>,
@@ -4,12 +4,15 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
+    audit::ObjectType,
     budgeting::{UserBudget, UserBudgetCreateData},
+    error::ErrorResponse,
     user::User,
 };
 use sqlx::MySqlPool;
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
     database::{
         budgeting::user_budget::{NewUserBudget, insert_user_budget_into_db},
@@ -18,10 +21,23 @@ use crate::{
     error::{NormalApiError, OptionApiError},
 };
 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "budgeting",
+    request_body = UserBudgetCreateData,
+    responses(
+        (status = 201, description = "Created user budget", body = UserBudget),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_budget_create")]
 pub async fn user_budget_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<UserBudgetCreateData>,
 ) -> Result<HttpResponse, OptionApiError> {
     require_admin_user(&user)?;
@@ -37,10 +53,6 @@ pub async fn user_budget_create(
         select_user_name_from_db(&mut transaction, data.user as u64).await?;
     let id =
         insert_user_budget_into_db(&mut transaction, &new_user_budget).await?;
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
     let user_budget_created = UserBudget {
         id: id as u32,
         user: new_user_budget.user_id as u32,
@@ -48,6 +60,18 @@ pub async fn user_budget_create(
         year: new_user_budget.year,
         amount: new_user_budget.amount as u32,
     };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::UserBudget,
+            user_budget_created.id,
+            &user_budget_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
     Ok(HttpResponse::Created()
         .content_type("application/json")
         .json(user_budget_created))
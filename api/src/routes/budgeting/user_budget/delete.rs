@@ -3,20 +3,35 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::UserBudgetIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::budgeting::user_budget::delete_user_budget_from_db,
+    database::budgeting::user_budget::{
+        delete_user_budget_from_db, select_maybe_user_budget_from_db,
+    },
     error::NormalApiError,
 };
 
+#[utoipa::path(
+    delete,
+    path = "/{user_budget_id}/",
+    tag = "budgeting",
+    params(UserBudgetIdParam),
+    responses(
+        (status = 204, description = "User budget deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_budget_delete")]
 pub async fn user_budget_delete(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     params: Path<UserBudgetIdParam>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -24,8 +39,21 @@ pub async fn user_budget_delete(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let user_budget_before = select_maybe_user_budget_from_db(
+        &mut transaction,
+        params.user_budget_id as u64,
+    )
+    .await?;
     delete_user_budget_from_db(&mut transaction, params.user_budget_id as u64)
         .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::UserBudget,
+            params.user_budget_id,
+            &user_budget_before,
+        )
+        .await?;
     transaction
         .commit()
         .await
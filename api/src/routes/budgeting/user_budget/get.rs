@@ -3,7 +3,7 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{budgeting::UserBudget, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::UserBudgetIdParam;
@@ -16,6 +16,18 @@ use crate::{
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "/{user_budget_id}",
+    tag = "budgeting",
+    params(UserBudgetIdParam),
+    responses(
+        (status = 200, description = "Requested user budget", body = UserBudget),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_budget_get")]
 pub async fn user_budget_get(
     user: ReqData<User>,
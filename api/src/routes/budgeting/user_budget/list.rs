@@ -1,10 +1,11 @@
 use actix_web::{
-    HttpResponse,
+    HttpRequest, HttpResponse,
     web::{Data, Query, ReqData},
 };
 use anyhow::Context;
 use avina_wire::{
-    budgeting::UserBudgetListParams,
+    budgeting::{UserBudget, UserBudgetListParams},
+    error::ErrorResponse,
     user::{Project, User},
 };
 use sqlx::MySqlPool;
@@ -13,37 +14,51 @@ use crate::{
     authorization::{require_admin_user, require_master_user},
     database::{
         budgeting::user_budget::{
-            select_all_user_budgets_from_db,
-            select_user_budgets_by_project_from_db,
-            select_user_budgets_by_user_from_db,
-            select_user_budgets_by_year_from_db,
+            UserBudgetFilter, select_user_budget_list_from_db,
         },
         user::user::select_user_from_db,
     },
     error::NormalApiError,
+    pagination::{ListQuery, Paginated, paginate},
 };
 
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "budgeting",
+    params(UserBudgetListParams),
+    responses(
+        (status = 200, description = "List of user budgets", body = Paginated<UserBudget>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_budget_list")]
 pub async fn user_budget_list(
     user: ReqData<User>,
     project: ReqData<Project>,
     db_pool: Data<MySqlPool>,
     params: Query<UserBudgetListParams>,
+    req: HttpRequest,
 ) -> Result<HttpResponse, NormalApiError> {
+    let list_query = ListQuery::new::<UserBudget>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
     let mut transaction = db_pool
         .begin()
         .await
         .context("Failed to begin transaction")?;
-    let user_budgets = if params.all.unwrap_or(false) {
+    let filter = if params.all.unwrap_or(false) {
         require_admin_user(&user)?;
-        select_all_user_budgets_from_db(&mut transaction).await?
+        UserBudgetFilter::default()
     } else if let Some(project_id) = params.project {
         require_master_user(&user, project_id)?;
-        select_user_budgets_by_project_from_db(
-            &mut transaction,
-            project_id as u64,
-        )
-        .await?
+        UserBudgetFilter {
+            project: Some(project_id as u64),
+            ..Default::default()
+        }
     } else if let Some(user_id) = params.user {
         // TODO: this can be optimized when the user is the current user
         let queried_user =
@@ -51,23 +66,28 @@ pub async fn user_budget_list(
                 .await
                 .context("Failed to select user")?;
         require_master_user(&user, queried_user.project)?;
-        select_user_budgets_by_user_from_db(
-            &mut transaction,
-            queried_user.id as u64,
-        )
-        .await?
+        UserBudgetFilter {
+            user: Some(queried_user.id as u64),
+            ..Default::default()
+        }
     } else if let Some(year) = params.year {
         require_admin_user(&user)?;
-        select_user_budgets_by_year_from_db(&mut transaction, year).await?
+        UserBudgetFilter {
+            year: Some(year),
+            ..Default::default()
+        }
     } else {
-        select_user_budgets_by_user_from_db(&mut transaction, user.id as u64)
-            .await?
+        UserBudgetFilter {
+            user: Some(user.id as u64),
+            ..Default::default()
+        }
     };
+    let (user_budgets, count) =
+        select_user_budget_list_from_db(&mut transaction, &filter, &list_query)
+            .await?;
     transaction
         .commit()
         .await
         .context("Failed to commit transaction")?;
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .json(user_budgets))
+    Ok(paginate(&req, user_budgets, count, &list_query))
 }
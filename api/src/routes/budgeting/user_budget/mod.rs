@@ -3,6 +3,7 @@ use actix_web::{
     web::{delete, get, patch, post, scope},
 };
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
 mod create;
 use create::user_budget_create;
@@ -30,7 +31,22 @@ pub fn user_budgets_scope() -> Scope {
         .route("/sync/", get().to(user_budget_sync))
 }
 
-#[derive(Deserialize, Debug)]
+/// OpenAPI documentation of the user budget endpoints
+/// routed by [user_budgets_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::user_budget_create,
+    list::user_budget_list,
+    get::user_budget_get,
+    modify::user_budget_modify,
+    delete::user_budget_delete,
+    over::user_budget_over,
+    sync::user_budget_sync
+))]
+pub struct UserBudgetsApiDoc;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
 struct UserBudgetIdParam {
     user_budget_id: u32,
 }
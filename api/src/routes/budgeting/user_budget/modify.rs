@@ -3,12 +3,18 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
 };
 use anyhow::{Context, anyhow};
-use avina_wire::{budgeting::UserBudgetModifyData, user::User};
+use avina_wire::{
+    audit::ObjectType,
+    budgeting::{UserBudget, UserBudgetModifyData},
+    error::ErrorResponse,
+    user::User,
+};
 use chrono::{Datelike, Utc};
 use sqlx::MySqlPool;
 
 use super::UserBudgetIdParam;
 use crate::{
+    audit::Audit,
     authorization::{
         require_admin_user, require_master_user_or_return_not_found,
     },
@@ -29,10 +35,24 @@ use crate::{
     utils::start_of_the_year,
 };
 
+#[utoipa::path(
+    patch,
+    path = "/{user_budget_id}/",
+    tag = "budgeting",
+    params(UserBudgetIdParam),
+    request_body = UserBudgetModifyData,
+    responses(
+        (status = 200, description = "Modified user budget", body = UserBudget),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_budget_modify")]
 pub async fn user_budget_modify(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<UserBudgetModifyData>,
     params: Path<UserBudgetIdParam>,
 ) -> Result<HttpResponse, OptionApiError> {
@@ -107,12 +127,22 @@ pub async fn user_budget_modify(
         )));
     }
 
-    let user_budget = update_user_budget_in_db(&mut transaction, &data).await?;
+    let user_budget_modified =
+        update_user_budget_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::UserBudget,
+            user_budget.id,
+            &user_budget,
+            &user_budget_modified,
+        )
+        .await?;
     transaction
         .commit()
         .await
         .context("Failed to commit transaction")?;
     Ok(HttpResponse::Ok()
         .content_type("application/json")
-        .json(user_budget))
+        .json(user_budget_modified))
 }
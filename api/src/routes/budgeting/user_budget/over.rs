@@ -8,11 +8,13 @@ use avina_wire::{
         UserBudgetOverCombined, UserBudgetOverCombinedDetail,
         UserBudgetOverDetail, UserBudgetOverParams, UserBudgetOverSimple,
     },
+    error::ErrorResponse,
     user::User,
 };
 use chrono::{DateTime, Datelike, Utc};
 use serde::Serialize;
 use sqlx::{MySql, MySqlPool, Transaction};
+use utoipa::ToSchema;
 
 use crate::{
     authorization::{
@@ -43,7 +45,7 @@ use crate::{
     utils::start_of_the_year,
 };
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum UserBudgetOver {
     Normal(Vec<UserBudgetOverSimple>),
@@ -190,6 +192,7 @@ pub async fn calculate_user_budget_over_for_budget_detail(
         user_name: budget.username,
         over: cost.total >= budget.amount as f64,
         cost: cost.total,
+        discount: cost.discount,
         budget: budget.amount,
     };
     overs.push(over);
@@ -260,8 +263,10 @@ pub async fn calculate_user_budget_over_for_budget_combined_detail(
                 None => false,
             },
         project_cost: project_cost.total,
+        project_discount: project_cost.discount,
         project_budget: project_budget.map(|b| b.amount),
         user_cost: cost.total,
+        user_discount: cost.discount,
         user_budget: budget.amount,
     };
     overs.push(over);
@@ -455,6 +460,7 @@ pub async fn calculate_user_budget_over_for_user_detail(
         user_name: budget.username,
         over: cost.total >= budget.amount as f64,
         cost: cost.total,
+        discount: cost.discount,
         budget: budget.amount,
     };
     overs.push(over);
@@ -529,8 +535,10 @@ pub async fn calculate_user_budget_over_for_user_combined_detail(
                 None => false,
             },
         project_cost: project_cost.total,
+        project_discount: project_cost.discount,
         project_budget: project_budget.map(|b| b.amount),
         user_cost: cost.total,
+        user_discount: cost.discount,
         user_budget: budget.amount,
     };
     overs.push(over);
@@ -729,6 +737,7 @@ pub async fn calculate_user_budget_over_for_project_detail(
             user_name: budget.username,
             over: cost.total >= budget.amount as f64,
             cost: cost.total,
+            discount: cost.discount,
             budget: budget.amount,
         };
         overs.push(over);
@@ -807,8 +816,10 @@ pub async fn calculate_user_budget_over_for_project_combined_detail(
                     None => false,
                 },
             project_cost: project_cost.total,
+            project_discount: project_cost.discount,
             project_budget: project_budget.map(|b| b.amount),
             user_cost: cost.total,
+            user_discount: cost.discount,
             user_budget: budget.amount,
         };
         overs.push(over);
@@ -993,6 +1004,7 @@ pub async fn calculate_user_budget_over_for_all_detail(
             user_name: budget.username,
             over: cost.total >= budget.amount as f64,
             cost: cost.total,
+            discount: cost.discount,
             budget: budget.amount,
         };
         overs.push(over);
@@ -1066,8 +1078,10 @@ pub async fn calculate_user_budget_over_for_all_combined_detail(
                     None => false,
                 },
             project_cost: project_cost.total,
+            project_discount: project_cost.discount,
             project_budget: project_budget.map(|b| b.amount),
             user_cost: cost.total,
+            user_discount: cost.discount,
             user_budget: budget.amount,
         };
         overs.push(over);
@@ -1102,6 +1116,18 @@ pub async fn calculate_user_budget_over_for_all(
     })
 }
 
+#[utoipa::path(
+    get,
+    path = "/over/",
+    tag = "budgeting",
+    params(UserBudgetOverParams),
+    responses(
+        (status = 200, description = "Whether the budgets are exceeded, in detail if requested", body = UserBudgetOver),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_budget_over")]
 pub async fn user_budget_over(
     user: ReqData<User>,
@@ -5,6 +5,7 @@ use actix_web::{
 use anyhow::Context;
 use avina_wire::{
     budgeting::UserBudgetSync,
+    error::ErrorResponse,
     user::{Project, User},
 };
 use sqlx::MySqlPool;
@@ -16,6 +17,16 @@ use crate::{
 };
 
 // TODO: write tests for this endpoint
+#[utoipa::path(
+    get,
+    path = "/sync/",
+    tag = "budgeting",
+    responses(
+        (status = 200, description = "Number of synced budgets", body = UserBudgetSync),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_budget_sync")]
 pub async fn user_budget_sync(
     user: ReqData<User>,
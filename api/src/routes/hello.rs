@@ -5,9 +5,11 @@ use actix_web::{
     web::{ReqData, get, scope},
 };
 use avina_wire::{
+    error::ErrorResponse,
     hello::Hello,
     user::{Project, User},
 };
+use utoipa::OpenApi;
 
 use crate::{authorization::require_admin_user, error::AuthOnlyError};
 
@@ -21,9 +23,22 @@ pub fn hello_scope() -> Scope {
         .route("/admin", get().to(hello_admin))
 }
 
+/// OpenAPI documentation of the hello endpoints routed by [hello_scope].
+#[derive(OpenApi)]
+#[openapi(paths(hello_user, hello_admin))]
+pub struct HelloApiDoc;
+
 /// Implementation of the hello-user endpoint.
 ///
 /// This simply returns a personalized hello message to an authenticated user.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "hello",
+    responses(
+        (status = 200, description = "Hello message for the user", body = Hello),
+    )
+)]
 #[tracing::instrument(name = "hello_user")]
 pub async fn hello_user(
     user: ReqData<User>,
@@ -44,6 +59,15 @@ pub async fn hello_user(
 /// This simply returns a personalized hello message to an admin user.
 /// Other users will get an authorization error, when attempting to access
 /// this endpoint.
+#[utoipa::path(
+    get,
+    path = "/admin",
+    tag = "hello",
+    responses(
+        (status = 200, description = "Hello message for the admin", body = Hello),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "hello_admin")]
 pub async fn hello_admin(
     user: ReqData<User>,
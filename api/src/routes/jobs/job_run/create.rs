@@ -0,0 +1,65 @@
+//! Implementation of the job-run-create endpoint.
+
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    jobs::{JobRun, JobRunCreateData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use crate::{
+    audit::Audit, authorization::require_admin_user, error::OptionApiError,
+    jobs::JobRunner,
+};
+
+/// Trigger a run of the job given in the [JobRunCreateData].
+///
+/// The job is run right away and the endpoint only returns after it finished. On success a
+/// HTTP 201 CREATED with the finished run in the response data is returned, which contains
+/// the error message in case the job itself failed.
+///
+/// Only admins can call this endpoint, otherwise an [OptionApiError::AuthorizationError] is
+/// returned. If the job is already running, an [OptionApiError::ValidationError] is returned.
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "jobs",
+    request_body = JobRunCreateData,
+    responses(
+        (status = 201, description = "Finished job run", body = JobRun),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "job_run_create", skip(job_runner))]
+pub async fn job_run_create(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    job_runner: Data<JobRunner>,
+    data: Json<JobRunCreateData>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let job_run = job_runner.run(data.job, Some(user.id)).await?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    audit
+        .created(&mut transaction, ObjectType::JobRun, job_run.id, &job_run)
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Created()
+        .content_type("application/json")
+        .json(job_run))
+}
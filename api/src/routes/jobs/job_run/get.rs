@@ -0,0 +1,54 @@
+//! Implementation of the job-run-get endpoint.
+
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{error::ErrorResponse, jobs::JobRun, user::User};
+use sqlx::MySqlPool;
+
+use super::JobRunIdParam;
+use crate::{
+    authorization::require_admin_user,
+    database::jobs::job_run::select_job_run_from_db, error::OptionApiError,
+};
+
+/// Get the job run with the given ID.
+///
+/// Only admins can call this endpoint, otherwise an [OptionApiError::AuthorizationError] is
+/// returned. If the job run does not exist, an [OptionApiError::NotFoundError] is returned.
+#[utoipa::path(
+    get,
+    path = "/{job_run_id}",
+    tag = "jobs",
+    params(JobRunIdParam),
+    responses(
+        (status = 200, description = "Requested job run", body = JobRun),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "job_run_get")]
+pub async fn job_run_get(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Path<JobRunIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let job_run =
+        select_job_run_from_db(&mut transaction, params.job_run_id as u64)
+            .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(job_run))
+}
@@ -0,0 +1,66 @@
+//! Implementation of the job-run-list endpoint.
+
+use actix_web::{
+    HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    error::ErrorResponse,
+    jobs::{JobRun, JobRunListParams},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use crate::{
+    authorization::require_admin_user,
+    database::jobs::job_run::{
+        select_job_runs_by_job_from_db, select_job_runs_from_db,
+    },
+    error::NormalApiError,
+};
+
+/// Number of job runs returned when no limit is given.
+const DEFAULT_LIMIT: u32 = 50;
+
+/// List the most recent job runs, optionally only those of a single job.
+///
+/// Only admins can call this endpoint, otherwise a [NormalApiError::AuthorizationError] is
+/// returned.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "jobs",
+    params(JobRunListParams),
+    responses(
+        (status = 200, description = "List of recent job runs", body = Vec<JobRun>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "job_run_list")]
+pub async fn job_run_list(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<JobRunListParams>,
+) -> Result<HttpResponse, NormalApiError> {
+    require_admin_user(&user)?;
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT);
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let job_runs = match params.job {
+        Some(job) => {
+            select_job_runs_by_job_from_db(&mut transaction, job, limit).await?
+        }
+        None => select_job_runs_from_db(&mut transaction, limit).await?,
+    };
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(job_runs))
+}
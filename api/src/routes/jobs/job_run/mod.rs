@@ -0,0 +1,42 @@
+//! Endpoints for job runs.
+
+use actix_web::{
+    Scope,
+    web::{get, post, scope},
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
+
+pub mod create;
+use create::job_run_create;
+pub mod list;
+use list::job_run_list;
+pub mod get;
+use get::job_run_get;
+
+/// Routes to job run endpoints.
+///
+///   - `POST /api/jobs/runs/` => [job_run_create] endpoint
+///   - `GET /api/jobs/runs` => [job_run_list] endpoint
+///   - `GET /api/jobs/runs/{id}` => [job_run_get] endpoint
+pub fn job_runs_scope() -> Scope {
+    scope("/runs")
+        .route("/", post().to(job_run_create))
+        .route("", get().to(job_run_list))
+        .route("/{job_run_id}", get().to(job_run_get))
+}
+
+/// OpenAPI documentation of the job run endpoints routed by [job_runs_scope].
+#[derive(OpenApi)]
+#[openapi(paths(create::job_run_create, list::job_run_list, get::job_run_get))]
+pub struct JobRunsApiDoc;
+
+/// Wrapper type for the job run ID parameter to job run endpoints.
+///
+/// As this is handed to endpoints as [actix_web::web::Path], it should to have a distinguishable type.
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct JobRunIdParam {
+    /// The wrapped job run ID.
+    job_run_id: u32,
+}
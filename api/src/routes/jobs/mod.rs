@@ -0,0 +1,19 @@
+//! Endpoints for the built-in jobs, so the job runs.
+
+use actix_web::{Scope, web::scope};
+use utoipa::OpenApi;
+
+pub mod job_run;
+use job_run::job_runs_scope;
+
+/// Main scope for the job endpoints.
+///
+///   - `/api/jobs/runs` => [job_runs_scope], see [job_run] submodule
+pub fn jobs_scope() -> Scope {
+    scope("/jobs").service(job_runs_scope())
+}
+
+/// OpenAPI documentation of the job endpoints routed by [jobs_scope].
+#[derive(OpenApi)]
+#[openapi(nest((path = "/runs", api = job_run::JobRunsApiDoc)))]
+pub struct JobsApiDoc;
@@ -0,0 +1,42 @@
+//! Endpoint exposing Prometheus metrics.
+
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::header::{AUTHORIZATION, CONTENT_TYPE},
+    web::Data,
+};
+use secrecy::ExposeSecret;
+use sqlx::MySqlPool;
+
+use crate::{
+    error::{internal_server_error, unauthorized_error},
+    metrics::render_metrics,
+    startup::MetricsToken,
+};
+
+/// Endpoint exposing the metrics in the Prometheus text format.
+///
+/// Like the health check, this is not located under `/api/` and does not
+/// require a Keystone token. When a metrics token is configured, it has to be
+/// given as bearer token in the Authorization header.
+pub async fn metrics(
+    req: HttpRequest,
+    db_pool: Data<MySqlPool>,
+    metrics_token: Data<MetricsToken>,
+) -> Result<HttpResponse, actix_web::Error> {
+    if let Some(token) = &metrics_token.0 {
+        let expected = format!("Bearer {}", token.expose_secret());
+        let authorized = req
+            .headers()
+            .get(AUTHORIZATION)
+            .is_some_and(|header| header.as_bytes() == expected.as_bytes());
+        if !authorized {
+            return Err(unauthorized_error("Invalid or missing metrics token"));
+        }
+    }
+    let body = render_metrics(&db_pool)
+        .map_err(|_| internal_server_error("Failed to render metrics"))?;
+    Ok(HttpResponse::Ok()
+        .insert_header((CONTENT_TYPE, "text/plain; version=0.0.4"))
+        .body(body))
+}
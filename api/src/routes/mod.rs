@@ -17,20 +17,30 @@
 //!
 //! Additional endpoints not following this convention may exist on further routes.
 
-mod accounting;
+pub mod accounting;
+mod audit;
 mod budgeting;
 mod health_check;
 mod hello;
+mod jobs;
+mod metrics;
+mod openapi;
 mod pricing;
 pub mod quota;
-mod resources;
+mod ready;
+pub mod resources;
 pub mod user;
 
 pub use accounting::*;
+pub use audit::*;
 pub use budgeting::*;
 pub use health_check::*;
 pub use hello::*;
+pub use jobs::*;
+pub use metrics::*;
+pub use openapi::*;
 pub use pricing::*;
 pub use quota::*;
+pub use ready::*;
 pub use resources::*;
 pub use user::*;
@@ -0,0 +1,122 @@
+//! Endpoint serving the OpenAPI specification of the API.
+
+use actix_web::HttpResponse;
+use avina_wire::error::ErrorResponse;
+use utoipa::{
+    Modify, OpenApi,
+    openapi::{
+        OpenApi as OpenApiSpec, Ref, RefOr, Response, ResponseBuilder,
+        content::ContentBuilder,
+        security::{ApiKey, ApiKeyValue, SecurityRequirement, SecurityScheme},
+    },
+};
+
+use crate::routes::{
+    AccountingApiDoc, AuditApiDoc, BudgetingApiDoc, HelloApiDoc, JobsApiDoc,
+    PricingApiDoc, QuotaApiDoc, ResourcesApiDoc, UserApiDoc,
+};
+
+/// OpenAPI specification of all endpoints under `/api`.
+///
+/// The specification is assembled from the documentation structs next to
+/// the `*_scope` functions, which in turn collect the `utoipa::path`
+/// annotations of their endpoints.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "avina-api",
+        description = "API for the LRZ-specific features of the \
+            Openstack-based LRZ Compute Cloud."
+    ),
+    nest(
+        (path = "/api/hello", api = HelloApiDoc),
+        (path = "/api/user", api = UserApiDoc),
+        (path = "/api/accounting", api = AccountingApiDoc),
+        (path = "/api/resources", api = ResourcesApiDoc),
+        (path = "/api/pricing", api = PricingApiDoc),
+        (path = "/api/budgeting", api = BudgetingApiDoc),
+        (path = "/api/quota", api = QuotaApiDoc),
+        (path = "/api/jobs", api = JobsApiDoc),
+        (path = "/api/audit", api = AuditApiDoc)
+    ),
+    components(schemas(ErrorResponse)),
+    modifiers(&KeystoneToken)
+)]
+pub struct ApiDoc;
+
+/// Modifier adding the Keystone token authentication to all endpoints.
+///
+/// Every endpoint under `/api` requires a valid token in the X-Auth-Token
+/// header, and may fail unexpectedly, so the respective security scheme and
+/// error responses are added here instead of to every single endpoint.
+struct KeystoneToken;
+
+impl Modify for KeystoneToken {
+    fn modify(&self, openapi: &mut OpenApiSpec) {
+        let components =
+            openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "keystone_token",
+            SecurityScheme::ApiKey(ApiKey::Header(
+                ApiKeyValue::with_description(
+                    "X-Auth-Token",
+                    "Openstack Keystone token",
+                ),
+            )),
+        );
+        for path_item in openapi.paths.paths.values_mut() {
+            for operation in [
+                &mut path_item.get,
+                &mut path_item.post,
+                &mut path_item.patch,
+                &mut path_item.delete,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                operation.security = Some(vec![SecurityRequirement::new(
+                    "keystone_token",
+                    Vec::<String>::new(),
+                )]);
+                for (status, description) in [
+                    ("401", "Missing or invalid token"),
+                    ("500", "Unexpected error"),
+                ] {
+                    operation
+                        .responses
+                        .responses
+                        .entry(status.to_string())
+                        .or_insert_with(|| error_response(description));
+                }
+            }
+        }
+    }
+}
+
+/// Build a response with the given description and an [ErrorResponse] body.
+fn error_response(description: &str) -> RefOr<Response> {
+    RefOr::T(
+        ResponseBuilder::new()
+            .description(description)
+            .content(
+                "application/json",
+                ContentBuilder::new()
+                    .schema(Some(RefOr::Ref(Ref::from_schema_name(
+                        "ErrorResponse",
+                    ))))
+                    .build(),
+            )
+            .build(),
+    )
+}
+
+/// Endpoint serving the OpenAPI specification as JSON.
+///
+/// Like the health check, this is not located under `/api/` itself and does
+/// not require a Keystone token, so that clients can be generated without
+/// access to the cloud.
+pub async fn openapi() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("application/json")
+        .json(ApiDoc::openapi())
+}
@@ -0,0 +1,83 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{BillingPolicy, BillingPolicyCreateData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use super::check_billing_policy_overlap;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::billing_policy::{
+        NewBillingPolicy, insert_billing_policy_into_db,
+    },
+    error::{NormalApiError, OptionApiError},
+};
+
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "pricing",
+    request_body = BillingPolicyCreateData,
+    responses(
+        (status = 201, description = "Created billing policy", body = BillingPolicy),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "billing_policy_create")]
+pub async fn billing_policy_create(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    data: Json<BillingPolicyCreateData>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let new_billing_policy: NewBillingPolicy = data
+        .clone()
+        .try_into()
+        .map_err(NormalApiError::ValidationError)?;
+    let mut billing_policy_created = BillingPolicy {
+        id: 0,
+        status: new_billing_policy.status.clone(),
+        user_class: new_billing_policy.user_class,
+        factor: new_billing_policy.factor,
+        start_time: new_billing_policy.start_time.fixed_offset(),
+        end_time: new_billing_policy
+            .end_time
+            .map(|end_time| end_time.fixed_offset()),
+    };
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    check_billing_policy_overlap(&mut transaction, &billing_policy_created)
+        .await?;
+    let id =
+        insert_billing_policy_into_db(&mut transaction, &new_billing_policy)
+            .await?;
+    billing_policy_created.id = id as u32;
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::BillingPolicy,
+            billing_policy_created.id,
+            &billing_policy_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Created()
+        .content_type("application/json")
+        .json(billing_policy_created))
+}
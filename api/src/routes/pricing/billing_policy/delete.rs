@@ -0,0 +1,65 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
+use sqlx::MySqlPool;
+
+use super::BillingPolicyIdParam;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::billing_policy::{
+        delete_billing_policy_from_db, select_maybe_billing_policy_from_db,
+    },
+    error::NormalApiError,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/{billing_policy_id}/",
+    tag = "pricing",
+    params(BillingPolicyIdParam),
+    responses(
+        (status = 204, description = "Billing policy deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "billing_policy_delete")]
+pub async fn billing_policy_delete(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    params: Path<BillingPolicyIdParam>,
+) -> Result<HttpResponse, NormalApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let billing_policy_before = select_maybe_billing_policy_from_db(
+        &mut transaction,
+        params.billing_policy_id as u64,
+    )
+    .await?;
+    delete_billing_policy_from_db(
+        &mut transaction,
+        params.billing_policy_id as u64,
+    )
+    .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::BillingPolicy,
+            params.billing_policy_id,
+            &billing_policy_before,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,49 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{error::ErrorResponse, pricing::BillingPolicy, user::User};
+use sqlx::MySqlPool;
+
+use super::BillingPolicyIdParam;
+use crate::{
+    database::pricing::billing_policy::select_billing_policy_from_db,
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    get,
+    path = "/{billing_policy_id}",
+    tag = "pricing",
+    params(BillingPolicyIdParam),
+    responses(
+        (status = 200, description = "Requested billing policy", body = BillingPolicy),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "billing_policy_get")]
+pub async fn billing_policy_get(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Path<BillingPolicyIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let billing_policy = select_billing_policy_from_db(
+        &mut transaction,
+        params.billing_policy_id as u64,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(billing_policy))
+}
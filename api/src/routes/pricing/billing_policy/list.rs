@@ -0,0 +1,65 @@
+use actix_web::{
+    HttpRequest, HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    error::ErrorResponse,
+    pricing::{BillingPolicy, BillingPolicyListParams},
+    user::User,
+};
+use chrono::Utc;
+use sqlx::MySqlPool;
+
+use crate::{
+    database::pricing::billing_policy::{
+        BillingPolicyFilter, select_billing_policy_list_from_db,
+    },
+    error::NormalApiError,
+    pagination::{ListQuery, Paginated, paginate},
+};
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "pricing",
+    params(BillingPolicyListParams),
+    responses(
+        (status = 200, description = "List of billing policies", body = Paginated<BillingPolicy>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "billing_policy_list")]
+pub async fn billing_policy_list(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<BillingPolicyListParams>,
+    req: HttpRequest,
+) -> Result<HttpResponse, NormalApiError> {
+    let list_query = ListQuery::new::<BillingPolicy>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
+    let filter = BillingPolicyFilter {
+        status: params.status.clone(),
+        user_class: params.user_class,
+        valid_at: params.current.unwrap_or(false).then(Utc::now),
+    };
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let (billing_policies, count) = select_billing_policy_list_from_db(
+        &mut transaction,
+        &filter,
+        &list_query,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(paginate(&req, billing_policies, count, &list_query))
+}
@@ -0,0 +1,83 @@
+use actix_web::{
+    Scope,
+    web::{delete, get, patch, post, scope},
+};
+use avina_wire::pricing::BillingPolicy;
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use sqlx::{MySql, Transaction};
+use utoipa::{IntoParams, OpenApi};
+
+use crate::{
+    database::pricing::billing_policy::select_all_billing_policies_from_db,
+    error::OptionApiError,
+};
+
+mod create;
+use create::billing_policy_create;
+mod list;
+use list::billing_policy_list;
+mod get;
+use get::billing_policy_get;
+mod modify;
+use modify::billing_policy_modify;
+mod delete;
+use delete::billing_policy_delete;
+
+pub fn billing_policies_scope() -> Scope {
+    scope("/billingpolicies")
+        .route("/", post().to(billing_policy_create))
+        .route("", get().to(billing_policy_list))
+        .route("/{billing_policy_id}", get().to(billing_policy_get))
+        .route("/{billing_policy_id}/", patch().to(billing_policy_modify))
+        .route("/{billing_policy_id}/", delete().to(billing_policy_delete))
+}
+
+/// OpenAPI documentation of the billing policy endpoints
+/// routed by [billing_policies_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::billing_policy_create,
+    list::billing_policy_list,
+    get::billing_policy_get,
+    modify::billing_policy_modify,
+    delete::billing_policy_delete
+))]
+pub struct BillingPoliciesApiDoc;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
+struct BillingPolicyIdParam {
+    billing_policy_id: u32,
+}
+
+/// Whether a policy with the given end time is still valid at the given time.
+fn valid_until_after(
+    end_time: Option<DateTime<FixedOffset>>,
+    time: DateTime<FixedOffset>,
+) -> bool {
+    end_time.is_none_or(|end_time| end_time > time)
+}
+
+/// Make sure no other policy for the same status and user class overlaps the
+/// given one, otherwise return a [OptionApiError::ValidationError].
+async fn check_billing_policy_overlap(
+    transaction: &mut Transaction<'_, MySql>,
+    billing_policy: &BillingPolicy,
+) -> Result<(), OptionApiError> {
+    let billing_policies =
+        select_all_billing_policies_from_db(transaction).await?;
+    if let Some(other) = billing_policies.iter().find(|other| {
+        other.id != billing_policy.id
+            && other.status == billing_policy.status
+            && other.user_class == billing_policy.user_class
+            && valid_until_after(other.end_time, billing_policy.start_time)
+            && valid_until_after(billing_policy.end_time, other.start_time)
+    }) {
+        return Err(OptionApiError::ValidationError(format!(
+            "Billing policy overlaps billing policy {}",
+            other.id
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{BillingPolicy, BillingPolicyModifyData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use super::{BillingPolicyIdParam, check_billing_policy_overlap};
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::billing_policy::{
+        select_billing_policy_from_db, update_billing_policy_in_db,
+        validate_billing_policy_fields,
+    },
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/{billing_policy_id}/",
+    tag = "pricing",
+    params(BillingPolicyIdParam),
+    request_body = BillingPolicyModifyData,
+    responses(
+        (status = 200, description = "Modified billing policy", body = BillingPolicy),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "billing_policy_modify")]
+pub async fn billing_policy_modify(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    data: Json<BillingPolicyModifyData>,
+    params: Path<BillingPolicyIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    if data.id != params.billing_policy_id {
+        return Err(OptionApiError::ValidationError(
+            "ID in URL does not match ID in body".to_string(),
+        ));
+    }
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let billing_policy_before = select_billing_policy_from_db(
+        &mut transaction,
+        params.billing_policy_id as u64,
+    )
+    .await?;
+    let billing_policy = BillingPolicy {
+        id: billing_policy_before.id,
+        status: data
+            .status
+            .clone()
+            .unwrap_or(billing_policy_before.status.clone()),
+        user_class: data.user_class.or(billing_policy_before.user_class),
+        factor: data.factor.unwrap_or(billing_policy_before.factor),
+        start_time: data.start_time.unwrap_or(billing_policy_before.start_time),
+        end_time: data.end_time.or(billing_policy_before.end_time),
+    };
+    validate_billing_policy_fields(
+        &billing_policy.status,
+        billing_policy.factor,
+        billing_policy.start_time.to_utc(),
+        billing_policy.end_time.map(|end_time| end_time.to_utc()),
+    )
+    .map_err(OptionApiError::ValidationError)?;
+    check_billing_policy_overlap(&mut transaction, &billing_policy).await?;
+    update_billing_policy_in_db(&mut transaction, &billing_policy).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::BillingPolicy,
+            params.billing_policy_id,
+            &billing_policy_before,
+            &billing_policy,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(billing_policy))
+}
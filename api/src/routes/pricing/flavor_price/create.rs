@@ -4,12 +4,15 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
     pricing::{FlavorPrice, FlavorPriceCreateData},
     user::User,
 };
 use sqlx::MySqlPool;
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
     database::{
         pricing::flavor_price::{NewFlavorPrice, insert_flavor_price_into_db},
@@ -18,10 +21,23 @@ use crate::{
     error::{NormalApiError, OptionApiError},
 };
 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "pricing",
+    request_body = FlavorPriceCreateData,
+    responses(
+        (status = 201, description = "Created flavor price", body = FlavorPrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_price_create")]
 pub async fn flavor_price_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<FlavorPriceCreateData>,
 ) -> Result<HttpResponse, OptionApiError> {
     require_admin_user(&user)?;
@@ -40,10 +56,6 @@ pub async fn flavor_price_create(
     .await?;
     let id = insert_flavor_price_into_db(&mut transaction, &new_flavor_price)
         .await?;
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
     let flavor_price_created = FlavorPrice {
         id: id as u32,
         flavor: new_flavor_price.flavor_id as u32,
@@ -52,6 +64,18 @@ pub async fn flavor_price_create(
         unit_price: new_flavor_price.unit_price,
         start_time: new_flavor_price.start_time.fixed_offset(),
     };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::FlavorPrice,
+            flavor_price_created.id,
+            &flavor_price_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
     Ok(HttpResponse::Created()
         .content_type("application/json")
         .json(flavor_price_created))
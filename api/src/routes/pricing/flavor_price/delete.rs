@@ -3,20 +3,35 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::FlavorPriceIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::pricing::flavor_price::delete_flavor_price_from_db,
+    database::pricing::flavor_price::{
+        delete_flavor_price_from_db, select_maybe_flavor_price_from_db,
+    },
     error::NormalApiError,
 };
 
+#[utoipa::path(
+    delete,
+    path = "/{flavor_price_id}/",
+    tag = "pricing",
+    params(FlavorPriceIdParam),
+    responses(
+        (status = 204, description = "Flavor price deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_price_delete")]
 pub async fn flavor_price_delete(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     params: Path<FlavorPriceIdParam>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -24,11 +39,24 @@ pub async fn flavor_price_delete(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let flavor_price_before = select_maybe_flavor_price_from_db(
+        &mut transaction,
+        params.flavor_price_id as u64,
+    )
+    .await?;
     delete_flavor_price_from_db(
         &mut transaction,
         params.flavor_price_id as u64,
     )
     .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::FlavorPrice,
+            params.flavor_price_id,
+            &flavor_price_before,
+        )
+        .await?;
     transaction
         .commit()
         .await
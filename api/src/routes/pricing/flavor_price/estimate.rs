@@ -0,0 +1,163 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    error::ErrorResponse,
+    pricing::{FlavorPriceEstimate, FlavorPriceEstimateParams},
+    user::{User, UserClass},
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use sqlx::{MySql, MySqlPool, Transaction};
+
+use crate::{
+    database::{
+        resources::flavor::select_flavor_from_db,
+        user::user::select_user_class_by_user_from_db,
+    },
+    error::{OptionApiError, UnexpectedOnlyError},
+    routes::accounting::{
+        server_consumption::get::BillingRates,
+        server_cost::get::{
+            calculate_flavor_consumption_cost, get_flavor_price_periods,
+        },
+    },
+};
+
+/// Status the estimated servers are billed in.
+const ESTIMATE_STATUS: &str = "ACTIVE";
+
+/// Calculate the cost of a single server of the flavor during the period.
+///
+/// The period is split by the price periods, and the server is billed like
+/// one in the [ESTIMATE_STATUS] by the billing policies of the user class and
+/// the price overrides of the project.
+pub async fn calculate_flavor_cost_for_period(
+    transaction: &mut Transaction<'_, MySql>,
+    flavor_name: &str,
+    project: u32,
+    user_class: UserClass,
+    begin: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<f64, UnexpectedOnlyError> {
+    let price_periods =
+        get_flavor_price_periods(transaction, begin, end).await?;
+    let rates = BillingRates::load(
+        transaction,
+        Some(user_class),
+        Some(begin),
+        Some(end),
+    )
+    .await?;
+
+    let mut end_times =
+        price_periods.keys().skip(1).cloned().collect::<Vec<_>>();
+    end_times.push(end);
+
+    let mut cost = 0.0;
+    for ((start_time, prices), end_time) in price_periods.iter().zip(end_times)
+    {
+        let seconds = rates.billable_seconds(
+            ESTIMATE_STATUS,
+            start_time.fixed_offset(),
+            end_time.fixed_offset(),
+        );
+        cost += calculate_flavor_consumption_cost(
+            seconds,
+            prices,
+            project,
+            user_class,
+            flavor_name,
+        );
+    }
+    Ok(cost)
+}
+
+/// Endpoint estimating the cost of running servers of a flavor.
+///
+/// The estimate is for the user class and price overrides of the requesting
+/// user's project, so any authenticated user can call it. If the project has
+/// no user class a [OptionApiError::ValidationError] is returned. The yearly
+/// unit price is also given per hour, day and month, based on a year of 365
+/// days.
+#[utoipa::path(
+    get,
+    path = "/estimate/",
+    tag = "pricing",
+    params(FlavorPriceEstimateParams),
+    responses(
+        (status = 200, description = "Estimated cost of the servers", body = FlavorPriceEstimate),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Flavor not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "flavor_price_estimate")]
+pub async fn flavor_price_estimate(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<FlavorPriceEstimateParams>,
+) -> Result<HttpResponse, OptionApiError> {
+    let count = params.count.unwrap_or(1);
+    if count == 0 {
+        return Err(OptionApiError::ValidationError(
+            "Count must be positive".to_string(),
+        ));
+    }
+    let begin = params.begin.map(|d| d.to_utc()).unwrap_or(Utc::now());
+    let Some(end) = i64::try_from(params.duration)
+        .ok()
+        .and_then(TimeDelta::try_seconds)
+        .filter(|duration| *duration > TimeDelta::zero())
+        .and_then(|duration| begin.checked_add_signed(duration))
+    else {
+        return Err(OptionApiError::ValidationError(
+            "Duration must be positive and not too long".to_string(),
+        ));
+    };
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let Some(user_class) =
+        select_user_class_by_user_from_db(&mut transaction, user.id as u64)
+            .await?
+    else {
+        return Err(OptionApiError::ValidationError(
+            "Project of the user has no user class".to_string(),
+        ));
+    };
+    let flavor =
+        select_flavor_from_db(&mut transaction, params.flavor as u64).await?;
+    let cost = calculate_flavor_cost_for_period(
+        &mut transaction,
+        &flavor.name,
+        user.project,
+        user_class,
+        begin,
+        end,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    let years = params.duration as f64 / ((365 * 24 * 60 * 60) as f64);
+    let unit_price = cost / years;
+    let estimate = FlavorPriceEstimate {
+        flavor: flavor.id,
+        flavor_name: flavor.name,
+        user_class,
+        count,
+        begin: begin.fixed_offset(),
+        end: end.fixed_offset(),
+        unit_price,
+        hourly_price: unit_price / (365 * 24) as f64,
+        daily_price: unit_price / 365.0,
+        monthly_price: unit_price / 12.0,
+        cost: cost * count as f64,
+    };
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(estimate))
+}
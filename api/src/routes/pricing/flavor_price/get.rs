@@ -3,7 +3,7 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{error::ErrorResponse, pricing::FlavorPrice, user::User};
 use sqlx::MySqlPool;
 
 use super::FlavorPriceIdParam;
@@ -12,6 +12,18 @@ use crate::{
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "/{flavor_price_id}",
+    tag = "pricing",
+    params(FlavorPriceIdParam),
+    responses(
+        (status = 200, description = "Requested flavor price", body = FlavorPrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_price_get")]
 pub async fn flavor_price_get(
     user: ReqData<User>,
@@ -1,10 +1,11 @@
 use actix_web::{
-    HttpResponse,
+    HttpRequest, HttpResponse,
     web::{Data, Query, ReqData},
 };
 use anyhow::Context;
 use avina_wire::{
-    pricing::FlavorPriceListParams,
+    error::ErrorResponse,
+    pricing::{FlavorPrice, FlavorPriceListParams},
     user::{Project, User},
 };
 use chrono::Utc;
@@ -12,57 +13,53 @@ use sqlx::MySqlPool;
 
 use crate::{
     database::pricing::flavor_price::{
-        select_all_flavor_prices_from_db,
-        select_flavor_prices_for_period_from_db,
-        select_flavor_prices_for_userclass_and_period_from_db,
-        select_flavor_prices_for_userclass_from_db,
+        FlavorPriceFilter, select_flavor_price_list_from_db,
     },
     error::NormalApiError,
+    pagination::{ListQuery, Paginated, paginate},
 };
 
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "pricing",
+    params(FlavorPriceListParams),
+    responses(
+        (status = 200, description = "List of flavor prices", body = Paginated<FlavorPrice>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_price_list")]
 pub async fn flavor_price_list(
     user: ReqData<User>,
     project: ReqData<Project>,
     db_pool: Data<MySqlPool>,
     params: Query<FlavorPriceListParams>,
+    req: HttpRequest,
 ) -> Result<HttpResponse, NormalApiError> {
+    let list_query = ListQuery::new::<FlavorPrice>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
+    let filter = FlavorPriceFilter {
+        user_class: params.user_class,
+        valid_at: params.current.unwrap_or(false).then(Utc::now),
+    };
     let mut transaction = db_pool
         .begin()
         .await
         .context("Failed to begin transaction")?;
-
-    let flavor_prices = match (params.user_class, params.current) {
-        (Some(user_class), Some(true)) => {
-            let now = Utc::now();
-            select_flavor_prices_for_userclass_and_period_from_db(
-                &mut transaction,
-                user_class,
-                now,
-                now,
-            )
-            .await?
-        }
-        (Some(user_class), Some(false) | None) => {
-            select_flavor_prices_for_userclass_from_db(
-                &mut transaction,
-                user_class,
-            )
-            .await?
-        }
-        (None, Some(true)) => {
-            let now = Utc::now();
-            select_flavor_prices_for_period_from_db(&mut transaction, now, now)
-                .await?
-        }
-        _ => select_all_flavor_prices_from_db(&mut transaction).await?,
-    };
-
+    let (flavor_prices, count) = select_flavor_price_list_from_db(
+        &mut transaction,
+        &filter,
+        &list_query,
+    )
+    .await?;
     transaction
         .commit()
         .await
         .context("Failed to commit transaction")?;
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .json(flavor_prices))
+    Ok(paginate(&req, flavor_prices, count, &list_query))
 }
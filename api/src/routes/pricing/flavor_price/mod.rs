@@ -3,6 +3,7 @@ use actix_web::{
     web::{delete, get, patch, post, scope},
 };
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
 mod create;
 use create::flavor_price_create;
@@ -14,17 +15,38 @@ mod modify;
 use modify::flavor_price_modify;
 mod delete;
 use delete::flavor_price_delete;
+mod estimate;
+use estimate::flavor_price_estimate;
+mod revise;
+use revise::flavor_price_revise;
 
 pub fn flavor_prices_scope() -> Scope {
     scope("/flavorprices")
         .route("/", post().to(flavor_price_create))
         .route("", get().to(flavor_price_list))
+        .route("/estimate/", get().to(flavor_price_estimate))
+        .route("/revise/", post().to(flavor_price_revise))
         .route("/{flavor_price_id}", get().to(flavor_price_get))
         .route("/{flavor_price_id}/", patch().to(flavor_price_modify))
         .route("/{flavor_price_id}/", delete().to(flavor_price_delete))
 }
 
-#[derive(Deserialize, Debug)]
+/// OpenAPI documentation of the flavor price endpoints
+/// routed by [flavor_prices_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::flavor_price_create,
+    list::flavor_price_list,
+    get::flavor_price_get,
+    modify::flavor_price_modify,
+    delete::flavor_price_delete,
+    estimate::flavor_price_estimate,
+    revise::flavor_price_revise
+))]
+pub struct FlavorPricesApiDoc;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
 struct FlavorPriceIdParam {
     flavor_price_id: u32,
 }
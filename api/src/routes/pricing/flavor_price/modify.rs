@@ -3,20 +3,42 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::{pricing::FlavorPriceModifyData, user::User};
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{FlavorPrice, FlavorPriceModifyData},
+    user::User,
+};
 use sqlx::MySqlPool;
 
 use super::FlavorPriceIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::pricing::flavor_price::update_flavor_price_in_db,
+    database::pricing::flavor_price::{
+        select_maybe_flavor_price_from_db, update_flavor_price_in_db,
+    },
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    patch,
+    path = "/{flavor_price_id}/",
+    tag = "pricing",
+    params(FlavorPriceIdParam),
+    request_body = FlavorPriceModifyData,
+    responses(
+        (status = 200, description = "Modified flavor price", body = FlavorPrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_price_modify")]
 pub async fn flavor_price_modify(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<FlavorPriceModifyData>,
     params: Path<FlavorPriceIdParam>,
 ) -> Result<HttpResponse, OptionApiError> {
@@ -31,8 +53,22 @@ pub async fn flavor_price_modify(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let flavor_price_before = select_maybe_flavor_price_from_db(
+        &mut transaction,
+        params.flavor_price_id as u64,
+    )
+    .await?;
     let flavor_price =
         update_flavor_price_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::FlavorPrice,
+            params.flavor_price_id,
+            &flavor_price_before,
+            &flavor_price,
+        )
+        .await?;
     transaction
         .commit()
         .await
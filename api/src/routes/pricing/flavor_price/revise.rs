@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    dry_run::{Change, ChangeAction, ChangeObject, DryRunParams},
+    error::ErrorResponse,
+    pricing::{FlavorPrice, FlavorPriceRevise, FlavorPriceReviseData},
+    user::User,
+};
+use sqlx::{MySql, MySqlPool, Transaction};
+
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::{
+        pricing::flavor_price::{
+            NewFlavorPrice, insert_flavor_price_into_db,
+            select_all_flavor_prices_from_db,
+        },
+        resources::flavor::select_all_flavors_from_db,
+    },
+    dry_run::{DryRunnable, finish},
+    error::OptionApiError,
+};
+
+/// Endpoint creating a new price period from the current flavor prices.
+///
+/// Only admins can call this endpoint, otherwise a
+/// [OptionApiError::AuthorizationError] is returned.
+#[utoipa::path(
+    post,
+    path = "/revise/",
+    tag = "pricing",
+    params(DryRunParams),
+    request_body = FlavorPriceReviseData,
+    responses(
+        (status = 200, description = "Summary of the revision, with the changes in dry runs", body = DryRunnable<FlavorPriceRevise>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "flavor_price_revise")]
+pub async fn flavor_price_revise(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    params: Query<DryRunParams>,
+    data: Json<FlavorPriceReviseData>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let mut changes = Vec::new();
+    let revise =
+        revise_flavor_prices(&mut transaction, &audit, &data, &mut changes)
+            .await?;
+    Ok(finish(transaction, &params, revise, changes).await?)
+}
+
+/// Check the percentage and factors of a revision.
+fn validate_revision(data: &FlavorPriceReviseData) -> Result<(), String> {
+    if let Some(percentage) = data.percentage
+        && (!percentage.is_finite() || percentage < -100.)
+    {
+        return Err("Percentage must be a number of at least -100".to_string());
+    }
+    if let Some((user_class, _)) = data
+        .factors
+        .iter()
+        .find(|(_, factor)| !factor.is_finite() || **factor < 0.)
+    {
+        return Err(format!(
+            "Factor for {user_class} must be a non-negative number"
+        ));
+    }
+    Ok(())
+}
+
+/// Copy the prices valid before the start time to new ones starting at it.
+///
+/// For each flavor, of the given flavor groups if any, and user class with a
+/// price before the start time, the latest one is multiplied by the factor of
+/// its user class, or else by the percentage, and rounded to cents. If any
+/// of these already has a price at the start time, a
+/// [OptionApiError::ValidationError] lists all of them instead. Every created
+/// price is recorded in the given changes.
+#[tracing::instrument(
+    name = "revise_flavor_prices",
+    skip(transaction, audit, changes)
+)]
+pub async fn revise_flavor_prices(
+    transaction: &mut Transaction<'_, MySql>,
+    audit: &Audit,
+    data: &FlavorPriceReviseData,
+    changes: &mut Vec<Change>,
+) -> Result<FlavorPriceRevise, OptionApiError> {
+    validate_revision(data).map_err(OptionApiError::ValidationError)?;
+    let start_time = data.start_time.to_utc();
+    let flavors = select_all_flavors_from_db(transaction)
+        .await?
+        .into_iter()
+        .filter(|flavor| {
+            data.flavor_groups.is_empty()
+                || flavor
+                    .group
+                    .is_some_and(|group| data.flavor_groups.contains(&group))
+        })
+        .map(|flavor| flavor.id)
+        .collect::<HashSet<_>>();
+
+    let mut current = HashMap::<_, FlavorPrice>::new();
+    let mut existing = HashSet::new();
+    for price in select_all_flavor_prices_from_db(transaction).await? {
+        if !flavors.contains(&price.flavor) {
+            continue;
+        }
+        let key = (price.flavor, price.user_class);
+        if price.start_time == start_time {
+            existing.insert(key);
+        } else if price.start_time < start_time
+            && current
+                .get(&key)
+                .is_none_or(|latest| latest.start_time < price.start_time)
+        {
+            current.insert(key, price);
+        }
+    }
+    let mut current = current.into_values().collect::<Vec<_>>();
+    current.sort_by(|a, b| {
+        (&a.flavor_name, a.user_class as u32)
+            .cmp(&(&b.flavor_name, b.user_class as u32))
+    });
+
+    let conflicts = current
+        .iter()
+        .filter(|price| existing.contains(&(price.flavor, price.user_class)))
+        .map(|price| {
+            format!(
+                "Flavor {} already has a price for {} at {}",
+                price.flavor_name, price.user_class, data.start_time
+            )
+        })
+        .collect::<Vec<_>>();
+    if !conflicts.is_empty() {
+        return Err(OptionApiError::ValidationError(conflicts.join("; ")));
+    }
+
+    let percentage_factor = 1. + data.percentage.unwrap_or(0.) / 100.;
+    let mut new_flavor_price_count = 0;
+    for price in current {
+        let factor = data
+            .factors
+            .get(&price.user_class)
+            .copied()
+            .unwrap_or(percentage_factor);
+        let new_flavor_price = NewFlavorPrice {
+            flavor_id: price.flavor as u64,
+            user_class: price.user_class,
+            unit_price: (price.unit_price * factor * 100.).round() / 100.,
+            start_time,
+        };
+        let id =
+            insert_flavor_price_into_db(transaction, &new_flavor_price).await?;
+        let flavor_price_created = FlavorPrice {
+            id: id as u32,
+            flavor: price.flavor,
+            flavor_name: price.flavor_name,
+            user_class: price.user_class,
+            unit_price: new_flavor_price.unit_price,
+            start_time: start_time.fixed_offset(),
+        };
+        audit
+            .created(
+                transaction,
+                ObjectType::FlavorPrice,
+                flavor_price_created.id,
+                &flavor_price_created,
+            )
+            .await?;
+        changes.push(Change {
+            action: ChangeAction::Create,
+            object: ChangeObject::FlavorPrice,
+            name: format!(
+                "{} {}",
+                flavor_price_created.flavor_name,
+                flavor_price_created.user_class
+            ),
+            reason: format!(
+                "price revised from {} to {}",
+                price.unit_price, flavor_price_created.unit_price
+            ),
+        });
+        new_flavor_price_count += 1;
+    }
+
+    Ok(FlavorPriceRevise {
+        new_flavor_price_count,
+    })
+}
@@ -0,0 +1,66 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
+use sqlx::MySqlPool;
+
+use super::FloatingIpPriceIdParam;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::floating_ip_price::{
+        delete_floating_ip_price_from_db,
+        select_maybe_floating_ip_price_from_db,
+    },
+    error::NormalApiError,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/{floating_ip_price_id}/",
+    tag = "pricing",
+    params(FloatingIpPriceIdParam),
+    responses(
+        (status = 204, description = "Floating IP price deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "floating_ip_price_delete")]
+pub async fn floating_ip_price_delete(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    params: Path<FloatingIpPriceIdParam>,
+) -> Result<HttpResponse, NormalApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let floating_ip_price_before = select_maybe_floating_ip_price_from_db(
+        &mut transaction,
+        params.floating_ip_price_id as u64,
+    )
+    .await?;
+    delete_floating_ip_price_from_db(
+        &mut transaction,
+        params.floating_ip_price_id as u64,
+    )
+    .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::FloatingIpPrice,
+            params.floating_ip_price_id,
+            &floating_ip_price_before,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::NoContent().finish())
+}
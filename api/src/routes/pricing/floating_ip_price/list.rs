@@ -0,0 +1,65 @@
+use actix_web::{
+    HttpRequest, HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    error::ErrorResponse,
+    pricing::{FloatingIpPrice, FloatingIpPriceListParams},
+    user::User,
+};
+use chrono::Utc;
+use sqlx::MySqlPool;
+
+use crate::{
+    database::pricing::floating_ip_price::{
+        FloatingIpPriceFilter, select_floating_ip_price_list_from_db,
+    },
+    error::NormalApiError,
+    pagination::{ListQuery, Paginated, paginate},
+};
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "pricing",
+    params(FloatingIpPriceListParams),
+    responses(
+        (status = 200, description = "List of floating IP prices", body = Paginated<FloatingIpPrice>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "floating_ip_price_list")]
+pub async fn floating_ip_price_list(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<FloatingIpPriceListParams>,
+    req: HttpRequest,
+) -> Result<HttpResponse, NormalApiError> {
+    let list_query = ListQuery::new::<FloatingIpPrice>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
+    let filter = FloatingIpPriceFilter {
+        user_class: params.user_class,
+        pool: params.pool,
+        valid_at: params.current.unwrap_or(false).then(Utc::now),
+    };
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let (floating_ip_prices, count) = select_floating_ip_price_list_from_db(
+        &mut transaction,
+        &filter,
+        &list_query,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(paginate(&req, floating_ip_prices, count, &list_query))
+}
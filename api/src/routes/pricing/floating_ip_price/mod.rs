@@ -0,0 +1,50 @@
+use actix_web::{
+    Scope,
+    web::{delete, get, patch, post, scope},
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
+
+mod create;
+use create::floating_ip_price_create;
+mod list;
+use list::floating_ip_price_list;
+mod get;
+use get::floating_ip_price_get;
+mod modify;
+use modify::floating_ip_price_modify;
+mod delete;
+use delete::floating_ip_price_delete;
+
+pub fn floating_ip_prices_scope() -> Scope {
+    scope("/floatingipprices")
+        .route("/", post().to(floating_ip_price_create))
+        .route("", get().to(floating_ip_price_list))
+        .route("/{floating_ip_price_id}", get().to(floating_ip_price_get))
+        .route(
+            "/{floating_ip_price_id}/",
+            patch().to(floating_ip_price_modify),
+        )
+        .route(
+            "/{floating_ip_price_id}/",
+            delete().to(floating_ip_price_delete),
+        )
+}
+
+/// OpenAPI documentation of the floating IP price endpoints
+/// routed by [floating_ip_prices_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::floating_ip_price_create,
+    list::floating_ip_price_list,
+    get::floating_ip_price_get,
+    modify::floating_ip_price_modify,
+    delete::floating_ip_price_delete
+))]
+pub struct FloatingIpPricesApiDoc;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
+struct FloatingIpPriceIdParam {
+    floating_ip_price_id: u32,
+}
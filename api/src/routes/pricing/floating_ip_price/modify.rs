@@ -0,0 +1,86 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{FloatingIpPrice, FloatingIpPriceModifyData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use super::FloatingIpPriceIdParam;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::floating_ip_price::{
+        select_maybe_floating_ip_price_from_db, update_floating_ip_price_in_db,
+    },
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/{floating_ip_price_id}/",
+    tag = "pricing",
+    params(FloatingIpPriceIdParam),
+    request_body = FloatingIpPriceModifyData,
+    responses(
+        (status = 200, description = "Modified floating IP price", body = FloatingIpPrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "floating_ip_price_modify")]
+pub async fn floating_ip_price_modify(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    data: Json<FloatingIpPriceModifyData>,
+    params: Path<FloatingIpPriceIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    if data.id != params.floating_ip_price_id {
+        return Err(OptionApiError::ValidationError(
+            "ID in URL does not match ID in body".to_string(),
+        ));
+    }
+    if data
+        .unit_price
+        .is_some_and(|price| !price.is_finite() || price < 0.)
+    {
+        return Err(OptionApiError::ValidationError(
+            "Price must be a non-negative number".to_string(),
+        ));
+    }
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let floating_ip_price_before = select_maybe_floating_ip_price_from_db(
+        &mut transaction,
+        params.floating_ip_price_id as u64,
+    )
+    .await?;
+    let floating_ip_price =
+        update_floating_ip_price_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::FloatingIpPrice,
+            params.floating_ip_price_id,
+            &floating_ip_price_before,
+            &floating_ip_price,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(floating_ip_price))
+}
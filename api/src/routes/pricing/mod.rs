@@ -1,8 +1,39 @@
 use actix_web::{Scope, web::scope};
+use utoipa::OpenApi;
 
+mod billing_policy;
+use billing_policy::billing_policies_scope;
 mod flavor_price;
 use flavor_price::flavor_prices_scope;
+mod volume_price;
+use volume_price::volume_prices_scope;
+mod floating_ip_price;
+use floating_ip_price::floating_ip_prices_scope;
+mod resource_price;
+use resource_price::resource_prices_scope;
+mod price_override;
+use price_override::price_overrides_scope;
 
 pub fn pricing_scope() -> Scope {
-    scope("/pricing").service(flavor_prices_scope())
+    scope("/pricing")
+        .service(flavor_prices_scope())
+        .service(billing_policies_scope())
+        .service(volume_prices_scope())
+        .service(floating_ip_prices_scope())
+        .service(resource_prices_scope())
+        .service(price_overrides_scope())
 }
+
+/// OpenAPI documentation of the pricing endpoints routed by [pricing_scope].
+#[derive(OpenApi)]
+#[openapi(
+    nest(
+        (path = "/flavorprices", api = flavor_price::FlavorPricesApiDoc),
+        (path = "/billingpolicies", api = billing_policy::BillingPoliciesApiDoc),
+        (path = "/volumeprices", api = volume_price::VolumePricesApiDoc),
+        (path = "/floatingipprices", api = floating_ip_price::FloatingIpPricesApiDoc),
+        (path = "/resourceprices", api = resource_price::ResourcePricesApiDoc),
+        (path = "/priceoverrides", api = price_override::PriceOverridesApiDoc)
+    )
+)]
+pub struct PricingApiDoc;
@@ -0,0 +1,99 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{PriceOverride, PriceOverrideCreateData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use super::check_price_override_overlap;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::{
+        pricing::price_override::{
+            NewPriceOverride, insert_price_override_into_db,
+        },
+        resources::flavor::select_flavor_from_db,
+        user::project::select_project_from_db,
+    },
+    error::{NormalApiError, OptionApiError},
+};
+
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "pricing",
+    request_body = PriceOverrideCreateData,
+    responses(
+        (status = 201, description = "Created price override", body = PriceOverride),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "price_override_create")]
+pub async fn price_override_create(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    data: Json<PriceOverrideCreateData>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let new_price_override: NewPriceOverride = data
+        .clone()
+        .try_into()
+        .map_err(NormalApiError::ValidationError)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let project =
+        select_project_from_db(&mut transaction, new_price_override.project_id)
+            .await?;
+    let flavor = match new_price_override.flavor_id {
+        Some(flavor_id) => {
+            Some(select_flavor_from_db(&mut transaction, flavor_id).await?)
+        }
+        None => None,
+    };
+    let mut price_override_created = PriceOverride {
+        id: 0,
+        project: project.id,
+        project_name: project.name,
+        flavor: flavor.as_ref().map(|flavor| flavor.id),
+        flavor_name: flavor.map(|flavor| flavor.name),
+        discount: new_price_override.discount,
+        unit_price: new_price_override.unit_price,
+        start_time: new_price_override.start_time.fixed_offset(),
+        end_time: new_price_override
+            .end_time
+            .map(|end_time| end_time.fixed_offset()),
+    };
+    check_price_override_overlap(&mut transaction, &price_override_created)
+        .await?;
+    let id =
+        insert_price_override_into_db(&mut transaction, &new_price_override)
+            .await?;
+    price_override_created.id = id as u32;
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::PriceOverride,
+            price_override_created.id,
+            &price_override_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Created()
+        .content_type("application/json")
+        .json(price_override_created))
+}
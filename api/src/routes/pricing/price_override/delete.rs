@@ -0,0 +1,65 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
+use sqlx::MySqlPool;
+
+use super::PriceOverrideIdParam;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::price_override::{
+        delete_price_override_from_db, select_maybe_price_override_from_db,
+    },
+    error::NormalApiError,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/{price_override_id}/",
+    tag = "pricing",
+    params(PriceOverrideIdParam),
+    responses(
+        (status = 204, description = "Price override deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "price_override_delete")]
+pub async fn price_override_delete(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    params: Path<PriceOverrideIdParam>,
+) -> Result<HttpResponse, NormalApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let price_override_before = select_maybe_price_override_from_db(
+        &mut transaction,
+        params.price_override_id as u64,
+    )
+    .await?;
+    delete_price_override_from_db(
+        &mut transaction,
+        params.price_override_id as u64,
+    )
+    .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::PriceOverride,
+            params.price_override_id,
+            &price_override_before,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,51 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{error::ErrorResponse, pricing::PriceOverride, user::User};
+use sqlx::MySqlPool;
+
+use super::PriceOverrideIdParam;
+use crate::{
+    authorization::require_admin_user,
+    database::pricing::price_override::select_price_override_from_db,
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    get,
+    path = "/{price_override_id}",
+    tag = "pricing",
+    params(PriceOverrideIdParam),
+    responses(
+        (status = 200, description = "Requested price override", body = PriceOverride),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "price_override_get")]
+pub async fn price_override_get(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Path<PriceOverrideIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let price_override = select_price_override_from_db(
+        &mut transaction,
+        params.price_override_id as u64,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(price_override))
+}
@@ -0,0 +1,67 @@
+use actix_web::{
+    HttpRequest, HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    error::ErrorResponse,
+    pricing::{PriceOverride, PriceOverrideListParams},
+    user::User,
+};
+use chrono::Utc;
+use sqlx::MySqlPool;
+
+use crate::{
+    authorization::require_admin_user,
+    database::pricing::price_override::{
+        PriceOverrideFilter, select_price_override_list_from_db,
+    },
+    error::NormalApiError,
+    pagination::{ListQuery, Paginated, paginate},
+};
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "pricing",
+    params(PriceOverrideListParams),
+    responses(
+        (status = 200, description = "List of price overrides", body = Paginated<PriceOverride>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "price_override_list")]
+pub async fn price_override_list(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<PriceOverrideListParams>,
+    req: HttpRequest,
+) -> Result<HttpResponse, NormalApiError> {
+    require_admin_user(&user)?;
+    let list_query = ListQuery::new::<PriceOverride>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
+    let filter = PriceOverrideFilter {
+        project: params.project.map(|project| project as u64),
+        flavor: params.flavor.map(|flavor| flavor as u64),
+        valid_at: params.current.unwrap_or(false).then(Utc::now),
+    };
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let (price_overrides, count) = select_price_override_list_from_db(
+        &mut transaction,
+        &filter,
+        &list_query,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(paginate(&req, price_overrides, count, &list_query))
+}
@@ -0,0 +1,83 @@
+use actix_web::{
+    Scope,
+    web::{delete, get, patch, post, scope},
+};
+use avina_wire::pricing::PriceOverride;
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use sqlx::{MySql, Transaction};
+use utoipa::{IntoParams, OpenApi};
+
+use crate::{
+    database::pricing::price_override::select_all_price_overrides_from_db,
+    error::OptionApiError,
+};
+
+mod create;
+use create::price_override_create;
+mod list;
+use list::price_override_list;
+mod get;
+use get::price_override_get;
+mod modify;
+use modify::price_override_modify;
+mod delete;
+use delete::price_override_delete;
+
+pub fn price_overrides_scope() -> Scope {
+    scope("/priceoverrides")
+        .route("/", post().to(price_override_create))
+        .route("", get().to(price_override_list))
+        .route("/{price_override_id}", get().to(price_override_get))
+        .route("/{price_override_id}/", patch().to(price_override_modify))
+        .route("/{price_override_id}/", delete().to(price_override_delete))
+}
+
+/// OpenAPI documentation of the price override endpoints
+/// routed by [price_overrides_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::price_override_create,
+    list::price_override_list,
+    get::price_override_get,
+    modify::price_override_modify,
+    delete::price_override_delete
+))]
+pub struct PriceOverridesApiDoc;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
+struct PriceOverrideIdParam {
+    price_override_id: u32,
+}
+
+/// Whether an override with the given end time is still valid at the given time.
+fn valid_until_after(
+    end_time: Option<DateTime<FixedOffset>>,
+    time: DateTime<FixedOffset>,
+) -> bool {
+    end_time.is_none_or(|end_time| end_time > time)
+}
+
+/// Make sure no other override for the same project and flavor overlaps the
+/// given one, otherwise return a [OptionApiError::ValidationError].
+async fn check_price_override_overlap(
+    transaction: &mut Transaction<'_, MySql>,
+    price_override: &PriceOverride,
+) -> Result<(), OptionApiError> {
+    let price_overrides =
+        select_all_price_overrides_from_db(transaction).await?;
+    if let Some(other) = price_overrides.iter().find(|other| {
+        other.id != price_override.id
+            && other.project == price_override.project
+            && other.flavor == price_override.flavor
+            && valid_until_after(other.end_time, price_override.start_time)
+            && valid_until_after(price_override.end_time, other.start_time)
+    }) {
+        return Err(OptionApiError::ValidationError(format!(
+            "Price override overlaps price override {}",
+            other.id
+        )));
+    }
+    Ok(())
+}
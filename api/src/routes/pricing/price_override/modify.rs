@@ -0,0 +1,106 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{PriceOverride, PriceOverrideModifyData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use super::{PriceOverrideIdParam, check_price_override_overlap};
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::price_override::{
+        select_price_override_from_db, update_price_override_in_db,
+        validate_price_override_fields,
+    },
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/{price_override_id}/",
+    tag = "pricing",
+    params(PriceOverrideIdParam),
+    request_body = PriceOverrideModifyData,
+    responses(
+        (status = 200, description = "Modified price override", body = PriceOverride),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "price_override_modify")]
+pub async fn price_override_modify(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    data: Json<PriceOverrideModifyData>,
+    params: Path<PriceOverrideIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    if data.id != params.price_override_id {
+        return Err(OptionApiError::ValidationError(
+            "ID in URL does not match ID in body".to_string(),
+        ));
+    }
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let price_override_before = select_price_override_from_db(
+        &mut transaction,
+        params.price_override_id as u64,
+    )
+    .await?;
+    // setting either the discount or the unit price replaces the other
+    let (discount, unit_price) = match (data.discount, data.unit_price) {
+        (None, None) => (
+            price_override_before.discount,
+            price_override_before.unit_price,
+        ),
+        pair => pair,
+    };
+    let price_override = PriceOverride {
+        id: price_override_before.id,
+        project: price_override_before.project,
+        project_name: price_override_before.project_name.clone(),
+        flavor: price_override_before.flavor,
+        flavor_name: price_override_before.flavor_name.clone(),
+        discount,
+        unit_price,
+        start_time: data.start_time.unwrap_or(price_override_before.start_time),
+        end_time: data.end_time.unwrap_or(price_override_before.end_time),
+    };
+    validate_price_override_fields(
+        price_override.flavor,
+        price_override.discount,
+        price_override.unit_price,
+        price_override.start_time.to_utc(),
+        price_override.end_time.map(|end_time| end_time.to_utc()),
+    )
+    .map_err(OptionApiError::ValidationError)?;
+    check_price_override_overlap(&mut transaction, &price_override).await?;
+    update_price_override_in_db(&mut transaction, &price_override).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::PriceOverride,
+            params.price_override_id,
+            &price_override_before,
+            &price_override,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(price_override))
+}
@@ -0,0 +1,76 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{ResourcePrice, ResourcePriceCreateData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::resource_price::{
+        NewResourcePrice, insert_resource_price_into_db,
+    },
+    error::{NormalApiError, OptionApiError},
+};
+
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "pricing",
+    request_body = ResourcePriceCreateData,
+    responses(
+        (status = 201, description = "Created resource price", body = ResourcePrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "resource_price_create")]
+pub async fn resource_price_create(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    data: Json<ResourcePriceCreateData>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let new_resource_price: NewResourcePrice = data
+        .clone()
+        .try_into()
+        .map_err(NormalApiError::ValidationError)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let id =
+        insert_resource_price_into_db(&mut transaction, &new_resource_price)
+            .await?;
+    let resource_price_created = ResourcePrice {
+        id: id as u32,
+        user_class: new_resource_price.user_class,
+        vcpu_price: new_resource_price.vcpu_price,
+        ram_price: new_resource_price.ram_price,
+        gpu_price: new_resource_price.gpu_price,
+        start_time: new_resource_price.start_time.fixed_offset(),
+    };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::ResourcePrice,
+            resource_price_created.id,
+            &resource_price_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Created()
+        .content_type("application/json")
+        .json(resource_price_created))
+}
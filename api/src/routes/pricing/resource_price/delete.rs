@@ -0,0 +1,65 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
+use sqlx::MySqlPool;
+
+use super::ResourcePriceIdParam;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::resource_price::{
+        delete_resource_price_from_db, select_maybe_resource_price_from_db,
+    },
+    error::NormalApiError,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/{resource_price_id}/",
+    tag = "pricing",
+    params(ResourcePriceIdParam),
+    responses(
+        (status = 204, description = "Resource price deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "resource_price_delete")]
+pub async fn resource_price_delete(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    params: Path<ResourcePriceIdParam>,
+) -> Result<HttpResponse, NormalApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let resource_price_before = select_maybe_resource_price_from_db(
+        &mut transaction,
+        params.resource_price_id as u64,
+    )
+    .await?;
+    delete_resource_price_from_db(
+        &mut transaction,
+        params.resource_price_id as u64,
+    )
+    .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::ResourcePrice,
+            params.resource_price_id,
+            &resource_price_before,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::NoContent().finish())
+}
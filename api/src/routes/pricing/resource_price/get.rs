@@ -0,0 +1,49 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{error::ErrorResponse, pricing::ResourcePrice, user::User};
+use sqlx::MySqlPool;
+
+use super::ResourcePriceIdParam;
+use crate::{
+    database::pricing::resource_price::select_resource_price_from_db,
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    get,
+    path = "/{resource_price_id}",
+    tag = "pricing",
+    params(ResourcePriceIdParam),
+    responses(
+        (status = 200, description = "Requested resource price", body = ResourcePrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "resource_price_get")]
+pub async fn resource_price_get(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Path<ResourcePriceIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let resource_price = select_resource_price_from_db(
+        &mut transaction,
+        params.resource_price_id as u64,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(resource_price))
+}
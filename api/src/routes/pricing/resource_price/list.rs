@@ -0,0 +1,64 @@
+use actix_web::{
+    HttpRequest, HttpResponse,
+    web::{Data, Query, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    error::ErrorResponse,
+    pricing::{ResourcePrice, ResourcePriceListParams},
+    user::User,
+};
+use chrono::Utc;
+use sqlx::MySqlPool;
+
+use crate::{
+    database::pricing::resource_price::{
+        ResourcePriceFilter, select_resource_price_list_from_db,
+    },
+    error::NormalApiError,
+    pagination::{ListQuery, Paginated, paginate},
+};
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "pricing",
+    params(ResourcePriceListParams),
+    responses(
+        (status = 200, description = "List of resource prices", body = Paginated<ResourcePrice>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "resource_price_list")]
+pub async fn resource_price_list(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Query<ResourcePriceListParams>,
+    req: HttpRequest,
+) -> Result<HttpResponse, NormalApiError> {
+    let list_query = ListQuery::new::<ResourcePrice>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
+    let filter = ResourcePriceFilter {
+        user_class: params.user_class,
+        valid_at: params.current.unwrap_or(false).then(Utc::now),
+    };
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let (resource_prices, count) = select_resource_price_list_from_db(
+        &mut transaction,
+        &filter,
+        &list_query,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(paginate(&req, resource_prices, count, &list_query))
+}
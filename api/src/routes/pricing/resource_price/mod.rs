@@ -0,0 +1,44 @@
+use actix_web::{
+    Scope,
+    web::{delete, get, patch, post, scope},
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
+
+mod create;
+use create::resource_price_create;
+mod list;
+use list::resource_price_list;
+mod get;
+use get::resource_price_get;
+mod modify;
+use modify::resource_price_modify;
+mod delete;
+use delete::resource_price_delete;
+
+pub fn resource_prices_scope() -> Scope {
+    scope("/resourceprices")
+        .route("/", post().to(resource_price_create))
+        .route("", get().to(resource_price_list))
+        .route("/{resource_price_id}", get().to(resource_price_get))
+        .route("/{resource_price_id}/", patch().to(resource_price_modify))
+        .route("/{resource_price_id}/", delete().to(resource_price_delete))
+}
+
+/// OpenAPI documentation of the resource price endpoints
+/// routed by [resource_prices_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::resource_price_create,
+    list::resource_price_list,
+    get::resource_price_get,
+    modify::resource_price_modify,
+    delete::resource_price_delete
+))]
+pub struct ResourcePricesApiDoc;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
+struct ResourcePriceIdParam {
+    resource_price_id: u32,
+}
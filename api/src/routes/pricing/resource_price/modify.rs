@@ -0,0 +1,87 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{ResourcePrice, ResourcePriceModifyData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use super::ResourcePriceIdParam;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::resource_price::{
+        select_maybe_resource_price_from_db, update_resource_price_in_db,
+    },
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/{resource_price_id}/",
+    tag = "pricing",
+    params(ResourcePriceIdParam),
+    request_body = ResourcePriceModifyData,
+    responses(
+        (status = 200, description = "Modified resource price", body = ResourcePrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "resource_price_modify")]
+pub async fn resource_price_modify(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    data: Json<ResourcePriceModifyData>,
+    params: Path<ResourcePriceIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    if data.id != params.resource_price_id {
+        return Err(OptionApiError::ValidationError(
+            "ID in URL does not match ID in body".to_string(),
+        ));
+    }
+    if [data.vcpu_price, data.ram_price, data.gpu_price]
+        .iter()
+        .flatten()
+        .any(|price| !price.is_finite() || *price < 0.)
+    {
+        return Err(OptionApiError::ValidationError(
+            "Prices must be non-negative numbers".to_string(),
+        ));
+    }
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let resource_price_before = select_maybe_resource_price_from_db(
+        &mut transaction,
+        params.resource_price_id as u64,
+    )
+    .await?;
+    let resource_price =
+        update_resource_price_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::ResourcePrice,
+            params.resource_price_id,
+            &resource_price_before,
+            &resource_price,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(resource_price))
+}
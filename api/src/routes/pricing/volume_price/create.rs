@@ -0,0 +1,73 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{VolumePrice, VolumePriceCreateData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::volume_price::{
+        NewVolumePrice, insert_volume_price_into_db,
+    },
+    error::{NormalApiError, OptionApiError},
+};
+
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "pricing",
+    request_body = VolumePriceCreateData,
+    responses(
+        (status = 201, description = "Created volume price", body = VolumePrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "volume_price_create")]
+pub async fn volume_price_create(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    data: Json<VolumePriceCreateData>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    let new_volume_price: NewVolumePrice = data
+        .clone()
+        .try_into()
+        .map_err(NormalApiError::ValidationError)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let id = insert_volume_price_into_db(&mut transaction, &new_volume_price)
+        .await?;
+    let volume_price_created = VolumePrice {
+        id: id as u32,
+        user_class: new_volume_price.user_class,
+        unit_price: new_volume_price.unit_price,
+        start_time: new_volume_price.start_time.fixed_offset(),
+    };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::VolumePrice,
+            volume_price_created.id,
+            &volume_price_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Created()
+        .content_type("application/json")
+        .json(volume_price_created))
+}
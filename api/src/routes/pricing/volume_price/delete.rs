@@ -0,0 +1,65 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
+use sqlx::MySqlPool;
+
+use super::VolumePriceIdParam;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::volume_price::{
+        delete_volume_price_from_db, select_maybe_volume_price_from_db,
+    },
+    error::NormalApiError,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/{volume_price_id}/",
+    tag = "pricing",
+    params(VolumePriceIdParam),
+    responses(
+        (status = 204, description = "Volume price deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "volume_price_delete")]
+pub async fn volume_price_delete(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    params: Path<VolumePriceIdParam>,
+) -> Result<HttpResponse, NormalApiError> {
+    require_admin_user(&user)?;
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let volume_price_before = select_maybe_volume_price_from_db(
+        &mut transaction,
+        params.volume_price_id as u64,
+    )
+    .await?;
+    delete_volume_price_from_db(
+        &mut transaction,
+        params.volume_price_id as u64,
+    )
+    .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::VolumePrice,
+            params.volume_price_id,
+            &volume_price_before,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,49 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{error::ErrorResponse, pricing::VolumePrice, user::User};
+use sqlx::MySqlPool;
+
+use super::VolumePriceIdParam;
+use crate::{
+    database::pricing::volume_price::select_volume_price_from_db,
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    get,
+    path = "/{volume_price_id}",
+    tag = "pricing",
+    params(VolumePriceIdParam),
+    responses(
+        (status = 200, description = "Requested volume price", body = VolumePrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "volume_price_get")]
+pub async fn volume_price_get(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    params: Path<VolumePriceIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let volume_price = select_volume_price_from_db(
+        &mut transaction,
+        params.volume_price_id as u64,
+    )
+    .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(volume_price))
+}
@@ -0,0 +1,44 @@
+use actix_web::{
+    Scope,
+    web::{delete, get, patch, post, scope},
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
+
+mod create;
+use create::volume_price_create;
+mod list;
+use list::volume_price_list;
+mod get;
+use get::volume_price_get;
+mod modify;
+use modify::volume_price_modify;
+mod delete;
+use delete::volume_price_delete;
+
+pub fn volume_prices_scope() -> Scope {
+    scope("/volumeprices")
+        .route("/", post().to(volume_price_create))
+        .route("", get().to(volume_price_list))
+        .route("/{volume_price_id}", get().to(volume_price_get))
+        .route("/{volume_price_id}/", patch().to(volume_price_modify))
+        .route("/{volume_price_id}/", delete().to(volume_price_delete))
+}
+
+/// OpenAPI documentation of the volume price endpoints
+/// routed by [volume_prices_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::volume_price_create,
+    list::volume_price_list,
+    get::volume_price_get,
+    modify::volume_price_modify,
+    delete::volume_price_delete
+))]
+pub struct VolumePricesApiDoc;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
+struct VolumePriceIdParam {
+    volume_price_id: u32,
+}
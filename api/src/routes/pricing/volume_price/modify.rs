@@ -0,0 +1,86 @@
+use actix_web::{
+    HttpResponse,
+    web::{Data, Json, Path, ReqData},
+};
+use anyhow::Context;
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    pricing::{VolumePrice, VolumePriceModifyData},
+    user::User,
+};
+use sqlx::MySqlPool;
+
+use super::VolumePriceIdParam;
+use crate::{
+    audit::Audit,
+    authorization::require_admin_user,
+    database::pricing::volume_price::{
+        select_maybe_volume_price_from_db, update_volume_price_in_db,
+    },
+    error::OptionApiError,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/{volume_price_id}/",
+    tag = "pricing",
+    params(VolumePriceIdParam),
+    request_body = VolumePriceModifyData,
+    responses(
+        (status = 200, description = "Modified volume price", body = VolumePrice),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
+#[tracing::instrument(name = "volume_price_modify")]
+pub async fn volume_price_modify(
+    user: ReqData<User>,
+    db_pool: Data<MySqlPool>,
+    audit: Audit,
+    data: Json<VolumePriceModifyData>,
+    params: Path<VolumePriceIdParam>,
+) -> Result<HttpResponse, OptionApiError> {
+    require_admin_user(&user)?;
+    if data.id != params.volume_price_id {
+        return Err(OptionApiError::ValidationError(
+            "ID in URL does not match ID in body".to_string(),
+        ));
+    }
+    if data
+        .unit_price
+        .is_some_and(|price| !price.is_finite() || price < 0.)
+    {
+        return Err(OptionApiError::ValidationError(
+            "Price must be a non-negative number".to_string(),
+        ));
+    }
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .context("Failed to begin transaction")?;
+    let volume_price_before = select_maybe_volume_price_from_db(
+        &mut transaction,
+        params.volume_price_id as u64,
+    )
+    .await?;
+    let volume_price =
+        update_volume_price_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::VolumePrice,
+            params.volume_price_id,
+            &volume_price_before,
+            &volume_price,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .json(volume_price))
+}
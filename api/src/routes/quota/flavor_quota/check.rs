@@ -6,6 +6,7 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
+    error::ErrorResponse,
     quota::{FlavorQuotaCheck, FlavorQuotaCheckParams},
     resources::Flavor,
     user::{Project, User},
@@ -23,6 +24,7 @@ use crate::{
         },
     },
     error::{OptionApiError, UnexpectedOnlyError},
+    metrics::record_cache_lookup,
     openstack::OpenStack,
     routes::flavor_group::usage::calculate_flavor_group_usage_for_user_aggregate,
 };
@@ -118,6 +120,18 @@ async fn check_flavor_quota(
     Ok(underquota)
 }
 
+#[utoipa::path(
+    get,
+    path = "/check/",
+    tag = "quota",
+    params(FlavorQuotaCheckParams),
+    responses(
+        (status = 200, description = "Whether the user is under quota", body = FlavorQuotaCheck),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(
     name = "flavor_quota_check",
     skip(openstack, quota_cache)
@@ -156,6 +170,7 @@ pub async fn flavor_quota_check(
     let underquota = {
         let key = CacheKey::new(&user.name, &flavor.name, count as usize);
         let cache_result = quota_cache.lock().unwrap().get(&key);
+        record_cache_lookup("quota", cache_result.is_some());
         match cache_result {
             Some(underquota) => underquota,
             None => {
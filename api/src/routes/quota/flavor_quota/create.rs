@@ -4,12 +4,15 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
     quota::{FlavorQuota, FlavorQuotaCreateData},
     user::User,
 };
 use sqlx::MySqlPool;
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
     database::{
         quota::flavor_quota::insert_flavor_quota_into_db,
@@ -19,10 +22,23 @@ use crate::{
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "quota",
+    request_body = FlavorQuotaCreateData,
+    responses(
+        (status = 201, description = "Created flavor quota", body = FlavorQuota),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_quota_create")]
 pub async fn flavor_quota_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<FlavorQuotaCreateData>,
 ) -> Result<HttpResponse, OptionApiError> {
     require_admin_user(&user)?;
@@ -38,10 +54,6 @@ pub async fn flavor_quota_create(
     )
     .await?;
     let id = insert_flavor_quota_into_db(&mut transaction, &data).await?;
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
     let flavor_quota_created = FlavorQuota {
         id: id as u32,
         user: data.user,
@@ -50,6 +62,18 @@ pub async fn flavor_quota_create(
         flavor_group: data.flavor_group,
         flavor_group_name,
     };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::FlavorQuota,
+            flavor_quota_created.id,
+            &flavor_quota_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
     Ok(HttpResponse::Created()
         .content_type("application/json")
         .json(flavor_quota_created))
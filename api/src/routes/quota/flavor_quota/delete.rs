@@ -3,20 +3,35 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::FlavorQuotaIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::quota::flavor_quota::delete_flavor_quota_from_db,
+    database::quota::flavor_quota::{
+        delete_flavor_quota_from_db, select_maybe_flavor_quota_from_db,
+    },
     error::NormalApiError,
 };
 
+#[utoipa::path(
+    delete,
+    path = "/{flavor_quota_id}/",
+    tag = "quota",
+    params(FlavorQuotaIdParam),
+    responses(
+        (status = 204, description = "Flavor quota deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_quota_delete")]
 pub async fn flavor_quota_delete(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     params: Path<FlavorQuotaIdParam>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -24,11 +39,24 @@ pub async fn flavor_quota_delete(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let flavor_quota_before = select_maybe_flavor_quota_from_db(
+        &mut transaction,
+        params.flavor_quota_id as u64,
+    )
+    .await?;
     delete_flavor_quota_from_db(
         &mut transaction,
         params.flavor_quota_id as u64,
     )
     .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::FlavorQuota,
+            params.flavor_quota_id,
+            &flavor_quota_before,
+        )
+        .await?;
     transaction
         .commit()
         .await
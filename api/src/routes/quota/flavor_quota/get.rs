@@ -3,7 +3,7 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{error::ErrorResponse, quota::FlavorQuota, user::User};
 use sqlx::MySqlPool;
 
 use super::FlavorQuotaIdParam;
@@ -12,6 +12,18 @@ use crate::{
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "/{flavor_quota_id}",
+    tag = "quota",
+    params(FlavorQuotaIdParam),
+    responses(
+        (status = 200, description = "Requested flavor quota", body = FlavorQuota),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_quota_get")]
 pub async fn flavor_quota_get(
     user: ReqData<User>,
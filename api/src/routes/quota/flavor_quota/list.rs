@@ -4,7 +4,8 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
-    quota::FlavorQuotaListParams,
+    error::ErrorResponse,
+    quota::{FlavorQuota, FlavorQuotaListParams},
     user::{Project, User},
 };
 use sqlx::MySqlPool;
@@ -19,6 +20,17 @@ use crate::{
     error::NormalApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "quota",
+    params(FlavorQuotaListParams),
+    responses(
+        (status = 200, description = "List of flavor quotas", body = Vec<FlavorQuota>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_quota_list")]
 pub async fn flavor_quota_list(
     user: ReqData<User>,
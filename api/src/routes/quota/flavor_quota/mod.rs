@@ -3,6 +3,7 @@ use actix_web::{
     web::{delete, get, patch, post, scope},
 };
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
 mod create;
 use create::flavor_quota_create;
@@ -27,7 +28,21 @@ pub fn flavor_quotas_scope() -> Scope {
         .route("/check/", get().to(flavor_quota_check))
 }
 
-#[derive(Deserialize, Debug)]
+/// OpenAPI documentation of the flavor quota endpoints
+/// routed by [flavor_quotas_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::flavor_quota_create,
+    list::flavor_quota_list,
+    get::flavor_quota_get,
+    modify::flavor_quota_modify,
+    delete::flavor_quota_delete,
+    check::flavor_quota_check
+))]
+pub struct FlavorQuotasApiDoc;
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
 struct FlavorQuotaIdParam {
     flavor_quota_id: u32,
 }
@@ -3,20 +3,42 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::{quota::FlavorQuotaModifyData, user::User};
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    quota::{FlavorQuota, FlavorQuotaModifyData},
+    user::User,
+};
 use sqlx::MySqlPool;
 
 use super::FlavorQuotaIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::quota::flavor_quota::update_flavor_quota_in_db,
+    database::quota::flavor_quota::{
+        select_maybe_flavor_quota_from_db, update_flavor_quota_in_db,
+    },
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    patch,
+    path = "/{flavor_quota_id}/",
+    tag = "quota",
+    params(FlavorQuotaIdParam),
+    request_body = FlavorQuotaModifyData,
+    responses(
+        (status = 200, description = "Modified flavor quota", body = FlavorQuota),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_quota_modify")]
 pub async fn flavor_quota_modify(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<FlavorQuotaModifyData>,
     params: Path<FlavorQuotaIdParam>,
 ) -> Result<HttpResponse, OptionApiError> {
@@ -31,8 +53,22 @@ pub async fn flavor_quota_modify(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let flavor_quota_before = select_maybe_flavor_quota_from_db(
+        &mut transaction,
+        params.flavor_quota_id as u64,
+    )
+    .await?;
     let flavor_quota =
         update_flavor_quota_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::FlavorQuota,
+            params.flavor_quota_id,
+            &flavor_quota_before,
+            &flavor_quota,
+        )
+        .await?;
     transaction
         .commit()
         .await
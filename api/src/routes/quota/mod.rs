@@ -1,4 +1,5 @@
 use actix_web::{Scope, web::scope};
+use utoipa::OpenApi;
 
 pub mod flavor_quota;
 use flavor_quota::flavor_quotas_scope;
@@ -6,3 +7,12 @@ use flavor_quota::flavor_quotas_scope;
 pub fn quota_scope() -> Scope {
     scope("/quota").service(flavor_quotas_scope())
 }
+
+/// OpenAPI documentation of the quota endpoints routed by [quota_scope].
+#[derive(OpenApi)]
+#[openapi(
+    nest(
+        (path = "/flavorquotas", api = flavor_quota::FlavorQuotasApiDoc)
+    )
+)]
+pub struct QuotaApiDoc;
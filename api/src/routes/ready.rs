@@ -0,0 +1,203 @@
+//! Readiness endpoint checking the dependencies of the API.
+
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use actix_web::{HttpResponse, web::Data};
+use anyhow::{Context, anyhow};
+use reqwest::Client;
+use serde::Serialize;
+use sqlx::{Connection, MySqlPool};
+
+use crate::{
+    ldap::check_avina_ldap,
+    openstack::OpenStack,
+    startup::{AvinaLdapConfig, CloudUsageUrl},
+};
+
+/// Maximum duration of a single dependency check.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Overall readiness of the API.
+#[derive(Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum Readiness {
+    /// All dependencies are up.
+    Ready,
+    /// All required dependencies are up, but some optional ones are down.
+    Degraded,
+    /// At least one required dependency is down.
+    Unavailable,
+}
+
+/// State of a single dependency.
+#[derive(Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum DependencyState {
+    Up,
+    Down,
+}
+
+/// Result of checking a single dependency.
+#[derive(Serialize)]
+struct DependencyStatus {
+    /// Whether the dependency is up or down.
+    status: DependencyState,
+    /// Whether the API is unavailable when the dependency is down.
+    required: bool,
+    /// Duration of the check in milliseconds.
+    latency_ms: f64,
+    /// Reason why the dependency is considered down.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Response of the readiness endpoint.
+#[derive(Serialize)]
+struct ReadinessReport {
+    /// Overall readiness derived from the dependencies.
+    status: Readiness,
+    /// Results of the dependency checks by dependency name.
+    dependencies: BTreeMap<&'static str, DependencyStatus>,
+}
+
+/// Run the given dependency check with a timeout and measure its latency.
+async fn check(
+    name: &str,
+    required: bool,
+    check: impl Future<Output = Result<(), anyhow::Error>>,
+) -> DependencyStatus {
+    let start = Instant::now();
+    let result = tokio::time::timeout(CHECK_TIMEOUT, check)
+        .await
+        .unwrap_or_else(|_| {
+            Err(anyhow!(
+                "Check timed out after {} seconds",
+                CHECK_TIMEOUT.as_secs()
+            ))
+        });
+    let latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+    match result {
+        Ok(()) => DependencyStatus {
+            status: DependencyState::Up,
+            required,
+            latency_ms,
+            error: None,
+        },
+        Err(error) => {
+            tracing::warn!("Dependency {name} is down: {error:?}");
+            DependencyStatus {
+                status: DependencyState::Down,
+                required,
+                latency_ms,
+                error: Some(format!("{error:#}")),
+            }
+        }
+    }
+}
+
+/// Check that a database connection can be acquired and pinged.
+async fn check_database(db_pool: &MySqlPool) -> Result<(), anyhow::Error> {
+    db_pool
+        .acquire()
+        .await
+        .context("Failed to acquire database connection")?
+        .ping()
+        .await
+        .context("Failed to ping database")
+}
+
+/// Check that cloud-usage is reachable at the given URL.
+///
+/// cloud-usage requires user data to answer, so any response but a server
+/// error is considered up.
+async fn check_cloud_usage(url: &str) -> Result<(), anyhow::Error> {
+    let response = Client::new()
+        .get(url)
+        .send()
+        .await
+        .context("Could not reach cloud-usage")?;
+    if response.status().is_server_error() {
+        return Err(anyhow!(
+            "cloud-usage returned code {}",
+            response.status().as_u16()
+        ));
+    }
+    Ok(())
+}
+
+/// Readiness endpoint checking all dependencies of the API.
+///
+/// Like the health check, this is not located under `/api/` and does not
+/// require authentication. Unlike it, this checks the database, Keystone and
+/// Nova, which are required, as well as cloud-usage and avina-ldap, which are
+/// optional and only checked when configured. The checks run concurrently,
+/// each limited to [CHECK_TIMEOUT]. The response contains the status and
+/// latency of each dependency, and has an HTTP 503 SERVICE UNAVAILABLE status
+/// code when a required dependency is down, so that traffic is no longer
+/// routed to this instance.
+#[tracing::instrument(name = "ready", skip_all)]
+pub async fn ready(
+    db_pool: Data<MySqlPool>,
+    openstack: Data<OpenStack>,
+    cloud_usage_url: Data<CloudUsageUrl>,
+    avina_ldap_config: Data<AvinaLdapConfig>,
+) -> HttpResponse {
+    let cloud_usage = async {
+        match &cloud_usage_url.0 {
+            Some(url) => {
+                Some(check("cloud_usage", false, check_cloud_usage(url)).await)
+            }
+            None => None,
+        }
+    };
+    let avina_ldap = async {
+        match avina_ldap_config.as_ref() {
+            AvinaLdapConfig::Enabled(url, token, _) => Some(
+                check("avina_ldap", false, check_avina_ldap(url, token)).await,
+            ),
+            AvinaLdapConfig::Disabled(_) => None,
+        }
+    };
+    let (database, keystone, nova, cloud_usage, avina_ldap) = tokio::join!(
+        check("database", true, check_database(&db_pool)),
+        check("keystone", true, openstack.check_keystone()),
+        check("nova", true, openstack.check_nova()),
+        cloud_usage,
+        avina_ldap,
+    );
+
+    let mut dependencies = BTreeMap::from([
+        ("database", database),
+        ("keystone", keystone),
+        ("nova", nova),
+    ]);
+    if let Some(cloud_usage) = cloud_usage {
+        dependencies.insert("cloud_usage", cloud_usage);
+    }
+    if let Some(avina_ldap) = avina_ldap {
+        dependencies.insert("avina_ldap", avina_ldap);
+    }
+    let down = dependencies
+        .values()
+        .filter(|dependency| dependency.status == DependencyState::Down);
+    let status = if down.clone().any(|dependency| dependency.required) {
+        Readiness::Unavailable
+    } else if down.count() > 0 {
+        Readiness::Degraded
+    } else {
+        Readiness::Ready
+    };
+
+    let mut response = if status == Readiness::Unavailable {
+        HttpResponse::ServiceUnavailable()
+    } else {
+        HttpResponse::Ok()
+    };
+    response.json(ReadinessReport {
+        status,
+        dependencies,
+    })
+}
@@ -6,12 +6,15 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
     resources::{FlavorCreateData, FlavorDetailed, FlavorGroupMinimal},
     user::User,
 };
 use sqlx::MySqlPool;
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
     database::resources::{
         flavor::insert_flavor_into_db,
@@ -27,10 +30,23 @@ use crate::{
 /// Only admins can call this endpoint, otherwise an [OptionApiError::AuthorizationError] is returned.
 /// If the flavor group ID given in the [FlavorCreateData] does not exist, an
 /// [OptionApiError::NotFoundError] is returned.
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "resources",
+    request_body = FlavorCreateData,
+    responses(
+        (status = 201, description = "Created flavor", body = FlavorDetailed),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_create")]
 pub async fn flavor_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<FlavorCreateData>,
 ) -> Result<HttpResponse, OptionApiError> {
     require_admin_user(&user)?;
@@ -49,10 +65,6 @@ pub async fn flavor_create(
         None
     };
     let group_name = group.as_ref().map(|g| g.name.clone());
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
     let flavor_created = FlavorDetailed {
         id: id as u32,
         name: data.name.clone(),
@@ -61,6 +73,18 @@ pub async fn flavor_create(
         group_name,
         weight: data.weight.unwrap_or(0),
     };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::Flavor,
+            flavor_created.id,
+            &flavor_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
     Ok(HttpResponse::Created()
         .content_type("application/json")
         .json(flavor_created))
@@ -3,19 +3,35 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::FlavorIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::resources::flavor::delete_flavor_from_db, error::NormalApiError,
+    database::resources::flavor::{
+        delete_flavor_from_db, select_maybe_flavor_from_db,
+    },
+    error::NormalApiError,
 };
 
+#[utoipa::path(
+    delete,
+    path = "/{flavor_id}/",
+    tag = "resources",
+    params(FlavorIdParam),
+    responses(
+        (status = 204, description = "Flavor deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_delete")]
 pub async fn flavor_delete(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     params: Path<FlavorIdParam>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -23,7 +39,18 @@ pub async fn flavor_delete(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let flavor_before =
+        select_maybe_flavor_from_db(&mut transaction, params.flavor_id as u64)
+            .await?;
     delete_flavor_from_db(&mut transaction, params.flavor_id as u64).await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::Flavor,
+            params.flavor_id,
+            &flavor_before,
+        )
+        .await?;
     transaction
         .commit()
         .await
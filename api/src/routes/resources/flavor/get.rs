@@ -3,7 +3,7 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{error::ErrorResponse, resources::FlavorDetailed, user::User};
 use sqlx::MySqlPool;
 
 use super::FlavorIdParam;
@@ -14,6 +14,18 @@ use crate::{
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "/{flavor_id}",
+    tag = "resources",
+    params(FlavorIdParam),
+    responses(
+        (status = 200, description = "Requested flavor", body = FlavorDetailed),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_get")]
 pub async fn flavor_get(
     user: ReqData<User>,
@@ -1,28 +1,58 @@
+use std::collections::HashMap;
+
 use actix_web::{
     HttpResponse,
-    web::{Data, ReqData},
+    web::{Data, Query, ReqData},
 };
 use anyhow::Context;
 use avina_wire::{
+    audit::ObjectType,
+    dry_run::{Change, ChangeAction, ChangeObject, DryRunParams},
+    error::ErrorResponse,
+    pricing::FlavorPrice,
     resources::{FlavorCreateData, FlavorImport},
     user::User,
 };
-use sqlx::MySqlPool;
+use sqlx::{MySql, MySqlPool, Transaction};
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::resources::flavor::{
-        insert_flavor_into_db, select_all_flavors_from_db,
+    database::{
+        pricing::{
+            flavor_price::{
+                NewFlavorPrice, insert_flavor_price_into_db,
+                select_all_flavor_prices_from_db,
+            },
+            resource_price::select_all_resource_prices_from_db,
+        },
+        resources::flavor::{
+            insert_flavor_into_db, select_all_flavors_from_db,
+        },
     },
+    dry_run::{DryRunnable, finish},
     error::NormalApiError,
-    openstack::OpenStack,
+    openstack::{FlavorDetailed, OpenStack},
 };
 
+#[utoipa::path(
+    get,
+    path = "/import/",
+    tag = "resources",
+    params(DryRunParams),
+    responses(
+        (status = 200, description = "Summary of the import, with the changes in dry runs", body = DryRunnable<FlavorImport>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_import", skip(openstack))]
 pub async fn flavor_import(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
     openstack: Data<OpenStack>,
+    audit: Audit,
+    params: Query<DryRunParams>,
     // TODO: is the ValidationError variant ever used?
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -30,16 +60,42 @@ pub async fn flavor_import(
         .begin()
         .await
         .context("Failed to begin transaction")?;
-    let existing_flavor_names = select_all_flavors_from_db(&mut transaction)
+    let mut changes = Vec::new();
+    let flavor_import = import_flavors(
+        &mut transaction,
+        &openstack,
+        Some(&audit),
+        &mut changes,
+    )
+    .await?;
+    Ok(finish(transaction, &params, flavor_import, changes).await?)
+}
+
+/// Import all flavors from OpenStack that are missing in the database.
+///
+/// Afterwards the flavor prices are generated from the resource prices, see
+/// [generate_flavor_prices]. Every created flavor and flavor price is
+/// recorded in the given changes.
+#[tracing::instrument(
+    name = "import_flavors",
+    skip(transaction, openstack, audit)
+)]
+pub async fn import_flavors(
+    transaction: &mut Transaction<'_, MySql>,
+    openstack: &OpenStack,
+    audit: Option<&Audit>,
+    changes: &mut Vec<Change>,
+) -> Result<FlavorImport, NormalApiError> {
+    let existing_flavor_names = select_all_flavors_from_db(transaction)
         .await?
         .iter()
         .map(|f| f.name.clone())
         .collect::<Vec<_>>();
-    let new_flavors = openstack
-        .get_flavors()
-        .await?
-        .into_iter()
+    let openstack_flavors = openstack.get_flavors().await?;
+    let new_flavors = openstack_flavors
+        .iter()
         .filter(|f| !existing_flavor_names.contains(&f.name))
+        .cloned()
         .collect::<Vec<_>>();
     let new_flavor_count = new_flavors.len() as u32;
     for flavor in new_flavors {
@@ -49,14 +105,125 @@ pub async fn flavor_import(
             group: None,
             weight: None,
         };
-        let _ = insert_flavor_into_db(&mut transaction, &data).await?;
+        let _ = insert_flavor_into_db(transaction, &data).await?;
+        changes.push(Change {
+            action: ChangeAction::Create,
+            object: ChangeObject::Flavor,
+            name: flavor.name,
+            reason: format!("flavor {} is missing", flavor.id),
+        });
     }
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
-    let flavor_import = FlavorImport { new_flavor_count };
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .json(flavor_import))
+    let new_flavor_price_count = generate_flavor_prices(
+        transaction,
+        &openstack_flavors,
+        openstack.gpu_aliases(),
+        audit,
+        changes,
+    )
+    .await?;
+    Ok(FlavorImport {
+        new_flavor_count,
+        new_flavor_price_count,
+    })
+}
+
+/// Generate the missing flavor prices of the given flavors from the resource
+/// prices.
+///
+/// For each resource price, every flavor gets a price for its user class
+/// starting at the same time, which is the sum of the prices of its vCPUs,
+/// RAM and GPUs rounded to cents, where only the given aliases of passed
+/// through devices count as GPUs, see [FlavorDetailed::gpus]. Flavors, that
+/// already have a price for the user class starting at or after that time,
+/// are skipped, so admins can override the generated price of a flavor by
+/// creating or modifying its price. Every created flavor price is recorded in
+/// the given changes, and in the audit log if an audit is given, which
+/// scheduled job runs lack.
+#[tracing::instrument(
+    name = "generate_flavor_prices",
+    skip(transaction, openstack_flavors, audit, changes)
+)]
+pub async fn generate_flavor_prices(
+    transaction: &mut Transaction<'_, MySql>,
+    openstack_flavors: &[FlavorDetailed],
+    gpu_aliases: &[String],
+    audit: Option<&Audit>,
+    changes: &mut Vec<Change>,
+) -> Result<u32, NormalApiError> {
+    let mut resource_prices =
+        select_all_resource_prices_from_db(transaction).await?;
+    if resource_prices.is_empty() {
+        return Ok(0);
+    }
+    resource_prices.sort_by_key(|price| price.start_time);
+    let flavor_ids = select_all_flavors_from_db(transaction)
+        .await?
+        .into_iter()
+        .map(|flavor| (flavor.name, flavor.id))
+        .collect::<HashMap<_, _>>();
+    let mut latest_start_times = HashMap::new();
+    for price in select_all_flavor_prices_from_db(transaction).await? {
+        let latest = latest_start_times
+            .entry((price.flavor, price.user_class))
+            .or_insert(price.start_time);
+        *latest = price.start_time.max(*latest);
+    }
+
+    let mut new_flavor_price_count = 0;
+    for resource_price in resource_prices {
+        for flavor in openstack_flavors {
+            let Some(&flavor_id) = flavor_ids.get(&flavor.name) else {
+                continue;
+            };
+            let key = (flavor_id, resource_price.user_class);
+            if latest_start_times
+                .get(&key)
+                .is_some_and(|latest| *latest >= resource_price.start_time)
+            {
+                continue;
+            }
+            let unit_price = flavor.vcpus as f64 * resource_price.vcpu_price
+                + flavor.ram_gib() * resource_price.ram_price
+                + flavor.gpus(gpu_aliases) as f64 * resource_price.gpu_price;
+            let new_flavor_price = NewFlavorPrice {
+                flavor_id: flavor_id as u64,
+                user_class: resource_price.user_class,
+                unit_price: (unit_price * 100.).round() / 100.,
+                start_time: resource_price.start_time.to_utc(),
+            };
+            let id =
+                insert_flavor_price_into_db(transaction, &new_flavor_price)
+                    .await?;
+            if let Some(audit) = audit {
+                let flavor_price_created = FlavorPrice {
+                    id: id as u32,
+                    flavor: flavor_id,
+                    flavor_name: flavor.name.clone(),
+                    user_class: new_flavor_price.user_class,
+                    unit_price: new_flavor_price.unit_price,
+                    start_time: new_flavor_price.start_time.fixed_offset(),
+                };
+                audit
+                    .created(
+                        transaction,
+                        ObjectType::FlavorPrice,
+                        flavor_price_created.id,
+                        &flavor_price_created,
+                    )
+                    .await?;
+            }
+            latest_start_times.insert(key, resource_price.start_time);
+            changes.push(Change {
+                action: ChangeAction::Create,
+                object: ChangeObject::FlavorPrice,
+                name: format!("{} {}", flavor.name, resource_price.user_class),
+                reason: format!(
+                    "price generated from resource price {}",
+                    resource_price.id
+                ),
+            });
+            new_flavor_price_count += 1;
+        }
+    }
+    Ok(new_flavor_price_count)
 }
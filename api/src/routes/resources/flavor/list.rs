@@ -4,7 +4,8 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
-    resources::FlavorListParams,
+    error::ErrorResponse,
+    resources::{Flavor, FlavorListParams},
     user::{Project, User},
 };
 use sqlx::MySqlPool;
@@ -18,6 +19,17 @@ use crate::{
     error::NormalApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "resources",
+    params(FlavorListParams),
+    responses(
+        (status = 200, description = "List of flavors", body = Vec<Flavor>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_list")]
 pub async fn flavor_list(
     user: ReqData<User>,
@@ -5,6 +5,7 @@ use actix_web::{
     web::{delete, get, patch, post, scope},
 };
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
 pub mod create;
 use create::flavor_create;
@@ -41,10 +42,24 @@ pub fn flavors_scope() -> Scope {
         .route("/usage/", get().to(flavor_usage))
 }
 
+/// OpenAPI documentation of the flavor endpoints routed by [flavors_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::flavor_create,
+    list::flavor_list,
+    get::flavor_get,
+    modify::flavor_modify,
+    delete::flavor_delete,
+    import::flavor_import,
+    usage::flavor_usage
+))]
+pub struct FlavorsApiDoc;
+
 /// Wrapper type for the flavor ID parameter to user endpoints.
 ///
 /// As this is handed to endpoints as [actix_web::web::Path], it should to have a distinguishable type.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
 pub struct FlavorIdParam {
     /// The wrapped flavor ID.
     flavor_id: u32,
@@ -3,19 +3,42 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::{resources::FlavorModifyData, user::User};
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    resources::{Flavor, FlavorModifyData},
+    user::User,
+};
 use sqlx::MySqlPool;
 
 use super::FlavorIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::resources::flavor::update_flavor_in_db, error::OptionApiError,
+    database::resources::flavor::{
+        select_maybe_flavor_from_db, update_flavor_in_db,
+    },
+    error::OptionApiError,
 };
 
+#[utoipa::path(
+    patch,
+    path = "/{flavor_id}/",
+    tag = "resources",
+    params(FlavorIdParam),
+    request_body = FlavorModifyData,
+    responses(
+        (status = 200, description = "Modified flavor", body = Flavor),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_modify")]
 pub async fn flavor_modify(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<FlavorModifyData>,
     params: Path<FlavorIdParam>,
 ) -> Result<HttpResponse, OptionApiError> {
@@ -30,7 +53,19 @@ pub async fn flavor_modify(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let flavor_before =
+        select_maybe_flavor_from_db(&mut transaction, params.flavor_id as u64)
+            .await?;
     let flavor = update_flavor_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::Flavor,
+            params.flavor_id,
+            &flavor_before,
+            &flavor,
+        )
+        .await?;
     transaction
         .commit()
         .await
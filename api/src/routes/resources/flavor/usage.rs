@@ -6,6 +6,7 @@ use actix_web::{
 };
 use anyhow::{Context, anyhow};
 use avina_wire::{
+    error::ErrorResponse,
     resources::{
         Flavor, FlavorUsageAggregate, FlavorUsageParams, FlavorUsageSimple,
     },
@@ -13,6 +14,7 @@ use avina_wire::{
 };
 use serde::Serialize;
 use sqlx::{MySql, MySqlPool, Transaction};
+use utoipa::ToSchema;
 
 use crate::{
     authorization::{
@@ -30,7 +32,7 @@ use crate::{
     openstack::OpenStack,
 };
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum FlavorUsage {
     Simple(Vec<FlavorUsageSimple>),
@@ -288,6 +290,18 @@ pub async fn calculate_flavor_usage_for_all(
     })
 }
 
+#[utoipa::path(
+    get,
+    path = "/usage/",
+    tag = "resources",
+    params(FlavorUsageParams),
+    responses(
+        (status = 200, description = "Flavor usage, aggregated if requested", body = FlavorUsage),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_usage", skip(openstack))]
 pub async fn flavor_usage(
     user: ReqData<User>,
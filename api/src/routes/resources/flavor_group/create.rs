@@ -6,13 +6,15 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
     resources::{FlavorGroup, FlavorGroupCreateData},
     user::User,
 };
 use sqlx::MySqlPool;
 
 use crate::{
-    authorization::require_admin_user,
+    audit::Audit, authorization::require_admin_user,
     database::resources::flavor_group::insert_flavor_group_into_db,
     error::NormalApiError,
 };
@@ -22,10 +24,22 @@ use crate::{
 /// On success a HTTP 201 CREATED with the created flavor group in the response data is returned.
 ///
 /// Only admins can call this endpoint, otherwise an [NormalApiError::AuthorizationError] is returned.
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "resources",
+    request_body = FlavorGroupCreateData,
+    responses(
+        (status = 201, description = "Created flavor group", body = FlavorGroup),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_group_create")]
 pub async fn flavor_group_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<FlavorGroupCreateData>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -41,16 +55,24 @@ pub async fn flavor_group_create(
         user.project as u64,
     )
     .await?;
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
     let flavor_group_created = FlavorGroup {
         id: id as u32,
         name,
         project: user.project,
         flavors: vec![],
     };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::FlavorGroup,
+            flavor_group_created.id,
+            &flavor_group_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
     Ok(HttpResponse::Created()
         .content_type("application/json")
         .json(flavor_group_created))
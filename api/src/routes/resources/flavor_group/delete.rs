@@ -3,20 +3,35 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::FlavorGroupIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::resources::flavor_group::delete_flavor_group_from_db,
+    database::resources::flavor_group::{
+        delete_flavor_group_from_db, select_maybe_flavor_group_from_db,
+    },
     error::NormalApiError,
 };
 
+#[utoipa::path(
+    delete,
+    path = "/{flavor_group_id}/",
+    tag = "resources",
+    params(FlavorGroupIdParam),
+    responses(
+        (status = 204, description = "Flavor group deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_group_delete")]
 pub async fn flavor_group_delete(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     params: Path<FlavorGroupIdParam>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -24,11 +39,24 @@ pub async fn flavor_group_delete(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let flavor_group_before = select_maybe_flavor_group_from_db(
+        &mut transaction,
+        params.flavor_group_id as u64,
+    )
+    .await?;
     delete_flavor_group_from_db(
         &mut transaction,
         params.flavor_group_id as u64,
     )
     .await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::FlavorGroup,
+            params.flavor_group_id,
+            &flavor_group_before,
+        )
+        .await?;
     transaction
         .commit()
         .await
@@ -3,7 +3,9 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::{resources::FlavorGroupDetailed, user::User};
+use avina_wire::{
+    error::ErrorResponse, resources::FlavorGroupDetailed, user::User,
+};
 use sqlx::MySqlPool;
 
 use super::FlavorGroupIdParam;
@@ -20,6 +22,18 @@ use crate::{
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "/{flavor_group_id}",
+    tag = "resources",
+    params(FlavorGroupIdParam),
+    responses(
+        (status = 200, description = "Requested flavor group", body = FlavorGroupDetailed),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_group_get")]
 pub async fn flavor_group_get(
     user: ReqData<User>,
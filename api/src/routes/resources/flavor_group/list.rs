@@ -4,7 +4,8 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
-    resources::FlavorGroupListParams,
+    error::ErrorResponse,
+    resources::{FlavorGroup, FlavorGroupListParams},
     user::{Project, User},
 };
 use sqlx::MySqlPool;
@@ -17,6 +18,17 @@ use crate::{
     error::NormalApiError,
 };
 
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "resources",
+    params(FlavorGroupListParams),
+    responses(
+        (status = 200, description = "List of flavor groups", body = Vec<FlavorGroup>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_group_list")]
 pub async fn flavor_group_list(
     user: ReqData<User>,
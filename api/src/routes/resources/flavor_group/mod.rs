@@ -5,6 +5,7 @@ use actix_web::{
     web::{delete, get, patch, post, scope},
 };
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
 pub mod create;
 use create::flavor_group_create;
@@ -37,10 +38,24 @@ pub fn flavor_groups_scope() -> Scope {
         .route("/usage/", get().to(flavor_group_usage))
 }
 
+/// OpenAPI documentation of the flavor group endpoints
+/// routed by [flavor_groups_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::flavor_group_create,
+    list::flavor_group_list,
+    get::flavor_group_get,
+    modify::flavor_group_modify,
+    delete::flavor_group_delete,
+    usage::flavor_group_usage
+))]
+pub struct FlavorGroupsApiDoc;
+
 /// Wrapper type for the flavor group ID parameter to user endpoints.
 ///
 /// As this is handed to endpoints as [actix_web::web::Path], it should to have a distinguishable type.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
 pub struct FlavorGroupIdParam {
     /// The wrapped flavor group ID.
     flavor_group_id: u32,
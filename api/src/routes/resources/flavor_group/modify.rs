@@ -3,20 +3,42 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::{resources::FlavorGroupModifyData, user::User};
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    resources::{FlavorGroup, FlavorGroupModifyData},
+    user::User,
+};
 use sqlx::MySqlPool;
 
 use super::FlavorGroupIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::resources::flavor_group::update_flavor_group_in_db,
+    database::resources::flavor_group::{
+        select_maybe_flavor_group_from_db, update_flavor_group_in_db,
+    },
     error::OptionApiError,
 };
 
+#[utoipa::path(
+    patch,
+    path = "/{flavor_group_id}/",
+    tag = "resources",
+    params(FlavorGroupIdParam),
+    request_body = FlavorGroupModifyData,
+    responses(
+        (status = 200, description = "Modified flavor group", body = FlavorGroup),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_group_modify")]
 pub async fn flavor_group_modify(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<FlavorGroupModifyData>,
     params: Path<FlavorGroupIdParam>,
 ) -> Result<HttpResponse, OptionApiError> {
@@ -31,8 +53,22 @@ pub async fn flavor_group_modify(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let flavor_group_before = select_maybe_flavor_group_from_db(
+        &mut transaction,
+        params.flavor_group_id as u64,
+    )
+    .await?;
     let flavor_group =
         update_flavor_group_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::FlavorGroup,
+            params.flavor_group_id,
+            &flavor_group_before,
+            &flavor_group,
+        )
+        .await?;
     transaction
         .commit()
         .await
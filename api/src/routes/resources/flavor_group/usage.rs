@@ -6,6 +6,7 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::{
+    error::ErrorResponse,
     resources::{
         FlavorGroupUsageAggregate, FlavorGroupUsageParams,
         FlavorGroupUsageSimple, FlavorUsageSimple,
@@ -14,6 +15,7 @@ use avina_wire::{
 };
 use serde::Serialize;
 use sqlx::{MySql, MySqlPool, Transaction};
+use utoipa::ToSchema;
 
 use crate::{
     authorization::{
@@ -30,7 +32,7 @@ use crate::{
     },
 };
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum FlavorGroupUsage {
     Simple(Vec<FlavorGroupUsageSimple>),
@@ -234,6 +236,18 @@ pub async fn calculate_flavor_group_usage_for_all(
     })
 }
 
+#[utoipa::path(
+    get,
+    path = "/usage/",
+    tag = "resources",
+    params(FlavorGroupUsageParams),
+    responses(
+        (status = 200, description = "Flavor group usage, aggregated if requested", body = FlavorGroupUsage),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "flavor_group_usage", skip(openstack))]
 pub async fn flavor_group_usage(
     user: ReqData<User>,
@@ -4,6 +4,7 @@ use actix_web::{
     Scope,
     web::{get, scope},
 };
+use utoipa::OpenApi;
 
 pub mod flavor_group;
 use flavor_group::flavor_groups_scope;
@@ -23,3 +24,16 @@ pub fn resources_scope() -> Scope {
         .service(flavors_scope())
         .route("/usage", get().to(cloud_usage))
 }
+
+/// OpenAPI documentation of the resource endpoints routed by [resources_scope].
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        usage::cloud_usage
+    ),
+    nest(
+        (path = "/flavorgroups", api = flavor_group::FlavorGroupsApiDoc),
+        (path = "/flavors", api = flavor::FlavorsApiDoc)
+    )
+)]
+pub struct ResourcesApiDoc;
@@ -6,7 +6,7 @@ use actix_web::{
     HttpResponse, http,
     web::{Data, ReqData},
 };
-use avina_wire::user::User;
+use avina_wire::{error::ErrorResponse, resources::CloudUsage, user::User};
 use reqwest::Client;
 
 use crate::{
@@ -16,6 +16,15 @@ use crate::{
 /// Get global usage information of the cloud.
 ///
 /// This basically calls cloud-usage and returns its response.
+#[utoipa::path(
+    get,
+    path = "/usage",
+    tag = "resources",
+    responses(
+        (status = 200, description = "Usage of the cloud", body = CloudUsage),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "cloud_usage")]
 pub async fn cloud_usage(
     user: ReqData<User>,
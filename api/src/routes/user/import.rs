@@ -4,12 +4,16 @@ use std::collections::HashMap;
 
 use actix_web::{
     HttpResponse,
-    web::{Data, ReqData},
+    web::{Data, Query, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{User, UserImport};
+use avina_wire::{
+    dry_run::{Change, ChangeAction, ChangeObject, DryRunParams},
+    error::ErrorResponse,
+    user::{User, UserImport},
+};
 use chrono::{Datelike, Utc};
-use sqlx::MySqlPool;
+use sqlx::{MySql, MySqlPool, Transaction};
 
 use crate::{
     authorization::require_admin_user,
@@ -25,6 +29,7 @@ use crate::{
             user::{NewUser, insert_user_into_db, select_all_users_from_db},
         },
     },
+    dry_run::{DryRunnable, finish},
     error::NormalApiError,
     ldap::AvinaLdap,
     openstack::OpenStack,
@@ -38,32 +43,66 @@ use crate::{
 /// budgets if necessary.
 ///
 /// On success this returns the number of imported users and projects with an HTTP 200 OK
-/// status code.
+/// status code. With the `dry_run` parameter, nothing is imported and the users and
+/// projects that would be imported are returned as well.
 ///
 /// Only admins are allowed to call this endpoint, otherwise a [NormalApiError::AuthorizationError]
 /// is returned.
+#[utoipa::path(
+    get,
+    path = "/import",
+    tag = "user",
+    params(DryRunParams),
+    responses(
+        (status = 200, description = "Summary of the import, with the changes in dry runs", body = DryRunnable<UserImport>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_import", skip(openstack))]
 pub async fn user_import(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
     openstack: Data<OpenStack>,
     avina_ldap_config: Data<AvinaLdapConfig>,
+    params: Query<DryRunParams>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
     let mut transaction = db_pool
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let mut changes = Vec::new();
+    let user_import = import_users(
+        &mut transaction,
+        &openstack,
+        &avina_ldap_config,
+        &mut changes,
+    )
+    .await?;
+    Ok(finish(transaction, &params, user_import, changes).await?)
+}
 
+/// Import all users and projects from OpenStack that are missing in the database.
+///
+/// This also creates the user and project budgets of the running year for them.
+/// Every created object is recorded in the given changes.
+#[tracing::instrument(name = "import_users", skip(transaction, openstack))]
+pub async fn import_users(
+    transaction: &mut Transaction<'_, MySql>,
+    openstack: &OpenStack,
+    avina_ldap_config: &AvinaLdapConfig,
+    changes: &mut Vec<Change>,
+) -> Result<UserImport, NormalApiError> {
     let os_domains = openstack.get_domains().await?;
     let os_projects = openstack.get_projects().await?;
 
-    let users = select_all_users_from_db(&mut transaction).await?;
-    let mut projects = select_all_projects_from_db(&mut transaction).await?;
+    let users = select_all_users_from_db(transaction).await?;
+    let mut projects = select_all_projects_from_db(transaction).await?;
     let usernames: Vec<String> = users.iter().map(|u| u.name.clone()).collect();
     let project_names: Vec<String> =
         projects.iter().map(|u| u.name.clone()).collect();
-    let ldap_data = AvinaLdap::new(&avina_ldap_config).await?;
+    let ldap_data = AvinaLdap::new(avina_ldap_config).await?;
 
     let mut new_user_count = 0;
     let mut new_project_count = 0;
@@ -81,10 +120,19 @@ pub async fn user_import(
                 openstack_id: os_domain.id,
                 user_class: ldap_data.get_userclass(&project_name),
             };
+            changes.push(Change {
+                action: ChangeAction::Create,
+                object: ChangeObject::Project,
+                name: project_name.clone(),
+                reason: format!(
+                    "domain {} is missing, user class {}",
+                    new_project.openstack_id, new_project.user_class
+                ),
+            });
             let project_id =
-                insert_project_into_db(&mut transaction, &new_project).await?;
+                insert_project_into_db(transaction, &new_project).await?;
             insert_project_budget_into_db(
-                &mut transaction,
+                transaction,
                 &NewProjectBudget {
                     project_id,
                     year,
@@ -92,13 +140,19 @@ pub async fn user_import(
                 },
             )
             .await?;
+            changes.push(Change {
+                action: ChangeAction::Create,
+                object: ChangeObject::ProjectBudget,
+                name: project_name,
+                reason: format!("project is new, year {year}"),
+            });
 
             new_project_count += 1;
         }
     }
 
     if new_project_count > 0 {
-        projects = select_all_projects_from_db(&mut transaction).await?;
+        projects = select_all_projects_from_db(transaction).await?;
     }
 
     let project_by_name = projects
@@ -127,9 +181,18 @@ pub async fn user_import(
             is_staff: false,
             is_active: true,
         };
-        let user_id = insert_user_into_db(&mut transaction, &new_user).await?;
+        changes.push(Change {
+            action: ChangeAction::Create,
+            object: ChangeObject::User,
+            name: username.clone(),
+            reason: format!(
+                "OpenStack project {} is missing, project {}, role {}",
+                new_user.openstack_id, project.name, new_user.role
+            ),
+        });
+        let user_id = insert_user_into_db(transaction, &new_user).await?;
         insert_user_budget_into_db(
-            &mut transaction,
+            transaction,
             &NewUserBudget {
                 user_id,
                 year,
@@ -137,18 +200,18 @@ pub async fn user_import(
             },
         )
         .await?;
+        changes.push(Change {
+            action: ChangeAction::Create,
+            object: ChangeObject::UserBudget,
+            name: username.clone(),
+            reason: format!("user is new, year {year}"),
+        });
 
         new_user_count += 1;
     }
 
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .json(UserImport {
-            new_project_count,
-            new_user_count,
-        }))
+    Ok(UserImport {
+        new_project_count,
+        new_user_count,
+    })
 }
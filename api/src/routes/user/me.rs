@@ -8,6 +8,14 @@ use avina_wire::user::{Project, ProjectMinimal, User, UserDetailed};
 /// Any user can call this, as they only get information about
 /// themselves. On success they get a HTTP 200 OK with an instance
 /// of [UserDetailed] as data.
+#[utoipa::path(
+    get,
+    path = "/me",
+    tag = "user",
+    responses(
+        (status = 200, description = "Calling user", body = UserDetailed),
+    )
+)]
 #[tracing::instrument(name = "user_me")]
 pub async fn user_me(
     user: ReqData<User>,
@@ -4,6 +4,7 @@ use actix_web::{
     Scope,
     web::{get, scope},
 };
+use utoipa::OpenApi;
 
 pub mod project;
 use project::projects_scope;
@@ -32,3 +33,19 @@ pub fn user_scope() -> Scope {
         .route("/import", get().to(user_import))
         .route("/sync", get().to(user_sync))
 }
+
+/// OpenAPI documentation of the user and project endpoints
+/// routed by [user_scope].
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        me::user_me,
+        import::user_import,
+        sync::user_sync
+    ),
+    nest(
+        (path = "/projects", api = project::ProjectsApiDoc),
+        (path = "/users", api = user::UsersApiDoc)
+    )
+)]
+pub struct UserApiDoc;
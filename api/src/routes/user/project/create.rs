@@ -5,10 +5,15 @@ use actix_web::{
     web::{Data, Json, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{Project, ProjectCreateData, User};
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    user::{Project, ProjectCreateData, User},
+};
 use sqlx::MySqlPool;
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
     database::user::project::{NewProject, insert_project_into_db},
     error::NormalApiError,
@@ -21,10 +26,22 @@ use crate::{
 /// Only admins can call this endpoint, otherwise an [NormalApiError::AuthorizationError] is returned.
 /// If the [ProjectCreateData] cannot be converted into a [NewProject] an [NormalApiError::ValidationError]
 /// is returned.
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "user",
+    request_body = ProjectCreateData,
+    responses(
+        (status = 201, description = "Created project", body = Project),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_create")]
 pub async fn project_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<ProjectCreateData>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -35,16 +52,24 @@ pub async fn project_create(
         .await
         .context("Failed to begin transaction")?;
     let id = insert_project_into_db(&mut transaction, &new_project).await?;
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
     let project_created = Project {
         id: id as u32,
         name: new_project.name.clone(),
         openstack_id: new_project.openstack_id.clone(),
         user_class: new_project.user_class,
     };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::Project,
+            project_created.id,
+            &project_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
     Ok(HttpResponse::Created()
         .content_type("application/json")
         .json(project_created))
@@ -5,13 +5,17 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::ProjectIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::user::project::delete_project_from_db, error::NormalApiError,
+    database::user::project::{
+        delete_project_from_db, select_maybe_project_from_db,
+    },
+    error::NormalApiError,
 };
 
 /// Delete the project with the given project ID.
@@ -20,10 +24,22 @@ use crate::{
 /// returned.
 ///
 /// Only admins can use this endpoint, otherwise an [NormalApiError::AuthorizationError] error is returned.
+#[utoipa::path(
+    delete,
+    path = "/{project_id}/",
+    tag = "user",
+    params(ProjectIdParam),
+    responses(
+        (status = 204, description = "Project deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_delete")]
 pub async fn project_delete(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     params: Path<ProjectIdParam>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -31,7 +47,20 @@ pub async fn project_delete(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let project_before = select_maybe_project_from_db(
+        &mut transaction,
+        params.project_id as u64,
+    )
+    .await?;
     delete_project_from_db(&mut transaction, params.project_id as u64).await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::Project,
+            params.project_id,
+            &project_before,
+        )
+        .await?;
     transaction
         .commit()
         .await
@@ -5,7 +5,10 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{Project, ProjectDetailed, ProjectRetrieved, User};
+use avina_wire::{
+    error::ErrorResponse,
+    user::{Project, ProjectDetailed, ProjectRetrieved, User},
+};
 use sqlx::MySqlPool;
 
 use super::ProjectIdParam;
@@ -33,6 +36,18 @@ use crate::{
 ///
 /// Also note, that admins get a [ProjectDetailed] while normal users only get the plain [Project]
 /// representation.
+#[utoipa::path(
+    get,
+    path = "/{project_id}",
+    tag = "user",
+    params(ProjectIdParam),
+    responses(
+        (status = 200, description = "Requested project", body = ProjectRetrieved),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_get")]
 pub async fn project_get(
     user: ReqData<User>,
@@ -5,7 +5,10 @@ use actix_web::{
     web::{Data, Query, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{Project, ProjectListParams, User};
+use avina_wire::{
+    error::ErrorResponse,
+    user::{Project, ProjectListParams, User},
+};
 use sqlx::MySqlPool;
 
 use crate::{
@@ -29,6 +32,17 @@ use crate::{
 ///
 /// Note, that given both filters, `all` takes precedence. If users other than admins use any of
 /// them, an [NormalApiError::AuthorizationError] is returned.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "user",
+    params(ProjectListParams),
+    responses(
+        (status = 200, description = "List of projects", body = Vec<Project>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_list")]
 pub async fn project_list(
     user: ReqData<User>,
@@ -5,6 +5,7 @@ use actix_web::{
     web::{delete, get, patch, post, scope},
 };
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
 pub mod create;
 use create::project_create;
@@ -33,10 +34,22 @@ pub fn projects_scope() -> Scope {
         .route("/{project_id}/", delete().to(project_delete))
 }
 
+/// OpenAPI documentation of the project endpoints routed by [projects_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::project_create,
+    list::project_list,
+    get::project_get,
+    modify::project_modify,
+    delete::project_delete
+))]
+pub struct ProjectsApiDoc;
+
 /// Wrapper type for the project ID parameter to project endpoints.
 ///
 /// As this is handed to endpoints as [actix_web::web::Path], it should to have a distinguishable type.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
 pub struct ProjectIdParam {
     /// The wrapped project ID.
     project_id: u32,
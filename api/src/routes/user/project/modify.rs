@@ -5,13 +5,21 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{ProjectModifyData, User};
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    user::{Project, ProjectModifyData, User},
+};
 use sqlx::MySqlPool;
 
 use super::ProjectIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::user::project::update_project_in_db, error::OptionApiError,
+    database::user::project::{
+        select_maybe_project_from_db, update_project_in_db,
+    },
+    error::OptionApiError,
 };
 
 /// Modify the project based on the given [ProjectModifyData].
@@ -22,10 +30,24 @@ use crate::{
 /// Only admins can call this endpoint, otherwise an [OptionApiError::AuthorizationError] is returned.
 /// If the ID given via URL parameters does not match the ID in the request data,
 /// an [OptionApiError::ValidationError] is returned.
+#[utoipa::path(
+    patch,
+    path = "/{project_id}/",
+    tag = "user",
+    params(ProjectIdParam),
+    request_body = ProjectModifyData,
+    responses(
+        (status = 200, description = "Modified project", body = Project),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "project_modify")]
 pub async fn project_modify(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<ProjectModifyData>,
     params: Path<ProjectIdParam>,
 ) -> Result<HttpResponse, OptionApiError> {
@@ -40,7 +62,21 @@ pub async fn project_modify(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let project_before = select_maybe_project_from_db(
+        &mut transaction,
+        params.project_id as u64,
+    )
+    .await?;
     let project = update_project_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::Project,
+            params.project_id,
+            &project_before,
+            &project,
+        )
+        .await?;
     transaction
         .commit()
         .await
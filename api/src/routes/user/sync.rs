@@ -2,11 +2,15 @@
 
 use actix_web::{
     HttpResponse,
-    web::{Data, ReqData},
+    web::{Data, Query, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{User, UserClass, UserSync};
-use sqlx::MySqlPool;
+use avina_wire::{
+    dry_run::{Change, ChangeAction, ChangeObject, DryRunParams},
+    error::ErrorResponse,
+    user::{User, UserClass, UserSync},
+};
+use sqlx::{MySql, MySqlPool, Transaction};
 
 use crate::{
     authorization::require_admin_user,
@@ -16,6 +20,7 @@ use crate::{
         },
         user::{select_all_users_from_db, update_user_role_in_db},
     },
+    dry_run::{DryRunnable, finish},
     error::NormalApiError,
     ldap::AvinaLdap,
     startup::AvinaLdapConfig,
@@ -29,39 +34,72 @@ use crate::{
 ///
 /// Only admins can call this endpoint, otherwise a [NormalApiError::AuthorizationError]
 /// is returned. On success an HTTP 200 OK is returned with the number of projects and
-/// users updated as data.
+/// users updated as data. With the `dry_run` parameter, nothing is updated and the
+/// updates that would be made are returned as well.
+#[utoipa::path(
+    get,
+    path = "/sync",
+    tag = "user",
+    params(DryRunParams),
+    responses(
+        (status = 200, description = "Summary of the sync, with the changes in dry runs", body = DryRunnable<UserSync>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_sync")]
 pub async fn user_sync(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
     avina_ldap_config: Data<AvinaLdapConfig>,
+    params: Query<DryRunParams>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
     let mut transaction = db_pool
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let mut changes = Vec::new();
+    let user_sync =
+        sync_users(&mut transaction, &avina_ldap_config, &mut changes).await?;
+    Ok(finish(transaction, &params, user_sync, changes).await?)
+}
 
-    let ldap_data = AvinaLdap::new(&avina_ldap_config).await?;
+/// Update the roles of users and user classes of projects from avina-ldap.
+///
+/// Every update is recorded in the given changes.
+#[tracing::instrument(name = "sync_users", skip(transaction))]
+pub async fn sync_users(
+    transaction: &mut Transaction<'_, MySql>,
+    avina_ldap_config: &AvinaLdapConfig,
+    changes: &mut Vec<Change>,
+) -> Result<UserSync, NormalApiError> {
+    let ldap_data = AvinaLdap::new(avina_ldap_config).await?;
 
-    let users = select_all_users_from_db(&mut transaction).await?;
+    let users = select_all_users_from_db(transaction).await?;
     let mut updated_user_count = 0;
     for user in users {
         let Some(role) = ldap_data.get_role_no_default(&user.name) else {
             continue;
         };
         if role != user.role {
-            update_user_role_in_db(&mut transaction, user.id, role)
+            update_user_role_in_db(transaction, user.id, role)
                 .await
                 .context(format!(
                     "Could not update role of user {}.",
                     user.name
                 ))?;
+            changes.push(Change {
+                action: ChangeAction::Update,
+                object: ChangeObject::User,
+                name: user.name,
+                reason: format!("role changed from {} to {}", user.role, role),
+            });
             updated_user_count += 1;
         }
     }
 
-    let projects = select_all_projects_from_db(&mut transaction).await?;
+    let projects = select_all_projects_from_db(transaction).await?;
     let mut updated_project_count = 0;
     for project in projects {
         let Some(user_class) =
@@ -71,7 +109,7 @@ pub async fn user_sync(
         };
         if project.user_class == UserClass::NA && user_class != UserClass::NA {
             update_project_user_class_in_db(
-                &mut transaction,
+                transaction,
                 project.id,
                 user_class,
             )
@@ -80,18 +118,21 @@ pub async fn user_sync(
                 "Could not update user class of project {}.",
                 project.name
             ))?;
+            changes.push(Change {
+                action: ChangeAction::Update,
+                object: ChangeObject::Project,
+                name: project.name,
+                reason: format!(
+                    "user class changed from {} to {}",
+                    project.user_class, user_class
+                ),
+            });
             updated_project_count += 1;
         }
     }
 
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .json(UserSync {
-            updated_project_count,
-            updated_user_count,
-        }))
+    Ok(UserSync {
+        updated_project_count,
+        updated_user_count,
+    })
 }
@@ -5,10 +5,15 @@ use actix_web::{
     web::{Data, Json, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{User, UserCreateData};
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    user::{User, UserCreateData},
+};
 use sqlx::MySqlPool;
 
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
     database::user::{
         project::select_project_from_db,
@@ -24,10 +29,23 @@ use crate::{
 /// Only admins can call this endpoint, otherwise an [OptionApiError::AuthorizationError] is returned.
 /// If the [UserCreateData] cannot be converted into a [NewUser] an [OptionApiError::ValidationError]
 /// is returned.
+#[utoipa::path(
+    post,
+    path = "/",
+    tag = "user",
+    request_body = UserCreateData,
+    responses(
+        (status = 201, description = "Created user", body = User),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_create")]
 pub async fn user_create(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<UserCreateData>,
 ) -> Result<HttpResponse, OptionApiError> {
     require_admin_user(&user)?;
@@ -42,10 +60,6 @@ pub async fn user_create(
         select_project_from_db(&mut transaction, new_user.project_id as u64)
             .await?;
     let id = insert_user_into_db(&mut transaction, &new_user).await?;
-    transaction
-        .commit()
-        .await
-        .context("Failed to commit transaction")?;
     let user_created = User {
         id: id as u32,
         name: new_user.name.clone(),
@@ -56,6 +70,18 @@ pub async fn user_create(
         is_staff: new_user.is_staff,
         is_active: new_user.is_active,
     };
+    audit
+        .created(
+            &mut transaction,
+            ObjectType::User,
+            user_created.id,
+            &user_created,
+        )
+        .await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit transaction")?;
     Ok(HttpResponse::Created()
         .content_type("application/json")
         .json(user_created))
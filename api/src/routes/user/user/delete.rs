@@ -5,13 +5,15 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::User;
+use avina_wire::{audit::ObjectType, error::ErrorResponse, user::User};
 use sqlx::MySqlPool;
 
 use super::UserIdParam;
 use crate::{
+    audit::Audit,
     authorization::require_admin_user,
-    database::user::user::delete_user_from_db, error::NormalApiError,
+    database::user::user::{delete_user_from_db, select_maybe_user_from_db},
+    error::NormalApiError,
 };
 
 /// Delete the user with the given user ID.
@@ -20,10 +22,22 @@ use crate::{
 /// returned.
 ///
 /// Only admins can use this endpoint, otherwise an [NormalApiError::AuthorizationError] error is returned.
+#[utoipa::path(
+    delete,
+    path = "/{user_id}/",
+    tag = "user",
+    params(UserIdParam),
+    responses(
+        (status = 204, description = "User deleted"),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_delete")]
 pub async fn user_delete(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     params: Path<UserIdParam>,
 ) -> Result<HttpResponse, NormalApiError> {
     require_admin_user(&user)?;
@@ -31,7 +45,18 @@ pub async fn user_delete(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let user_before =
+        select_maybe_user_from_db(&mut transaction, params.user_id as u64)
+            .await?;
     delete_user_from_db(&mut transaction, params.user_id as u64).await?;
+    audit
+        .deleted(
+            &mut transaction,
+            ObjectType::User,
+            params.user_id,
+            &user_before,
+        )
+        .await?;
     transaction
         .commit()
         .await
@@ -5,7 +5,10 @@ use actix_web::{
     web::{Data, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{Project, User};
+use avina_wire::{
+    error::ErrorResponse,
+    user::{Project, User, UserDetailed},
+};
 use sqlx::MySqlPool;
 
 use super::UserIdParam;
@@ -23,6 +26,18 @@ use crate::{
 /// Otherwise an [OptionApiError::NotFoundError] error is returned. We use this instead of some
 /// authorization error to ensure this endpoint doesn't leak information about existing but
 /// invisible user IDs.
+#[utoipa::path(
+    get,
+    path = "/{user_id}",
+    tag = "user",
+    params(UserIdParam),
+    responses(
+        (status = 200, description = "Requested user", body = UserDetailed),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_get")]
 pub async fn user_get(
     user: ReqData<User>,
@@ -1,22 +1,23 @@
 //! Implementation of the user-list endpoint.
 
 use actix_web::{
-    HttpResponse,
+    HttpRequest, HttpResponse,
     web::{Data, Query, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{Project, User, UserListParams};
+use avina_wire::{
+    error::ErrorResponse,
+    user::{Project, User, UserListParams},
+};
 use sqlx::MySqlPool;
 
 use crate::{
     authorization::{
         require_admin_user, require_master_user_or_return_not_found,
     },
-    database::user::user::{
-        select_all_users_from_db, select_users_by_id_from_db,
-        select_users_by_project_from_db,
-    },
+    database::user::user::{UserFilter, select_user_list_from_db},
     error::OptionApiError,
+    pagination::{ListQuery, Paginated, paginate},
 };
 
 /// Get a list of users.
@@ -33,32 +34,56 @@ use crate::{
 ///     of an authorization error to prevent leaking existing but invisible project IDs to users.
 ///
 /// Note, that given both filters, `all` takes precedence.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "user",
+    params(UserListParams),
+    responses(
+        (status = 200, description = "List of users", body = Paginated<User>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_list")]
 pub async fn user_list(
     user: ReqData<User>,
     project: ReqData<Project>,
     db_pool: Data<MySqlPool>,
     params: Query<UserListParams>,
+    req: HttpRequest,
 ) -> Result<HttpResponse, OptionApiError> {
+    let list_query = ListQuery::new::<User>(
+        params.ordering.as_deref(),
+        params.limit,
+        params.offset,
+    )?;
     let mut transaction = db_pool
         .begin()
         .await
         .context("Failed to begin transaction")?;
-    let users = if params.all.unwrap_or(false) {
+    let filter = if params.all.unwrap_or(false) {
         require_admin_user(&user)?;
-        select_all_users_from_db(&mut transaction).await?
+        UserFilter::default()
     } else if let Some(project_id) = params.project {
         require_master_user_or_return_not_found(&user, project_id)?;
-        select_users_by_project_from_db(&mut transaction, project_id as u64)
-            .await?
+        UserFilter {
+            project: Some(project_id as u64),
+            ..Default::default()
+        }
     } else {
-        select_users_by_id_from_db(&mut transaction, user.id as u64).await?
+        UserFilter {
+            id: Some(user.id as u64),
+            ..Default::default()
+        }
     };
+    let (users, count) =
+        select_user_list_from_db(&mut transaction, &filter, &list_query)
+            .await?;
     transaction
         .commit()
         .await
         .context("Failed to commit transaction")?;
-    Ok(HttpResponse::Ok()
-        .content_type("application/json")
-        .json(users))
+    Ok(paginate(&req, users, count, &list_query))
 }
@@ -5,6 +5,7 @@ use actix_web::{
     web::{delete, get, patch, post, scope},
 };
 use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
 
 pub mod create;
 use create::user_create;
@@ -33,10 +34,22 @@ pub fn users_scope() -> Scope {
         .route("/{user_id}/", delete().to(user_delete))
 }
 
+/// OpenAPI documentation of the user endpoints routed by [users_scope].
+#[derive(OpenApi)]
+#[openapi(paths(
+    create::user_create,
+    list::user_list,
+    get::user_get,
+    modify::user_modify,
+    delete::user_delete
+))]
+pub struct UsersApiDoc;
+
 /// Wrapper type for the user ID parameter to user endpoints.
 ///
 /// As this is handed to endpoints as [actix_web::web::Path], it should to have a distinguishable type.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Path)]
 pub struct UserIdParam {
     /// The wrapped user ID.
     user_id: u32,
@@ -5,12 +5,18 @@ use actix_web::{
     web::{Data, Json, Path, ReqData},
 };
 use anyhow::Context;
-use avina_wire::user::{User, UserModifyData};
+use avina_wire::{
+    audit::ObjectType,
+    error::ErrorResponse,
+    user::{User, UserModifyData},
+};
 use sqlx::MySqlPool;
 
 use super::UserIdParam;
 use crate::{
-    authorization::require_admin_user, database::user::user::update_user_in_db,
+    audit::Audit,
+    authorization::require_admin_user,
+    database::user::user::{select_maybe_user_from_db, update_user_in_db},
     error::OptionApiError,
 };
 
@@ -22,10 +28,24 @@ use crate::{
 /// Only admins can call this endpoint, otherwise an [OptionApiError::AuthorizationError] is returned.
 /// If the ID given via URL parameters does not match the ID in the request data,
 /// an [OptionApiError::ValidationError] is returned.
+#[utoipa::path(
+    patch,
+    path = "/{user_id}/",
+    tag = "user",
+    params(UserIdParam),
+    request_body = UserModifyData,
+    responses(
+        (status = 200, description = "Modified user", body = User),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Insufficient privileges", body = ErrorResponse),
+        (status = 404, description = "Object not found", body = ErrorResponse),
+    )
+)]
 #[tracing::instrument(name = "user_modify")]
 pub async fn user_modify(
     user: ReqData<User>,
     db_pool: Data<MySqlPool>,
+    audit: Audit,
     data: Json<UserModifyData>,
     params: Path<UserIdParam>,
 ) -> Result<HttpResponse, OptionApiError> {
@@ -40,7 +60,19 @@ pub async fn user_modify(
         .begin()
         .await
         .context("Failed to begin transaction")?;
+    let user_before =
+        select_maybe_user_from_db(&mut transaction, params.user_id as u64)
+            .await?;
     let user = update_user_in_db(&mut transaction, &data).await?;
+    audit
+        .modified(
+            &mut transaction,
+            ObjectType::User,
+            params.user_id,
+            &user_before,
+            &user,
+        )
+        .await?;
     transaction
         .commit()
         .await
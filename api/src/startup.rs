@@ -10,21 +10,30 @@ use actix_web::{
 };
 use anyhow::Context;
 use avina_wire::user::UserClass;
+use secrecy::SecretString;
 use sqlx::{MySqlPool, mysql::MySqlPoolOptions};
 use tracing_actix_web::TracingLogger;
 
 use crate::{
-    authentication::{extract_user_and_project, require_valid_token},
-    configuration::{DatabaseSettings, Settings},
+    authentication::{
+        TokenCache, extract_user_and_project, require_valid_token,
+    },
+    configuration::{
+        ApplicationSettings, CorsSettings, DatabaseSettings, JobsSettings,
+        Settings,
+    },
     database::user::{
         project::{NewProject, insert_project_into_db},
         user::{NewUser, insert_user_into_db},
     },
     error::{MinimalApiError, not_found},
+    jobs::{JobRunner, start_scheduler},
+    metrics::track_requests,
     openstack::OpenStack,
     routes::{
-        accounting_scope, budgeting_scope, health_check, hello_scope,
-        pricing_scope, quota::flavor_quota::check::QuotaCache, quota_scope,
+        accounting_scope, audit_scope, budgeting_scope, health_check,
+        hello_scope, jobs_scope, metrics, openapi, pricing_scope,
+        quota::flavor_quota::check::QuotaCache, quota_scope, ready,
         resources_scope, user_scope,
     },
 };
@@ -61,19 +70,13 @@ impl Application {
         }
 
         let openstack = OpenStack::new(configuration.openstack).await?;
-        let avina_ldap_config = AvinaLdapConfig::new(
-            configuration.application.avina_ldap_url,
-            configuration.application.avina_ldap_token,
-            configuration.application.avina_ldap_default,
-        );
 
         let server = run(
             listener,
             connection_pool,
-            configuration.application.base_url,
             openstack,
-            configuration.application.cloud_usage_url,
-            avina_ldap_config,
+            configuration.application,
+            &configuration.jobs,
         )
         .await?;
 
@@ -157,6 +160,11 @@ pub struct ApplicationBaseUrl(pub String);
 #[derive(Debug)]
 pub struct CloudUsageUrl(pub Option<String>);
 
+/// Wrapper type for the token protecting the metrics endpoint.
+///
+/// As this is handed to endpoints as [Data], it needs to have a distinguishable type.
+pub struct MetricsToken(pub Option<SecretString>);
+
 /// Configuration abstraction for avina-ldap access.
 #[derive(Debug)]
 pub enum AvinaLdapConfig {
@@ -191,41 +199,58 @@ impl AvinaLdapConfig {
 ///
 /// This function packs all relevant data and functionality together in the resulting server. This
 /// includes wrapping all state objects in [Data], setting up CORS (Cross-Origin Resource Sharing),
-/// handing in the logger, and registering all the implemented API endpoints. It returns the
-/// asynchronously running server.
+/// handing in the logger, and registering all the implemented API endpoints. It also starts the
+/// scheduler for the configured jobs. It returns the asynchronously running server.
 async fn run(
     listener: TcpListener,
     db_pool: MySqlPool,
-    base_url: String,
     openstack: OpenStack,
-    cloud_usage_url: Option<String>,
-    avina_ldap_data: AvinaLdapConfig,
+    settings: ApplicationSettings,
+    jobs_settings: &JobsSettings,
 ) -> Result<Server, anyhow::Error> {
     let db_pool = Data::new(db_pool);
-    let base_url = Data::new(ApplicationBaseUrl(base_url));
+    let base_url = Data::new(ApplicationBaseUrl(settings.base_url));
     let openstack = Data::new(openstack);
-    let cloud_usage_url = Data::new(CloudUsageUrl(cloud_usage_url));
+    let cloud_usage_url = Data::new(CloudUsageUrl(settings.cloud_usage_url));
     let quota_cache = Data::new(Mutex::new(QuotaCache::new()));
-    let avina_ldap_data = Data::new(avina_ldap_data);
+    let avina_ldap_data = Data::new(AvinaLdapConfig::new(
+        settings.avina_ldap_url,
+        settings.avina_ldap_token,
+        settings.avina_ldap_default,
+    ));
+    let token_cache =
+        Data::new(Mutex::new(TokenCache::new(&settings.token_cache)));
+    let metrics_token = Data::new(MetricsToken(settings.metrics_token));
+    let job_runner = Data::new(JobRunner::new(
+        db_pool.clone(),
+        openstack.clone(),
+        avina_ldap_data.clone(),
+    ));
+    start_scheduler(job_runner.clone(), jobs_settings)?;
+    let cors_settings = settings.cors;
+    if cors_settings.permissive {
+        tracing::warn!(
+            "CORS is permissive, this is only meant for development"
+        );
+    }
     let server = HttpServer::new(move || {
-        // TODO: this should be configurable
-        let cors = Cors::default()
-            .allowed_origin("http://localhost:8080")
-            .allowed_origin("https://tcc.cloud.mwn.de:1339")
-            .allowed_origin("https://cc.lrz.de:1339")
-            .allow_any_header()
-            .allow_any_method()
-            .expose_any_header();
         App::new()
-            .wrap(cors)
+            .wrap(build_cors(&cors_settings))
             .wrap(TracingLogger::default())
+            .wrap(from_fn(track_requests))
             .app_data(db_pool.clone())
             .app_data(base_url.clone())
             .app_data(openstack.clone())
             .app_data(cloud_usage_url.clone())
             .app_data(quota_cache.clone())
             .app_data(avina_ldap_data.clone())
+            .app_data(token_cache.clone())
+            .app_data(metrics_token.clone())
+            .app_data(job_runner.clone())
             .route("/health_check", web::get().to(health_check))
+            .route("/ready", web::get().to(ready))
+            .route("/metrics", web::get().to(metrics))
+            .route("/api/openapi.json", web::get().to(openapi))
             .service(
                 web::scope("/api")
                     .wrap(from_fn(extract_user_and_project))
@@ -237,7 +262,9 @@ async fn run(
                     .service(resources_scope())
                     .service(pricing_scope())
                     .service(budgeting_scope())
-                    .service(quota_scope()),
+                    .service(quota_scope())
+                    .service(jobs_scope())
+                    .service(audit_scope()),
             )
             .default_service(web::route().to(not_found))
     })
@@ -247,6 +274,32 @@ async fn run(
     Ok(server)
 }
 
+/// Build the CORS (Cross-Origin Resource Sharing) middleware from the settings.
+///
+/// The settings are expected to be validated already, see [CorsSettings::validate].
+fn build_cors(settings: &CorsSettings) -> Cors {
+    if settings.permissive {
+        return Cors::permissive();
+    }
+    let mut cors = Cors::default().expose_any_header();
+    for origin in &settings.allowed_origins {
+        cors = cors.allowed_origin(origin);
+    }
+    cors = match &settings.allowed_methods {
+        Some(methods) => {
+            cors.allowed_methods(methods.iter().map(|m| m.as_str()))
+        }
+        None => cors.allow_any_method(),
+    };
+    cors = match &settings.allowed_headers {
+        Some(headers) => {
+            cors.allowed_headers(headers.iter().map(|h| h.as_str()))
+        }
+        None => cors.allow_any_header(),
+    };
+    cors.max_age(settings.max_age)
+}
+
 /// Setup a connection pool with the configured database.
 pub fn get_connection_pool(configuration: &DatabaseSettings) -> MySqlPool {
     MySqlPoolOptions::new().connect_lazy_with(configuration.with_db())
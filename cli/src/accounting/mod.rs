@@ -1,9 +1,13 @@
+mod floating_ip_state;
 mod server_consumption;
 mod server_cost;
 mod server_state;
+mod volume_state;
 
+pub(crate) use floating_ip_state::FloatingIpStateCommand;
 pub(crate) use server_consumption::{
     ServerConsumptionFilter, server_consumption,
 };
 pub(crate) use server_cost::{ServerCostFilter, server_cost};
 pub(crate) use server_state::ServerStateCommand;
+pub(crate) use volume_state::VolumeStateCommand;
@@ -1,10 +1,13 @@
 use std::error::Error;
 
+use avina_wire::accounting::ServerCostInterval;
 use chrono::{DateTime, FixedOffset};
 use clap::Args;
 use uuid::Uuid;
 
-use crate::common::{Format, print_json, print_single_object};
+use crate::common::{
+    Format, print_json, print_object_list, print_single_object,
+};
 #[cfg(not(feature = "user"))]
 use crate::common::{find_id as project_find_id, find_id as user_find_id};
 #[cfg(feature = "user")]
@@ -52,7 +55,12 @@ pub(crate) async fn server_cost(
     end: Option<DateTime<FixedOffset>>,
     filter: ServerCostFilter,
     detail: bool,
+    interval: Option<ServerCostInterval>,
 ) -> Result<(), Box<dyn Error>> {
+    if let Some(interval) = interval {
+        return server_cost_series(api, format, begin, end, filter, interval)
+            .await;
+    }
     let mut request = api.server_cost.get();
     if let Some(begin) = begin {
         request.begin(begin);
@@ -91,3 +99,35 @@ pub(crate) async fn server_cost(
         }
     }
 }
+
+async fn server_cost_series(
+    api: avina::Api,
+    format: Format,
+    begin: Option<DateTime<FixedOffset>>,
+    end: Option<DateTime<FixedOffset>>,
+    filter: ServerCostFilter,
+    interval: ServerCostInterval,
+) -> Result<(), Box<dyn Error>> {
+    let mut request = api.server_cost.series();
+    request.interval(interval);
+    if let Some(begin) = begin {
+        request.begin(begin);
+    }
+    if let Some(end) = end {
+        request.end(end);
+    }
+    let series = if let Some(server) = filter.server {
+        request.server(server).await?
+    } else if let Some(user) = filter.user {
+        let user_id = user_find_id(&api, &user).await?;
+        request.user(user_id).await?
+    } else if let Some(project) = filter.project {
+        let project_id = project_find_id(&api, &project).await?;
+        request.project(project_id).await?
+    } else if filter.all {
+        request.all().await?
+    } else {
+        request.mine().await?
+    };
+    print_object_list(series, format)
+}
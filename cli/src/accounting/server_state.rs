@@ -1,4 +1,10 @@
-use std::error::Error;
+use std::{
+    error::Error,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use avina_wire::accounting::ServerStateLoadData;
 
 use chrono::{DateTime, FixedOffset};
 use clap::{Args, Subcommand};
@@ -7,8 +13,8 @@ use uuid::Uuid;
 #[cfg(not(feature = "resources"))]
 use crate::common::find_id as flavor_find_id;
 use crate::common::{
-    Execute, Format, ask_for_confirmation, print_object_list,
-    print_single_object,
+    Execute, Format, PAGE_SIZE, ask_for_confirmation, print_dry_run,
+    print_object_list, print_single_object,
 };
 #[cfg(not(feature = "user"))]
 use crate::common::{find_id as user_find_id, find_id as project_find_id};
@@ -54,6 +60,12 @@ pub(crate) enum ServerStateCommand {
     List {
         #[clap(flatten)]
         filter: ServerStateListFilter,
+
+        #[clap(
+            long,
+            help = "Comma-separated fields to order by, prefix with - for descending order"
+        )]
+        ordering: Option<String>,
     },
 
     #[clap(visible_alias = "show", about = "Show server state with given ID")]
@@ -145,6 +157,27 @@ pub(crate) enum ServerStateCommand {
             help = "Suppress output if nothing is imported"
         )]
         quiet: bool,
+
+        #[clap(
+            long,
+            action,
+            help = "Only show the changes instead of applying them"
+        )]
+        dry_run: bool,
+    },
+
+    #[clap(about = "Check server states for anomalies")]
+    Check,
+
+    #[clap(about = "Repair unambiguous anomalies of server states")]
+    Repair,
+
+    #[clap(about = "Load server states in bulk from a JSON or CSV file")]
+    Load {
+        #[clap(
+            help = "JSON file with an array of server states, or CSV file (with .csv extension) with a header line"
+        )]
+        file: PathBuf,
     },
 }
 pub(crate) use ServerStateCommand::*;
@@ -156,7 +189,9 @@ impl Execute for ServerStateCommand {
         format: Format,
     ) -> Result<(), Box<dyn Error>> {
         match self {
-            List { filter } => list(api, format, filter).await,
+            List { filter, ordering } => {
+                list(api, format, filter, ordering.as_deref()).await
+            }
             Get { id } => get(api, format, id).await,
             Create {
                 begin,
@@ -205,7 +240,12 @@ impl Execute for ServerStateCommand {
                 .await
             }
             Delete { id } => delete(api, id).await,
-            Import { quiet } => import(api, format, *quiet).await,
+            Import { quiet, dry_run } => {
+                import(api, format, *quiet, *dry_run).await
+            }
+            Check => check(api, format).await,
+            Repair => repair(api, format).await,
+            Load { file } => load(api, format, file).await,
         }
     }
 }
@@ -214,6 +254,7 @@ async fn list(
     api: avina::Api,
     format: Format,
     filter: &ServerStateListFilter,
+    ordering: Option<&str>,
 ) -> Result<(), Box<dyn Error>> {
     let mut request = api.server_state.list();
     if let Some(server) = &filter.server {
@@ -227,7 +268,10 @@ async fn list(
     } else if filter.all {
         request.all();
     }
-    print_object_list(request.send().await?, format)
+    if let Some(ordering) = ordering {
+        request.ordering(ordering);
+    }
+    print_object_list(request.limit(PAGE_SIZE).all_pages().await?, format)
 }
 
 async fn get(
@@ -317,10 +361,45 @@ async fn import(
     api: avina::Api,
     format: Format,
     quiet: bool,
+    dry_run: bool,
 ) -> Result<(), Box<dyn Error>> {
+    if dry_run {
+        let result = api.server_state.import_dry_run().await?;
+        if !quiet || !result.changes.is_empty() {
+            return print_dry_run(result, format);
+        }
+        return Ok(());
+    }
     let result = api.server_state.import().await?;
     if !quiet || result.new_state_count > 0 || result.end_state_count > 0 {
         return print_single_object(result, format);
     }
     Ok(())
 }
+
+async fn check(api: avina::Api, format: Format) -> Result<(), Box<dyn Error>> {
+    print_object_list(api.server_state.check().await?, format)
+}
+
+async fn repair(api: avina::Api, format: Format) -> Result<(), Box<dyn Error>> {
+    ask_for_confirmation()?;
+    print_object_list(api.server_state.repair().await?, format)
+}
+
+async fn load(
+    api: avina::Api,
+    format: Format,
+    file: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let content = read_to_string(file)?;
+    let is_csv = file
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+    let result = if is_csv {
+        api.server_state.load_csv(content).await?
+    } else {
+        let data: Vec<ServerStateLoadData> = serde_json::from_str(&content)?;
+        api.server_state.load(&data).await?
+    };
+    print_single_object(result, format)
+}
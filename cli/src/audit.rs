@@ -0,0 +1,95 @@
+use std::error::Error;
+
+use avina_wire::audit::ObjectType;
+use chrono::{DateTime, FixedOffset};
+use clap::Subcommand;
+
+#[cfg(not(feature = "user"))]
+use crate::common::find_id as user_find_id;
+use crate::common::{Execute, Format, print_object_list};
+#[cfg(feature = "user")]
+use crate::user::user::find_id as user_find_id;
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum AuditCommand {
+    #[clap(about = "List recent audit log entries")]
+    List {
+        #[clap(
+            short,
+            long,
+            help = "Display only entries targeting objects of given type"
+        )]
+        object_type: Option<ObjectType>,
+
+        #[clap(
+            short,
+            long,
+            help = "Display only entries of given user, acting or impersonated (name, ID, or OpenStack UUIDv4)"
+        )]
+        user: Option<String>,
+
+        #[clap(
+            short,
+            long,
+            help = "Display only entries at or after given time"
+        )]
+        begin: Option<DateTime<FixedOffset>>,
+
+        #[clap(short, long, help = "Display only entries before given time")]
+        end: Option<DateTime<FixedOffset>>,
+
+        #[clap(short, long, help = "Maximum number of entries to display")]
+        limit: Option<u32>,
+    },
+}
+pub(crate) use AuditCommand::*;
+
+impl Execute for AuditCommand {
+    async fn execute(
+        &self,
+        api: avina::Api,
+        format: Format,
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            List {
+                object_type,
+                user,
+                begin,
+                end,
+                limit,
+            } => {
+                list(api, format, *object_type, user, *begin, *end, *limit)
+                    .await
+            }
+        }
+    }
+}
+
+async fn list(
+    api: avina::Api,
+    format: Format,
+    object_type: Option<ObjectType>,
+    user: &Option<String>,
+    begin: Option<DateTime<FixedOffset>>,
+    end: Option<DateTime<FixedOffset>>,
+    limit: Option<u32>,
+) -> Result<(), Box<dyn Error>> {
+    let mut request = api.audit.list();
+    if let Some(object_type) = object_type {
+        request.object_type(object_type);
+    }
+    if let Some(user) = user {
+        let user_id = user_find_id(&api, user).await?;
+        request.user(user_id);
+    }
+    if let Some(begin) = begin {
+        request.begin(begin);
+    }
+    if let Some(end) = end {
+        request.end(end);
+    }
+    if let Some(limit) = limit {
+        request.limit(limit);
+    }
+    print_object_list(request.send().await?, format)
+}
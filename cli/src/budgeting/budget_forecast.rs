@@ -0,0 +1,45 @@
+use std::error::Error;
+
+use clap::Args;
+
+use crate::common::{Format, print_single_object};
+#[cfg(not(feature = "user"))]
+use crate::common::{find_id as user_find_id, find_id as project_find_id};
+#[cfg(feature = "user")]
+use crate::user::{
+    project::find_id as project_find_id, user::find_id as user_find_id,
+};
+
+#[derive(Args, Debug)]
+#[group(multiple = false)]
+pub(crate) struct BudgetForecastFilter {
+    #[clap(
+        short,
+        long,
+        help = "Forecast for project with given name, ID, or OpenStack ID"
+    )]
+    project: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        help = "Forecast for user with given name, ID, or OpenStack ID"
+    )]
+    user: Option<String>,
+}
+
+pub(crate) async fn budget_forecast(
+    api: avina::Api,
+    format: Format,
+    filter: BudgetForecastFilter,
+) -> Result<(), Box<dyn Error>> {
+    let mut request = api.budget_forecast.get();
+    if let Some(project) = &filter.project {
+        let project_id = project_find_id(&api, project).await?;
+        request.project(project_id);
+    } else if let Some(user) = &filter.user {
+        let user_id = user_find_id(&api, user).await?;
+        request.user(user_id);
+    }
+    print_single_object(&request.send().await?, format)
+}
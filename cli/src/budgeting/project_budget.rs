@@ -4,7 +4,7 @@ use chrono::{DateTime, FixedOffset};
 use clap::{Args, Subcommand};
 
 use crate::common::{
-    Execute, Format, ask_for_confirmation, print_object_list,
+    Execute, Format, PAGE_SIZE, ask_for_confirmation, print_object_list,
     print_single_object,
 };
 #[cfg(not(feature = "user"))]
@@ -67,6 +67,12 @@ pub(crate) enum ProjectBudgetCommand {
     List {
         #[clap(flatten)]
         filter: ProjectBudgetListFilter,
+
+        #[clap(
+            long,
+            help = "Comma-separated fields to order by, prefix with - for descending order"
+        )]
+        ordering: Option<String>,
     },
 
     #[clap(visible_alias = "show", about = "Show project budget with given ID")]
@@ -135,7 +141,9 @@ impl Execute for ProjectBudgetCommand {
         format: Format,
     ) -> Result<(), Box<dyn Error>> {
         match self {
-            List { filter } => list(api, format, filter).await,
+            List { filter, ordering } => {
+                list(api, format, filter, ordering.as_deref()).await
+            }
             Get { id } => get(api, format, id).await,
             Create {
                 project,
@@ -159,6 +167,7 @@ async fn list(
     api: avina::Api,
     format: Format,
     filter: &ProjectBudgetListFilter,
+    ordering: Option<&str>,
 ) -> Result<(), Box<dyn Error>> {
     let mut request = api.project_budget.list();
     if let Some(user) = &filter.user {
@@ -173,7 +182,10 @@ async fn list(
     if let Some(year) = filter.year {
         request.year(year);
     }
-    print_object_list(request.send().await?, format)
+    if let Some(ordering) = ordering {
+        request.ordering(ordering);
+    }
+    print_object_list(request.limit(PAGE_SIZE).all_pages().await?, format)
 }
 
 async fn get(
@@ -5,6 +5,7 @@ use std::{
 };
 
 use anyhow::{Context, anyhow};
+use avina_wire::dry_run::DryRun;
 use chrono::Datelike;
 use clap::{ValueEnum, builder::PossibleValue};
 use serde::Serialize;
@@ -13,6 +14,9 @@ use tabled::{
     settings::Style,
 };
 
+/// Number of objects requested per page by the list commands.
+pub(crate) const PAGE_SIZE: u32 = 1000;
+
 #[derive(Debug, Clone)]
 pub(crate) enum TableFormat {
     Empty,
@@ -107,6 +111,20 @@ where
     Ok(())
 }
 
+#[allow(dead_code)]
+pub(crate) fn print_dry_run<T>(
+    dry_run: DryRun<T>,
+    format: Format,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    T: Serialize,
+{
+    match format {
+        Format::Json => print_json(dry_run),
+        Format::Table(_) => print_object_list(dry_run.changes, format),
+    }
+}
+
 #[allow(dead_code)]
 pub(crate) fn print_hashmap<K, V>(
     hashmap: std::collections::HashMap<K, V>,
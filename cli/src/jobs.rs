@@ -0,0 +1,74 @@
+use std::error::Error;
+
+use avina_wire::jobs::Job;
+use clap::Subcommand;
+
+use crate::common::{Execute, Format, print_object_list, print_single_object};
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum JobRunCommand {
+    #[clap(about = "List recent job runs")]
+    List {
+        #[clap(short, long, help = "Display only runs of given job")]
+        job: Option<Job>,
+
+        #[clap(short, long, help = "Maximum number of runs to display")]
+        limit: Option<u32>,
+    },
+
+    #[clap(visible_alias = "show", about = "Show job run with given ID")]
+    Get { id: u32 },
+
+    #[clap(about = "Run the given job now and wait for it to finish")]
+    Trigger {
+        #[clap(help = "Job to run")]
+        job: Job,
+    },
+}
+pub(crate) use JobRunCommand::*;
+
+impl Execute for JobRunCommand {
+    async fn execute(
+        &self,
+        api: avina::Api,
+        format: Format,
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            List { job, limit } => list(api, format, *job, *limit).await,
+            Get { id } => get(api, format, *id).await,
+            Trigger { job } => trigger(api, format, *job).await,
+        }
+    }
+}
+
+async fn list(
+    api: avina::Api,
+    format: Format,
+    job: Option<Job>,
+    limit: Option<u32>,
+) -> Result<(), Box<dyn Error>> {
+    let mut request = api.job_run.list();
+    if let Some(job) = job {
+        request.job(job);
+    }
+    if let Some(limit) = limit {
+        request.limit(limit);
+    }
+    print_object_list(request.send().await?, format)
+}
+
+async fn get(
+    api: avina::Api,
+    format: Format,
+    id: u32,
+) -> Result<(), Box<dyn Error>> {
+    print_single_object(api.job_run.get(id).await?, format)
+}
+
+async fn trigger(
+    api: avina::Api,
+    format: Format,
+    job: Job,
+) -> Result<(), Box<dyn Error>> {
+    print_single_object(api.job_run.trigger(job).await?, format)
+}
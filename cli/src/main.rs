@@ -10,10 +10,14 @@ mod common;
 
 #[cfg(feature = "accounting")]
 mod accounting;
+#[cfg(feature = "audit")]
+mod audit;
 #[cfg(feature = "budgeting")]
 mod budgeting;
 #[cfg(feature = "hello")]
 mod hello;
+#[cfg(feature = "jobs")]
+mod jobs;
 #[cfg(feature = "pricing")]
 mod pricing;
 #[cfg(feature = "quota")]
@@ -35,12 +39,7 @@ struct CredentialArgs {
         short = 'A',
         long,
         help = "Openstack authentication URL",
-        env = "OS_AUTH_URL",
-        requires = "username",
-        requires = "password",
-        requires = "project_name",
-        requires = "user_domain_name",
-        requires = "project_domain_id"
+        env = "OS_AUTH_URL"
     )]
     auth_url: Option<String>,
 
@@ -109,6 +108,77 @@ struct CredentialArgs {
         requires = "user_domain_name"
     )]
     project_domain_id: Option<String>,
+
+    #[clap(
+        long,
+        help = "Openstack application credential ID",
+        env = "OS_APPLICATION_CREDENTIAL_ID",
+        requires = "auth_url",
+        requires = "application_credential_secret"
+    )]
+    application_credential_id: Option<String>,
+
+    #[clap(
+        long,
+        help = "Openstack application credential secret",
+        env = "OS_APPLICATION_CREDENTIAL_SECRET",
+        requires = "auth_url",
+        requires = "application_credential_id"
+    )]
+    application_credential_secret: Option<String>,
+}
+
+impl CredentialArgs {
+    async fn token(self) -> Result<Token, anyhow::Error> {
+        if let Some(token) = self.token {
+            // TODO: handle error when Token cannot be created due to wrong format
+            return Token::from_str(token.as_str());
+        }
+        let Some(auth_url) = self.auth_url else {
+            return Err(anyhow::anyhow!(
+                "Openstack authentication URL missing"
+            ));
+        };
+        if let (Some(id), Some(secret)) = (
+            self.application_credential_id,
+            self.application_credential_secret,
+        ) {
+            return Token::from_application_credential(
+                auth_url.as_str(),
+                id.as_str(),
+                secret.as_str(),
+            )
+            .await;
+        }
+        let (
+            Some(username),
+            Some(password),
+            Some(project_name),
+            Some(user_domain_name),
+            Some(project_domain_id),
+        ) = (
+            self.username,
+            self.password,
+            self.project_name,
+            self.user_domain_name,
+            self.project_domain_id,
+        )
+        else {
+            return Err(anyhow::anyhow!(
+                "Either Openstack password or application credential \
+                authentication needs to be configured"
+            ));
+        };
+        Token::new(
+            auth_url.as_str(),
+            username.as_str(),
+            password.as_str(),
+            project_name.as_str(),
+            user_domain_name.as_str(),
+            project_domain_id.as_str(),
+        )
+        .await
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -203,6 +273,41 @@ enum Command {
         command: pricing::FlavorPriceCommand,
     },
 
+    #[cfg(feature = "pricing")]
+    #[clap(about = "Billing policy command")]
+    BillingPolicy {
+        #[clap(subcommand)]
+        command: pricing::BillingPolicyCommand,
+    },
+
+    #[cfg(feature = "pricing")]
+    #[clap(about = "Volume price command")]
+    VolumePrice {
+        #[clap(subcommand)]
+        command: pricing::VolumePriceCommand,
+    },
+
+    #[cfg(feature = "pricing")]
+    #[clap(about = "Floating IP price command")]
+    FloatingIpPrice {
+        #[clap(subcommand)]
+        command: pricing::FloatingIpPriceCommand,
+    },
+
+    #[cfg(feature = "pricing")]
+    #[clap(about = "Resource price command")]
+    ResourcePrice {
+        #[clap(subcommand)]
+        command: pricing::ResourcePriceCommand,
+    },
+
+    #[cfg(feature = "pricing")]
+    #[clap(about = "Price override command")]
+    PriceOverride {
+        #[clap(subcommand)]
+        command: pricing::PriceOverrideCommand,
+    },
+
     #[cfg(feature = "quota")]
     #[clap(about = "Flavor quota command")]
     FlavorQuota {
@@ -238,6 +343,20 @@ enum Command {
         command: accounting::ServerStateCommand,
     },
 
+    #[cfg(feature = "accounting")]
+    #[clap(about = "Volume state command")]
+    VolumeState {
+        #[clap(subcommand)]
+        command: accounting::VolumeStateCommand,
+    },
+
+    #[cfg(feature = "accounting")]
+    #[clap(about = "Floating IP state command")]
+    FloatingIpState {
+        #[clap(subcommand)]
+        command: accounting::FloatingIpStateCommand,
+    },
+
     #[cfg(feature = "accounting")]
     #[clap(about = "Server cost command")]
     ServerCost {
@@ -260,6 +379,14 @@ enum Command {
 
         #[clap(long, short, help = "Show detailed cost breakdown")]
         detail: bool,
+
+        #[clap(
+            long,
+            short,
+            conflicts_with = "detail",
+            help = "Show consumption and cost per day, week or month instead of the total"
+        )]
+        interval: Option<avina_wire::accounting::ServerCostInterval>,
     },
 
     #[cfg(feature = "accounting")]
@@ -314,6 +441,13 @@ enum Command {
         end: Option<DateTime<FixedOffset>>,
     },
 
+    #[cfg(feature = "budgeting")]
+    #[clap(about = "Budget forecast command")]
+    BudgetForecast {
+        #[clap(flatten)]
+        filter: budgeting::BudgetForecastFilter,
+    },
+
     #[cfg(feature = "budgeting")]
     #[clap(about = "Budget bulk create command")]
     BudgetBulkCreate {
@@ -325,6 +459,20 @@ enum Command {
         )]
         year: i32,
     },
+
+    #[cfg(feature = "jobs")]
+    #[clap(about = "Job run command")]
+    JobRun {
+        #[clap(subcommand)]
+        command: jobs::JobRunCommand,
+    },
+
+    #[cfg(feature = "audit")]
+    #[clap(about = "Audit log command")]
+    Audit {
+        #[clap(subcommand)]
+        command: audit::AuditCommand,
+    },
 }
 
 #[test]
@@ -336,27 +484,7 @@ fn check_cli_parser() {
 #[tokio::main]
 async fn main() -> ExitCode {
     let cli = Cli::parse();
-    let token = match match cli.credentials.token {
-        // TODO: handle error when Token cannot be created due to wrong format
-        Some(token) => Token::from_str(token.as_str()),
-        None => {
-            let auth_url = cli.credentials.auth_url.unwrap();
-            let username = cli.credentials.username.unwrap();
-            let password = cli.credentials.password.unwrap();
-            let project_name = cli.credentials.project_name.unwrap();
-            let user_domain_name = cli.credentials.user_domain_name.unwrap();
-            let project_domain_id = cli.credentials.project_domain_id.unwrap();
-            Token::new(
-                auth_url.as_str(),
-                username.as_str(),
-                password.as_str(),
-                project_name.as_str(),
-                user_domain_name.as_str(),
-                project_domain_id.as_str(),
-            )
-            .await
-        }
-    } {
+    let token = match cli.credentials.token().await {
         Ok(token) => token,
         Err(error) => {
             eprintln!("{}: {}", "error".bold().red(), error);
@@ -373,8 +501,10 @@ async fn main() -> ExitCode {
     };
     #[cfg(any(
         feature = "accounting",
+        feature = "audit",
         feature = "budgeting",
         feature = "hello",
+        feature = "jobs",
         feature = "pricing",
         feature = "resources",
         feature = "user",
@@ -394,6 +524,26 @@ async fn main() -> ExitCode {
         Command::FlavorPrice { ref command } => {
             command.execute(api, cli.format).await
         }
+        #[cfg(feature = "pricing")]
+        Command::BillingPolicy { ref command } => {
+            command.execute(api, cli.format).await
+        }
+        #[cfg(feature = "pricing")]
+        Command::VolumePrice { ref command } => {
+            command.execute(api, cli.format).await
+        }
+        #[cfg(feature = "pricing")]
+        Command::FloatingIpPrice { ref command } => {
+            command.execute(api, cli.format).await
+        }
+        #[cfg(feature = "pricing")]
+        Command::ResourcePrice { ref command } => {
+            command.execute(api, cli.format).await
+        }
+        #[cfg(feature = "pricing")]
+        Command::PriceOverride { ref command } => {
+            command.execute(api, cli.format).await
+        }
         #[cfg(feature = "quota")]
         Command::FlavorQuota { ref command } => {
             command.execute(api, cli.format).await
@@ -413,14 +563,25 @@ async fn main() -> ExitCode {
             command.execute(api, cli.format).await
         }
         #[cfg(feature = "accounting")]
+        Command::VolumeState { ref command } => {
+            command.execute(api, cli.format).await
+        }
+        #[cfg(feature = "accounting")]
+        Command::FloatingIpState { ref command } => {
+            command.execute(api, cli.format).await
+        }
+        #[cfg(feature = "accounting")]
         Command::ServerCost {
             begin,
             end,
             filter,
             detail,
+            interval,
         } => {
-            accounting::server_cost(api, cli.format, begin, end, filter, detail)
-                .await
+            accounting::server_cost(
+                api, cli.format, begin, end, filter, detail, interval,
+            )
+            .await
         }
         #[cfg(feature = "accounting")]
         Command::ServerConsumption {
@@ -447,9 +608,21 @@ async fn main() -> ExitCode {
             budgeting::budget_over_tree(api, filter, end).await
         }
         #[cfg(feature = "budgeting")]
+        Command::BudgetForecast { filter } => {
+            budgeting::budget_forecast(api, cli.format, filter).await
+        }
+        #[cfg(feature = "budgeting")]
         Command::BudgetBulkCreate { year } => {
             budgeting::budget_bulk_create(api, cli.format, year).await
         }
+        #[cfg(feature = "jobs")]
+        Command::JobRun { ref command } => {
+            command.execute(api, cli.format).await
+        }
+        #[cfg(feature = "audit")]
+        Command::Audit { ref command } => {
+            command.execute(api, cli.format).await
+        }
     } {
         Ok(_) => {}
         Err(error) => {
@@ -1,17 +1,26 @@
 use std::error::Error;
 
-use avina_wire::user::UserClass;
+use avina_wire::{accounting::FloatingIpPool, user::UserClass};
 use chrono::{DateTime, FixedOffset};
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 
+#[cfg(not(feature = "user"))]
+use crate::common::find_id as project_find_id;
+use crate::common::{
+    Execute, Format, PAGE_SIZE, ask_for_confirmation, print_dry_run,
+    print_object_list, print_single_object,
+};
 #[cfg(not(feature = "resources"))]
-use crate::common::find_id as flavor_find_id;
 use crate::common::{
-    Execute, Format, ask_for_confirmation, print_object_list,
-    print_single_object,
+    find_id as flavor_find_id, find_id as flavor_group_find_id,
 };
 #[cfg(feature = "resources")]
-use crate::resources::flavor::find_id as flavor_find_id;
+use crate::resources::{
+    flavor::find_id as flavor_find_id,
+    flavor_group::find_id as flavor_group_find_id,
+};
+#[cfg(feature = "user")]
+use crate::user::project::find_id as project_find_id;
 
 #[derive(Subcommand, Debug)]
 pub(crate) enum FlavorPriceCommand {
@@ -21,6 +30,11 @@ pub(crate) enum FlavorPriceCommand {
         user_class: Option<UserClass>,
         #[clap(short, long, help = "List active flavor prices", action)]
         current: bool,
+        #[clap(
+            long,
+            help = "Comma-separated fields to order by, prefix with - for descending order"
+        )]
+        ordering: Option<String>,
     },
 
     #[clap(visible_alias = "show", about = "Show flavor price with given ID")]
@@ -70,6 +84,53 @@ pub(crate) enum FlavorPriceCommand {
 
     #[clap(about = "Initialize first flavor prices")]
     Initialize,
+
+    #[clap(about = "Revise all current flavor prices from a start time")]
+    Revise {
+        #[clap(help = "Start time of the revised prices")]
+        start_time: DateTime<FixedOffset>,
+
+        #[clap(long, short, help = "Change of the prices in percent")]
+        percentage: Option<f64>,
+
+        #[clap(
+            long,
+            short,
+            value_parser = parse_user_class_factor,
+            help = "Factor for the prices of a user class, e.g. uc1=1.1"
+        )]
+        factor: Vec<(UserClass, f64)>,
+
+        #[clap(
+            long,
+            short = 'g',
+            help = "Name or ID of a flavor group to restrict the revision to"
+        )]
+        flavor_group: Vec<String>,
+
+        #[clap(
+            long,
+            short,
+            action,
+            help = "Only show the changes instead of applying them"
+        )]
+        dry_run: bool,
+    },
+
+    #[clap(about = "Estimate the cost of servers of a flavor for you")]
+    Estimate {
+        #[clap(help = "Name, ID, or OpenStack UUIDv4 of the flavor")]
+        flavor: String,
+
+        #[clap(help = "Duration in seconds, e.g. 86400 for a day")]
+        duration: u64,
+
+        #[clap(long, short, help = "Number of servers, default: 1")]
+        count: Option<u32>,
+
+        #[clap(long, short, help = "Begin of the duration, default: now")]
+        begin: Option<DateTime<FixedOffset>>,
+    },
 }
 pub(crate) use FlavorPriceCommand::*;
 
@@ -83,7 +144,11 @@ impl Execute for FlavorPriceCommand {
             List {
                 user_class,
                 current,
-            } => list(api, format, *user_class, *current).await,
+                ordering,
+            } => {
+                list(api, format, *user_class, *current, ordering.as_deref())
+                    .await
+            }
             Get { id } => get(api, format, id).await,
             Create {
                 flavor,
@@ -114,6 +179,30 @@ impl Execute for FlavorPriceCommand {
             }
             Delete { id } => delete(api, id).await,
             Initialize => initialize(api, format).await,
+            Revise {
+                start_time,
+                percentage,
+                factor,
+                flavor_group,
+                dry_run,
+            } => {
+                revise(
+                    api,
+                    format,
+                    *start_time,
+                    *percentage,
+                    factor,
+                    flavor_group,
+                    *dry_run,
+                )
+                .await
+            }
+            Estimate {
+                flavor,
+                duration,
+                count,
+                begin,
+            } => estimate(api, format, flavor, *duration, *count, *begin).await,
         }
     }
 }
@@ -123,6 +212,7 @@ async fn list(
     format: Format,
     user_class: Option<UserClass>,
     current: bool,
+    ordering: Option<&str>,
 ) -> Result<(), Box<dyn Error>> {
     let mut request = api.flavor_price.list();
     if let Some(user_class) = user_class {
@@ -131,7 +221,10 @@ async fn list(
     if current {
         request.current();
     }
-    print_object_list(request.send().await?, format)
+    if let Some(ordering) = ordering {
+        request.ordering(ordering);
+    }
+    print_object_list(request.limit(PAGE_SIZE).all_pages().await?, format)
 }
 
 async fn get(
@@ -199,3 +292,840 @@ async fn initialize(
     let result = api.flavor_price.initialize().await?;
     print_single_object(result, format)
 }
+
+/// Parse a factor for the prices of a user class given as `CLASS=FACTOR`.
+fn parse_user_class_factor(s: &str) -> Result<(UserClass, f64), String> {
+    let Some((user_class, factor)) = s.split_once('=') else {
+        return Err("Expected CLASS=FACTOR".to_string());
+    };
+    Ok((
+        UserClass::from_str(user_class, true)?,
+        factor.parse().map_err(|e| format!("Invalid factor: {e}"))?,
+    ))
+}
+
+async fn revise(
+    api: avina::Api,
+    format: Format,
+    start_time: DateTime<FixedOffset>,
+    percentage: Option<f64>,
+    factors: &[(UserClass, f64)],
+    flavor_groups: &[String],
+    dry_run: bool,
+) -> Result<(), Box<dyn Error>> {
+    let mut request = api.flavor_price.revise(start_time);
+    if let Some(percentage) = percentage {
+        request.percentage(percentage);
+    }
+    for (user_class, factor) in factors {
+        request.factor(*user_class, *factor);
+    }
+    for flavor_group in flavor_groups {
+        let flavor_group_id = flavor_group_find_id(&api, flavor_group).await?;
+        request.flavor_group(flavor_group_id);
+    }
+    if dry_run {
+        return print_dry_run(request.send_dry_run().await?, format);
+    }
+    print_single_object(request.send().await?, format)
+}
+
+async fn estimate(
+    api: avina::Api,
+    format: Format,
+    flavor: &str,
+    duration: u64,
+    count: Option<u32>,
+    begin: Option<DateTime<FixedOffset>>,
+) -> Result<(), Box<dyn Error>> {
+    let flavor_id = flavor_find_id(&api, flavor).await?;
+    let mut request = api.flavor_price.estimate(flavor_id, duration);
+    if let Some(count) = count {
+        request.count(count);
+    }
+    if let Some(begin) = begin {
+        request.begin(begin);
+    }
+    print_single_object(request.send().await?, format)
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum BillingPolicyCommand {
+    #[clap(about = "List billing policies")]
+    List {
+        #[clap(short, long, help = "List billing policies for server status")]
+        status: Option<String>,
+        #[clap(short, long, help = "List billing policies for user class")]
+        user_class: Option<UserClass>,
+        #[clap(short, long, help = "List valid billing policies", action)]
+        current: bool,
+        #[clap(
+            long,
+            help = "Comma-separated fields to order by, prefix with - for descending order"
+        )]
+        ordering: Option<String>,
+    },
+
+    #[clap(visible_alias = "show", about = "Show billing policy with given ID")]
+    Get { id: u32 },
+
+    #[clap(about = "Create a new billing policy")]
+    Create {
+        #[clap(help = "Server status of the policy, e.g. SHUTOFF")]
+        status: String,
+
+        #[clap(help = "Factor by which the runtime in the status is billed")]
+        factor: f64,
+
+        #[clap(
+            long,
+            short,
+            help = "User class of the policy (1-6), default: all"
+        )]
+        user_class: Option<UserClass>,
+
+        #[clap(long, short, help = "Start time of the policy, default: now")]
+        start_time: Option<DateTime<FixedOffset>>,
+
+        #[clap(long, short, help = "End time of the policy, default: none")]
+        end_time: Option<DateTime<FixedOffset>>,
+    },
+
+    #[clap(about = "Modify a billing policy")]
+    Modify {
+        #[clap(help = "ID of the billing policy")]
+        id: u32,
+
+        #[clap(long, help = "Server status of the policy")]
+        status: Option<String>,
+
+        #[clap(long, short, help = "User class of the policy (1-6)")]
+        user_class: Option<UserClass>,
+
+        #[clap(long, short, help = "Factor by which the runtime is billed")]
+        factor: Option<f64>,
+
+        #[clap(long, short, help = "Start time of the policy")]
+        start_time: Option<DateTime<FixedOffset>>,
+
+        #[clap(long, short, help = "End time of the policy")]
+        end_time: Option<DateTime<FixedOffset>>,
+    },
+
+    #[clap(about = "Delete billing policy with given ID")]
+    Delete { id: u32 },
+}
+
+impl Execute for BillingPolicyCommand {
+    async fn execute(
+        &self,
+        api: avina::Api,
+        format: Format,
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            BillingPolicyCommand::List {
+                status,
+                user_class,
+                current,
+                ordering,
+            } => {
+                billing_policy_list(
+                    api,
+                    format,
+                    status.as_deref(),
+                    *user_class,
+                    *current,
+                    ordering.as_deref(),
+                )
+                .await
+            }
+            BillingPolicyCommand::Get { id } => {
+                print_single_object(api.billing_policy.get(*id).await?, format)
+            }
+            BillingPolicyCommand::Create {
+                status,
+                factor,
+                user_class,
+                start_time,
+                end_time,
+            } => {
+                let mut request = api.billing_policy.create(status, *factor);
+                if let Some(user_class) = user_class {
+                    request.user_class(*user_class);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                if let Some(end_time) = end_time {
+                    request.end_time(*end_time);
+                }
+                print_single_object(request.send().await?, format)
+            }
+            BillingPolicyCommand::Modify {
+                id,
+                status,
+                user_class,
+                factor,
+                start_time,
+                end_time,
+            } => {
+                let mut request = api.billing_policy.modify(*id);
+                if let Some(status) = status {
+                    request.status(status);
+                }
+                if let Some(user_class) = user_class {
+                    request.user_class(*user_class);
+                }
+                if let Some(factor) = factor {
+                    request.factor(*factor);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                if let Some(end_time) = end_time {
+                    request.end_time(*end_time);
+                }
+                print_single_object(request.send().await?, format)
+            }
+            BillingPolicyCommand::Delete { id } => {
+                ask_for_confirmation()?;
+                Ok(api.billing_policy.delete(*id).await?)
+            }
+        }
+    }
+}
+
+async fn billing_policy_list(
+    api: avina::Api,
+    format: Format,
+    status: Option<&str>,
+    user_class: Option<UserClass>,
+    current: bool,
+    ordering: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let mut request = api.billing_policy.list();
+    if let Some(status) = status {
+        request.status(status);
+    }
+    if let Some(user_class) = user_class {
+        request.user_class(user_class);
+    }
+    if current {
+        request.current();
+    }
+    if let Some(ordering) = ordering {
+        request.ordering(ordering);
+    }
+    print_object_list(request.limit(PAGE_SIZE).all_pages().await?, format)
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum VolumePriceCommand {
+    #[clap(about = "List volume prices")]
+    List {
+        #[clap(short, long, help = "List volume prices for user class")]
+        user_class: Option<UserClass>,
+        #[clap(short, long, help = "List active volume prices", action)]
+        current: bool,
+        #[clap(
+            long,
+            help = "Comma-separated fields to order by, prefix with - for descending order"
+        )]
+        ordering: Option<String>,
+    },
+
+    #[clap(visible_alias = "show", about = "Show volume price with given ID")]
+    Get { id: u32 },
+
+    #[clap(about = "Create a new volume price")]
+    Create {
+        #[clap(help = "User class of the price (1-6)")]
+        user_class: UserClass,
+
+        #[clap(
+            long,
+            short,
+            help = "Price per GiB and year of the volumes, default: 0.0"
+        )]
+        price: Option<f64>,
+
+        #[clap(long, short, help = "Start time of the price, default: now")]
+        start_time: Option<DateTime<FixedOffset>>,
+    },
+
+    #[clap(about = "Modify a volume price")]
+    Modify {
+        #[clap(help = "ID of the volume price")]
+        id: u32,
+
+        #[clap(long, short, help = "User class of the price (1-6)")]
+        user_class: Option<UserClass>,
+
+        #[clap(long, short, help = "Price per GiB and year of the volumes")]
+        price: Option<f64>,
+
+        #[clap(long, short, help = "Start time of the volume price")]
+        start_time: Option<DateTime<FixedOffset>>,
+    },
+
+    #[clap(about = "Delete volume price with given ID")]
+    Delete { id: u32 },
+}
+
+impl Execute for VolumePriceCommand {
+    async fn execute(
+        &self,
+        api: avina::Api,
+        format: Format,
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            VolumePriceCommand::List {
+                user_class,
+                current,
+                ordering,
+            } => {
+                let mut request = api.volume_price.list();
+                if let Some(user_class) = user_class {
+                    request.user_class(*user_class);
+                }
+                if *current {
+                    request.current();
+                }
+                if let Some(ordering) = ordering {
+                    request.ordering(ordering);
+                }
+                print_object_list(
+                    request.limit(PAGE_SIZE).all_pages().await?,
+                    format,
+                )
+            }
+            VolumePriceCommand::Get { id } => {
+                print_single_object(api.volume_price.get(*id).await?, format)
+            }
+            VolumePriceCommand::Create {
+                user_class,
+                price,
+                start_time,
+            } => {
+                let mut request = api.volume_price.create(*user_class);
+                if let Some(price) = price {
+                    request.price(*price);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                print_single_object(request.send().await?, format)
+            }
+            VolumePriceCommand::Modify {
+                id,
+                user_class,
+                price,
+                start_time,
+            } => {
+                let mut request = api.volume_price.modify(*id);
+                if let Some(user_class) = user_class {
+                    request.user_class(*user_class);
+                }
+                if let Some(price) = price {
+                    request.unit_price(*price);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                print_single_object(request.send().await?, format)
+            }
+            VolumePriceCommand::Delete { id } => {
+                ask_for_confirmation()?;
+                Ok(api.volume_price.delete(*id).await?)
+            }
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum ResourcePriceCommand {
+    #[clap(about = "List resource prices")]
+    List {
+        #[clap(short, long, help = "List resource prices for user class")]
+        user_class: Option<UserClass>,
+        #[clap(short, long, help = "List active resource prices", action)]
+        current: bool,
+        #[clap(
+            long,
+            help = "Comma-separated fields to order by, prefix with - for descending order"
+        )]
+        ordering: Option<String>,
+    },
+
+    #[clap(visible_alias = "show", about = "Show resource price with given ID")]
+    Get { id: u32 },
+
+    #[clap(about = "Create a new resource price")]
+    Create {
+        #[clap(help = "User class of the price (1-6)")]
+        user_class: UserClass,
+
+        #[clap(long, help = "Price per vCPU and year, default: 0.0")]
+        vcpu_price: Option<f64>,
+
+        #[clap(long, help = "Price per GiB of RAM and year, default: 0.0")]
+        ram_price: Option<f64>,
+
+        #[clap(long, help = "Price per GPU and year, default: 0.0")]
+        gpu_price: Option<f64>,
+
+        #[clap(long, short, help = "Start time of the price, default: now")]
+        start_time: Option<DateTime<FixedOffset>>,
+    },
+
+    #[clap(about = "Modify a resource price")]
+    Modify {
+        #[clap(help = "ID of the resource price")]
+        id: u32,
+
+        #[clap(long, short, help = "User class of the price (1-6)")]
+        user_class: Option<UserClass>,
+
+        #[clap(long, help = "Price per vCPU and year")]
+        vcpu_price: Option<f64>,
+
+        #[clap(long, help = "Price per GiB of RAM and year")]
+        ram_price: Option<f64>,
+
+        #[clap(long, help = "Price per GPU and year")]
+        gpu_price: Option<f64>,
+
+        #[clap(long, short, help = "Start time of the resource price")]
+        start_time: Option<DateTime<FixedOffset>>,
+    },
+
+    #[clap(about = "Delete resource price with given ID")]
+    Delete { id: u32 },
+}
+
+impl Execute for ResourcePriceCommand {
+    async fn execute(
+        &self,
+        api: avina::Api,
+        format: Format,
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            ResourcePriceCommand::List {
+                user_class,
+                current,
+                ordering,
+            } => {
+                let mut request = api.resource_price.list();
+                if let Some(user_class) = user_class {
+                    request.user_class(*user_class);
+                }
+                if *current {
+                    request.current();
+                }
+                if let Some(ordering) = ordering {
+                    request.ordering(ordering);
+                }
+                print_object_list(
+                    request.limit(PAGE_SIZE).all_pages().await?,
+                    format,
+                )
+            }
+            ResourcePriceCommand::Get { id } => {
+                print_single_object(api.resource_price.get(*id).await?, format)
+            }
+            ResourcePriceCommand::Create {
+                user_class,
+                vcpu_price,
+                ram_price,
+                gpu_price,
+                start_time,
+            } => {
+                let mut request = api.resource_price.create(*user_class);
+                if let Some(vcpu_price) = vcpu_price {
+                    request.vcpu_price(*vcpu_price);
+                }
+                if let Some(ram_price) = ram_price {
+                    request.ram_price(*ram_price);
+                }
+                if let Some(gpu_price) = gpu_price {
+                    request.gpu_price(*gpu_price);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                print_single_object(request.send().await?, format)
+            }
+            ResourcePriceCommand::Modify {
+                id,
+                user_class,
+                vcpu_price,
+                ram_price,
+                gpu_price,
+                start_time,
+            } => {
+                let mut request = api.resource_price.modify(*id);
+                if let Some(user_class) = user_class {
+                    request.user_class(*user_class);
+                }
+                if let Some(vcpu_price) = vcpu_price {
+                    request.vcpu_price(*vcpu_price);
+                }
+                if let Some(ram_price) = ram_price {
+                    request.ram_price(*ram_price);
+                }
+                if let Some(gpu_price) = gpu_price {
+                    request.gpu_price(*gpu_price);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                print_single_object(request.send().await?, format)
+            }
+            ResourcePriceCommand::Delete { id } => {
+                ask_for_confirmation()?;
+                Ok(api.resource_price.delete(*id).await?)
+            }
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum FloatingIpPriceCommand {
+    #[clap(about = "List floating IP prices")]
+    List {
+        #[clap(short, long, help = "List floating IP prices for user class")]
+        user_class: Option<UserClass>,
+        #[clap(short, long, help = "List floating IP prices for pool")]
+        pool: Option<FloatingIpPool>,
+        #[clap(short, long, help = "List active floating IP prices", action)]
+        current: bool,
+        #[clap(
+            long,
+            help = "Comma-separated fields to order by, prefix with - for descending order"
+        )]
+        ordering: Option<String>,
+    },
+
+    #[clap(
+        visible_alias = "show",
+        about = "Show floating IP price with given ID"
+    )]
+    Get { id: u32 },
+
+    #[clap(about = "Create a new floating IP price")]
+    Create {
+        #[clap(help = "User class of the price (1-6)")]
+        user_class: UserClass,
+
+        #[clap(help = "Pool of the floating IPs")]
+        pool: FloatingIpPool,
+
+        #[clap(
+            long,
+            short,
+            help = "Price per year of a floating IP, default: 0.0"
+        )]
+        price: Option<f64>,
+
+        #[clap(long, short, help = "Start time of the price, default: now")]
+        start_time: Option<DateTime<FixedOffset>>,
+    },
+
+    #[clap(about = "Modify a floating IP price")]
+    Modify {
+        #[clap(help = "ID of the floating IP price")]
+        id: u32,
+
+        #[clap(long, short, help = "User class of the price (1-6)")]
+        user_class: Option<UserClass>,
+
+        #[clap(long, help = "Pool of the floating IPs")]
+        pool: Option<FloatingIpPool>,
+
+        #[clap(long, short, help = "Price per year of a floating IP")]
+        price: Option<f64>,
+
+        #[clap(long, short, help = "Start time of the floating IP price")]
+        start_time: Option<DateTime<FixedOffset>>,
+    },
+
+    #[clap(about = "Delete floating IP price with given ID")]
+    Delete { id: u32 },
+}
+
+impl Execute for FloatingIpPriceCommand {
+    async fn execute(
+        &self,
+        api: avina::Api,
+        format: Format,
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            FloatingIpPriceCommand::List {
+                user_class,
+                pool,
+                current,
+                ordering,
+            } => {
+                let mut request = api.floating_ip_price.list();
+                if let Some(user_class) = user_class {
+                    request.user_class(*user_class);
+                }
+                if let Some(pool) = pool {
+                    request.pool(*pool);
+                }
+                if *current {
+                    request.current();
+                }
+                if let Some(ordering) = ordering {
+                    request.ordering(ordering);
+                }
+                print_object_list(
+                    request.limit(PAGE_SIZE).all_pages().await?,
+                    format,
+                )
+            }
+            FloatingIpPriceCommand::Get { id } => print_single_object(
+                api.floating_ip_price.get(*id).await?,
+                format,
+            ),
+            FloatingIpPriceCommand::Create {
+                user_class,
+                pool,
+                price,
+                start_time,
+            } => {
+                let mut request =
+                    api.floating_ip_price.create(*user_class, *pool);
+                if let Some(price) = price {
+                    request.price(*price);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                print_single_object(request.send().await?, format)
+            }
+            FloatingIpPriceCommand::Modify {
+                id,
+                user_class,
+                pool,
+                price,
+                start_time,
+            } => {
+                let mut request = api.floating_ip_price.modify(*id);
+                if let Some(user_class) = user_class {
+                    request.user_class(*user_class);
+                }
+                if let Some(pool) = pool {
+                    request.pool(*pool);
+                }
+                if let Some(price) = price {
+                    request.unit_price(*price);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                print_single_object(request.send().await?, format)
+            }
+            FloatingIpPriceCommand::Delete { id } => {
+                ask_for_confirmation()?;
+                Ok(api.floating_ip_price.delete(*id).await?)
+            }
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum PriceOverrideCommand {
+    #[clap(about = "List price overrides")]
+    List {
+        #[clap(
+            short,
+            long,
+            help = "List price overrides of project with given name, ID, or OpenStack UUIDv4"
+        )]
+        project: Option<String>,
+        #[clap(
+            short,
+            long,
+            help = "List price overrides for flavor with given name, ID, or OpenStack UUIDv4"
+        )]
+        flavor: Option<String>,
+        #[clap(short, long, help = "List valid price overrides", action)]
+        current: bool,
+        #[clap(
+            long,
+            help = "Comma-separated fields to order by, prefix with - for descending order"
+        )]
+        ordering: Option<String>,
+    },
+
+    #[clap(visible_alias = "show", about = "Show price override with given ID")]
+    Get { id: u32 },
+
+    #[clap(about = "Create a new price override")]
+    Create {
+        #[clap(
+            help = "Name, ID, or OpenStack UUIDv4 of the project of the override"
+        )]
+        project: String,
+
+        #[clap(
+            long,
+            short,
+            help = "Name, ID, or OpenStack UUIDv4 of the flavor of the override, default: all"
+        )]
+        flavor: Option<String>,
+
+        #[clap(long, short, help = "Discount in percent on the list price")]
+        discount: Option<f64>,
+
+        #[clap(long, short, help = "Unit price replacing the list price")]
+        unit_price: Option<f64>,
+
+        #[clap(long, short, help = "Start time of the override, default: now")]
+        start_time: Option<DateTime<FixedOffset>>,
+
+        #[clap(long, short, help = "End time of the override, default: none")]
+        end_time: Option<DateTime<FixedOffset>>,
+    },
+
+    #[clap(about = "Modify a price override")]
+    Modify {
+        #[clap(help = "ID of the price override")]
+        id: u32,
+
+        #[clap(long, short, help = "Discount in percent on the list price")]
+        discount: Option<f64>,
+
+        #[clap(long, short, help = "Unit price replacing the list price")]
+        unit_price: Option<f64>,
+
+        #[clap(long, short, help = "Start time of the override")]
+        start_time: Option<DateTime<FixedOffset>>,
+
+        #[clap(long, short, help = "End time of the override")]
+        end_time: Option<DateTime<FixedOffset>>,
+
+        #[clap(
+            long,
+            short = 'E',
+            help = "Remove the end time of the override",
+            action,
+            conflicts_with = "end_time"
+        )]
+        no_end_time: bool,
+    },
+
+    #[clap(about = "Delete price override with given ID")]
+    Delete { id: u32 },
+}
+
+impl Execute for PriceOverrideCommand {
+    async fn execute(
+        &self,
+        api: avina::Api,
+        format: Format,
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            PriceOverrideCommand::List {
+                project,
+                flavor,
+                current,
+                ordering,
+            } => {
+                price_override_list(
+                    api,
+                    format,
+                    project.as_deref(),
+                    flavor.as_deref(),
+                    *current,
+                    ordering.as_deref(),
+                )
+                .await
+            }
+            PriceOverrideCommand::Get { id } => {
+                print_single_object(api.price_override.get(*id).await?, format)
+            }
+            PriceOverrideCommand::Create {
+                project,
+                flavor,
+                discount,
+                unit_price,
+                start_time,
+                end_time,
+            } => {
+                let project_id = project_find_id(&api, project).await?;
+                let mut request = api.price_override.create(project_id);
+                if let Some(flavor) = flavor {
+                    request.flavor(flavor_find_id(&api, flavor).await?);
+                }
+                if let Some(discount) = discount {
+                    request.discount(*discount);
+                }
+                if let Some(unit_price) = unit_price {
+                    request.unit_price(*unit_price);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                if let Some(end_time) = end_time {
+                    request.end_time(*end_time);
+                }
+                print_single_object(request.send().await?, format)
+            }
+            PriceOverrideCommand::Modify {
+                id,
+                discount,
+                unit_price,
+                start_time,
+                end_time,
+                no_end_time,
+            } => {
+                let mut request = api.price_override.modify(*id);
+                if let Some(discount) = discount {
+                    request.discount(*discount);
+                }
+                if let Some(unit_price) = unit_price {
+                    request.unit_price(*unit_price);
+                }
+                if let Some(start_time) = start_time {
+                    request.start_time(*start_time);
+                }
+                if let Some(end_time) = end_time {
+                    request.end_time(*end_time);
+                } else if *no_end_time {
+                    request.no_end_time();
+                }
+                print_single_object(request.send().await?, format)
+            }
+            PriceOverrideCommand::Delete { id } => {
+                ask_for_confirmation()?;
+                Ok(api.price_override.delete(*id).await?)
+            }
+        }
+    }
+}
+
+async fn price_override_list(
+    api: avina::Api,
+    format: Format,
+    project: Option<&str>,
+    flavor: Option<&str>,
+    current: bool,
+    ordering: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let mut request = api.price_override.list();
+    if let Some(project) = project {
+        request.project(project_find_id(&api, project).await?);
+    }
+    if let Some(flavor) = flavor {
+        request.flavor(flavor_find_id(&api, flavor).await?);
+    }
+    if current {
+        request.current();
+    }
+    if let Some(ordering) = ordering {
+        request.ordering(ordering);
+    }
+    print_object_list(request.limit(PAGE_SIZE).all_pages().await?, format)
+}
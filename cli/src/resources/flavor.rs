@@ -11,8 +11,8 @@ use crate::user::{
 };
 use crate::{
     common::{
-        Execute, Format, ask_for_confirmation, print_object_list,
-        print_single_object,
+        Execute, Format, ask_for_confirmation, print_dry_run,
+        print_object_list, print_single_object,
     },
     resources::flavor_group::find_id as flavor_group_find_id,
 };
@@ -127,6 +127,13 @@ pub(crate) enum FlavorCommand {
             help = "Suppress output if nothing is imported"
         )]
         quiet: bool,
+
+        #[clap(
+            long,
+            action,
+            help = "Only show the changes instead of applying them"
+        )]
+        dry_run: bool,
     },
 
     #[clap(about = "Flavor usage command")]
@@ -184,7 +191,9 @@ impl Execute for FlavorCommand {
                 .await
             }
             Delete { name_or_id } => delete(api, name_or_id).await,
-            Import { quiet } => import(api, format, *quiet).await,
+            Import { quiet, dry_run } => {
+                import(api, format, *quiet, *dry_run).await
+            }
             Usage { filter, aggregate } => {
                 usage(api, format, filter, *aggregate).await
             }
@@ -275,9 +284,20 @@ async fn import(
     api: avina::Api,
     format: Format,
     quiet: bool,
+    dry_run: bool,
 ) -> Result<(), Box<dyn Error>> {
+    if dry_run {
+        let result = api.flavor.import_dry_run().await?;
+        if !quiet || !result.changes.is_empty() {
+            return print_dry_run(result, format);
+        }
+        return Ok(());
+    }
     let result = api.flavor.import().await?;
-    if !quiet || result.new_flavor_count > 0 {
+    if !quiet
+        || result.new_flavor_count > 0
+        || result.new_flavor_price_count > 0
+    {
         return print_single_object(result, format);
     }
     Ok(())
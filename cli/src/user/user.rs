@@ -5,8 +5,8 @@ use clap::{Args, Subcommand};
 
 use crate::{
     common::{
-        Execute, Format, ask_for_confirmation, print_object_list,
-        print_single_object,
+        Execute, Format, PAGE_SIZE, ask_for_confirmation, print_dry_run,
+        print_object_list, print_single_object,
     },
     user::project::find_id as project_find_id,
 };
@@ -32,6 +32,12 @@ pub(crate) enum UserCommand {
     List {
         #[clap(flatten)]
         filter: UserListFilter,
+
+        #[clap(
+            long,
+            help = "Comma-separated fields to order by, prefix with - for descending order"
+        )]
+        ordering: Option<String>,
     },
 
     #[clap(
@@ -108,6 +114,13 @@ pub(crate) enum UserCommand {
             help = "Suppress output if nothing is imported"
         )]
         quiet: bool,
+
+        #[clap(
+            long,
+            action,
+            help = "Only show the changes instead of applying them"
+        )]
+        dry_run: bool,
     },
 
     #[clap(about = "Sync users and projects to latest LDAP data")]
@@ -119,6 +132,13 @@ pub(crate) enum UserCommand {
             help = "Suppress output if nothing is imported"
         )]
         quiet: bool,
+
+        #[clap(
+            long,
+            action,
+            help = "Only show the changes instead of applying them"
+        )]
+        dry_run: bool,
     },
 }
 pub(crate) use UserCommand::*;
@@ -130,7 +150,9 @@ impl Execute for UserCommand {
         format: Format,
     ) -> Result<(), Box<dyn Error>> {
         match self {
-            List { filter } => list(api, format, filter).await,
+            List { filter, ordering } => {
+                list(api, format, filter, ordering.as_deref()).await
+            }
             Get { name_or_id } => get(api, format, name_or_id).await,
             Create {
                 name,
@@ -176,8 +198,12 @@ impl Execute for UserCommand {
             }
             Delete { name_or_id } => delete(api, name_or_id).await,
             Me => me(api, format).await,
-            Import { quiet } => import(api, format, *quiet).await,
-            Sync { quiet } => sync(api, format, *quiet).await,
+            Import { quiet, dry_run } => {
+                import(api, format, *quiet, *dry_run).await
+            }
+            Sync { quiet, dry_run } => {
+                sync(api, format, *quiet, *dry_run).await
+            }
         }
     }
 }
@@ -186,6 +212,7 @@ async fn list(
     api: avina::Api,
     format: Format,
     filter: &UserListFilter,
+    ordering: Option<&str>,
 ) -> Result<(), Box<dyn Error>> {
     let mut request = api.user.list();
     if filter.all {
@@ -194,7 +221,10 @@ async fn list(
         let project_id = project_find_id(&api, project).await?;
         request.project(project_id);
     }
-    print_object_list(request.send().await?, format)
+    if let Some(ordering) = ordering {
+        request.ordering(ordering);
+    }
+    print_object_list(request.limit(PAGE_SIZE).all_pages().await?, format)
 }
 
 async fn get(
@@ -284,7 +314,15 @@ async fn import(
     api: avina::Api,
     format: Format,
     quiet: bool,
+    dry_run: bool,
 ) -> Result<(), Box<dyn Error>> {
+    if dry_run {
+        let result = api.user.import_dry_run().await?;
+        if !quiet || !result.changes.is_empty() {
+            return print_dry_run(result, format);
+        }
+        return Ok(());
+    }
     let result = api.user.import().await?;
     if !quiet || result.new_project_count > 0 || result.new_user_count > 0 {
         return print_single_object(result, format);
@@ -296,7 +334,15 @@ async fn sync(
     api: avina::Api,
     format: Format,
     quiet: bool,
+    dry_run: bool,
 ) -> Result<(), Box<dyn Error>> {
+    if dry_run {
+        let result = api.user.sync_dry_run().await?;
+        if !quiet || !result.changes.is_empty() {
+            return print_dry_run(result, format);
+        }
+        return Ok(());
+    }
     let result = api.user.sync().await?;
     if !quiet
         || result.updated_project_count > 0
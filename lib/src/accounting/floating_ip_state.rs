@@ -0,0 +1,180 @@
+use std::rc::Rc;
+
+use avina_wire::{
+    accounting::{
+        FloatingIpPool, FloatingIpState, FloatingIpStateImport,
+        FloatingIpStateListParams,
+    },
+    dry_run::DryRun,
+    pagination::Page,
+};
+use futures::Stream;
+use reqwest::{Client, Method, StatusCode};
+use uuid::Uuid;
+
+use crate::{
+    common::{
+        DEFAULT_PAGE_SIZE, SerializableNone, list_url, request,
+        request_all_pages, request_pages,
+    },
+    error::ApiError,
+};
+
+#[derive(Debug)]
+pub struct FloatingIpStateApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct FloatingIpStateListRequest {
+    url: String,
+    client: Rc<Client>,
+    params: FloatingIpStateListParams,
+}
+
+impl FloatingIpStateListRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
+    pub async fn send(&self) -> Result<Vec<FloatingIpState>, ApiError> {
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn page(&self) -> Result<Page<FloatingIpState>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<FloatingIpState>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<FloatingIpState>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+
+    pub fn floating_ip(&mut self, floating_ip: Uuid) -> &mut Self {
+        self.params.floating_ip = Some(floating_ip);
+        self
+    }
+
+    pub fn pool(&mut self, pool: FloatingIpPool) -> &mut Self {
+        self.params.pool = Some(pool);
+        self
+    }
+
+    pub fn user(&mut self, user: u32) -> &mut Self {
+        self.params.user = Some(user);
+        self
+    }
+
+    pub fn project(&mut self, project: u32) -> &mut Self {
+        self.params.project = Some(project);
+        self
+    }
+
+    pub fn all(&mut self) -> &mut Self {
+        self.params.all = Some(true);
+        self
+    }
+}
+
+impl FloatingIpStateApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> FloatingIpStateApi {
+        FloatingIpStateApi {
+            url: format!("{base_url}/accounting/floatingipstates"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn list(&self) -> FloatingIpStateListRequest {
+        FloatingIpStateListRequest::new(self.url.as_ref(), &self.client)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<FloatingIpState, ApiError> {
+        let url = format!("{}/{}", self.url, id);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn import(&self) -> Result<FloatingIpStateImport, ApiError> {
+        let url = format!("{}/import/", self.url);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn import_dry_run(
+        &self,
+    ) -> Result<DryRun<FloatingIpStateImport>, ApiError> {
+        let url = format!("{}/import/?dry_run=true", self.url);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+}
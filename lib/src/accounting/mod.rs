@@ -1,7 +1,11 @@
+mod floating_ip_state;
 mod server_consumption;
 mod server_cost;
 mod server_state;
+mod volume_state;
 
+pub use floating_ip_state::FloatingIpStateApi;
 pub use server_consumption::ServerConsumptionApi;
 pub use server_cost::ServerCostApi;
 pub use server_state::ServerStateApi;
+pub use volume_state::VolumeStateApi;
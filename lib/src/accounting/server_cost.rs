@@ -2,7 +2,8 @@ use std::{fmt::Debug, rc::Rc};
 
 use anyhow::Context;
 use avina_wire::accounting::{
-    ServerCostAll, ServerCostParams, ServerCostProject, ServerCostServer,
+    ServerCostAll, ServerCostBucket, ServerCostInterval, ServerCostParams,
+    ServerCostProject, ServerCostSeriesParams, ServerCostServer,
     ServerCostSimple, ServerCostUser,
 };
 use chrono::{DateTime, FixedOffset};
@@ -252,6 +253,89 @@ impl ServerCostRequest {
     }
 }
 
+#[derive(Debug)]
+pub struct ServerCostSeriesRequest {
+    url: String,
+    client: Rc<Client>,
+    params: ServerCostSeriesParams,
+}
+
+impl ServerCostSeriesRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub fn begin(&mut self, begin: DateTime<FixedOffset>) -> &mut Self {
+        self.params.begin = Some(begin);
+        self
+    }
+
+    pub fn end(&mut self, end: DateTime<FixedOffset>) -> &mut Self {
+        self.params.end = Some(end);
+        self
+    }
+
+    pub fn interval(&mut self, interval: ServerCostInterval) -> &mut Self {
+        self.params.interval = Some(interval);
+        self
+    }
+
+    async fn send(&self) -> Result<Vec<ServerCostBucket>, ApiError> {
+        let params = serde_urlencoded::to_string(&self.params)
+            .context("Failed to encode URL parameters")?;
+        let url = if params.is_empty() {
+            self.url.clone()
+        } else {
+            format!("{}?{}", self.url, params)
+        };
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn server(
+        &mut self,
+        server: Uuid,
+    ) -> Result<Vec<ServerCostBucket>, ApiError> {
+        self.params.server = Some(server);
+        self.send().await
+    }
+
+    pub async fn user(
+        &mut self,
+        user: u32,
+    ) -> Result<Vec<ServerCostBucket>, ApiError> {
+        self.params.user = Some(user);
+        self.send().await
+    }
+
+    pub async fn project(
+        &mut self,
+        project: u32,
+    ) -> Result<Vec<ServerCostBucket>, ApiError> {
+        self.params.project = Some(project);
+        self.send().await
+    }
+
+    pub async fn all(&mut self) -> Result<Vec<ServerCostBucket>, ApiError> {
+        self.params.all = Some(true);
+        self.send().await
+    }
+
+    pub async fn mine(&mut self) -> Result<Vec<ServerCostBucket>, ApiError> {
+        self.send().await
+    }
+}
+
 #[derive(Debug)]
 pub struct ServerCostApi {
     pub url: String,
@@ -269,4 +353,11 @@ impl ServerCostApi {
     pub fn get(&self) -> ServerCostRequest {
         ServerCostRequest::new(self.url.as_str(), &self.client)
     }
+
+    pub fn series(&self) -> ServerCostSeriesRequest {
+        ServerCostSeriesRequest::new(
+            format!("{}series/", self.url).as_str(),
+            &self.client,
+        )
+    }
 }
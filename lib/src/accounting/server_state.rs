@@ -1,16 +1,24 @@
 use std::rc::Rc;
 
-use anyhow::Context;
-use avina_wire::accounting::{
-    ServerState, ServerStateCreateData, ServerStateImport,
-    ServerStateListParams, ServerStateModifyData,
+use avina_wire::{
+    accounting::{
+        ServerState, ServerStateAnomaly, ServerStateCreateData,
+        ServerStateImport, ServerStateListParams, ServerStateLoad,
+        ServerStateLoadData, ServerStateModifyData,
+    },
+    dry_run::DryRun,
+    pagination::Page,
 };
 use chrono::{DateTime, FixedOffset};
+use futures::Stream;
 use reqwest::{Client, Method, StatusCode};
 use uuid::Uuid;
 
 use crate::{
-    common::{SerializableNone, request, request_bare},
+    common::{
+        DEFAULT_PAGE_SIZE, SerializableNone, list_url, request,
+        request_all_pages, request_bare, request_csv, request_pages,
+    },
     error::ApiError,
 };
 
@@ -36,14 +44,26 @@ impl ServerStateListRequest {
         }
     }
 
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
     pub async fn send(&self) -> Result<Vec<ServerState>, ApiError> {
-        let params = serde_urlencoded::to_string(&self.params)
-            .context("Failed to encode URL parameters")?;
-        let url = if params.is_empty() {
-            self.url.clone()
-        } else {
-            format!("{}?{}", self.url, params)
-        };
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
         request(
             &self.client,
             Method::GET,
@@ -54,6 +74,36 @@ impl ServerStateListRequest {
         .await
     }
 
+    pub async fn page(&self) -> Result<Page<ServerState>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<ServerState>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<ServerState>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+
     pub fn server(&mut self, server: Uuid) -> &mut Self {
         self.params.server = Some(server);
         self
@@ -263,4 +313,72 @@ impl ServerStateApi {
         )
         .await
     }
+
+    pub async fn import_dry_run(
+        &self,
+    ) -> Result<DryRun<ServerStateImport>, ApiError> {
+        let url = format!("{}/import/?dry_run=true", self.url);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn check(&self) -> Result<Vec<ServerStateAnomaly>, ApiError> {
+        let url = format!("{}/check/", self.url);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn repair(&self) -> Result<Vec<ServerStateAnomaly>, ApiError> {
+        let url = format!("{}/repair/", self.url);
+        request(
+            &self.client,
+            Method::POST,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn load(
+        &self,
+        data: &[ServerStateLoadData],
+    ) -> Result<ServerStateLoad, ApiError> {
+        let url = format!("{}/load/", self.url);
+        request(
+            &self.client,
+            Method::POST,
+            url.as_str(),
+            Some(data),
+            StatusCode::CREATED,
+        )
+        .await
+    }
+
+    pub async fn load_csv(
+        &self,
+        csv: String,
+    ) -> Result<ServerStateLoad, ApiError> {
+        let url = format!("{}/load/", self.url);
+        request_csv(
+            &self.client,
+            Method::POST,
+            url.as_str(),
+            csv,
+            StatusCode::CREATED,
+        )
+        .await
+    }
 }
@@ -0,0 +1,172 @@
+use std::rc::Rc;
+
+use avina_wire::{
+    accounting::{VolumeState, VolumeStateImport, VolumeStateListParams},
+    dry_run::DryRun,
+    pagination::Page,
+};
+use futures::Stream;
+use reqwest::{Client, Method, StatusCode};
+use uuid::Uuid;
+
+use crate::{
+    common::{
+        DEFAULT_PAGE_SIZE, SerializableNone, list_url, request,
+        request_all_pages, request_pages,
+    },
+    error::ApiError,
+};
+
+#[derive(Debug)]
+pub struct VolumeStateApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct VolumeStateListRequest {
+    url: String,
+    client: Rc<Client>,
+    params: VolumeStateListParams,
+}
+
+impl VolumeStateListRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
+    pub async fn send(&self) -> Result<Vec<VolumeState>, ApiError> {
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn page(&self) -> Result<Page<VolumeState>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<VolumeState>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<VolumeState>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+
+    pub fn volume(&mut self, volume: Uuid) -> &mut Self {
+        self.params.volume = Some(volume);
+        self
+    }
+
+    pub fn user(&mut self, user: u32) -> &mut Self {
+        self.params.user = Some(user);
+        self
+    }
+
+    pub fn project(&mut self, project: u32) -> &mut Self {
+        self.params.project = Some(project);
+        self
+    }
+
+    pub fn all(&mut self) -> &mut Self {
+        self.params.all = Some(true);
+        self
+    }
+}
+
+impl VolumeStateApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> VolumeStateApi {
+        VolumeStateApi {
+            url: format!("{base_url}/accounting/volumestates"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn list(&self) -> VolumeStateListRequest {
+        VolumeStateListRequest::new(self.url.as_ref(), &self.client)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<VolumeState, ApiError> {
+        let url = format!("{}/{}", self.url, id);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn import(&self) -> Result<VolumeStateImport, ApiError> {
+        let url = format!("{}/import/", self.url);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn import_dry_run(
+        &self,
+    ) -> Result<DryRun<VolumeStateImport>, ApiError> {
+        let url = format!("{}/import/?dry_run=true", self.url);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+}
@@ -0,0 +1,93 @@
+//! Bindings for audit endpoints of the API.
+
+use std::rc::Rc;
+
+use anyhow::Context;
+use avina_wire::audit::{AuditLogEntry, AuditLogListParams, ObjectType};
+use chrono::{DateTime, FixedOffset};
+use reqwest::{Client, Method, StatusCode};
+
+use crate::{
+    common::{SerializableNone, request},
+    error::ApiError,
+};
+
+#[derive(Debug)]
+pub struct AuditApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct AuditLogListRequest {
+    url: String,
+    client: Rc<Client>,
+    params: AuditLogListParams,
+}
+
+impl AuditLogListRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<Vec<AuditLogEntry>, ApiError> {
+        let params = serde_urlencoded::to_string(&self.params)
+            .context("Failed to encode URL parameters")?;
+        let url = if params.is_empty() {
+            self.url.clone()
+        } else {
+            format!("{}?{}", self.url, params)
+        };
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn object_type(&mut self, object_type: ObjectType) -> &mut Self {
+        self.params.object_type = Some(object_type);
+        self
+    }
+
+    pub fn user(&mut self, user: u32) -> &mut Self {
+        self.params.user = Some(user);
+        self
+    }
+
+    pub fn begin(&mut self, begin: DateTime<FixedOffset>) -> &mut Self {
+        self.params.begin = Some(begin);
+        self
+    }
+
+    pub fn end(&mut self, end: DateTime<FixedOffset>) -> &mut Self {
+        self.params.end = Some(end);
+        self
+    }
+
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+}
+
+impl AuditApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> AuditApi {
+        AuditApi {
+            url: format!("{base_url}/audit"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn list(&self) -> AuditLogListRequest {
+        let url = format!("{}/", self.url);
+        AuditLogListRequest::new(url.as_str(), &self.client)
+    }
+}
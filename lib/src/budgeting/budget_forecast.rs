@@ -0,0 +1,74 @@
+use std::rc::Rc;
+
+use anyhow::Context;
+use avina_wire::budgeting::{BudgetForecast, BudgetForecastParams};
+use reqwest::{Client, Method, StatusCode};
+
+use crate::{
+    common::{SerializableNone, request},
+    error::ApiError,
+};
+
+#[derive(Debug)]
+pub struct BudgetForecastApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct BudgetForecastRequest {
+    url: String,
+    client: Rc<Client>,
+    params: BudgetForecastParams,
+}
+
+impl BudgetForecastRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<BudgetForecast, ApiError> {
+        let params = serde_urlencoded::to_string(&self.params)
+            .context("Failed to encode URL parameters")?;
+        let url = if params.is_empty() {
+            self.url.clone()
+        } else {
+            format!("{}?{}", self.url, params)
+        };
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn project(&mut self, project: u32) -> &mut Self {
+        self.params.project = Some(project);
+        self
+    }
+
+    pub fn user(&mut self, user: u32) -> &mut Self {
+        self.params.user = Some(user);
+        self
+    }
+}
+
+impl BudgetForecastApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> BudgetForecastApi {
+        BudgetForecastApi {
+            url: format!("{base_url}/budgeting/budgetforecast/"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn get(&self) -> BudgetForecastRequest {
+        BudgetForecastRequest::new(&self.url, &self.client)
+    }
+}
@@ -1,17 +1,24 @@
 use std::rc::Rc;
 
 use anyhow::Context;
-use avina_wire::budgeting::{
-    UserBudget, UserBudgetCreateData, UserBudgetListParams,
-    UserBudgetModifyData, UserBudgetOverCombined, UserBudgetOverCombinedDetail,
-    UserBudgetOverDetail, UserBudgetOverParams, UserBudgetOverSimple,
-    UserBudgetSync,
+use avina_wire::{
+    budgeting::{
+        UserBudget, UserBudgetCreateData, UserBudgetListParams,
+        UserBudgetModifyData, UserBudgetOverCombined,
+        UserBudgetOverCombinedDetail, UserBudgetOverDetail,
+        UserBudgetOverParams, UserBudgetOverSimple, UserBudgetSync,
+    },
+    pagination::Page,
 };
 use chrono::{DateTime, FixedOffset};
+use futures::Stream;
 use reqwest::{Client, Method, StatusCode};
 
 use crate::{
-    common::{SerializableNone, request, request_bare},
+    common::{
+        DEFAULT_PAGE_SIZE, SerializableNone, list_url, request,
+        request_all_pages, request_bare, request_pages,
+    },
     error::ApiError,
 };
 
@@ -39,14 +46,26 @@ impl UserBudgetListRequest {
         }
     }
 
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
     pub async fn send(&self) -> Result<Vec<UserBudget>, ApiError> {
-        let params = serde_urlencoded::to_string(&self.params)
-            .context("Failed to encode URL parameters")?;
-        let url = if params.is_empty() {
-            self.url.clone()
-        } else {
-            format!("{}?{}", self.url, params)
-        };
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
         request(
             &self.client,
             Method::GET,
@@ -57,6 +76,36 @@ impl UserBudgetListRequest {
         .await
     }
 
+    pub async fn page(&self) -> Result<Page<UserBudget>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<UserBudget>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<UserBudget>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+
     pub fn user(&mut self, user: u32) -> &mut Self {
         self.params.user = Some(user);
         self
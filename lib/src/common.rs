@@ -1,14 +1,20 @@
 //! Common helper types and functions.
 
-use std::fmt::Debug;
+use std::{fmt::Debug, rc::Rc};
 
 use anyhow::Context;
-use avina_wire::error::ErrorResponse;
-use reqwest::{Client, Method, Response, StatusCode};
+use avina_wire::{error::ErrorResponse, pagination::Page};
+use futures::{Stream, TryStreamExt, stream};
+use reqwest::{
+    Client, Method, RequestBuilder, Response, StatusCode, header::CONTENT_TYPE,
+};
 use serde::{de::DeserializeOwned, ser::Serialize};
 
 use crate::error::ApiError;
 
+/// Number of objects per page requested when paginating without a limit.
+pub(crate) const DEFAULT_PAGE_SIZE: u32 = 100;
+
 /// Zero-size marker type that implements [serde::Serialize].
 ///
 /// This is use by the [SerializableNone] macro to pass a [None] as parameter that only requires
@@ -64,6 +70,17 @@ where
             format!("Could not serialize json request body from {data:?}"),
         )?);
     }
+    send(request, expected_status).await
+}
+
+/// Send the given request and check the status code of the response.
+///
+/// This is the common part of [request_bare] and [request_csv], see the
+/// former for the returned errors.
+async fn send(
+    request: RequestBuilder,
+    expected_status: StatusCode,
+) -> Result<Response, ApiError> {
     let response = match request.send().await.context("") {
         Ok(response) => response,
         Err(err) => {
@@ -86,6 +103,20 @@ where
     Ok(response)
 }
 
+/// Deserialize the data of the given response.
+async fn deserialize_response<U>(response: Response) -> Result<U, ApiError>
+where
+    U: DeserializeOwned,
+{
+    let text = response
+        .text()
+        .await
+        .context("Could not retrieve response text.")?;
+    let u: U = serde_json::from_str(text.as_str())
+        .context(format!("Could not parse response text: {text}"))?;
+    Ok(u)
+}
+
 /// Perform an HTTP request and deserialize the response data.
 ///
 /// This calls [request_bare] and attempts to deserialize the response data
@@ -112,11 +143,103 @@ where
 {
     let response =
         request_bare(client, method, url, data, expected_status).await?;
-    let text = response
-        .text()
+    deserialize_response(response).await
+}
+
+/// Perform an HTTP request with CSV data and deserialize the response data.
+///
+/// This works like [request], but sends the given text as is with the
+/// content type `text/csv` instead of serializing data to JSON.
+///
+/// # Arguments
+///
+///   - `client` - HTTP client.
+///   - `method` - Request method to use.
+///   - `url` - URL to call.
+///   - `csv` - CSV text to send.
+///   - `expected_status` - Response status code to expect.
+pub(crate) async fn request_csv<U>(
+    client: &Client,
+    method: Method,
+    url: &str,
+    csv: String,
+    expected_status: StatusCode,
+) -> Result<U, ApiError>
+where
+    U: DeserializeOwned,
+{
+    let request = client
+        .request(method, url)
+        .header(CONTENT_TYPE, "text/csv")
+        .body(csv);
+    let response = send(request, expected_status).await?;
+    deserialize_response(response).await
+}
+
+/// Append the given parameters to the URL of a list endpoint.
+pub(crate) fn list_url<T>(url: &str, params: &T) -> Result<String, ApiError>
+where
+    T: Serialize,
+{
+    let params = serde_urlencoded::to_string(params)
+        .context("Failed to encode URL parameters")?;
+    if params.is_empty() {
+        Ok(url.to_string())
+    } else {
+        Ok(format!("{url}?{params}"))
+    }
+}
+
+/// Request the pages of a list endpoint one after another.
+///
+/// The returned stream starts with the page at the given URL, whose
+/// parameters are expected to contain a limit, and then follows the `next`
+/// URLs of the pages until the last one.
+///
+/// # Arguments
+///
+///   - `client` - HTTP client.
+///   - `url` - URL of the first page.
+pub(crate) fn request_pages<T>(
+    client: &Rc<Client>,
+    url: String,
+) -> impl Stream<Item = Result<Page<T>, ApiError>> + use<T>
+where
+    T: DeserializeOwned,
+{
+    let client = Rc::clone(client);
+    stream::try_unfold(Some(url), move |url| {
+        let client = Rc::clone(&client);
+        async move {
+            let Some(url) = url else {
+                return Ok(None);
+            };
+            let page: Page<T> = request(
+                &client,
+                Method::GET,
+                url.as_str(),
+                SerializableNone!(),
+                StatusCode::OK,
+            )
+            .await?;
+            let next = page.next.clone();
+            Ok(Some((page, next)))
+        }
+    })
+}
+
+/// Request all pages of a list endpoint and concatenate their objects.
+///
+/// See [request_pages] for the arguments.
+pub(crate) async fn request_all_pages<T>(
+    client: &Rc<Client>,
+    url: String,
+) -> Result<Vec<T>, ApiError>
+where
+    T: DeserializeOwned,
+{
+    request_pages(client, url)
+        .map_ok(|page| page.results)
+        .try_concat()
         .await
-        .context("Could not retrieve response text.")?;
-    let u: U = serde_json::from_str(text.as_str())
-        .context(format!("Could not parse response text: {text}"))?;
-    Ok(u)
 }
@@ -0,0 +1,98 @@
+use std::rc::Rc;
+
+use anyhow::Context;
+use avina_wire::jobs::{Job, JobRun, JobRunCreateData, JobRunListParams};
+use reqwest::{Client, Method, StatusCode};
+
+use crate::{
+    common::{SerializableNone, request},
+    error::ApiError,
+};
+
+#[derive(Debug)]
+pub struct JobRunApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct JobRunListRequest {
+    url: String,
+    client: Rc<Client>,
+    params: JobRunListParams,
+}
+
+impl JobRunListRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub async fn send(&self) -> Result<Vec<JobRun>, ApiError> {
+        let params = serde_urlencoded::to_string(&self.params)
+            .context("Failed to encode URL parameters")?;
+        let url = if params.is_empty() {
+            self.url.clone()
+        } else {
+            format!("{}?{}", self.url, params)
+        };
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn job(&mut self, job: Job) -> &mut Self {
+        self.params.job = Some(job);
+        self
+    }
+
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+}
+
+impl JobRunApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> JobRunApi {
+        JobRunApi {
+            url: format!("{base_url}/jobs/runs"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn list(&self) -> JobRunListRequest {
+        JobRunListRequest::new(self.url.as_ref(), &self.client)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<JobRun, ApiError> {
+        let url = format!("{}/{}", self.url, id);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn trigger(&self, job: Job) -> Result<JobRun, ApiError> {
+        let url = format!("{}/", self.url);
+        request(
+            &self.client,
+            Method::POST,
+            url.as_str(),
+            Some(&JobRunCreateData::new(job)),
+            StatusCode::CREATED,
+        )
+        .await
+    }
+}
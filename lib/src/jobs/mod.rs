@@ -0,0 +1,3 @@
+mod job_run;
+
+pub use job_run::JobRunApi;
@@ -56,10 +56,14 @@ pub use token::Token;
 
 #[cfg(feature = "accounting")]
 pub mod accounting;
+#[cfg(feature = "audit")]
+pub mod audit;
 #[cfg(feature = "budgeting")]
 pub mod budgeting;
 #[cfg(feature = "hello")]
 pub mod hello;
+#[cfg(feature = "jobs")]
+pub mod jobs;
 #[cfg(feature = "pricing")]
 pub mod pricing;
 #[cfg(feature = "quota")]
@@ -69,15 +73,23 @@ pub mod resources;
 #[cfg(feature = "user")]
 pub mod user;
 
+#[cfg(feature = "accounting")]
+use accounting::FloatingIpStateApi;
 #[cfg(feature = "accounting")]
 use accounting::ServerConsumptionApi;
 #[cfg(feature = "accounting")]
 use accounting::ServerCostApi;
 #[cfg(feature = "accounting")]
 use accounting::ServerStateApi;
+#[cfg(feature = "accounting")]
+use accounting::VolumeStateApi;
+#[cfg(feature = "audit")]
+use audit::AuditApi;
 #[cfg(feature = "budgeting")]
 use budgeting::BudgetBulkCreateApi;
 #[cfg(feature = "budgeting")]
+use budgeting::BudgetForecastApi;
+#[cfg(feature = "budgeting")]
 use budgeting::BudgetOverTreeApi;
 #[cfg(feature = "budgeting")]
 use budgeting::ProjectBudgetApi;
@@ -85,8 +97,13 @@ use budgeting::ProjectBudgetApi;
 use budgeting::UserBudgetApi;
 #[cfg(feature = "hello")]
 use hello::HelloApi;
+#[cfg(feature = "jobs")]
+use jobs::JobRunApi;
 #[cfg(feature = "pricing")]
-use pricing::FlavorPriceApi;
+use pricing::{
+    BillingPolicyApi, FlavorPriceApi, FloatingIpPriceApi, PriceOverrideApi,
+    ResourcePriceApi, VolumePriceApi,
+};
 #[cfg(feature = "quota")]
 use quota::FlavorQuotaApi;
 #[cfg(feature = "resources")]
@@ -135,12 +152,32 @@ pub struct Api {
     /// Client for flavor price endpoints.
     #[cfg(feature = "pricing")]
     pub flavor_price: FlavorPriceApi,
+    #[cfg(feature = "pricing")]
+    pub billing_policy: BillingPolicyApi,
+    /// Client for volume price endpoints.
+    #[cfg(feature = "pricing")]
+    pub volume_price: VolumePriceApi,
+    /// Client for floating IP price endpoints.
+    #[cfg(feature = "pricing")]
+    pub floating_ip_price: FloatingIpPriceApi,
+    /// Client for resource price endpoints.
+    #[cfg(feature = "pricing")]
+    pub resource_price: ResourcePriceApi,
+    /// Client for price override endpoints.
+    #[cfg(feature = "pricing")]
+    pub price_override: PriceOverrideApi,
     /// Client for flavor quota endpoints.
     #[cfg(feature = "quota")]
     pub flavor_quota: FlavorQuotaApi,
     /// Client for server state endpoints.
     #[cfg(feature = "accounting")]
     pub server_state: ServerStateApi,
+    /// Client for volume state endpoints.
+    #[cfg(feature = "accounting")]
+    pub volume_state: VolumeStateApi,
+    /// Client for floating IP state endpoints.
+    #[cfg(feature = "accounting")]
+    pub floating_ip_state: FloatingIpStateApi,
     /// Client for server cost endpoint.
     #[cfg(feature = "accounting")]
     pub server_cost: ServerCostApi,
@@ -159,6 +196,15 @@ pub struct Api {
     /// Client for the budget-bulk-create endpoint.
     #[cfg(feature = "budgeting")]
     pub budget_bulk_create: BudgetBulkCreateApi,
+    /// Client for the budget-forecast endpoint.
+    #[cfg(feature = "budgeting")]
+    pub budget_forecast: BudgetForecastApi,
+    /// Client for job run endpoints.
+    #[cfg(feature = "jobs")]
+    pub job_run: JobRunApi,
+    /// Client for audit endpoints.
+    #[cfg(feature = "audit")]
+    pub audit: AuditApi,
 }
 
 impl Api {
@@ -224,11 +270,25 @@ impl Api {
             usage: UsageApi::new(&url, &client),
             #[cfg(feature = "pricing")]
             flavor_price: FlavorPriceApi::new(&url, &client),
+            #[cfg(feature = "pricing")]
+            billing_policy: BillingPolicyApi::new(&url, &client),
+            #[cfg(feature = "pricing")]
+            volume_price: VolumePriceApi::new(&url, &client),
+            #[cfg(feature = "pricing")]
+            floating_ip_price: FloatingIpPriceApi::new(&url, &client),
+            #[cfg(feature = "pricing")]
+            resource_price: ResourcePriceApi::new(&url, &client),
+            #[cfg(feature = "pricing")]
+            price_override: PriceOverrideApi::new(&url, &client),
             #[cfg(feature = "quota")]
             flavor_quota: FlavorQuotaApi::new(&url, &client),
             #[cfg(feature = "accounting")]
             server_state: ServerStateApi::new(&url, &client),
             #[cfg(feature = "accounting")]
+            volume_state: VolumeStateApi::new(&url, &client),
+            #[cfg(feature = "accounting")]
+            floating_ip_state: FloatingIpStateApi::new(&url, &client),
+            #[cfg(feature = "accounting")]
             server_cost: ServerCostApi::new(&url, &client),
             #[cfg(feature = "accounting")]
             server_consumption: ServerConsumptionApi::new(&url, &client),
@@ -240,6 +300,12 @@ impl Api {
             budget_over_tree: BudgetOverTreeApi::new(&url, &client),
             #[cfg(feature = "budgeting")]
             budget_bulk_create: BudgetBulkCreateApi::new(&url, &client),
+            #[cfg(feature = "budgeting")]
+            budget_forecast: BudgetForecastApi::new(&url, &client),
+            #[cfg(feature = "jobs")]
+            job_run: JobRunApi::new(&url, &client),
+            #[cfg(feature = "audit")]
+            audit: AuditApi::new(&url, &client),
         })
     }
 }
@@ -1,18 +1,32 @@
 use std::rc::Rc;
 
-use anyhow::Context;
 use avina_wire::{
+    accounting::FloatingIpPool,
+    dry_run::DryRun,
+    pagination::Page,
     pricing::{
-        FlavorPrice, FlavorPriceCreateData, FlavorPriceInitialize,
-        FlavorPriceListParams, FlavorPriceModifyData,
+        BillingPolicy, BillingPolicyCreateData, BillingPolicyListParams,
+        BillingPolicyModifyData, FlavorPrice, FlavorPriceCreateData,
+        FlavorPriceEstimate, FlavorPriceEstimateParams, FlavorPriceInitialize,
+        FlavorPriceListParams, FlavorPriceModifyData, FlavorPriceRevise,
+        FlavorPriceReviseData, FloatingIpPrice, FloatingIpPriceCreateData,
+        FloatingIpPriceListParams, FloatingIpPriceModifyData, PriceOverride,
+        PriceOverrideCreateData, PriceOverrideListParams,
+        PriceOverrideModifyData, ResourcePrice, ResourcePriceCreateData,
+        ResourcePriceListParams, ResourcePriceModifyData, VolumePrice,
+        VolumePriceCreateData, VolumePriceListParams, VolumePriceModifyData,
     },
     user::UserClass,
 };
 use chrono::{DateTime, FixedOffset};
+use futures::Stream;
 use reqwest::{Client, Method, StatusCode};
 
 use crate::{
-    common::{SerializableNone, request, request_bare},
+    common::{
+        DEFAULT_PAGE_SIZE, SerializableNone, list_url, request,
+        request_all_pages, request_bare, request_pages,
+    },
     error::ApiError,
 };
 
@@ -48,14 +62,26 @@ impl FlavorPriceListRequest {
         self
     }
 
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
     pub async fn send(&self) -> Result<Vec<FlavorPrice>, ApiError> {
-        let params = serde_urlencoded::to_string(&self.params)
-            .context("Failed to encode URL parameters.")?;
-        let url = if params.is_empty() {
-            self.url.clone()
-        } else {
-            format!("{}?{}", self.url, params)
-        };
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
         request(
             &self.client,
             Method::GET,
@@ -65,6 +91,36 @@ impl FlavorPriceListRequest {
         )
         .await
     }
+
+    pub async fn page(&self) -> Result<Page<FlavorPrice>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<FlavorPrice>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<FlavorPrice>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
 }
 
 pub struct FlavorPriceCreateRequest {
@@ -164,6 +220,110 @@ impl FlavorPriceModifyRequest {
     }
 }
 
+pub struct FlavorPriceEstimateRequest {
+    url: String,
+    client: Rc<Client>,
+
+    params: FlavorPriceEstimateParams,
+}
+
+impl FlavorPriceEstimateRequest {
+    pub fn new(
+        url: &str,
+        client: &Rc<Client>,
+        flavor: u32,
+        duration: u64,
+    ) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: FlavorPriceEstimateParams::new(flavor, duration),
+        }
+    }
+
+    pub fn count(&mut self, count: u32) -> &mut Self {
+        self.params.count = Some(count);
+        self
+    }
+
+    pub fn begin(&mut self, begin: DateTime<FixedOffset>) -> &mut Self {
+        self.params.begin = Some(begin);
+        self
+    }
+
+    pub async fn send(&self) -> Result<FlavorPriceEstimate, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            list_url(&self.url, &self.params)?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+}
+
+pub struct FlavorPriceReviseRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: FlavorPriceReviseData,
+}
+
+impl FlavorPriceReviseRequest {
+    pub fn new(
+        url: &str,
+        client: &Rc<Client>,
+        start_time: DateTime<FixedOffset>,
+    ) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: FlavorPriceReviseData::new(start_time),
+        }
+    }
+
+    pub fn percentage(&mut self, percentage: f64) -> &mut Self {
+        self.data.percentage = Some(percentage);
+        self
+    }
+
+    pub fn factor(&mut self, user_class: UserClass, factor: f64) -> &mut Self {
+        self.data.factors.insert(user_class, factor);
+        self
+    }
+
+    pub fn flavor_group(&mut self, flavor_group: u32) -> &mut Self {
+        self.data.flavor_groups.push(flavor_group);
+        self
+    }
+
+    pub async fn send(&self) -> Result<FlavorPriceRevise, ApiError> {
+        request(
+            &self.client,
+            Method::POST,
+            &self.url,
+            Some(&self.data),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn send_dry_run(
+        &self,
+    ) -> Result<DryRun<FlavorPriceRevise>, ApiError> {
+        let url = format!("{}?dry_run=true", self.url);
+        request(
+            &self.client,
+            Method::POST,
+            url.as_str(),
+            Some(&self.data),
+            StatusCode::OK,
+        )
+        .await
+    }
+}
+
 impl FlavorPriceApi {
     pub fn new(base_url: &str, client: &Rc<Client>) -> FlavorPriceApi {
         FlavorPriceApi {
@@ -220,6 +380,28 @@ impl FlavorPriceApi {
         Ok(())
     }
 
+    pub fn revise(
+        &self,
+        start_time: DateTime<FixedOffset>,
+    ) -> FlavorPriceReviseRequest {
+        let url = format!("{}/revise/", self.url);
+        FlavorPriceReviseRequest::new(url.as_ref(), &self.client, start_time)
+    }
+
+    pub fn estimate(
+        &self,
+        flavor: u32,
+        duration: u64,
+    ) -> FlavorPriceEstimateRequest {
+        let url = format!("{}/estimate/", self.url);
+        FlavorPriceEstimateRequest::new(
+            url.as_ref(),
+            &self.client,
+            flavor,
+            duration,
+        )
+    }
+
     pub async fn initialize(&self) -> Result<FlavorPriceInitialize, ApiError> {
         let url = format!("{}/initialize/", self.url);
         request(
@@ -232,3 +414,1251 @@ impl FlavorPriceApi {
         .await
     }
 }
+
+#[derive(Debug)]
+pub struct BillingPolicyApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct BillingPolicyListRequest {
+    url: String,
+    client: Rc<Client>,
+    params: BillingPolicyListParams,
+}
+
+impl BillingPolicyListRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub fn status(&mut self, status: &str) -> &mut Self {
+        self.params.status = Some(status.to_string());
+        self
+    }
+
+    pub fn user_class(&mut self, user_class: UserClass) -> &mut Self {
+        self.params.user_class = Some(user_class);
+        self
+    }
+
+    pub fn current(&mut self) -> &mut Self {
+        self.params.current = Some(true);
+        self
+    }
+
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
+    pub async fn send(&self) -> Result<Vec<BillingPolicy>, ApiError> {
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn page(&self) -> Result<Page<BillingPolicy>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<BillingPolicy>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<BillingPolicy>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+}
+
+pub struct BillingPolicyCreateRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: BillingPolicyCreateData,
+}
+
+impl BillingPolicyCreateRequest {
+    pub fn new(
+        url: &str,
+        client: &Rc<Client>,
+        status: String,
+        factor: f64,
+    ) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: BillingPolicyCreateData::new(status, factor),
+        }
+    }
+
+    pub fn user_class(&mut self, user_class: UserClass) -> &mut Self {
+        self.data.user_class = Some(user_class);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(&mut self, end_time: DateTime<FixedOffset>) -> &mut Self {
+        self.data.end_time = Some(end_time);
+        self
+    }
+
+    pub async fn send(&self) -> Result<BillingPolicy, ApiError> {
+        request(
+            &self.client,
+            Method::POST,
+            &self.url,
+            Some(&self.data),
+            StatusCode::CREATED,
+        )
+        .await
+    }
+}
+
+pub struct BillingPolicyModifyRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: BillingPolicyModifyData,
+}
+
+impl BillingPolicyModifyRequest {
+    pub fn new(url: &str, client: &Rc<Client>, id: u32) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: BillingPolicyModifyData::new(id),
+        }
+    }
+
+    pub fn status(&mut self, status: &str) -> &mut Self {
+        self.data.status = Some(status.to_string());
+        self
+    }
+
+    pub fn user_class(&mut self, user_class: UserClass) -> &mut Self {
+        self.data.user_class = Some(user_class);
+        self
+    }
+
+    pub fn factor(&mut self, factor: f64) -> &mut Self {
+        self.data.factor = Some(factor);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(&mut self, end_time: DateTime<FixedOffset>) -> &mut Self {
+        self.data.end_time = Some(end_time);
+        self
+    }
+
+    pub async fn send(&self) -> Result<BillingPolicy, ApiError> {
+        request(
+            &self.client,
+            Method::PATCH,
+            &self.url,
+            Some(&self.data),
+            StatusCode::OK,
+        )
+        .await
+    }
+}
+
+impl BillingPolicyApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> BillingPolicyApi {
+        BillingPolicyApi {
+            url: format!("{base_url}/pricing/billingpolicies"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn list(&self) -> BillingPolicyListRequest {
+        BillingPolicyListRequest::new(self.url.as_ref(), &self.client)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<BillingPolicy, ApiError> {
+        let url = format!("{}/{}", self.url, id);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn create(
+        &self,
+        status: &str,
+        factor: f64,
+    ) -> BillingPolicyCreateRequest {
+        let url = format!("{}/", self.url);
+        BillingPolicyCreateRequest::new(
+            url.as_ref(),
+            &self.client,
+            status.to_string(),
+            factor,
+        )
+    }
+
+    pub fn modify(&self, id: u32) -> BillingPolicyModifyRequest {
+        let url = format!("{}/{}/", self.url, id);
+        BillingPolicyModifyRequest::new(url.as_ref(), &self.client, id)
+    }
+
+    pub async fn delete(&self, id: u32) -> Result<(), ApiError> {
+        let url = format!("{}/{}/", self.url, id);
+        request_bare(
+            &self.client,
+            Method::DELETE,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::NO_CONTENT,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct VolumePriceApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct VolumePriceListRequest {
+    url: String,
+    client: Rc<Client>,
+    params: VolumePriceListParams,
+}
+
+impl VolumePriceListRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub fn user_class(&mut self, user_class: UserClass) -> &mut Self {
+        self.params.user_class = Some(user_class);
+        self
+    }
+
+    pub fn current(&mut self) -> &mut Self {
+        self.params.current = Some(true);
+        self
+    }
+
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
+    pub async fn send(&self) -> Result<Vec<VolumePrice>, ApiError> {
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn page(&self) -> Result<Page<VolumePrice>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<VolumePrice>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<VolumePrice>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+}
+
+pub struct VolumePriceCreateRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: VolumePriceCreateData,
+}
+
+impl VolumePriceCreateRequest {
+    pub fn new(url: &str, client: &Rc<Client>, user_class: UserClass) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: VolumePriceCreateData::new(user_class),
+        }
+    }
+
+    pub fn price(&mut self, price: f64) -> &mut Self {
+        self.data.price = Some(price);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub async fn send(&self) -> Result<VolumePrice, ApiError> {
+        request(
+            &self.client,
+            Method::POST,
+            &self.url,
+            Some(&self.data),
+            StatusCode::CREATED,
+        )
+        .await
+    }
+}
+
+pub struct VolumePriceModifyRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: VolumePriceModifyData,
+}
+
+impl VolumePriceModifyRequest {
+    pub fn new(url: &str, client: &Rc<Client>, id: u32) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: VolumePriceModifyData::new(id),
+        }
+    }
+
+    pub fn user_class(&mut self, user_class: UserClass) -> &mut Self {
+        self.data.user_class = Some(user_class);
+        self
+    }
+
+    pub fn unit_price(&mut self, unit_price: f64) -> &mut Self {
+        self.data.unit_price = Some(unit_price);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub async fn send(&self) -> Result<VolumePrice, ApiError> {
+        request(
+            &self.client,
+            Method::PATCH,
+            &self.url,
+            Some(&self.data),
+            StatusCode::OK,
+        )
+        .await
+    }
+}
+
+impl VolumePriceApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> VolumePriceApi {
+        VolumePriceApi {
+            url: format!("{base_url}/pricing/volumeprices"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn list(&self) -> VolumePriceListRequest {
+        VolumePriceListRequest::new(self.url.as_ref(), &self.client)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<VolumePrice, ApiError> {
+        let url = format!("{}/{}", self.url, id);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn create(&self, user_class: UserClass) -> VolumePriceCreateRequest {
+        let url = format!("{}/", self.url);
+        VolumePriceCreateRequest::new(url.as_ref(), &self.client, user_class)
+    }
+
+    pub fn modify(&self, id: u32) -> VolumePriceModifyRequest {
+        let url = format!("{}/{}/", self.url, id);
+        VolumePriceModifyRequest::new(url.as_ref(), &self.client, id)
+    }
+
+    pub async fn delete(&self, id: u32) -> Result<(), ApiError> {
+        let url = format!("{}/{}/", self.url, id);
+        request_bare(
+            &self.client,
+            Method::DELETE,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::NO_CONTENT,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct FloatingIpPriceApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct FloatingIpPriceListRequest {
+    url: String,
+    client: Rc<Client>,
+    params: FloatingIpPriceListParams,
+}
+
+impl FloatingIpPriceListRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub fn user_class(&mut self, user_class: UserClass) -> &mut Self {
+        self.params.user_class = Some(user_class);
+        self
+    }
+
+    pub fn pool(&mut self, pool: FloatingIpPool) -> &mut Self {
+        self.params.pool = Some(pool);
+        self
+    }
+
+    pub fn current(&mut self) -> &mut Self {
+        self.params.current = Some(true);
+        self
+    }
+
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
+    pub async fn send(&self) -> Result<Vec<FloatingIpPrice>, ApiError> {
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn page(&self) -> Result<Page<FloatingIpPrice>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<FloatingIpPrice>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<FloatingIpPrice>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+}
+
+pub struct FloatingIpPriceCreateRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: FloatingIpPriceCreateData,
+}
+
+impl FloatingIpPriceCreateRequest {
+    pub fn new(
+        url: &str,
+        client: &Rc<Client>,
+        user_class: UserClass,
+        pool: FloatingIpPool,
+    ) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: FloatingIpPriceCreateData::new(user_class, pool),
+        }
+    }
+
+    pub fn price(&mut self, price: f64) -> &mut Self {
+        self.data.price = Some(price);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub async fn send(&self) -> Result<FloatingIpPrice, ApiError> {
+        request(
+            &self.client,
+            Method::POST,
+            &self.url,
+            Some(&self.data),
+            StatusCode::CREATED,
+        )
+        .await
+    }
+}
+
+pub struct FloatingIpPriceModifyRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: FloatingIpPriceModifyData,
+}
+
+impl FloatingIpPriceModifyRequest {
+    pub fn new(url: &str, client: &Rc<Client>, id: u32) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: FloatingIpPriceModifyData::new(id),
+        }
+    }
+
+    pub fn user_class(&mut self, user_class: UserClass) -> &mut Self {
+        self.data.user_class = Some(user_class);
+        self
+    }
+
+    pub fn pool(&mut self, pool: FloatingIpPool) -> &mut Self {
+        self.data.pool = Some(pool);
+        self
+    }
+
+    pub fn unit_price(&mut self, unit_price: f64) -> &mut Self {
+        self.data.unit_price = Some(unit_price);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub async fn send(&self) -> Result<FloatingIpPrice, ApiError> {
+        request(
+            &self.client,
+            Method::PATCH,
+            &self.url,
+            Some(&self.data),
+            StatusCode::OK,
+        )
+        .await
+    }
+}
+
+impl FloatingIpPriceApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> FloatingIpPriceApi {
+        FloatingIpPriceApi {
+            url: format!("{base_url}/pricing/floatingipprices"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn list(&self) -> FloatingIpPriceListRequest {
+        FloatingIpPriceListRequest::new(self.url.as_ref(), &self.client)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<FloatingIpPrice, ApiError> {
+        let url = format!("{}/{}", self.url, id);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn create(
+        &self,
+        user_class: UserClass,
+        pool: FloatingIpPool,
+    ) -> FloatingIpPriceCreateRequest {
+        let url = format!("{}/", self.url);
+        FloatingIpPriceCreateRequest::new(
+            url.as_ref(),
+            &self.client,
+            user_class,
+            pool,
+        )
+    }
+
+    pub fn modify(&self, id: u32) -> FloatingIpPriceModifyRequest {
+        let url = format!("{}/{}/", self.url, id);
+        FloatingIpPriceModifyRequest::new(url.as_ref(), &self.client, id)
+    }
+
+    pub async fn delete(&self, id: u32) -> Result<(), ApiError> {
+        let url = format!("{}/{}/", self.url, id);
+        request_bare(
+            &self.client,
+            Method::DELETE,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::NO_CONTENT,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ResourcePriceApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct ResourcePriceListRequest {
+    url: String,
+    client: Rc<Client>,
+    params: ResourcePriceListParams,
+}
+
+impl ResourcePriceListRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub fn user_class(&mut self, user_class: UserClass) -> &mut Self {
+        self.params.user_class = Some(user_class);
+        self
+    }
+
+    pub fn current(&mut self) -> &mut Self {
+        self.params.current = Some(true);
+        self
+    }
+
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
+    pub async fn send(&self) -> Result<Vec<ResourcePrice>, ApiError> {
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn page(&self) -> Result<Page<ResourcePrice>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<ResourcePrice>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<ResourcePrice>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+}
+
+pub struct ResourcePriceCreateRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: ResourcePriceCreateData,
+}
+
+impl ResourcePriceCreateRequest {
+    pub fn new(url: &str, client: &Rc<Client>, user_class: UserClass) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: ResourcePriceCreateData::new(user_class),
+        }
+    }
+
+    pub fn vcpu_price(&mut self, vcpu_price: f64) -> &mut Self {
+        self.data.vcpu_price = Some(vcpu_price);
+        self
+    }
+
+    pub fn ram_price(&mut self, ram_price: f64) -> &mut Self {
+        self.data.ram_price = Some(ram_price);
+        self
+    }
+
+    pub fn gpu_price(&mut self, gpu_price: f64) -> &mut Self {
+        self.data.gpu_price = Some(gpu_price);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub async fn send(&self) -> Result<ResourcePrice, ApiError> {
+        request(
+            &self.client,
+            Method::POST,
+            &self.url,
+            Some(&self.data),
+            StatusCode::CREATED,
+        )
+        .await
+    }
+}
+
+pub struct ResourcePriceModifyRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: ResourcePriceModifyData,
+}
+
+impl ResourcePriceModifyRequest {
+    pub fn new(url: &str, client: &Rc<Client>, id: u32) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: ResourcePriceModifyData::new(id),
+        }
+    }
+
+    pub fn user_class(&mut self, user_class: UserClass) -> &mut Self {
+        self.data.user_class = Some(user_class);
+        self
+    }
+
+    pub fn vcpu_price(&mut self, vcpu_price: f64) -> &mut Self {
+        self.data.vcpu_price = Some(vcpu_price);
+        self
+    }
+
+    pub fn ram_price(&mut self, ram_price: f64) -> &mut Self {
+        self.data.ram_price = Some(ram_price);
+        self
+    }
+
+    pub fn gpu_price(&mut self, gpu_price: f64) -> &mut Self {
+        self.data.gpu_price = Some(gpu_price);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub async fn send(&self) -> Result<ResourcePrice, ApiError> {
+        request(
+            &self.client,
+            Method::PATCH,
+            &self.url,
+            Some(&self.data),
+            StatusCode::OK,
+        )
+        .await
+    }
+}
+
+impl ResourcePriceApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> ResourcePriceApi {
+        ResourcePriceApi {
+            url: format!("{base_url}/pricing/resourceprices"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn list(&self) -> ResourcePriceListRequest {
+        ResourcePriceListRequest::new(self.url.as_ref(), &self.client)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<ResourcePrice, ApiError> {
+        let url = format!("{}/{}", self.url, id);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn create(&self, user_class: UserClass) -> ResourcePriceCreateRequest {
+        let url = format!("{}/", self.url);
+        ResourcePriceCreateRequest::new(url.as_ref(), &self.client, user_class)
+    }
+
+    pub fn modify(&self, id: u32) -> ResourcePriceModifyRequest {
+        let url = format!("{}/{}/", self.url, id);
+        ResourcePriceModifyRequest::new(url.as_ref(), &self.client, id)
+    }
+
+    pub async fn delete(&self, id: u32) -> Result<(), ApiError> {
+        let url = format!("{}/{}/", self.url, id);
+        request_bare(
+            &self.client,
+            Method::DELETE,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::NO_CONTENT,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct PriceOverrideApi {
+    pub url: String,
+    pub client: Rc<Client>,
+}
+
+#[derive(Debug)]
+pub struct PriceOverrideListRequest {
+    url: String,
+    client: Rc<Client>,
+    params: PriceOverrideListParams,
+}
+
+impl PriceOverrideListRequest {
+    pub fn new(url: &str, client: &Rc<Client>) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            params: Default::default(),
+        }
+    }
+
+    pub fn project(&mut self, project: u32) -> &mut Self {
+        self.params.project = Some(project);
+        self
+    }
+
+    pub fn flavor(&mut self, flavor: u32) -> &mut Self {
+        self.params.flavor = Some(flavor);
+        self
+    }
+
+    pub fn current(&mut self) -> &mut Self {
+        self.params.current = Some(true);
+        self
+    }
+
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
+    pub async fn send(&self) -> Result<Vec<PriceOverride>, ApiError> {
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub async fn page(&self) -> Result<Page<PriceOverride>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<PriceOverride>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<PriceOverride>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+}
+
+pub struct PriceOverrideCreateRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: PriceOverrideCreateData,
+}
+
+impl PriceOverrideCreateRequest {
+    pub fn new(url: &str, client: &Rc<Client>, project: u32) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: PriceOverrideCreateData::new(project),
+        }
+    }
+
+    pub fn flavor(&mut self, flavor: u32) -> &mut Self {
+        self.data.flavor = Some(flavor);
+        self
+    }
+
+    pub fn discount(&mut self, discount: f64) -> &mut Self {
+        self.data.discount = Some(discount);
+        self
+    }
+
+    pub fn unit_price(&mut self, unit_price: f64) -> &mut Self {
+        self.data.unit_price = Some(unit_price);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(&mut self, end_time: DateTime<FixedOffset>) -> &mut Self {
+        self.data.end_time = Some(end_time);
+        self
+    }
+
+    pub async fn send(&self) -> Result<PriceOverride, ApiError> {
+        request(
+            &self.client,
+            Method::POST,
+            &self.url,
+            Some(&self.data),
+            StatusCode::CREATED,
+        )
+        .await
+    }
+}
+
+pub struct PriceOverrideModifyRequest {
+    url: String,
+    client: Rc<Client>,
+
+    data: PriceOverrideModifyData,
+}
+
+impl PriceOverrideModifyRequest {
+    pub fn new(url: &str, client: &Rc<Client>, id: u32) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Rc::clone(client),
+            data: PriceOverrideModifyData::new(id),
+        }
+    }
+
+    pub fn discount(&mut self, discount: f64) -> &mut Self {
+        self.data.discount = Some(discount);
+        self
+    }
+
+    pub fn unit_price(&mut self, unit_price: f64) -> &mut Self {
+        self.data.unit_price = Some(unit_price);
+        self
+    }
+
+    pub fn start_time(
+        &mut self,
+        start_time: DateTime<FixedOffset>,
+    ) -> &mut Self {
+        self.data.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(&mut self, end_time: DateTime<FixedOffset>) -> &mut Self {
+        self.data.end_time = Some(Some(end_time));
+        self
+    }
+
+    pub fn no_end_time(&mut self) -> &mut Self {
+        self.data.end_time = Some(None);
+        self
+    }
+
+    pub async fn send(&self) -> Result<PriceOverride, ApiError> {
+        request(
+            &self.client,
+            Method::PATCH,
+            &self.url,
+            Some(&self.data),
+            StatusCode::OK,
+        )
+        .await
+    }
+}
+
+impl PriceOverrideApi {
+    pub fn new(base_url: &str, client: &Rc<Client>) -> PriceOverrideApi {
+        PriceOverrideApi {
+            url: format!("{base_url}/pricing/priceoverrides"),
+            client: Rc::clone(client),
+        }
+    }
+
+    pub fn list(&self) -> PriceOverrideListRequest {
+        PriceOverrideListRequest::new(self.url.as_ref(), &self.client)
+    }
+
+    pub async fn get(&self, id: u32) -> Result<PriceOverride, ApiError> {
+        let url = format!("{}/{}", self.url, id);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn create(&self, project: u32) -> PriceOverrideCreateRequest {
+        let url = format!("{}/", self.url);
+        PriceOverrideCreateRequest::new(url.as_ref(), &self.client, project)
+    }
+
+    pub fn modify(&self, id: u32) -> PriceOverrideModifyRequest {
+        let url = format!("{}/{}/", self.url, id);
+        PriceOverrideModifyRequest::new(url.as_ref(), &self.client, id)
+    }
+
+    pub async fn delete(&self, id: u32) -> Result<(), ApiError> {
+        let url = format!("{}/{}/", self.url, id);
+        request_bare(
+            &self.client,
+            Method::DELETE,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::NO_CONTENT,
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -1,10 +1,13 @@
 use std::rc::Rc;
 
 use anyhow::Context;
-use avina_wire::resources::{
-    Flavor, FlavorCreateData, FlavorDetailed, FlavorImport, FlavorListParams,
-    FlavorModifyData, FlavorUsageAggregate, FlavorUsageParams,
-    FlavorUsageSimple,
+use avina_wire::{
+    dry_run::DryRun,
+    resources::{
+        Flavor, FlavorCreateData, FlavorDetailed, FlavorImport,
+        FlavorListParams, FlavorModifyData, FlavorUsageAggregate,
+        FlavorUsageParams, FlavorUsageSimple,
+    },
 };
 use reqwest::{Client, Method, StatusCode};
 
@@ -410,6 +413,20 @@ impl FlavorApi {
         .await
     }
 
+    pub async fn import_dry_run(
+        &self,
+    ) -> Result<DryRun<FlavorImport>, ApiError> {
+        let url = format!("{}/import/?dry_run=true", self.url);
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
     pub fn usage(&self) -> FlavorUsageRequest {
         let url = format!("{}/usage/", self.url);
         FlavorUsageRequest::new(url.as_ref(), &self.client)
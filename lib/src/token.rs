@@ -1,7 +1,7 @@
 use std::{convert::AsRef, str::FromStr};
 
 use anyhow::Context;
-use jzon::object;
+use jzon::{JsonValue, object};
 use reqwest::{
     Client, ClientBuilder,
     header::{CONTENT_TYPE, HeaderMap, HeaderValue},
@@ -29,14 +29,6 @@ impl Token {
         user_domain_name: &str,
         project_domain_id: &str,
     ) -> Result<Self, anyhow::Error> {
-        let mut headers = HeaderMap::new();
-        headers
-            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        let client = ClientBuilder::new()
-            .default_headers(headers)
-            .build()
-            .unwrap();
-        let url = format!("{auth_url}/auth/tokens/");
         let data = object! {
             "auth": {
                 "identity": {
@@ -57,6 +49,40 @@ impl Token {
                 }
             }
         };
+        Self::issue(auth_url, data).await
+    }
+
+    pub async fn from_application_credential(
+        auth_url: &str,
+        application_credential_id: &str,
+        application_credential_secret: &str,
+    ) -> Result<Self, anyhow::Error> {
+        let data = object! {
+            "auth": {
+                "identity": {
+                    "methods": ["application_credential"],
+                    "application_credential": {
+                        "id": application_credential_id,
+                        "secret": application_credential_secret,
+                    }
+                }
+            }
+        };
+        Self::issue(auth_url, data).await
+    }
+
+    async fn issue(
+        auth_url: &str,
+        data: JsonValue,
+    ) -> Result<Self, anyhow::Error> {
+        let mut headers = HeaderMap::new();
+        headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        let client = ClientBuilder::new()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+        let url = format!("{auth_url}/auth/tokens/");
         let response = match client
             .post(url.as_str())
             .body(data.to_string())
@@ -1,14 +1,21 @@
 use std::rc::Rc;
 
-use anyhow::Context;
-use avina_wire::user::{
-    User, UserCreateData, UserDetailed, UserImport, UserListParams,
-    UserModifyData, UserSync,
+use avina_wire::{
+    dry_run::DryRun,
+    pagination::Page,
+    user::{
+        User, UserCreateData, UserDetailed, UserImport, UserListParams,
+        UserModifyData, UserSync,
+    },
 };
+use futures::Stream;
 use reqwest::{Client, Method, StatusCode};
 
 use crate::{
-    common::{SerializableNone, request, request_bare},
+    common::{
+        DEFAULT_PAGE_SIZE, SerializableNone, list_url, request,
+        request_all_pages, request_bare, request_pages,
+    },
     error::ApiError,
 };
 
@@ -34,14 +41,26 @@ impl UserListRequest {
         }
     }
 
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.params.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u32) -> &mut Self {
+        self.params.offset = Some(offset);
+        self
+    }
+
+    pub fn ordering(&mut self, ordering: &str) -> &mut Self {
+        self.params.ordering = Some(ordering.to_string());
+        self
+    }
+
     pub async fn send(&self) -> Result<Vec<User>, ApiError> {
-        let params = serde_urlencoded::to_string(&self.params)
-            .context("Failed to encode URL parameters.")?;
-        let url = if params.is_empty() {
-            self.url.clone()
-        } else {
-            format!("{}?{}", self.url, params)
-        };
+        let url = list_url(&self.url, &self.params)?;
+        if self.params.limit.is_some() {
+            return Ok(self.page().await?.results);
+        }
         request(
             &self.client,
             Method::GET,
@@ -52,6 +71,36 @@ impl UserListRequest {
         .await
     }
 
+    pub async fn page(&self) -> Result<Page<User>, ApiError> {
+        request(
+            &self.client,
+            Method::GET,
+            self.paged_url()?.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
+    pub fn pages(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<Page<User>, ApiError>> + use<>,
+        ApiError,
+    > {
+        Ok(request_pages(&self.client, self.paged_url()?))
+    }
+
+    pub async fn all_pages(&self) -> Result<Vec<User>, ApiError> {
+        request_all_pages(&self.client, self.paged_url()?).await
+    }
+
+    fn paged_url(&self) -> Result<String, ApiError> {
+        let mut params = self.params.clone();
+        params.limit.get_or_insert(DEFAULT_PAGE_SIZE);
+        list_url(&self.url, &params)
+    }
+
     pub fn all(&mut self) -> &mut Self {
         self.params.all = Some(true);
         self
@@ -264,6 +313,24 @@ impl UserApi {
         .await
     }
 
+    pub async fn import_dry_run(&self) -> Result<DryRun<UserImport>, ApiError> {
+        let url = format!(
+            "{}/import?dry_run=true",
+            self.url
+                .rfind('/')
+                .map(|i| &self.url[..i])
+                .unwrap_or(&self.url)
+        );
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
+
     pub async fn sync(&self) -> Result<UserSync, ApiError> {
         let url = format!(
             "{}/sync",
@@ -281,4 +348,22 @@ impl UserApi {
         )
         .await
     }
+
+    pub async fn sync_dry_run(&self) -> Result<DryRun<UserSync>, ApiError> {
+        let url = format!(
+            "{}/sync?dry_run=true",
+            self.url
+                .rfind('/')
+                .map(|i| &self.url[..i])
+                .unwrap_or(&self.url)
+        );
+        request(
+            &self.client,
+            Method::GET,
+            url.as_str(),
+            SerializableNone!(),
+            StatusCode::OK,
+        )
+        .await
+    }
 }
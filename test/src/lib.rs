@@ -2,16 +2,26 @@ use std::ops::Range;
 
 use anyhow::Context;
 use avina_api::{
-    configuration::{DatabaseSettings, get_configuration},
+    configuration::{DatabaseSettings, Settings, get_configuration},
     database::{
-        accounting::server_state::{
-            NewServerState, insert_server_state_into_db,
+        accounting::{
+            floating_ip_state::{
+                NewFloatingIpState, insert_floating_ip_state_into_db,
+            },
+            server_state::{NewServerState, insert_server_state_into_db},
+            volume_state::{NewVolumeState, insert_volume_state_into_db},
         },
         budgeting::{
             project_budget::{NewProjectBudget, insert_project_budget_into_db},
             user_budget::{NewUserBudget, insert_user_budget_into_db},
         },
-        pricing::flavor_price::{NewFlavorPrice, insert_flavor_price_into_db},
+        pricing::{
+            flavor_price::{NewFlavorPrice, insert_flavor_price_into_db},
+            floating_ip_price::{
+                NewFloatingIpPrice, insert_floating_ip_price_into_db,
+            },
+            volume_price::{NewVolumePrice, insert_volume_price_into_db},
+        },
         quota::flavor_quota::insert_flavor_quota_into_db,
         resources::{
             flavor::insert_flavor_into_db,
@@ -23,14 +33,14 @@ use avina_api::{
     telemetry::{get_subscriber, init_subscriber},
 };
 use avina_wire::{
-    accounting::ServerState,
+    accounting::{FloatingIpState, ServerState, VolumeState},
     budgeting::{ProjectBudget, UserBudget},
-    pricing::FlavorPrice,
+    pricing::{FlavorPrice, FloatingIpPrice, VolumePrice},
     quota::{FlavorQuota, FlavorQuotaCreateData},
     resources::{Flavor, FlavorCreateData, FlavorGroup, FlavorGroupCreateData},
     user::{Project, User, UserClass},
 };
-use chrono::{DateTime, Datelike, FixedOffset, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, TimeDelta, Utc};
 use once_cell::sync::Lazy;
 use rand::{Rng, distr::Alphanumeric, rng};
 use serde_json::json;
@@ -102,7 +112,11 @@ impl TestApp {
                             "project": {
                                 "id": os_project_id,
                                 "name": os_project_name,
-                            }
+                            },
+                            "issued_at": Utc::now().to_rfc3339(),
+                            "expires_at": (Utc::now() + TimeDelta::hours(1))
+                                .to_rfc3339(),
+                            "audit_ids": [Uuid::new_v4().simple().to_string()],
                         }
                     })),
             )
@@ -394,6 +408,69 @@ impl TestApp {
         Ok(server_state)
     }
 
+    pub async fn setup_test_volume_state_with_volume_state(
+        &self,
+        user: &User,
+        new_volume_state: NewVolumeState,
+    ) -> Result<VolumeState, MinimalApiError> {
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .expect("Failed to begin transaction.");
+        let volume_state_id =
+            insert_volume_state_into_db(&mut transaction, &new_volume_state)
+                .await? as u32;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit transaction")?;
+        let volume_state = VolumeState {
+            id: volume_state_id,
+            begin: new_volume_state.begin.fixed_offset(),
+            end: new_volume_state.end.map(|end| end.fixed_offset()),
+            volume_id: new_volume_state.volume_id,
+            volume_name: new_volume_state.volume_name,
+            status: new_volume_state.status,
+            size: new_volume_state.size,
+            user: user.id,
+            username: user.name.clone(),
+        };
+        Ok(volume_state)
+    }
+
+    pub async fn setup_test_floating_ip_state_with_floating_ip_state(
+        &self,
+        user: &User,
+        new_floating_ip_state: NewFloatingIpState,
+    ) -> Result<FloatingIpState, MinimalApiError> {
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .expect("Failed to begin transaction.");
+        let floating_ip_state_id = insert_floating_ip_state_into_db(
+            &mut transaction,
+            &new_floating_ip_state,
+        )
+        .await? as u32;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit transaction")?;
+        let floating_ip_state = FloatingIpState {
+            id: floating_ip_state_id,
+            begin: new_floating_ip_state.begin.fixed_offset(),
+            end: new_floating_ip_state.end.map(|end| end.fixed_offset()),
+            floating_ip_id: new_floating_ip_state.floating_ip_id,
+            address: new_floating_ip_state.address,
+            pool: new_floating_ip_state.pool,
+            user: user.id,
+            username: user.name.clone(),
+        };
+        Ok(floating_ip_state)
+    }
+
     pub async fn setup_test_user_budget(
         &self,
         user: &User,
@@ -529,6 +606,59 @@ impl TestApp {
         Ok(flavor_price)
     }
 
+    pub async fn setup_test_volume_price_with_new_volume_price(
+        &self,
+        new_volume_price: NewVolumePrice,
+    ) -> Result<VolumePrice, MinimalApiError> {
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .expect("Failed to begin transaction.");
+        let volume_price_id =
+            insert_volume_price_into_db(&mut transaction, &new_volume_price)
+                .await? as u32;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit transaction")?;
+        let volume_price = VolumePrice {
+            id: volume_price_id,
+            user_class: new_volume_price.user_class,
+            unit_price: new_volume_price.unit_price,
+            start_time: new_volume_price.start_time.into(),
+        };
+        Ok(volume_price)
+    }
+
+    pub async fn setup_test_floating_ip_price_with_new_floating_ip_price(
+        &self,
+        new_floating_ip_price: NewFloatingIpPrice,
+    ) -> Result<FloatingIpPrice, MinimalApiError> {
+        let mut transaction = self
+            .db_pool
+            .begin()
+            .await
+            .expect("Failed to begin transaction.");
+        let floating_ip_price_id = insert_floating_ip_price_into_db(
+            &mut transaction,
+            &new_floating_ip_price,
+        )
+        .await? as u32;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit transaction")?;
+        let floating_ip_price = FloatingIpPrice {
+            id: floating_ip_price_id,
+            user_class: new_floating_ip_price.user_class,
+            pool: new_floating_ip_price.pool,
+            unit_price: new_floating_ip_price.unit_price,
+            start_time: new_floating_ip_price.start_time.into(),
+        };
+        Ok(floating_ip_price)
+    }
+
     pub async fn setup_test_flavor_quota(
         &self,
         flavor_group: &FlavorGroup,
@@ -564,6 +694,12 @@ impl TestApp {
 }
 
 pub async fn spawn_app() -> TestApp {
+    spawn_app_with_configuration(|_| {}).await
+}
+
+pub async fn spawn_app_with_configuration(
+    configure: impl FnOnce(&mut Settings),
+) -> TestApp {
     Lazy::force(&TRACING);
 
     let keystone_server = MockServer::start().await;
@@ -575,6 +711,7 @@ pub async fn spawn_app() -> TestApp {
         c.application.port = 0;
         c.openstack.keystone_endpoint = keystone_server.uri();
         c.application.insert_admin = false;
+        configure(&mut c);
         c
     };
 
@@ -589,6 +726,13 @@ pub async fn spawn_app() -> TestApp {
         .mount(&keystone_server)
         .await;
     // TODO: check data sent to keystone
+    Mock::given(method("GET"))
+        .and(path("/OS-REVOKE/events"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({"events": []})),
+        )
+        .mount(&keystone_server)
+        .await;
 
     let application = Application::build(configuration.clone())
         .await
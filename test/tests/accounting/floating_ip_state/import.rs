@@ -0,0 +1,337 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::accounting::floating_ip_state::NewFloatingIpState;
+use avina_test::{TestApp, spawn_app_with_configuration};
+use avina_wire::{
+    accounting::{FloatingIpPool, FloatingIpState, FloatingIpStateImport},
+    dry_run::{ChangeAction, ChangeObject},
+    user::User,
+};
+use chrono::Utc;
+use serde_json::{Value, json};
+use uuid::Uuid;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+const MWN_NETWORK_ID: &str = "mwn-network";
+const INTERNET_NETWORK_ID: &str = "internet-network";
+
+fn floating_ip(
+    floating_ip_id: Uuid,
+    address: &str,
+    network_id: &str,
+    user: &User,
+) -> Value {
+    json!({
+        "id": floating_ip_id,
+        "floating_ip_address": address,
+        "floating_network_id": network_id,
+        "project_id": user.openstack_id,
+        "status": "ACTIVE",
+        "port_id": null,
+        "fixed_ip_address": null,
+        "description": "",
+    })
+}
+
+async fn spawn_app_with_neutron() -> (TestApp, MockServer) {
+    let neutron_server = MockServer::start().await;
+    let neutron_endpoint = neutron_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.neutron_endpoint = neutron_endpoint;
+        c.openstack.mwn_network_id = MWN_NETWORK_ID.to_string();
+        c.openstack.internet_network_id = INTERNET_NETWORK_ID.to_string();
+    })
+    .await;
+    (server, neutron_server)
+}
+
+async fn mock_neutron_floating_ips(
+    neutron_server: &MockServer,
+    floating_ips: Vec<Value>,
+) {
+    Mock::given(method("GET"))
+        .and(path("/v2.0/floatingips"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"floatingips": floating_ips})),
+        )
+        .mount(neutron_server)
+        .await;
+}
+
+async fn setup_test_floating_ip_state(
+    server: &TestApp,
+    user: &User,
+    address: &str,
+    pool: FloatingIpPool,
+) -> FloatingIpState {
+    server
+        .setup_test_floating_ip_state_with_floating_ip_state(
+            user,
+            NewFloatingIpState {
+                begin: Utc::now(),
+                end: None,
+                floating_ip_id: Uuid::new_v4(),
+                address: address.to_string(),
+                pool,
+                user: user.id,
+            },
+        )
+        .await
+        .expect("Failed to setup test floating IP state")
+}
+
+#[tokio::test]
+async fn e2e_lib_floating_ip_state_import_creates_states_per_pool() {
+    // arrange
+    let (server, neutron_server) = spawn_app_with_neutron().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let mwn_id = Uuid::new_v4();
+    let internet_id = Uuid::new_v4();
+    mock_neutron_floating_ips(
+        &neutron_server,
+        vec![
+            floating_ip(mwn_id, "10.195.0.1", MWN_NETWORK_ID, &user),
+            floating_ip(internet_id, "138.246.0.1", INTERNET_NETWORK_ID, &user),
+            floating_ip(Uuid::new_v4(), "192.168.0.1", "other-network", &user),
+        ],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.floating_ip_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        FloatingIpStateImport {
+            new_state_count: 2,
+            end_state_count: 0,
+        }
+    );
+    let mwn_states = client
+        .floating_ip_state
+        .list()
+        .all()
+        .pool(FloatingIpPool::Mwn)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(mwn_states.len(), 1);
+    assert_eq!(mwn_states[0].floating_ip_id, mwn_id);
+    assert_eq!(mwn_states[0].address, "10.195.0.1");
+    assert_eq!(mwn_states[0].user, user.id);
+    let internet_states = client
+        .floating_ip_state
+        .list()
+        .all()
+        .pool(FloatingIpPool::Internet)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(internet_states.len(), 1);
+    assert_eq!(internet_states[0].floating_ip_id, internet_id);
+}
+
+#[tokio::test]
+async fn e2e_lib_floating_ip_state_import_keeps_unchanged_floating_ip_state() {
+    // arrange
+    let (server, neutron_server) = spawn_app_with_neutron().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let floating_ip_state = setup_test_floating_ip_state(
+        &server,
+        &user,
+        "138.246.0.1",
+        FloatingIpPool::Internet,
+    )
+    .await;
+    mock_neutron_floating_ips(
+        &neutron_server,
+        vec![floating_ip(
+            floating_ip_state.floating_ip_id,
+            &floating_ip_state.address,
+            INTERNET_NETWORK_ID,
+            &user,
+        )],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.floating_ip_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        FloatingIpStateImport {
+            new_state_count: 0,
+            end_state_count: 0,
+        }
+    );
+    let floating_ip_states =
+        client.floating_ip_state.list().all().send().await.unwrap();
+    assert_eq!(floating_ip_states.len(), 1);
+    assert_eq!(floating_ip_states[0].end, None);
+}
+
+#[tokio::test]
+async fn e2e_lib_floating_ip_state_import_detects_reassignment() {
+    // arrange
+    let (server, neutron_server) = spawn_app_with_neutron().await;
+    let test_project = server
+        .setup_test_project(1, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    let other_user = test_project.normals[0].user.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let floating_ip_state = setup_test_floating_ip_state(
+        &server,
+        &user,
+        "10.195.0.1",
+        FloatingIpPool::Mwn,
+    )
+    .await;
+    mock_neutron_floating_ips(
+        &neutron_server,
+        vec![floating_ip(
+            floating_ip_state.floating_ip_id,
+            &floating_ip_state.address,
+            MWN_NETWORK_ID,
+            &other_user,
+        )],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.floating_ip_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        FloatingIpStateImport {
+            new_state_count: 1,
+            end_state_count: 1,
+        }
+    );
+    let old = client
+        .floating_ip_state
+        .get(floating_ip_state.id)
+        .await
+        .unwrap();
+    assert!(old.end.is_some());
+    assert_eq!(old.user, user.id);
+    let floating_ip_states = client
+        .floating_ip_state
+        .list()
+        .floating_ip(floating_ip_state.floating_ip_id)
+        .send()
+        .await
+        .unwrap();
+    let new = floating_ip_states
+        .iter()
+        .find(|s| s.end.is_none())
+        .expect("No unfinished floating IP state");
+    assert_eq!(new.user, other_user.id);
+    assert_eq!(new.pool, FloatingIpPool::Mwn);
+}
+
+#[tokio::test]
+async fn e2e_lib_floating_ip_state_import_ends_released_floating_ip() {
+    // arrange
+    let (server, neutron_server) = spawn_app_with_neutron().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let floating_ip_state = setup_test_floating_ip_state(
+        &server,
+        &user,
+        "138.246.0.1",
+        FloatingIpPool::Internet,
+    )
+    .await;
+    mock_neutron_floating_ips(&neutron_server, vec![]).await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let dry_run = client.floating_ip_state.import_dry_run().await.unwrap();
+    let import = client.floating_ip_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(dry_run.summary, import);
+    assert_eq!(dry_run.changes.len(), 1);
+    assert_eq!(dry_run.changes[0].action, ChangeAction::End);
+    assert_eq!(dry_run.changes[0].object, ChangeObject::FloatingIpState);
+    assert_eq!(
+        import,
+        FloatingIpStateImport {
+            new_state_count: 0,
+            end_state_count: 1,
+        }
+    );
+    let old = client
+        .floating_ip_state
+        .get(floating_ip_state.id)
+        .await
+        .unwrap();
+    assert!(old.end.is_some());
+}
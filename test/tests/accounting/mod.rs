@@ -1,2 +1,4 @@
+mod floating_ip_state;
 mod server_cost;
 mod server_state;
+mod volume_state;
@@ -2,11 +2,20 @@ use std::str::FromStr;
 
 use avina::{Api, Token};
 use avina_api::database::{
-    accounting::server_state::NewServerState,
-    pricing::flavor_price::NewFlavorPrice,
+    accounting::{
+        floating_ip_state::NewFloatingIpState, server_state::NewServerState,
+        volume_state::NewVolumeState,
+    },
+    pricing::{
+        flavor_price::NewFlavorPrice, floating_ip_price::NewFloatingIpPrice,
+        volume_price::NewVolumePrice,
+    },
 };
 use avina_test::{random_alphanumeric_string, random_uuid, spawn_app};
-use avina_wire::user::{Project, UserClass};
+use avina_wire::{
+    accounting::FloatingIpPool,
+    user::{Project, UserClass},
+};
 use chrono::{Datelike, TimeZone, Utc};
 use uuid::Uuid;
 
@@ -603,3 +612,271 @@ async fn e2e_lib_server_cost_all() {
 
     assert_eq!(cost.total.round(), 1666.0);
 }
+
+#[tokio::test]
+async fn e2e_lib_server_cost_for_user_detail_includes_volumes() {
+    // arrange
+    let server = spawn_app().await;
+    let user_class = UserClass::UC1;
+    let project_new = Project {
+        id: 1,
+        name: random_alphanumeric_string(10),
+        openstack_id: random_uuid(),
+        user_class,
+    };
+    let test_project = server
+        .setup_test_project_with_project(0, 1, 0, project_new)
+        .await
+        .expect("Failed to setup test project");
+    let master_user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &master_user.openstack_id,
+            &master_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let begin = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2023, 1, 11, 0, 0, 0).unwrap();
+    server
+        .setup_test_volume_price_with_new_volume_price(NewVolumePrice {
+            user_class,
+            unit_price: 365.0,
+            start_time: begin,
+        })
+        .await
+        .expect("Failed to setup test volume price");
+    server
+        .setup_test_volume_state_with_volume_state(
+            &master_user,
+            NewVolumeState {
+                begin,
+                end: Some(end),
+                volume_id: Uuid::new_v4(),
+                volume_name: random_alphanumeric_string(10),
+                status: "in-use".to_string(),
+                size: 10,
+                user: master_user.id,
+            },
+        )
+        .await
+        .expect("Failed to setup test volume state");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let cost = client
+        .server_cost
+        .get()
+        .begin(begin.into())
+        .end(end.into())
+        .user_detail(master_user.id)
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(cost.volumes.round(), 100.0);
+    assert_eq!(cost.total.round(), 100.0);
+}
+
+#[tokio::test]
+async fn e2e_lib_server_cost_for_project_detail_includes_floating_ips() {
+    // arrange
+    let server = spawn_app().await;
+    let user_class = UserClass::UC1;
+    let project_new = Project {
+        id: 1,
+        name: random_alphanumeric_string(10),
+        openstack_id: random_uuid(),
+        user_class,
+    };
+    let test_project = server
+        .setup_test_project_with_project(0, 1, 0, project_new)
+        .await
+        .expect("Failed to setup test project");
+    let master_user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &master_user.openstack_id,
+            &master_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let begin = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2023, 1, 11, 0, 0, 0).unwrap();
+    for (pool, unit_price, address) in [
+        (FloatingIpPool::Mwn, 36.5, "10.195.0.1"),
+        (FloatingIpPool::Internet, 365.0, "138.246.0.1"),
+    ] {
+        server
+            .setup_test_floating_ip_price_with_new_floating_ip_price(
+                NewFloatingIpPrice {
+                    user_class,
+                    pool,
+                    unit_price,
+                    start_time: begin,
+                },
+            )
+            .await
+            .expect("Failed to setup test floating IP price");
+        server
+            .setup_test_floating_ip_state_with_floating_ip_state(
+                &master_user,
+                NewFloatingIpState {
+                    begin,
+                    end: Some(end),
+                    floating_ip_id: Uuid::new_v4(),
+                    address: address.to_string(),
+                    pool,
+                    user: master_user.id,
+                },
+            )
+            .await
+            .expect("Failed to setup test floating IP state");
+    }
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let cost = client
+        .server_cost
+        .get()
+        .begin(begin.into())
+        .end(end.into())
+        .project_detail(test_project.project.id)
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(cost.floating_ips.round(), 11.0);
+    assert_eq!(cost.total.round(), 11.0);
+    assert_eq!(cost.users[&master_user.name].floating_ips.round(), 11.0);
+}
+
+#[tokio::test]
+async fn e2e_lib_server_cost_for_project_detail_applies_price_overrides() {
+    // arrange
+    let server = spawn_app().await;
+    let user_class = UserClass::UC1;
+    let project_new = Project {
+        id: 1,
+        name: random_alphanumeric_string(10),
+        openstack_id: random_uuid(),
+        user_class,
+    };
+    let test_project = server
+        .setup_test_project_with_project(1, 0, 0, project_new)
+        .await
+        .expect("Failed to setup test project");
+    let admin = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &admin.openstack_id, &admin.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let begin = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2023, 1, 3, 0, 0, 0).unwrap();
+    // 8760 EUR per year are 1 EUR per hour
+    server
+        .setup_test_flavor_price_with_new_flavor_price(
+            &flavor,
+            NewFlavorPrice {
+                flavor_id: flavor.id as u64,
+                user_class,
+                unit_price: 8760.0,
+                start_time: begin,
+            },
+        )
+        .await
+        .expect("Failed to setup test flavor price");
+    server
+        .setup_test_server_state_with_server_state(
+            &flavor,
+            &admin,
+            NewServerState {
+                begin,
+                end: Some(end),
+                instance_id: Uuid::new_v4(),
+                instance_name: random_alphanumeric_string(10),
+                flavor: flavor.id,
+                status: "ACTIVE".to_string(),
+                user: admin.id,
+            },
+        )
+        .await
+        .expect("Failed to setup test server state");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    client
+        .price_override
+        .create(test_project.project.id)
+        .discount(50.)
+        .start_time(Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap().into())
+        .send()
+        .await
+        .expect("Failed to create discount");
+    // the flavor price takes precedence over the discount for 6 hours
+    client
+        .price_override
+        .create(test_project.project.id)
+        .flavor(flavor.id)
+        .unit_price(0.)
+        .start_time(Utc.with_ymd_and_hms(2023, 1, 2, 12, 0, 0).unwrap().into())
+        .end_time(Utc.with_ymd_and_hms(2023, 1, 2, 18, 0, 0).unwrap().into())
+        .send()
+        .await
+        .expect("Failed to create flavor price override");
+
+    // act
+    let cost = client
+        .server_cost
+        .get()
+        .begin(begin.into())
+        .end(end.into())
+        .project_detail(test_project.project.id)
+        .await
+        .unwrap();
+    let tree = client
+        .budget_over_tree
+        .get()
+        .project(test_project.project.id)
+        .end(end.into())
+        .send()
+        .await
+        .unwrap();
+
+    // assert
+    assert!((cost.total - (24.0 + 6.0 + 3.0)).abs() < 1e-6);
+    assert!((cost.discount - (6.0 + 6.0 + 3.0)).abs() < 1e-6);
+    assert!((cost.flavors[&flavor.name] - cost.total).abs() < 1e-6);
+    assert!((cost.users[&admin.name].discount - cost.discount).abs() < 1e-6);
+    let tree_project = &tree.projects[&test_project.project.name];
+    assert!((tree_project.cost - cost.total).abs() < 1e-6);
+    assert!((tree_project.discount - cost.discount).abs() < 1e-6);
+    assert!(
+        (tree_project.users[&admin.name].discount - cost.discount).abs() < 1e-6
+    );
+}
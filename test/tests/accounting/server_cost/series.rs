@@ -0,0 +1,262 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::{
+    accounting::server_state::NewServerState,
+    pricing::flavor_price::NewFlavorPrice,
+};
+use avina_test::{random_alphanumeric_string, random_uuid, spawn_app};
+use avina_wire::{
+    accounting::ServerCostInterval,
+    user::{Project, UserClass},
+};
+use chrono::{TimeZone, Utc};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn e2e_lib_server_cost_series_splits_price_change_within_month() {
+    // arrange
+    let server = spawn_app().await;
+    let user_class = UserClass::UC1;
+    let project_new = Project {
+        id: 1,
+        name: random_alphanumeric_string(10),
+        openstack_id: random_uuid(),
+        user_class,
+    };
+    let test_project = server
+        .setup_test_project_with_project(0, 1, 0, project_new)
+        .await
+        .expect("Failed to setup test project");
+    let master_user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &master_user.openstack_id,
+            &master_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let begin = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let price_change = Utc.with_ymd_and_hms(2023, 2, 15, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+    for (unit_price, start_time) in [(365.0, begin), (730.0, price_change)] {
+        server
+            .setup_test_flavor_price_with_new_flavor_price(
+                &flavor,
+                NewFlavorPrice {
+                    flavor_id: flavor.id as u64,
+                    user_class,
+                    unit_price,
+                    start_time,
+                },
+            )
+            .await
+            .expect("Failed to setup test flavor price");
+    }
+    let instance_id = Uuid::new_v4();
+    server
+        .setup_test_server_state_with_server_state(
+            &flavor,
+            &master_user,
+            NewServerState {
+                begin,
+                end: Some(end),
+                instance_id,
+                instance_name: random_alphanumeric_string(10),
+                flavor: flavor.id,
+                status: "ACTIVE".to_string(),
+                user: master_user.id,
+            },
+        )
+        .await
+        .expect("Failed to setup test server state");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let series = client
+        .server_cost
+        .series()
+        .begin(begin.into())
+        .end(end.into())
+        .interval(ServerCostInterval::Month)
+        .server(instance_id)
+        .await
+        .unwrap();
+    let total = client
+        .server_cost
+        .get()
+        .begin(begin.into())
+        .end(end.into())
+        .server(instance_id)
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0].begin, begin);
+    assert_eq!(
+        series[0].end,
+        Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap()
+    );
+    assert_eq!(series[1].end, end);
+    assert_eq!(series[0].consumption, (31 * 24 * 60 * 60) as f64);
+    assert_eq!(series[1].consumption, (28 * 24 * 60 * 60) as f64);
+    assert_eq!(series[0].cost.round(), 31.0);
+    assert_eq!(series[1].cost.round(), 14.0 + 28.0);
+    let sum = series.iter().map(|bucket| bucket.cost).sum::<f64>();
+    assert!((sum - total.total).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn e2e_lib_server_cost_series_aligns_weeks_to_monday() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let normal_user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &normal_user.openstack_id,
+            &normal_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    // 2023-03-01 is a Wednesday
+    let begin = Utc.with_ymd_and_hms(2023, 3, 1, 12, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2023, 3, 15, 0, 0, 0).unwrap();
+
+    // act
+    let series = client
+        .server_cost
+        .series()
+        .begin(begin.into())
+        .end(end.into())
+        .interval(ServerCostInterval::Week)
+        .mine()
+        .await
+        .unwrap();
+
+    // assert
+    let boundaries = series
+        .iter()
+        .map(|bucket| (bucket.begin, bucket.end))
+        .collect::<Vec<_>>();
+    let monday = Utc.with_ymd_and_hms(2023, 3, 6, 0, 0, 0).unwrap();
+    let next_monday = Utc.with_ymd_and_hms(2023, 3, 13, 0, 0, 0).unwrap();
+    assert_eq!(
+        boundaries,
+        vec![
+            (begin.fixed_offset(), monday.fixed_offset()),
+            (monday.fixed_offset(), next_monday.fixed_offset()),
+            (next_monday.fixed_offset(), end.fixed_offset()),
+        ]
+    );
+    assert!(
+        series
+            .iter()
+            .all(|bucket| bucket.consumption == 0.0 && bucket.cost == 0.0)
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_server_cost_series_for_all_requires_admin() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 1, 0)
+        .await
+        .expect("Failed to setup test project");
+    let master_user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &master_user.openstack_id,
+            &master_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let series = client
+        .server_cost
+        .series()
+        .interval(ServerCostInterval::Day)
+        .all()
+        .await;
+
+    // assert
+    assert!(series.is_err());
+}
+
+#[tokio::test]
+async fn e2e_lib_server_cost_series_rejects_too_many_buckets() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let begin = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2010, 1, 1, 0, 0, 0).unwrap();
+
+    // act
+    let series = client
+        .server_cost
+        .series()
+        .begin(begin.into())
+        .end(end.into())
+        .interval(ServerCostInterval::Day)
+        .mine()
+        .await;
+
+    // assert
+    assert!(series.is_err());
+    assert_eq!(
+        series.unwrap_err().to_string(),
+        "Period must not span more than 1000 buckets"
+    );
+}
@@ -0,0 +1,175 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::accounting::server_state::NewServerState;
+use avina_test::{TestApp, random_alphanumeric_string, spawn_app};
+use avina_wire::{
+    accounting::{ServerState, ServerStateAnomalyKind},
+    resources::Flavor,
+    user::User,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use uuid::Uuid;
+
+async fn setup_server_state(
+    server: &TestApp,
+    flavor: &Flavor,
+    user: &User,
+    instance_id: Uuid,
+    begin: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+) -> ServerState {
+    server
+        .setup_test_server_state_with_server_state(
+            flavor,
+            user,
+            NewServerState {
+                begin,
+                end,
+                instance_id,
+                instance_name: random_alphanumeric_string(10),
+                flavor: flavor.id,
+                status: "ACTIVE".to_string(),
+                user: user.id,
+            },
+        )
+        .await
+        .expect("Failed to setup test server state")
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_check_denies_access_to_normal_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let check = client.server_state.check().await;
+    let repair = client.server_state.repair().await;
+
+    // assert
+    assert_eq!(check.unwrap_err().to_string(), "Admin privileges required");
+    assert_eq!(repair.unwrap_err().to_string(), "Admin privileges required");
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_check_and_repair_work() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let now = Utc::now();
+    let hour = TimeDelta::hours(1);
+    let server1 = Uuid::new_v4();
+    let unfinished1 = setup_server_state(
+        &server,
+        &flavor,
+        &user,
+        server1,
+        now - hour * 3,
+        None,
+    )
+    .await;
+    let unfinished2 =
+        setup_server_state(&server, &flavor, &user, server1, now - hour, None)
+            .await;
+    let server2 = Uuid::new_v4();
+    let before_gap = setup_server_state(
+        &server,
+        &flavor,
+        &user,
+        server2,
+        now - hour * 3,
+        Some(now - hour * 2),
+    )
+    .await;
+    let after_gap =
+        setup_server_state(&server, &flavor, &user, server2, now - hour, None)
+            .await;
+    let server3 = Uuid::new_v4();
+    let inverted = setup_server_state(
+        &server,
+        &flavor,
+        &user,
+        server3,
+        now - hour,
+        Some(now - hour * 2),
+    )
+    .await;
+    let consistent =
+        setup_server_state(&server, &flavor, &user, Uuid::new_v4(), now, None)
+            .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let anomalies = client.server_state.check().await.unwrap();
+    let repaired = client.server_state.repair().await.unwrap();
+    let remaining = client.server_state.check().await.unwrap();
+
+    // assert
+    assert_eq!(anomalies.len(), 3);
+    let unfinished =
+        anomalies.iter().find(|a| a.instance_id == server1).unwrap();
+    assert_eq!(unfinished.kind, ServerStateAnomalyKind::MultipleUnfinished);
+    assert_eq!(unfinished.server_state, unfinished1.id);
+    assert_eq!(unfinished.other_server_state, Some(unfinished2.id));
+    assert!(unfinished.repairable);
+    let gap = anomalies.iter().find(|a| a.instance_id == server2).unwrap();
+    assert_eq!(gap.kind, ServerStateAnomalyKind::Gap);
+    assert_eq!(gap.server_state, before_gap.id);
+    assert_eq!(gap.other_server_state, Some(after_gap.id));
+    assert!(!gap.repairable);
+    let end_before_begin =
+        anomalies.iter().find(|a| a.instance_id == server3).unwrap();
+    assert_eq!(
+        end_before_begin.kind,
+        ServerStateAnomalyKind::EndBeforeBegin
+    );
+    assert_eq!(end_before_begin.server_state, inverted.id);
+    assert!(!end_before_begin.repairable);
+    assert!(!anomalies.iter().any(|a| a.server_state == consistent.id));
+
+    assert_eq!(repaired, vec![unfinished.clone()]);
+    let repaired_state = client.server_state.get(unfinished1.id).await.unwrap();
+    assert_eq!(
+        repaired_state.end.map(|end| end.timestamp()),
+        Some(unfinished2.begin.timestamp())
+    );
+
+    assert_eq!(remaining.len(), 2);
+    assert!(!remaining.iter().any(|a| a.instance_id == server1));
+}
@@ -0,0 +1,538 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::{
+    TestApp, random_alphanumeric_string, spawn_app_with_configuration,
+};
+use avina_wire::{
+    accounting::{ServerState, ServerStateImport},
+    dry_run::{ChangeAction, ChangeObject},
+    resources::Flavor,
+    user::User,
+};
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, query_param, query_param_is_missing},
+};
+
+fn server_detailed(state: &ServerState, flavor: &Flavor, user: &User) -> Value {
+    json!({
+        "id": state.instance_id,
+        "name": state.instance_name,
+        "status": state.status,
+        "tenant_id": user.openstack_id,
+        "user_id": random_alphanumeric_string(32),
+        "metadata": {},
+        "hostId": "",
+        "image": "",
+        "flavor": {"id": flavor.openstack_id, "links": []},
+        "created": "2024-01-01T00:00:00Z",
+        "updated": "2024-01-01T00:00:00Z",
+        "addresses": {},
+        "accessIPv4": "",
+        "accessIPv6": "",
+        "links": [],
+        "OS-DCF:diskConfig": "MANUAL",
+        "OS-EXT-AZ:availability_zone": "nova",
+        "config_drive": "",
+        "OS-EXT-SRV-ATTR:instance_name": "instance-00000001",
+        "OS-EXT-STS:vm_state": "active",
+        "OS-EXT-STS:power_state": 1,
+        "os-extended-volumes:volumes_attached": [],
+    })
+}
+
+async fn spawn_app_with_nova() -> (TestApp, MockServer) {
+    let nova_server = MockServer::start().await;
+    let nova_endpoint = nova_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.nova_endpoint = nova_endpoint;
+    })
+    .await;
+    (server, nova_server)
+}
+
+async fn mock_nova_servers(nova_server: &MockServer, servers: Vec<Value>) {
+    Mock::given(method("GET"))
+        .and(path("/v2.1/servers/detail"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"servers": servers})),
+        )
+        .mount(nova_server)
+        .await;
+}
+
+/// Mock Nova listing the given pages of servers, linked via `servers_links`.
+///
+/// Each page is expected to be requested exactly once, the first one without
+/// and the following ones with the last server of the previous page as marker.
+async fn mock_nova_server_pages(
+    nova_server: &MockServer,
+    pages: Vec<Vec<Value>>,
+) {
+    let mut marker: Option<String> = None;
+    let page_count = pages.len();
+    for (i, servers) in pages.into_iter().enumerate() {
+        let next_marker = servers
+            .last()
+            .map(|server| server["id"].as_str().unwrap().to_string());
+        let servers_links = if i + 1 < page_count {
+            json!([{
+                "href": format!(
+                    "{}/v2.1/servers/detail?marker={}",
+                    nova_server.uri(),
+                    next_marker.as_deref().unwrap()
+                ),
+                "rel": "next",
+            }])
+        } else {
+            json!([])
+        };
+        let mock = Mock::given(method("GET")).and(path("/v2.1/servers/detail"));
+        let mock = match &marker {
+            Some(marker) => mock.and(query_param("marker", marker.as_str())),
+            None => mock.and(query_param_is_missing("marker")),
+        };
+        mock.respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "servers": servers,
+            "servers_links": servers_links,
+        })))
+        .expect(1)
+        .mount(nova_server)
+        .await;
+        marker = next_marker;
+    }
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_import_keeps_unchanged_server_state() {
+    // arrange
+    let (server, nova_server) = spawn_app_with_nova().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let server_state = server
+        .setup_test_server_state(&flavor, &user)
+        .await
+        .expect("Failed to setup test server state");
+    mock_nova_servers(
+        &nova_server,
+        vec![server_detailed(&server_state, &flavor, &user)],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.server_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        ServerStateImport {
+            new_state_count: 0,
+            end_state_count: 0,
+        }
+    );
+    let server_states = client.server_state.list().all().send().await.unwrap();
+    assert_eq!(server_states.len(), 1);
+    assert_eq!(server_states[0].end, None);
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_import_detects_resize() {
+    // arrange
+    let (server, nova_server) = spawn_app_with_nova().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor1 = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let flavor2 = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let server_state = server
+        .setup_test_server_state(&flavor1, &user)
+        .await
+        .expect("Failed to setup test server state");
+    mock_nova_servers(
+        &nova_server,
+        vec![server_detailed(&server_state, &flavor2, &user)],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.server_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        ServerStateImport {
+            new_state_count: 1,
+            end_state_count: 1,
+        }
+    );
+    let old = client.server_state.get(server_state.id).await.unwrap();
+    assert!(old.end.is_some());
+    let server_states = client
+        .server_state
+        .list()
+        .server(server_state.instance_id)
+        .send()
+        .await
+        .unwrap();
+    let new = server_states
+        .iter()
+        .find(|s| s.end.is_none())
+        .expect("No unfinished server state");
+    assert_eq!(new.flavor, flavor2.id);
+    assert_eq!(new.instance_name, server_state.instance_name);
+    assert_eq!(new.status, server_state.status);
+    assert_eq!(new.user, user.id);
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_import_detects_rename() {
+    // arrange
+    let (server, nova_server) = spawn_app_with_nova().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let server_state = server
+        .setup_test_server_state(&flavor, &user)
+        .await
+        .expect("Failed to setup test server state");
+    let mut renamed = server_state.clone();
+    renamed.instance_name = random_alphanumeric_string(10);
+    mock_nova_servers(
+        &nova_server,
+        vec![server_detailed(&renamed, &flavor, &user)],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.server_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        ServerStateImport {
+            new_state_count: 1,
+            end_state_count: 1,
+        }
+    );
+    let old = client.server_state.get(server_state.id).await.unwrap();
+    assert!(old.end.is_some());
+    assert_eq!(old.instance_name, server_state.instance_name);
+    let server_states = client
+        .server_state
+        .list()
+        .server(server_state.instance_id)
+        .send()
+        .await
+        .unwrap();
+    let new = server_states
+        .iter()
+        .find(|s| s.end.is_none())
+        .expect("No unfinished server state");
+    assert_eq!(new.instance_name, renamed.instance_name);
+    assert_eq!(new.flavor, flavor.id);
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_import_dry_run_reports_resize_without_applying_it()
+ {
+    // arrange
+    let (server, nova_server) = spawn_app_with_nova().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor1 = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let flavor2 = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let server_state = server
+        .setup_test_server_state(&flavor1, &user)
+        .await
+        .expect("Failed to setup test server state");
+    mock_nova_servers(
+        &nova_server,
+        vec![server_detailed(&server_state, &flavor2, &user)],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let dry_run = client.server_state.import_dry_run().await.unwrap();
+
+    // assert
+    assert_eq!(
+        dry_run.summary,
+        ServerStateImport {
+            new_state_count: 1,
+            end_state_count: 1,
+        }
+    );
+    assert_eq!(dry_run.changes.len(), 2);
+    for change in dry_run.changes.iter() {
+        assert_eq!(change.object, ChangeObject::ServerState);
+        assert_eq!(change.name, server_state.instance_id.to_string());
+        assert_eq!(
+            change.reason,
+            format!("flavor changed from {} to {}", flavor1.name, flavor2.name)
+        );
+    }
+    assert_eq!(dry_run.changes[0].action, ChangeAction::End);
+    assert_eq!(dry_run.changes[1].action, ChangeAction::Create);
+    let server_states = client.server_state.list().all().send().await.unwrap();
+    assert_eq!(server_states.len(), 1);
+    assert_eq!(server_states[0].end, None);
+    assert_eq!(server_states[0].flavor, flavor1.id);
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_import_follows_nova_servers_links() {
+    // arrange
+    let (server, nova_server) = spawn_app_with_nova().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let mut pages = Vec::new();
+    for _ in 0..3 {
+        let server_state = server
+            .setup_test_server_state(&flavor, &user)
+            .await
+            .expect("Failed to setup test server state");
+        pages.push(vec![server_detailed(&server_state, &flavor, &user)]);
+    }
+    mock_nova_server_pages(&nova_server, pages).await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.server_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        ServerStateImport {
+            new_state_count: 0,
+            end_state_count: 0,
+        }
+    );
+    let server_states = client.server_state.list().all().send().await.unwrap();
+    assert_eq!(server_states.len(), 3);
+    assert!(server_states.iter().all(|s| s.end.is_none()));
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_import_detects_resize_on_later_page() {
+    // arrange
+    let (server, nova_server) = spawn_app_with_nova().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor1 = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let flavor2 = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let server_state1 = server
+        .setup_test_server_state(&flavor1, &user)
+        .await
+        .expect("Failed to setup test server state 1");
+    let server_state2 = server
+        .setup_test_server_state(&flavor1, &user)
+        .await
+        .expect("Failed to setup test server state 2");
+    mock_nova_server_pages(
+        &nova_server,
+        vec![
+            vec![server_detailed(&server_state1, &flavor1, &user)],
+            vec![server_detailed(&server_state2, &flavor2, &user)],
+        ],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.server_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        ServerStateImport {
+            new_state_count: 1,
+            end_state_count: 1,
+        }
+    );
+    let unchanged = client.server_state.get(server_state1.id).await.unwrap();
+    assert_eq!(unchanged.end, None);
+    let old = client.server_state.get(server_state2.id).await.unwrap();
+    assert!(old.end.is_some());
+    let server_states = client
+        .server_state
+        .list()
+        .server(server_state2.instance_id)
+        .send()
+        .await
+        .unwrap();
+    let new = server_states
+        .iter()
+        .find(|s| s.end.is_none())
+        .expect("No unfinished server state");
+    assert_eq!(new.flavor, flavor2.id);
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_import_keeps_server_state_with_unknown_flavor() {
+    // arrange
+    let (server, nova_server) = spawn_app_with_nova().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let server_state = server
+        .setup_test_server_state(&flavor, &user)
+        .await
+        .expect("Failed to setup test server state");
+    let mut nova_server_detailed =
+        server_detailed(&server_state, &flavor, &user);
+    nova_server_detailed["flavor"]["id"] =
+        json!(random_alphanumeric_string(32));
+    mock_nova_servers(&nova_server, vec![nova_server_detailed]).await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.server_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        ServerStateImport {
+            new_state_count: 0,
+            end_state_count: 0,
+        }
+    );
+    let server_states = client.server_state.list().all().send().await.unwrap();
+    assert_eq!(server_states.len(), 1);
+    assert_eq!(server_states[0].end, None);
+}
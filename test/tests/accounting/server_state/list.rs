@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use avina::{Api, Token};
 use avina_test::spawn_app;
+use futures::TryStreamExt;
 use uuid::Uuid;
 
 use super::assert_contains_server_state;
@@ -741,3 +742,98 @@ async fn e2e_lib_admin_user_can_combine_server_state_list_filters() {
     assert_eq!(server_states2.len(), 1);
     assert_contains_server_state(&server_states2, &server_state1);
 }
+
+#[tokio::test]
+async fn e2e_lib_server_state_list_can_be_paginated_and_ordered() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    let normal_user = test_project.normals[0].user.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    for _ in 0..5 {
+        server
+            .setup_test_server_state(&flavor, &normal_user)
+            .await
+            .expect("Failed to setup test server state");
+    }
+
+    // arrange
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let server_states = client
+        .server_state
+        .list()
+        .all()
+        .ordering("-id")
+        .send()
+        .await
+        .unwrap();
+    let page1 = client
+        .server_state
+        .list()
+        .all()
+        .ordering("-id")
+        .limit(2)
+        .page()
+        .await
+        .unwrap();
+    let page3 = client
+        .server_state
+        .list()
+        .all()
+        .ordering("-id")
+        .limit(2)
+        .offset(4)
+        .page()
+        .await
+        .unwrap();
+    let pages = client
+        .server_state
+        .list()
+        .all()
+        .ordering("-id")
+        .limit(2)
+        .pages()
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(server_states.len(), 5);
+    assert!(server_states.windows(2).all(|w| w[0].id > w[1].id));
+    assert_eq!(page1.count, 5);
+    assert_eq!(page1.results, server_states[0..2]);
+    assert!(page1.next.unwrap().contains("offset=2"));
+    assert!(page1.previous.is_none());
+    assert_eq!(page3.count, 5);
+    assert_eq!(page3.results, server_states[4..5]);
+    assert!(page3.next.is_none());
+    assert!(page3.previous.unwrap().contains("offset=2"));
+    assert_eq!(
+        pages
+            .into_iter()
+            .flat_map(|p| p.results)
+            .collect::<Vec<_>>(),
+        server_states
+    );
+}
@@ -0,0 +1,241 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::accounting::server_state::NewServerState;
+use avina_test::{random_alphanumeric_string, spawn_app};
+use avina_wire::accounting::{ServerStateLoad, ServerStateLoadData};
+use chrono::{TimeZone, Utc};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn e2e_lib_server_state_load_denies_access_to_master_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 1, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let load = client.server_state.load(&[]).await;
+
+    // assert
+    assert_eq!(load.unwrap_err().to_string(), "Admin privileges required");
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_load_resolves_flavors_and_users() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let instance_id = Uuid::new_v4();
+    let begin = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    let middle = Utc.with_ymd_and_hms(2020, 2, 1, 0, 0, 0).unwrap();
+    let data = vec![
+        ServerStateLoadData {
+            begin: begin.into(),
+            end: Some(middle.into()),
+            instance_id,
+            instance_name: random_alphanumeric_string(10),
+            flavor: flavor.name.clone(),
+            status: "ACTIVE".to_string(),
+            user: user.name.clone(),
+        },
+        ServerStateLoadData {
+            begin: middle.into(),
+            end: None,
+            instance_id,
+            instance_name: random_alphanumeric_string(10),
+            flavor: flavor.openstack_id.clone(),
+            status: "SHUTOFF".to_string(),
+            user: user.openstack_id.clone(),
+        },
+    ];
+
+    // act
+    let load = client.server_state.load(&data).await.unwrap();
+
+    // assert
+    assert_eq!(load, ServerStateLoad { new_state_count: 2 });
+    let server_states = client
+        .server_state
+        .list()
+        .server(instance_id)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(server_states.len(), 2);
+    assert!(
+        server_states
+            .iter()
+            .all(|s| s.flavor == flavor.id && s.user == user.id)
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_load_accepts_csv() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let instance_id = Uuid::new_v4();
+    let csv = format!(
+        "instance_id,instance_name,begin,end,flavor,status,user\n\
+         {instance_id},\"web, frontend\",2020-01-01T00:00:00Z,2020-02-01T00:00:00Z,{},ACTIVE,{}\n\
+         {instance_id},\"web, frontend\",2020-02-01T00:00:00Z,,{},ACTIVE,{}\n",
+        flavor.name, user.name, flavor.id, user.id,
+    );
+
+    // act
+    let load = client.server_state.load_csv(csv).await.unwrap();
+
+    // assert
+    assert_eq!(load, ServerStateLoad { new_state_count: 2 });
+    let server_states = client
+        .server_state
+        .list()
+        .server(instance_id)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(server_states.len(), 2);
+    assert!(
+        server_states
+            .iter()
+            .all(|s| s.instance_name == "web, frontend")
+    );
+    assert_eq!(server_states.iter().filter(|s| s.end.is_none()).count(), 1);
+}
+
+#[tokio::test]
+async fn e2e_lib_server_state_load_rejects_whole_invalid_batch() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let instance_id = Uuid::new_v4();
+    let existing = server
+        .setup_test_server_state_with_server_state(
+            &flavor,
+            &user,
+            NewServerState {
+                begin: Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+                end: Some(Utc.with_ymd_and_hms(2020, 2, 1, 0, 0, 0).unwrap()),
+                instance_id,
+                instance_name: random_alphanumeric_string(10),
+                flavor: flavor.id,
+                status: "ACTIVE".to_string(),
+                user: user.id,
+            },
+        )
+        .await
+        .expect("Failed to setup test server state");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let valid = ServerStateLoadData {
+        begin: Utc.with_ymd_and_hms(2020, 2, 1, 0, 0, 0).unwrap().into(),
+        end: None,
+        instance_id: Uuid::new_v4(),
+        instance_name: random_alphanumeric_string(10),
+        flavor: flavor.name.clone(),
+        status: "ACTIVE".to_string(),
+        user: user.name.clone(),
+    };
+    let overlapping = ServerStateLoadData {
+        begin: Utc.with_ymd_and_hms(2020, 1, 15, 0, 0, 0).unwrap().into(),
+        end: None,
+        instance_id,
+        ..valid.clone()
+    };
+    let unknown_flavor = ServerStateLoadData {
+        flavor: random_alphanumeric_string(10),
+        instance_id: Uuid::new_v4(),
+        ..valid.clone()
+    };
+
+    // act
+    let load = client
+        .server_state
+        .load(&[valid.clone(), overlapping, unknown_flavor.clone()])
+        .await;
+
+    // assert
+    assert_eq!(
+        load.unwrap_err().to_string(),
+        format!(
+            "Entry 3: unknown flavor {}; Entry 2: overlaps server state {}",
+            unknown_flavor.flavor, existing.id
+        )
+    );
+    let server_states = client.server_state.list().all().send().await.unwrap();
+    assert_eq!(server_states.len(), 1);
+}
@@ -1,7 +1,10 @@
+mod check;
 mod create;
 mod delete;
 mod get;
+mod import;
 mod list;
+mod load;
 mod modify;
 
 use avina_wire::accounting::ServerState;
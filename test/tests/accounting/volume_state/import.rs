@@ -0,0 +1,287 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::accounting::volume_state::NewVolumeState;
+use avina_test::{
+    TestApp, random_alphanumeric_string, spawn_app_with_configuration,
+};
+use avina_wire::{
+    accounting::{VolumeState, VolumeStateImport},
+    dry_run::{ChangeAction, ChangeObject},
+    user::User,
+};
+use chrono::Utc;
+use serde_json::{Value, json};
+use uuid::Uuid;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path_regex},
+};
+
+fn volume_detailed(
+    volume_id: Uuid,
+    name: &str,
+    size: u32,
+    user: &User,
+) -> Value {
+    json!({
+        "id": volume_id,
+        "name": name,
+        "description": null,
+        "status": "in-use",
+        "size": size,
+        "os-vol-tenant-attr:tenant_id": user.openstack_id,
+        "user_id": random_alphanumeric_string(32),
+        "volume_type": "__DEFAULT__",
+        "bootable": "false",
+        "created_at": "2024-01-01T00:00:00.000000",
+    })
+}
+
+async fn spawn_app_with_cinder() -> (TestApp, MockServer) {
+    let cinder_server = MockServer::start().await;
+    let cinder_endpoint = cinder_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.cinder_endpoint = cinder_endpoint;
+    })
+    .await;
+    (server, cinder_server)
+}
+
+async fn mock_cinder_volumes(cinder_server: &MockServer, volumes: Vec<Value>) {
+    Mock::given(method("GET"))
+        .and(path_regex(r"^/v3/[^/]+/volumes/detail$"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"volumes": volumes})),
+        )
+        .mount(cinder_server)
+        .await;
+}
+
+async fn setup_test_volume_state(
+    server: &TestApp,
+    user: &User,
+    size: u32,
+) -> VolumeState {
+    server
+        .setup_test_volume_state_with_volume_state(
+            user,
+            NewVolumeState {
+                begin: Utc::now(),
+                end: None,
+                volume_id: Uuid::new_v4(),
+                volume_name: random_alphanumeric_string(10),
+                status: "in-use".to_string(),
+                size,
+                user: user.id,
+            },
+        )
+        .await
+        .expect("Failed to setup test volume state")
+}
+
+#[tokio::test]
+async fn e2e_lib_volume_state_import_creates_volume_state() {
+    // arrange
+    let (server, cinder_server) = spawn_app_with_cinder().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let volume_id = Uuid::new_v4();
+    let volume_name = random_alphanumeric_string(10);
+    mock_cinder_volumes(
+        &cinder_server,
+        vec![volume_detailed(volume_id, &volume_name, 100, &user)],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.volume_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        VolumeStateImport {
+            new_state_count: 1,
+            end_state_count: 0,
+        }
+    );
+    let volume_states = client.volume_state.list().all().send().await.unwrap();
+    assert_eq!(volume_states.len(), 1);
+    assert_eq!(volume_states[0].volume_id, volume_id);
+    assert_eq!(volume_states[0].volume_name, volume_name);
+    assert_eq!(volume_states[0].size, 100);
+    assert_eq!(volume_states[0].user, user.id);
+    assert_eq!(volume_states[0].end, None);
+}
+
+#[tokio::test]
+async fn e2e_lib_volume_state_import_keeps_unchanged_volume_state() {
+    // arrange
+    let (server, cinder_server) = spawn_app_with_cinder().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let volume_state = setup_test_volume_state(&server, &user, 100).await;
+    mock_cinder_volumes(
+        &cinder_server,
+        vec![volume_detailed(
+            volume_state.volume_id,
+            &volume_state.volume_name,
+            volume_state.size,
+            &user,
+        )],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.volume_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        VolumeStateImport {
+            new_state_count: 0,
+            end_state_count: 0,
+        }
+    );
+    let volume_states = client.volume_state.list().all().send().await.unwrap();
+    assert_eq!(volume_states.len(), 1);
+    assert_eq!(volume_states[0].end, None);
+}
+
+#[tokio::test]
+async fn e2e_lib_volume_state_import_detects_extension() {
+    // arrange
+    let (server, cinder_server) = spawn_app_with_cinder().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let volume_state = setup_test_volume_state(&server, &user, 100).await;
+    mock_cinder_volumes(
+        &cinder_server,
+        vec![volume_detailed(
+            volume_state.volume_id,
+            &volume_state.volume_name,
+            200,
+            &user,
+        )],
+    )
+    .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.volume_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        VolumeStateImport {
+            new_state_count: 1,
+            end_state_count: 1,
+        }
+    );
+    let old = client.volume_state.get(volume_state.id).await.unwrap();
+    assert!(old.end.is_some());
+    assert_eq!(old.size, 100);
+    let volume_states = client
+        .volume_state
+        .list()
+        .volume(volume_state.volume_id)
+        .send()
+        .await
+        .unwrap();
+    let new = volume_states
+        .iter()
+        .find(|s| s.end.is_none())
+        .expect("No unfinished volume state");
+    assert_eq!(new.size, 200);
+    assert_eq!(new.volume_name, volume_state.volume_name);
+    assert_eq!(new.user, user.id);
+}
+
+#[tokio::test]
+async fn e2e_lib_volume_state_import_ends_deleted_volume() {
+    // arrange
+    let (server, cinder_server) = spawn_app_with_cinder().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let volume_state = setup_test_volume_state(&server, &user, 100).await;
+    mock_cinder_volumes(&cinder_server, vec![]).await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let dry_run = client.volume_state.import_dry_run().await.unwrap();
+    let import = client.volume_state.import().await.unwrap();
+
+    // assert
+    assert_eq!(dry_run.summary, import);
+    assert_eq!(dry_run.changes.len(), 1);
+    assert_eq!(dry_run.changes[0].action, ChangeAction::End);
+    assert_eq!(dry_run.changes[0].object, ChangeObject::VolumeState);
+    assert_eq!(
+        import,
+        VolumeStateImport {
+            new_state_count: 0,
+            end_state_count: 1,
+        }
+    );
+    let old = client.volume_state.get(volume_state.id).await.unwrap();
+    assert!(old.end.is_some());
+}
@@ -0,0 +1,211 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::{random_alphanumeric_string, spawn_app};
+use avina_wire::audit::ObjectType;
+use chrono::{Duration, Utc};
+
+#[tokio::test]
+async fn e2e_lib_audit_log_list_denies_access_to_normal_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let list = client.audit.list().send().await;
+
+    // assert
+    assert!(list.is_err());
+    assert_eq!(list.unwrap_err().to_string(), "Admin privileges required");
+}
+
+#[tokio::test]
+async fn e2e_lib_audit_log_records_create_modify_and_delete() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let name = random_alphanumeric_string(10);
+    let new_name = random_alphanumeric_string(10);
+
+    // act
+    let created = client
+        .flavor_group
+        .create(name.clone())
+        .send()
+        .await
+        .unwrap();
+    client
+        .flavor_group
+        .modify(created.id)
+        .name(new_name.clone())
+        .send()
+        .await
+        .unwrap();
+    client.flavor_group.delete(created.id).await.unwrap();
+    let entries = client
+        .audit
+        .list()
+        .object_type(ObjectType::FlavorGroup)
+        .send()
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(entries.len(), 3);
+    let (deleted, modified, created_entry) =
+        (&entries[0], &entries[1], &entries[2]);
+    for entry in &entries {
+        assert_eq!(entry.user, user.id);
+        assert_eq!(entry.impersonated_user, None);
+        assert_eq!(entry.object_type, ObjectType::FlavorGroup);
+        assert_eq!(entry.object_id, Some(created.id));
+    }
+    assert_eq!(created_entry.method, "POST");
+    assert!(created_entry.before.is_none());
+    assert_eq!(created_entry.after.as_ref().unwrap()["name"], name.as_str());
+    assert_eq!(modified.method, "PATCH");
+    assert_eq!(modified.before.as_ref().unwrap()["name"], name.as_str());
+    assert_eq!(modified.after.as_ref().unwrap()["name"], new_name.as_str());
+    assert_eq!(deleted.method, "DELETE");
+    assert_eq!(deleted.before.as_ref().unwrap()["name"], new_name.as_str());
+    assert!(deleted.after.is_none());
+}
+
+#[tokio::test]
+async fn e2e_lib_audit_log_records_impersonated_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(2, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let admin = test_project.admins[0].user.clone();
+    let other = test_project.admins[1].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &admin.openstack_id, &admin.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let impersonating_client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        Some(other.id),
+        None,
+    )
+    .unwrap();
+
+    // act
+    let created = impersonating_client
+        .flavor_group
+        .create(random_alphanumeric_string(10))
+        .send()
+        .await
+        .unwrap();
+    let by_admin = client.audit.list().user(admin.id).send().await.unwrap();
+    let by_other = client.audit.list().user(other.id).send().await.unwrap();
+
+    // assert
+    assert_eq!(by_admin.len(), 1);
+    assert_eq!(by_admin, by_other);
+    assert_eq!(by_admin[0].user, admin.id);
+    assert_eq!(by_admin[0].impersonated_user, Some(other.id));
+    assert_eq!(by_admin[0].object_id, Some(created.id));
+}
+
+#[tokio::test]
+async fn e2e_lib_audit_log_list_filters_by_time_and_limit() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let begin = Utc::now().fixed_offset();
+    client
+        .flavor_group
+        .create(random_alphanumeric_string(10))
+        .send()
+        .await
+        .unwrap();
+    let second = client
+        .flavor_group
+        .create(random_alphanumeric_string(10))
+        .send()
+        .await
+        .unwrap();
+    let end = Utc::now().fixed_offset() + Duration::seconds(1);
+
+    // act
+    let within = client
+        .audit
+        .list()
+        .begin(begin)
+        .end(end)
+        .send()
+        .await
+        .unwrap();
+    let after = client.audit.list().begin(end).send().await.unwrap();
+    let before = client.audit.list().end(begin).send().await.unwrap();
+    let limited = client.audit.list().limit(1).send().await.unwrap();
+    let invalid = client.audit.list().begin(end).end(begin).send().await;
+
+    // assert
+    assert_eq!(within.len(), 2);
+    assert!(after.is_empty());
+    assert!(before.is_empty());
+    assert_eq!(limited.len(), 1);
+    assert_eq!(limited[0].object_id, Some(second.id));
+    assert_eq!(invalid.unwrap_err().to_string(), "Begin must be before end");
+}
@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::{spawn_app, spawn_app_with_configuration};
+use chrono::Utc;
+use serde_json::json;
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path},
+};
+
+#[tokio::test]
+async fn e2e_lib_token_validation_is_cached() {
+    // arrange
+    let server = spawn_app().await;
+    let (user, _project, token) = server
+        .setup_test_user_and_project(false)
+        .await
+        .expect("Failed to setup test user and project.");
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .expect(1)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let first = client.hello.user().await;
+    let second = client.hello.user().await;
+
+    // assert
+    assert!(first.is_ok());
+    assert!(second.is_ok());
+}
+
+#[tokio::test]
+async fn e2e_lib_revoked_token_is_rejected() {
+    // arrange
+    let server = spawn_app_with_configuration(|c| {
+        c.application.token_cache.revocation_check_interval = 0;
+    })
+    .await;
+    let (user, _project, token) = server
+        .setup_test_user_and_project(false)
+        .await
+        .expect("Failed to setup test user and project.");
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .up_to_n_times(1)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    assert!(client.hello.user().await.is_ok());
+    Mock::given(method("GET"))
+        .and(path("/OS-REVOKE/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "events": [{
+                "project_id": user.openstack_id,
+                "issued_before": Utc::now().to_rfc3339(),
+            }]
+        })))
+        .with_priority(1)
+        .mount(&server.keystone_server)
+        .await;
+
+    // act
+    let hello = client.hello.user().await;
+
+    // assert
+    assert!(hello.is_err());
+    assert_eq!(
+        hello.unwrap_err().to_string(),
+        "Failed to validate user token"
+    );
+}
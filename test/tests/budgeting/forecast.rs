@@ -0,0 +1,197 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::{
+    accounting::server_state::NewServerState,
+    budgeting::user_budget::NewUserBudget,
+    pricing::flavor_price::NewFlavorPrice,
+};
+use avina_test::{random_alphanumeric_string, random_uuid, spawn_app};
+use avina_wire::user::{Project, UserClass};
+use chrono::{Datelike, TimeDelta, Utc};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn e2e_lib_budget_forecast_for_user_includes_scheduled_price_change() {
+    // arrange
+    let server = spawn_app().await;
+    let user_class = UserClass::UC1;
+    let project_new = Project {
+        id: 1,
+        name: random_alphanumeric_string(10),
+        openstack_id: random_uuid(),
+        user_class,
+    };
+    let test_project = server
+        .setup_test_project_with_project(0, 0, 1, project_new)
+        .await
+        .expect("Failed to setup test project");
+    let normal_user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &normal_user.openstack_id,
+            &normal_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let now = Utc::now();
+    let begin = now - TimeDelta::hours(10);
+    let price_change = now + TimeDelta::hours(2);
+    // 8760 EUR per year are 1 EUR per hour
+    for (unit_price, start_time) in [
+        (8760.0, begin - TimeDelta::days(1)),
+        (17520.0, price_change),
+    ] {
+        server
+            .setup_test_flavor_price_with_new_flavor_price(
+                &flavor,
+                NewFlavorPrice {
+                    flavor_id: flavor.id as u64,
+                    user_class,
+                    unit_price,
+                    start_time,
+                },
+            )
+            .await
+            .expect("Failed to setup test flavor price");
+    }
+    server
+        .setup_test_server_state_with_server_state(
+            &flavor,
+            &normal_user,
+            NewServerState {
+                begin,
+                end: None,
+                instance_id: Uuid::new_v4(),
+                instance_name: random_alphanumeric_string(10),
+                flavor: flavor.id,
+                status: "ACTIVE".to_string(),
+                user: normal_user.id,
+            },
+        )
+        .await
+        .expect("Failed to setup test server state");
+    let budget = server
+        .setup_test_user_budget_with_new_user_budget(
+            &normal_user,
+            &NewUserBudget {
+                user_id: normal_user.id as u64,
+                year: now.year() as u32,
+                amount: 20,
+            },
+        )
+        .await
+        .expect("Failed to setup test user budget");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let forecast = client.budget_forecast.get().send().await.unwrap();
+
+    // assert
+    assert_eq!(forecast.budget_id, Some(budget.id));
+    assert_eq!(forecast.budget, Some(20));
+    assert!((forecast.hourly_cost - 1.0).abs() < 0.01);
+    let remaining_hours =
+        (forecast.end.to_utc() - price_change).num_seconds() as f64 / 3600.;
+    assert!((forecast.forecast - 12.0 - 2.0 * remaining_hours).abs() < 0.01);
+    // 12 EUR are spent until the price change, the other 8 EUR in 4 hours
+    let exceeded_at = forecast.exceeded_at.unwrap().to_utc();
+    let expected = price_change + TimeDelta::hours(4);
+    assert!((exceeded_at - expected).num_seconds().abs() <= 2);
+}
+
+#[tokio::test]
+async fn e2e_lib_budget_forecast_for_project_already_exceeded() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 1, 0)
+        .await
+        .expect("Failed to setup test project");
+    let master_user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &master_user.openstack_id,
+            &master_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let budget = server
+        .setup_test_project_budget(&test_project.project)
+        .await
+        .expect("Failed to setup test project budget");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let forecast = client
+        .budget_forecast
+        .get()
+        .project(test_project.project.id)
+        .send()
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(forecast.budget_id, Some(budget.id));
+    assert_eq!(forecast.hourly_cost, 0.0);
+    assert_eq!(forecast.forecast, 0.0);
+    assert!(forecast.exceeded_at.unwrap() <= Utc::now());
+}
+
+#[tokio::test]
+async fn e2e_lib_normal_user_cannot_get_budget_forecast_for_project() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let normal_user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &normal_user.openstack_id,
+            &normal_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let forecast = client
+        .budget_forecast
+        .get()
+        .project(test_project.project.id)
+        .send()
+        .await;
+
+    // assert
+    assert!(forecast.is_err());
+}
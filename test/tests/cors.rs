@@ -0,0 +1,91 @@
+use avina_api::configuration::CorsSettings;
+use avina_test::spawn_app_with_configuration;
+
+#[tokio::test]
+async fn e2e_cors_allowed_origin_is_echoed() {
+    // arrange
+    let server = spawn_app_with_configuration(|c| {
+        c.application.cors.allowed_origins =
+            vec!["https://avina.example.com".to_string()];
+    })
+    .await;
+    let client = reqwest::Client::new();
+
+    // act
+    let response = client
+        .get(format!("{}/health_check", server.address))
+        .header("Origin", "https://avina.example.com")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // assert
+    assert!(response.status().is_success());
+    assert_eq!(
+        response
+            .headers()
+            .get("Access-Control-Allow-Origin")
+            .map(|h| h.to_str().unwrap()),
+        Some("https://avina.example.com")
+    );
+}
+
+#[tokio::test]
+async fn e2e_cors_denies_other_origin() {
+    // arrange
+    let server = spawn_app_with_configuration(|c| {
+        c.application.cors.allowed_origins =
+            vec!["https://avina.example.com".to_string()];
+    })
+    .await;
+    let client = reqwest::Client::new();
+
+    // act
+    let response = client
+        .request(
+            reqwest::Method::OPTIONS,
+            format!("{}/health_check", server.address),
+        )
+        .header("Origin", "https://other.example.com")
+        .header("Access-Control-Request-Method", "GET")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // assert
+    assert!(response.status().is_client_error());
+    assert!(
+        response
+            .headers()
+            .get("Access-Control-Allow-Origin")
+            .is_none()
+    );
+}
+
+#[test]
+fn cors_settings_reject_invalid_values() {
+    let settings = |origin: &str, method: &str| CorsSettings {
+        allowed_origins: vec![origin.to_string()],
+        allowed_methods: Some(vec![method.to_string()]),
+        ..Default::default()
+    };
+
+    assert!(settings("https://cc.lrz.de:1339", "GET").validate().is_ok());
+    assert!(
+        settings("https://cc.lrz.de:1339/", "GET")
+            .validate()
+            .is_err()
+    );
+    assert!(settings("cc.lrz.de", "GET").validate().is_err());
+    assert!(settings("*", "GET").validate().is_err());
+    assert!(settings("https://cc.lrz.de", "FETCH").validate().is_err());
+    assert!(
+        CorsSettings {
+            allowed_origins: vec!["https://cc.lrz.de".to_string()],
+            permissive: true,
+            ..Default::default()
+        }
+        .validate()
+        .is_err()
+    );
+}
@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::{random_uuid, spawn_app, spawn_app_with_configuration};
+use avina_wire::jobs::Job;
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+#[tokio::test]
+async fn e2e_lib_job_run_create_denies_access_to_normal_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let trigger = client.job_run.trigger(Job::FlavorImport).await;
+
+    // assert
+    assert!(trigger.is_err());
+    assert_eq!(
+        trigger.unwrap_err().to_string(),
+        "Admin privileges required"
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_job_run_create_runs_flavor_import() {
+    // arrange
+    let nova_server = MockServer::start().await;
+    let nova_endpoint = nova_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.nova_endpoint = nova_endpoint;
+    })
+    .await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2.1/flavors/detail"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "flavors": [{
+                "OS-FLV-DISABLED:disabled": false,
+                "disk": 20,
+                "os-flavor-access:is_public": true,
+                "id": random_uuid(),
+                "links": [],
+                "name": "lrz.small",
+                "ram": 4096,
+                "vcpus": 1,
+                "rxtx_factor": 1.0,
+                "description": null,
+            }]
+        })))
+        .expect(1)
+        .mount(&nova_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let job_run = client.job_run.trigger(Job::FlavorImport).await.unwrap();
+
+    // assert
+    assert_eq!(job_run.job, Job::FlavorImport);
+    assert!(job_run.manual);
+    assert_eq!(job_run.user, Some(user.id));
+    assert!(job_run.finished_at.is_some());
+    assert_eq!(
+        job_run.result,
+        Some(
+            "{\"new_flavor_count\":1,\"new_flavor_price_count\":0}".to_string()
+        )
+    );
+    assert_eq!(job_run.error, None);
+    let flavors = client.flavor.list().all().send().await.unwrap();
+    assert_eq!(flavors.len(), 1);
+    assert_eq!(flavors[0].name, "lrz.small");
+    assert_eq!(client.job_run.get(job_run.id).await.unwrap(), job_run);
+}
+
+#[tokio::test]
+async fn e2e_lib_job_run_create_records_failed_run() {
+    // arrange
+    let nova_server = MockServer::start().await;
+    let nova_endpoint = nova_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.nova_endpoint = nova_endpoint;
+    })
+    .await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2.1/flavors/detail"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&nova_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let job_run = client.job_run.trigger(Job::FlavorImport).await.unwrap();
+
+    // assert
+    assert!(job_run.finished_at.is_some());
+    assert_eq!(job_run.result, None);
+    assert!(job_run.error.is_some());
+    assert!(client.flavor.list().all().send().await.unwrap().is_empty());
+}
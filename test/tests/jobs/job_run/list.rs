@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::spawn_app;
+use avina_wire::jobs::Job;
+
+#[tokio::test]
+async fn e2e_lib_job_run_list_denies_access_to_master_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 1, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let list = client.job_run.list().send().await;
+
+    // assert
+    assert!(list.is_err());
+    assert_eq!(list.unwrap_err().to_string(), "Admin privileges required");
+}
+
+#[tokio::test]
+async fn e2e_lib_job_run_list_filters_by_job() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let first = client.job_run.trigger(Job::UserBudgetSync).await.unwrap();
+    let second = client.job_run.trigger(Job::UserBudgetSync).await.unwrap();
+    let other = client.job_run.trigger(Job::UserSync).await.unwrap();
+
+    // act
+    let all = client.job_run.list().send().await.unwrap();
+    let filtered = client
+        .job_run
+        .list()
+        .job(Job::UserBudgetSync)
+        .send()
+        .await
+        .unwrap();
+    let limited = client.job_run.list().limit(1).send().await.unwrap();
+
+    // assert
+    assert_eq!(all, vec![other.clone(), second.clone(), first.clone()]);
+    assert_eq!(filtered, vec![second, first]);
+    assert_eq!(limited, vec![other]);
+}
@@ -0,0 +1,22 @@
+use avina_api::configuration::{JobSettings, JobsSettings};
+
+mod job_run;
+
+#[test]
+fn jobs_settings_reject_invalid_schedules() {
+    let settings = |interval: Option<u64>, cron: Option<&str>| JobsSettings {
+        flavor_import: Some(JobSettings {
+            interval,
+            cron: cron.map(|c| c.to_string()),
+        }),
+        ..Default::default()
+    };
+
+    assert!(JobsSettings::default().validate().is_ok());
+    assert!(settings(Some(300), None).validate().is_ok());
+    assert!(settings(None, Some("0 0 * * * *")).validate().is_ok());
+    assert!(settings(Some(0), None).validate().is_err());
+    assert!(settings(None, Some("every hour")).validate().is_err());
+    assert!(settings(Some(300), Some("0 0 * * * *")).validate().is_err());
+    assert!(settings(None, None).validate().is_err());
+}
@@ -1,7 +1,15 @@
 mod accounting;
+mod audit;
+mod authentication;
 mod budgeting;
+mod cors;
 mod hello;
+mod jobs;
+mod metrics;
+mod openapi;
+mod openstack;
 mod pricing;
 mod quota;
+mod ready;
 mod resources;
 mod user;
@@ -0,0 +1,56 @@
+use avina_test::{spawn_app, spawn_app_with_configuration};
+use secrecy::SecretString;
+
+#[tokio::test]
+async fn e2e_metrics_are_exposed() {
+    // arrange
+    let server = spawn_app().await;
+    let client = reqwest::Client::new();
+    client
+        .get(format!("{}/health_check", server.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // act
+    let response = client
+        .get(format!("{}/metrics", server.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // assert
+    assert!(response.status().is_success());
+    let body = response.text().await.unwrap();
+    assert!(body.contains(
+        "avina_http_requests_total{method=\"GET\",route=\"/health_check\",status=\"200\"}"
+    ));
+    assert!(body.contains("avina_database_connections{state=\"max\"}"));
+}
+
+#[tokio::test]
+async fn e2e_metrics_require_configured_token() {
+    // arrange
+    let server = spawn_app_with_configuration(|c| {
+        c.application.metrics_token = Some(SecretString::from("metricstoken"));
+    })
+    .await;
+    let client = reqwest::Client::new();
+
+    // act
+    let without_token = client
+        .get(format!("{}/metrics", server.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    let with_token = client
+        .get(format!("{}/metrics", server.address))
+        .bearer_auth("metricstoken")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // assert
+    assert_eq!(without_token.status().as_u16(), 401);
+    assert!(with_token.status().is_success());
+}
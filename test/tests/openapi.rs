@@ -0,0 +1,123 @@
+use avina_test::spawn_app;
+use serde_json::Value;
+
+#[tokio::test]
+async fn e2e_openapi_specification_is_served_without_token() {
+    // arrange
+    let server = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    // act
+    let response = client
+        .get(format!("{}/api/openapi.json", server.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // assert
+    assert!(response.status().is_success());
+    let spec: Value = response.json().await.unwrap();
+    assert!(spec["openapi"].as_str().unwrap().starts_with("3."));
+    assert_eq!(spec["info"]["title"], "avina-api");
+}
+
+#[tokio::test]
+async fn e2e_openapi_specification_covers_all_scopes() {
+    // arrange
+    let server = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    // act
+    let spec: Value = client
+        .get(format!("{}/api/openapi.json", server.address))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json()
+        .await
+        .unwrap();
+
+    // assert
+    let paths = spec["paths"].as_object().unwrap();
+    for (path, method) in [
+        ("/api/hello", "get"),
+        ("/api/user/users/", "post"),
+        ("/api/user/users/{user_id}", "get"),
+        ("/api/user/projects/{project_id}/", "patch"),
+        ("/api/accounting/serverstates/{server_state_id}/", "delete"),
+        ("/api/accounting/serverstates/load/", "post"),
+        ("/api/accounting/servercost/", "get"),
+        ("/api/accounting/servercost/series/", "get"),
+        ("/api/accounting/serverconsumption/", "get"),
+        ("/api/accounting/volumestates/import/", "get"),
+        ("/api/accounting/floatingipstates/import/", "get"),
+        ("/api/resources/flavors", "get"),
+        ("/api/resources/flavorgroups/usage/", "get"),
+        ("/api/pricing/flavorprices/", "post"),
+        ("/api/pricing/flavorprices/estimate/", "get"),
+        ("/api/pricing/flavorprices/revise/", "post"),
+        ("/api/pricing/billingpolicies/", "post"),
+        ("/api/pricing/volumeprices/", "post"),
+        ("/api/pricing/floatingipprices/", "post"),
+        ("/api/pricing/resourceprices/", "post"),
+        ("/api/pricing/priceoverrides/", "post"),
+        ("/api/budgeting/userbudgets/over/", "get"),
+        ("/api/budgeting/budgetovertree/", "get"),
+        ("/api/budgeting/budgetforecast/", "get"),
+        ("/api/quota/flavorquotas/check/", "get"),
+    ] {
+        assert!(
+            paths
+                .get(path)
+                .is_some_and(|item| item.get(method).is_some()),
+            "{method} {path} is missing"
+        );
+    }
+    let schemas = spec["components"]["schemas"].as_object().unwrap();
+    assert!(schemas.contains_key("ErrorResponse"));
+    assert!(schemas.contains_key("UserDetailed"));
+    assert!(schemas.contains_key("FlavorPriceCreateData"));
+}
+
+#[tokio::test]
+async fn e2e_openapi_specification_documents_parameters_and_errors() {
+    // arrange
+    let server = spawn_app().await;
+    let client = reqwest::Client::new();
+
+    // act
+    let spec: Value = client
+        .get(format!("{}/api/openapi.json", server.address))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json()
+        .await
+        .unwrap();
+
+    // assert
+    let server_cost = &spec["paths"]["/api/accounting/servercost/"]["get"];
+    let parameters = server_cost["parameters"].as_array().unwrap();
+    for name in ["begin", "end", "server", "user", "project", "all", "detail"] {
+        assert!(
+            parameters
+                .iter()
+                .any(|p| p["name"] == name && p["in"] == "query"),
+            "parameter {name} is missing"
+        );
+    }
+    for status in ["200", "401", "403", "404", "500"] {
+        assert_eq!(
+            server_cost["responses"][status]["content"]["application/json"]
+                ["schema"]["$ref"]
+                .as_str()
+                .is_some_and(|r| r.ends_with("ErrorResponse")),
+            status != "200",
+            "response {status} is not documented as expected"
+        );
+    }
+    assert_eq!(
+        server_cost["security"][0]["keystone_token"],
+        Value::Array(vec![])
+    );
+}
@@ -0,0 +1,279 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::{TestApp, spawn_app, spawn_app_with_configuration};
+use chrono::{TimeDelta, Utc};
+use secrecy::SecretString;
+use serde_json::{Value, json};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{body_partial_json, method, path},
+};
+
+/// Spawn an app that renews its admin token before every OpenStack call.
+///
+/// Keystone's token mock does not tell an expiry, so the admin token is
+/// assumed valid for an hour, which is within the renewal margin set here.
+async fn spawn_app_renewing_admin_token() -> TestApp {
+    spawn_app_with_configuration(|c| {
+        c.openstack.token_renewal_margin = 7200;
+    })
+    .await
+}
+
+fn mock_keystone_outage(times: u64) -> Mock {
+    Mock::given(method("POST"))
+        .and(path("/auth/tokens/"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(times)
+        .with_priority(1)
+}
+
+#[tokio::test]
+async fn e2e_admin_token_renewal_is_retried_during_keystone_outage() {
+    // arrange
+    let server = spawn_app_renewing_admin_token().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    mock_keystone_outage(2)
+        .expect(2)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let hello = client.hello.user().await;
+
+    // assert
+    assert!(hello.is_ok());
+}
+
+#[tokio::test]
+async fn e2e_admin_token_renewal_failure_is_an_internal_server_error() {
+    // arrange
+    let server = spawn_app_renewing_admin_token().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    mock_keystone_outage(3)
+        .expect(3)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let hello1 = client.hello.user().await;
+    let hello2 = client.hello.user().await;
+
+    // assert
+    assert_eq!(
+        hello1.unwrap_err().to_string(),
+        "Internal server error, contact admin or check logs"
+    );
+    assert!(hello2.is_ok());
+}
+
+#[tokio::test]
+async fn e2e_admin_token_renewal_respects_expiry_and_is_deduplicated() {
+    // arrange
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.token_renewal_margin = 3660;
+    })
+    .await;
+    let test_project = server
+        .setup_test_project(0, 0, 2)
+        .await
+        .expect("Failed to setup test project");
+    let user1 = test_project.normals[0].user.clone();
+    let token1 = test_project.normals[0].token.clone();
+    let user2 = test_project.normals[1].user.clone();
+    let token2 = test_project.normals[1].token.clone();
+    server
+        .mock_keystone_auth(&token1, &user1.openstack_id, &user1.name)
+        .mount(&server.keystone_server)
+        .await;
+    server
+        .mock_keystone_auth(&token2, &user2.openstack_id, &user2.name)
+        .mount(&server.keystone_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/auth/tokens/"))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .append_header("X-Subject-Token", &server.keystone_token)
+                .set_body_json(json!({
+                    "token": {
+                        "expires_at": (Utc::now() + TimeDelta::hours(3))
+                            .to_rfc3339(),
+                    }
+                })),
+        )
+        .with_priority(1)
+        .expect(1)
+        .mount(&server.keystone_server)
+        .await;
+    let client1 = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token1).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let client2 = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token2).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let hellos1 =
+        futures::future::join_all((0..5).map(|_| client1.hello.user())).await;
+    let hello2 = client2.hello.user().await;
+
+    // assert
+    assert!(hellos1.iter().all(|hello| hello.is_ok()));
+    assert!(hello2.is_ok());
+}
+
+#[tokio::test]
+async fn e2e_admin_token_is_issued_with_application_credential() {
+    // arrange
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.password = None;
+        c.openstack.application_credential_id = Some("appcredid".to_string());
+        c.openstack.application_credential_secret =
+            Some(SecretString::from("appcredsecret"));
+    })
+    .await;
+
+    // act
+    let requests = server.keystone_server.received_requests().await.unwrap();
+
+    // assert
+    let bodies = requests
+        .iter()
+        .filter(|request| request.method.as_str() == "POST")
+        .map(|request| request.body_json::<Value>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(bodies.len(), 1);
+    assert_eq!(
+        bodies[0],
+        json!({
+            "auth": {
+                "identity": {
+                    "methods": ["application_credential"],
+                    "application_credential": {
+                        "id": "appcredid",
+                        "secret": "appcredsecret",
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn e2e_client_token_can_be_issued_with_application_credential() {
+    // arrange
+    let server = spawn_app().await;
+    Mock::given(method("POST"))
+        .and(path("/auth/tokens/"))
+        .and(body_partial_json(json!({
+            "auth": {
+                "identity": {
+                    "methods": ["application_credential"],
+                    "application_credential": {
+                        "id": "appcredid",
+                        "secret": "appcredsecret",
+                    }
+                }
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .append_header("X-Subject-Token", "appcredtoken"),
+        )
+        .with_priority(1)
+        .expect(1)
+        .mount(&server.keystone_server)
+        .await;
+
+    // act
+    let token = Token::from_application_credential(
+        &server.keystone_server.uri(),
+        "appcredid",
+        "appcredsecret",
+    )
+    .await;
+
+    // assert
+    assert_eq!(token.unwrap().as_ref(), "appcredtoken");
+}
+
+#[tokio::test]
+async fn e2e_lib_user_import_fails_on_truncated_keystone_list() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/domains"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domains": [],
+            "links": {"next": null},
+            "truncated": true,
+        })))
+        .expect(1)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.user.import().await;
+
+    // assert
+    assert!(import.is_err());
+}
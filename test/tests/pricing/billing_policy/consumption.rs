@@ -0,0 +1,130 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::{
+    accounting::server_state::NewServerState,
+    pricing::flavor_price::NewFlavorPrice,
+};
+use avina_test::{random_alphanumeric_string, random_uuid, spawn_app};
+use avina_wire::user::{Project, UserClass};
+use chrono::{TimeZone, Utc};
+use uuid::Uuid;
+
+#[tokio::test]
+async fn e2e_lib_billing_policy_factor_applies_to_consumption_and_cost() {
+    // arrange
+    let server = spawn_app().await;
+    let user_class = UserClass::UC1;
+    let project_new = Project {
+        id: 1,
+        name: random_alphanumeric_string(10),
+        openstack_id: random_uuid(),
+        user_class,
+    };
+    let test_project = server
+        .setup_test_project_with_project(1, 0, 0, project_new)
+        .await
+        .expect("Failed to setup test project");
+    let admin_user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &admin_user.openstack_id, &admin_user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let begin = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let policy_change = Utc.with_ymd_and_hms(2023, 1, 6, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2023, 1, 11, 0, 0, 0).unwrap();
+    server
+        .setup_test_flavor_price_with_new_flavor_price(
+            &flavor,
+            NewFlavorPrice {
+                flavor_id: flavor.id as u64,
+                user_class,
+                unit_price: 365.0,
+                start_time: begin,
+            },
+        )
+        .await
+        .expect("Failed to setup test flavor price");
+    let mut instance_ids = Vec::new();
+    for status in ["SHUTOFF", "SHELVED_OFFLOADED"] {
+        let instance_id = Uuid::new_v4();
+        server
+            .setup_test_server_state_with_server_state(
+                &flavor,
+                &admin_user,
+                NewServerState {
+                    begin,
+                    end: Some(end),
+                    instance_id,
+                    instance_name: random_alphanumeric_string(10),
+                    flavor: flavor.id,
+                    status: status.to_string(),
+                    user: admin_user.id,
+                },
+            )
+            .await
+            .expect("Failed to setup test server state");
+        instance_ids.push(instance_id);
+    }
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    client
+        .billing_policy
+        .create("SHUTOFF", 0.5)
+        .user_class(user_class)
+        .start_time(policy_change.into())
+        .send()
+        .await
+        .unwrap();
+    let shutoff_consumption = client
+        .server_consumption
+        .get()
+        .begin(begin.into())
+        .end(end.into())
+        .server(instance_ids[0])
+        .await
+        .unwrap();
+    let shutoff_cost = client
+        .server_cost
+        .get()
+        .begin(begin.into())
+        .end(end.into())
+        .server(instance_ids[0])
+        .await
+        .unwrap();
+    let shelved_cost = client
+        .server_cost
+        .get()
+        .begin(begin.into())
+        .end(end.into())
+        .server(instance_ids[1])
+        .await
+        .unwrap();
+    let user_cost = client
+        .server_cost
+        .get()
+        .begin(begin.into())
+        .end(end.into())
+        .user(admin_user.id)
+        .await
+        .unwrap();
+
+    // assert
+    let day = (24 * 60 * 60) as f64;
+    assert_eq!(shutoff_consumption[&flavor.name], 5. * day + 2.5 * day);
+    assert!((shutoff_cost.total - 7.5).abs() < 1e-6);
+    assert_eq!(shelved_cost.total, 0.);
+    assert!((user_cost.total - 7.5).abs() < 1e-6);
+}
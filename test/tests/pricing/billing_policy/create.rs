@@ -0,0 +1,141 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::spawn_app;
+use avina_wire::user::UserClass;
+use chrono::{TimeZone, Utc};
+
+#[tokio::test]
+async fn e2e_lib_billing_policy_create_denies_access_to_master_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 1, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let create = client.billing_policy.create("SHELVED", 0.).send().await;
+
+    // assert
+    assert!(create.is_err());
+    assert_eq!(
+        create.unwrap_err().to_string(),
+        format!("Admin privileges required")
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_billing_policy_create_rejects_overlapping_policy() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let start_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let end_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let created = client
+        .billing_policy
+        .create("SHUTOFF", 0.5)
+        .user_class(UserClass::UC2)
+        .start_time(start_time.into())
+        .end_time(end_time.into())
+        .send()
+        .await
+        .unwrap();
+
+    // act
+    let overlapping = client
+        .billing_policy
+        .create("SHUTOFF", 0.)
+        .user_class(UserClass::UC2)
+        .start_time(Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap().into())
+        .send()
+        .await;
+    let following = client
+        .billing_policy
+        .create("SHUTOFF", 0.)
+        .user_class(UserClass::UC2)
+        .start_time(end_time.into())
+        .send()
+        .await;
+
+    // assert
+    assert_eq!(created.factor, 0.5);
+    assert_eq!(created.end_time, Some(end_time.fixed_offset()));
+    assert_eq!(
+        overlapping.unwrap_err().to_string(),
+        format!("Billing policy overlaps billing policy {}", created.id)
+    );
+    let following = following.unwrap();
+    let listed = client
+        .billing_policy
+        .list()
+        .status("SHUTOFF")
+        .user_class(UserClass::UC2)
+        .send()
+        .await
+        .unwrap();
+    assert!(listed.iter().any(|policy| policy.id == created.id));
+    assert!(listed.iter().any(|policy| policy.id == following.id));
+    assert!(listed.iter().any(|policy| policy.user_class.is_none()));
+}
+
+#[tokio::test]
+async fn e2e_lib_billing_policy_create_rejects_negative_factor() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let create = client.billing_policy.create("SHELVED", -1.).send().await;
+
+    // assert
+    assert_eq!(
+        create.unwrap_err().to_string(),
+        "Factor must be a non-negative number"
+    );
+}
@@ -0,0 +1,2 @@
+mod consumption;
+mod create;
@@ -0,0 +1,170 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::pricing::flavor_price::NewFlavorPrice;
+use avina_test::{random_alphanumeric_string, random_uuid, spawn_app};
+use avina_wire::user::{Project, UserClass};
+use chrono::{TimeZone, Utc};
+
+#[tokio::test]
+async fn e2e_lib_flavor_price_estimate_uses_user_class_and_price_changes() {
+    // arrange
+    let server = spawn_app().await;
+    let user_class = UserClass::UC1;
+    let project_new = Project {
+        id: 1,
+        name: random_alphanumeric_string(10),
+        openstack_id: random_uuid(),
+        user_class,
+    };
+    let test_project = server
+        .setup_test_project_with_project(0, 0, 1, project_new)
+        .await
+        .expect("Failed to setup test project");
+    let normal_user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &normal_user.openstack_id,
+            &normal_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let begin = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let price_change = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+    // 8760 EUR per year are 1 EUR per hour
+    for (user_class, unit_price, start_time) in [
+        (UserClass::UC1, 8760.0, begin),
+        (UserClass::UC1, 17520.0, price_change),
+        (UserClass::UC2, 87600.0, begin),
+    ] {
+        server
+            .setup_test_flavor_price_with_new_flavor_price(
+                &flavor,
+                NewFlavorPrice {
+                    flavor_id: flavor.id as u64,
+                    user_class,
+                    unit_price,
+                    start_time,
+                },
+            )
+            .await
+            .expect("Failed to setup test flavor price");
+    }
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let estimate = client
+        .flavor_price
+        .estimate(flavor.id, 2 * 24 * 60 * 60)
+        .count(2)
+        .begin(begin.into())
+        .send()
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(estimate.flavor, flavor.id);
+    assert_eq!(estimate.flavor_name, flavor.name);
+    assert_eq!(estimate.user_class, user_class);
+    assert_eq!(estimate.count, 2);
+    assert_eq!(
+        estimate.end,
+        Utc.with_ymd_and_hms(2023, 1, 3, 0, 0, 0).unwrap()
+    );
+    assert!((estimate.cost - 2.0 * (24.0 + 48.0)).abs() < 1e-6);
+    assert!((estimate.unit_price - (8760.0 + 17520.0) / 2.0).abs() < 1e-6);
+    assert!((estimate.hourly_price - 1.5).abs() < 1e-6);
+    assert!((estimate.daily_price - 36.0).abs() < 1e-6);
+    assert!((estimate.monthly_price - 1095.0).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn e2e_lib_flavor_price_estimate_returns_not_found_for_unknown_flavor() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let normal_user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &normal_user.openstack_id,
+            &normal_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let estimate = client.flavor_price.estimate(12345, 3600).send().await;
+
+    // assert
+    assert!(estimate.is_err());
+}
+
+#[tokio::test]
+async fn e2e_lib_flavor_price_estimate_rejects_zero_count() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let normal_user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &normal_user.openstack_id,
+            &normal_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let estimate = client
+        .flavor_price
+        .estimate(flavor.id, 3600)
+        .count(0)
+        .send()
+        .await;
+
+    // assert
+    assert!(estimate.is_err());
+    assert_eq!(
+        estimate.unwrap_err().to_string(),
+        "Count must be positive".to_string()
+    );
+}
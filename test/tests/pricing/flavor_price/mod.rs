@@ -1,2 +1,4 @@
 mod delete;
+mod estimate;
 mod list;
+mod revise;
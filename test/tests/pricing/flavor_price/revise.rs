@@ -0,0 +1,259 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::pricing::flavor_price::NewFlavorPrice;
+use avina_test::spawn_app;
+use avina_wire::{
+    dry_run::{ChangeAction, ChangeObject},
+    user::UserClass,
+};
+use chrono::{TimeZone, Utc};
+
+#[tokio::test]
+async fn e2e_lib_master_user_cannot_revise_flavor_prices() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 1, 0)
+        .await
+        .expect("Failed to setup test project");
+    let master_user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(
+            &token,
+            &master_user.openstack_id,
+            &master_user.name,
+        )
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let revise = client
+        .flavor_price
+        .revise(Utc::now().into())
+        .percentage(10.0)
+        .send()
+        .await;
+
+    // assert
+    assert!(revise.is_err());
+    assert_eq!(revise.unwrap_err().to_string(), "Admin privileges required");
+}
+
+#[tokio::test]
+async fn e2e_lib_admin_can_revise_flavor_prices_with_dry_run() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let admin = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &admin.openstack_id, &admin.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let outdated = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+    let current = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    // only the latest price of each user class is revised
+    for (user_class, unit_price, start_time) in [
+        (UserClass::UC1, 50.0, outdated),
+        (UserClass::UC1, 100.0, current),
+        (UserClass::UC2, 200.0, current),
+    ] {
+        server
+            .setup_test_flavor_price_with_new_flavor_price(
+                &flavor,
+                NewFlavorPrice {
+                    flavor_id: flavor.id as u64,
+                    user_class,
+                    unit_price,
+                    start_time,
+                },
+            )
+            .await
+            .expect("Failed to setup test flavor price");
+    }
+    let start_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let mut request = client.flavor_price.revise(start_time.into());
+    request.percentage(10.0).factor(UserClass::UC2, 0.5);
+
+    // act
+    let dry_run = request.send_dry_run().await.unwrap();
+    let prices_after_dry_run = client.flavor_price.list().send().await.unwrap();
+    let revise = request.send().await.unwrap();
+    let prices = client.flavor_price.list().send().await.unwrap();
+
+    // assert
+    assert_eq!(dry_run.summary.new_flavor_price_count, 2);
+    assert_eq!(dry_run.changes.len(), 2);
+    assert!(dry_run.changes.iter().all(|change| {
+        change.action == ChangeAction::Create
+            && change.object == ChangeObject::FlavorPrice
+    }));
+    assert_eq!(prices_after_dry_run.len(), 3);
+    assert_eq!(revise.new_flavor_price_count, 2);
+    let mut revised = prices
+        .into_iter()
+        .filter(|price| price.start_time == start_time)
+        .map(|price| (price.user_class as u32, price.unit_price))
+        .collect::<Vec<_>>();
+    revised.sort_by_key(|(user_class, _)| *user_class);
+    assert_eq!(revised, vec![(1, 110.0), (2, 100.0)]);
+}
+
+#[tokio::test]
+async fn e2e_lib_revise_flavor_prices_rejects_existing_start_time() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let admin = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &admin.openstack_id, &admin.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let start_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    for unit_price_start_time in [
+        Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+        start_time,
+    ] {
+        server
+            .setup_test_flavor_price_with_new_flavor_price(
+                &flavor,
+                NewFlavorPrice {
+                    flavor_id: flavor.id as u64,
+                    user_class: UserClass::UC1,
+                    unit_price: 100.0,
+                    start_time: unit_price_start_time,
+                },
+            )
+            .await
+            .expect("Failed to setup test flavor price");
+    }
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let revise = client
+        .flavor_price
+        .revise(start_time.into())
+        .percentage(10.0)
+        .send()
+        .await;
+
+    // assert
+    assert!(revise.is_err());
+    assert!(
+        revise.unwrap_err().to_string().starts_with(&format!(
+            "Flavor {} already has a price",
+            flavor.name
+        ))
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_revise_flavor_prices_restricts_to_flavor_groups() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let admin = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &admin.openstack_id, &admin.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor_group = server
+        .setup_test_flavor_group(test_project.project.id)
+        .await
+        .expect("Failed to setup test flavor group");
+    let grouped_flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let other_flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    for flavor in [&grouped_flavor, &other_flavor] {
+        server
+            .setup_test_flavor_price_with_new_flavor_price(
+                flavor,
+                NewFlavorPrice {
+                    flavor_id: flavor.id as u64,
+                    user_class: UserClass::UC1,
+                    unit_price: 100.0,
+                    start_time: Utc
+                        .with_ymd_and_hms(2023, 1, 1, 0, 0, 0)
+                        .unwrap(),
+                },
+            )
+            .await
+            .expect("Failed to setup test flavor price");
+    }
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    client
+        .flavor
+        .modify(grouped_flavor.id)
+        .group(flavor_group.id)
+        .send()
+        .await
+        .expect("Failed to add flavor to group");
+
+    // act
+    let dry_run = client
+        .flavor_price
+        .revise(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap().into())
+        .flavor_group(flavor_group.id)
+        .send_dry_run()
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(dry_run.summary.new_flavor_price_count, 1);
+    assert_eq!(
+        dry_run.changes[0].name,
+        format!("{} {}", grouped_flavor.name, UserClass::UC1)
+    );
+}
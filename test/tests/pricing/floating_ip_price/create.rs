@@ -0,0 +1,106 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::spawn_app;
+use avina_wire::{accounting::FloatingIpPool, user::UserClass};
+use chrono::{TimeZone, Utc};
+
+#[tokio::test]
+async fn e2e_lib_floating_ip_price_create_denies_access_to_master_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 1, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let create = client
+        .floating_ip_price
+        .create(UserClass::UC1, FloatingIpPool::Internet)
+        .send()
+        .await;
+
+    // assert
+    assert!(create.is_err());
+    assert_eq!(
+        create.unwrap_err().to_string(),
+        format!("Admin privileges required")
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_floating_ip_price_create_separates_pools() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+    // act
+    let mwn = client
+        .floating_ip_price
+        .create(UserClass::UC2, FloatingIpPool::Mwn)
+        .price(5.0)
+        .start_time(start.into())
+        .send()
+        .await
+        .unwrap();
+    let internet = client
+        .floating_ip_price
+        .create(UserClass::UC2, FloatingIpPool::Internet)
+        .price(20.0)
+        .start_time(start.into())
+        .send()
+        .await
+        .unwrap();
+    let duplicate = client
+        .floating_ip_price
+        .create(UserClass::UC2, FloatingIpPool::Internet)
+        .price(25.0)
+        .start_time(start.into())
+        .send()
+        .await;
+    let current = client
+        .floating_ip_price
+        .list()
+        .user_class(UserClass::UC2)
+        .pool(FloatingIpPool::Internet)
+        .current()
+        .send()
+        .await
+        .unwrap();
+
+    // assert
+    assert!(duplicate.is_err());
+    assert_eq!(mwn.pool, FloatingIpPool::Mwn);
+    assert_eq!(current, vec![internet]);
+}
@@ -1 +1,6 @@
+mod billing_policy;
 mod flavor_price;
+mod floating_ip_price;
+mod price_override;
+mod resource_price;
+mod volume_price;
@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::spawn_app;
+use chrono::{TimeZone, Utc};
+
+#[tokio::test]
+async fn e2e_lib_price_override_create_denies_access_to_master_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 1, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let create = client
+        .price_override
+        .create(test_project.project.id)
+        .discount(50.)
+        .send()
+        .await;
+
+    // assert
+    assert!(create.is_err());
+    assert_eq!(create.unwrap_err().to_string(), "Admin privileges required");
+}
+
+#[tokio::test]
+async fn e2e_lib_price_override_create_rejects_unit_price_without_flavor() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let create = client
+        .price_override
+        .create(test_project.project.id)
+        .unit_price(100.)
+        .send()
+        .await;
+
+    // assert
+    assert_eq!(
+        create.unwrap_err().to_string(),
+        "Unit price requires a flavor"
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_price_override_create_rejects_overlapping_override() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let project = test_project.project;
+    let start_time = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let end_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let created = client
+        .price_override
+        .create(project.id)
+        .discount(20.)
+        .start_time(start_time.into())
+        .end_time(end_time.into())
+        .send()
+        .await
+        .unwrap();
+
+    // act
+    let overlapping = client
+        .price_override
+        .create(project.id)
+        .discount(30.)
+        .start_time(Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap().into())
+        .send()
+        .await;
+    let for_flavor = client
+        .price_override
+        .create(project.id)
+        .flavor(flavor.id)
+        .unit_price(100.)
+        .start_time(start_time.into())
+        .send()
+        .await;
+
+    // assert
+    assert_eq!(created.project_name, project.name);
+    assert_eq!(created.flavor, None);
+    assert_eq!(created.discount, Some(20.));
+    assert_eq!(
+        overlapping.unwrap_err().to_string(),
+        format!("Price override overlaps price override {}", created.id)
+    );
+    let for_flavor = for_flavor.unwrap();
+    assert_eq!(for_flavor.flavor_name, Some(flavor.name));
+    let listed = client
+        .price_override
+        .list()
+        .project(project.id)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 2);
+}
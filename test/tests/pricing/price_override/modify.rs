@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::spawn_app;
+use chrono::{TimeZone, Utc};
+
+#[tokio::test]
+async fn e2e_lib_price_override_modify_keeps_and_removes_end_time() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let end_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let created = client
+        .price_override
+        .create(test_project.project.id)
+        .discount(20.)
+        .start_time(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap().into())
+        .end_time(end_time.into())
+        .send()
+        .await
+        .unwrap();
+
+    // act
+    let modified = client
+        .price_override
+        .modify(created.id)
+        .discount(30.)
+        .send()
+        .await
+        .unwrap();
+    let cleared = client
+        .price_override
+        .modify(created.id)
+        .no_end_time()
+        .send()
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(modified.discount, Some(30.));
+    assert_eq!(modified.end_time, Some(end_time.into()));
+    assert_eq!(cleared.discount, Some(30.));
+    assert_eq!(cleared.end_time, None);
+    assert_eq!(
+        client.price_override.get(created.id).await.unwrap(),
+        cleared
+    );
+}
@@ -0,0 +1,101 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_test::spawn_app;
+use avina_wire::user::UserClass;
+use chrono::{TimeZone, Utc};
+
+#[tokio::test]
+async fn e2e_lib_volume_price_create_denies_access_to_master_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 1, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.masters[0].user.clone();
+    let token = test_project.masters[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let create = client.volume_price.create(UserClass::UC1).send().await;
+
+    // assert
+    assert!(create.is_err());
+    assert_eq!(
+        create.unwrap_err().to_string(),
+        format!("Admin privileges required")
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_volume_price_create_and_list_current_price() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    let old_start = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let new_start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+    // act
+    client
+        .volume_price
+        .create(UserClass::UC2)
+        .price(10.0)
+        .start_time(old_start.into())
+        .send()
+        .await
+        .unwrap();
+    let created = client
+        .volume_price
+        .create(UserClass::UC2)
+        .price(12.5)
+        .start_time(new_start.into())
+        .send()
+        .await
+        .unwrap();
+    let duplicate = client
+        .volume_price
+        .create(UserClass::UC2)
+        .price(15.0)
+        .start_time(new_start.into())
+        .send()
+        .await;
+    let current = client
+        .volume_price
+        .list()
+        .user_class(UserClass::UC2)
+        .current()
+        .send()
+        .await
+        .unwrap();
+
+    // assert
+    assert!(duplicate.is_err());
+    assert_eq!(current, vec![created]);
+}
@@ -0,0 +1,141 @@
+use avina_test::{TestApp, spawn_app_with_configuration};
+use serde_json::Value;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+async fn mock_openstack(server: &TestApp, nova_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/auth/tokens/"))
+        .and(header("X-Subject-Token", server.keystone_token.as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server.keystone_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2.1/flavors"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(nova_server)
+        .await;
+}
+
+#[tokio::test]
+async fn e2e_ready_reports_required_dependencies_up() {
+    // arrange
+    let nova_server = MockServer::start().await;
+    let nova_endpoint = nova_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.nova_endpoint = nova_endpoint;
+        c.application.cloud_usage_url = None;
+        c.application.avina_ldap_url = None;
+    })
+    .await;
+    mock_openstack(&server, &nova_server).await;
+
+    // act
+    let response = reqwest::Client::new()
+        .get(format!("{}/ready", server.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // assert
+    assert_eq!(response.status().as_u16(), 200);
+    let report: Value = response.json().await.unwrap();
+    assert_eq!(report["status"], "ready");
+    let dependencies = report["dependencies"].as_object().unwrap();
+    assert_eq!(dependencies.len(), 3);
+    for name in ["database", "keystone", "nova"] {
+        assert_eq!(dependencies[name]["status"], "up");
+        assert_eq!(dependencies[name]["required"], true);
+        assert!(dependencies[name]["latency_ms"].is_f64());
+        assert!(dependencies[name].get("error").is_none());
+    }
+}
+
+#[tokio::test]
+async fn e2e_ready_is_unavailable_when_required_dependency_is_down() {
+    // arrange
+    let nova_server = MockServer::start().await;
+    let nova_endpoint = nova_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.nova_endpoint = nova_endpoint;
+        c.application.cloud_usage_url = None;
+        c.application.avina_ldap_url = None;
+    })
+    .await;
+    Mock::given(method("GET"))
+        .and(path("/auth/tokens/"))
+        .and(header("X-Subject-Token", server.keystone_token.as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server.keystone_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2.1/flavors"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&nova_server)
+        .await;
+
+    // act
+    let response = reqwest::Client::new()
+        .get(format!("{}/ready", server.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // assert
+    assert_eq!(response.status().as_u16(), 503);
+    let report: Value = response.json().await.unwrap();
+    assert_eq!(report["status"], "unavailable");
+    assert_eq!(report["dependencies"]["database"]["status"], "up");
+    assert_eq!(report["dependencies"]["keystone"]["status"], "up");
+    assert_eq!(report["dependencies"]["nova"]["status"], "down");
+    assert_eq!(
+        report["dependencies"]["nova"]["error"],
+        "Failed to list flavors, returned code 503"
+    );
+}
+
+#[tokio::test]
+async fn e2e_ready_is_degraded_when_optional_dependency_is_down() {
+    // arrange
+    let nova_server = MockServer::start().await;
+    let nova_endpoint = nova_server.uri();
+    let cloud_usage_server = MockServer::start().await;
+    let cloud_usage_url = cloud_usage_server.uri();
+    let avina_ldap_server = MockServer::start().await;
+    let avina_ldap_url = avina_ldap_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.nova_endpoint = nova_endpoint;
+        c.application.cloud_usage_url = Some(cloud_usage_url);
+        c.application.avina_ldap_url = Some(avina_ldap_url);
+        c.application.avina_ldap_token = Some("ldaptoken".to_string());
+    })
+    .await;
+    mock_openstack(&server, &nova_server).await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&cloud_usage_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(header("Authorization", "ldaptoken"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&avina_ldap_server)
+        .await;
+
+    // act
+    let response = reqwest::Client::new()
+        .get(format!("{}/ready", server.address))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    // assert
+    assert_eq!(response.status().as_u16(), 200);
+    let report: Value = response.json().await.unwrap();
+    assert_eq!(report["status"], "degraded");
+    assert_eq!(report["dependencies"]["cloud_usage"]["status"], "down");
+    assert_eq!(report["dependencies"]["cloud_usage"]["required"], false);
+    assert_eq!(report["dependencies"]["avina_ldap"]["status"], "up");
+    assert_eq!(report["dependencies"]["avina_ldap"]["required"], false);
+}
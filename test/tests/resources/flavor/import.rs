@@ -0,0 +1,278 @@
+use std::str::FromStr;
+
+use avina::{Api, Token};
+use avina_api::database::pricing::flavor_price::NewFlavorPrice;
+use avina_test::{random_uuid, spawn_app_with_configuration};
+use avina_wire::{
+    audit::ObjectType,
+    dry_run::{ChangeAction, ChangeObject},
+    resources::FlavorImport,
+    user::UserClass,
+};
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path, query_param, query_param_is_missing},
+};
+
+fn nova_flavor(name: &str) -> serde_json::Value {
+    json!({
+        "OS-FLV-DISABLED:disabled": false,
+        "disk": 20,
+        "os-flavor-access:is_public": true,
+        "id": random_uuid(),
+        "links": [],
+        "name": name,
+        "ram": 4096,
+        "vcpus": 1,
+        "rxtx_factor": 1.0,
+        "description": null,
+    })
+}
+
+#[tokio::test]
+async fn e2e_lib_flavor_import_dry_run_reports_flavors_without_importing_them()
+{
+    // arrange
+    let nova_server = MockServer::start().await;
+    let nova_endpoint = nova_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.nova_endpoint = nova_endpoint;
+    })
+    .await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2.1/flavors/detail"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "flavors": [nova_flavor("lrz.small")],
+        })))
+        .mount(&nova_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let dry_run = client.flavor.import_dry_run().await.unwrap();
+
+    // assert
+    assert_eq!(
+        dry_run.summary,
+        FlavorImport {
+            new_flavor_count: 1,
+            new_flavor_price_count: 0,
+        }
+    );
+    assert_eq!(dry_run.changes.len(), 1);
+    assert_eq!(dry_run.changes[0].action, ChangeAction::Create);
+    assert_eq!(dry_run.changes[0].object, ChangeObject::Flavor);
+    assert_eq!(dry_run.changes[0].name, "lrz.small");
+    assert!(client.flavor.list().all().send().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn e2e_lib_flavor_import_generates_flavor_prices_from_resource_prices() {
+    // arrange
+    let nova_server = MockServer::start().await;
+    let nova_endpoint = nova_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.nova_endpoint = nova_endpoint;
+        c.openstack.gpu_aliases = vec!["a100".to_string()];
+    })
+    .await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let overridden_flavor = server
+        .setup_test_flavor()
+        .await
+        .expect("Failed to setup test flavor");
+    let start_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    server
+        .setup_test_flavor_price_with_new_flavor_price(
+            &overridden_flavor,
+            NewFlavorPrice {
+                flavor_id: overridden_flavor.id as u64,
+                user_class: UserClass::UC1,
+                unit_price: 42.0,
+                start_time: Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap(),
+            },
+        )
+        .await
+        .expect("Failed to setup test flavor price");
+    let mut gpu_flavor = nova_flavor("lrz.gpu");
+    gpu_flavor["vcpus"] = json!(4);
+    gpu_flavor["ram"] = json!(8192);
+    gpu_flavor["extra_specs"] =
+        json!({"pci_passthrough:alias": "a100:1,nvme:2"});
+    Mock::given(method("GET"))
+        .and(path("/v2.1/flavors/detail"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "flavors": [gpu_flavor, nova_flavor(&overridden_flavor.name)],
+        })))
+        .mount(&nova_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+    client
+        .resource_price
+        .create(UserClass::UC1)
+        .vcpu_price(100.0)
+        .ram_price(10.0)
+        .gpu_price(1000.0)
+        .start_time(start_time.into())
+        .send()
+        .await
+        .expect("Failed to create resource price");
+
+    // act
+    let import = client.flavor.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        FlavorImport {
+            new_flavor_count: 1,
+            new_flavor_price_count: 1,
+        }
+    );
+    let prices = client
+        .flavor_price
+        .list()
+        .user_class(UserClass::UC1)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(prices.len(), 2);
+    let gpu_price = prices
+        .iter()
+        .find(|price| price.flavor_name == "lrz.gpu")
+        .unwrap();
+    // 4 vCPUs, 8 GiB RAM and 1 GPU, as NVMe devices are not charged
+    assert_eq!(gpu_price.unit_price, 4.0 * 100.0 + 8.0 * 10.0 + 1000.0);
+    assert_eq!(gpu_price.start_time, start_time);
+    let overridden_price = prices
+        .iter()
+        .find(|price| price.flavor == overridden_flavor.id)
+        .unwrap();
+    assert_eq!(overridden_price.unit_price, 42.0);
+    let entries = client
+        .audit
+        .list()
+        .object_type(ObjectType::FlavorPrice)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].user, user.id);
+    assert_eq!(entries[0].object_id, Some(gpu_price.id));
+    assert_eq!(
+        entries[0].after.as_ref().unwrap()["unit_price"],
+        gpu_price.unit_price
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_flavor_import_follows_nova_flavors_links() {
+    // arrange
+    let nova_server = MockServer::start().await;
+    let nova_endpoint = nova_server.uri();
+    let server = spawn_app_with_configuration(|c| {
+        c.openstack.nova_endpoint = nova_endpoint;
+    })
+    .await;
+    let test_project = server
+        .setup_test_project(1, 0, 0)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+    let flavor1 = nova_flavor("lrz.small");
+    let flavor2 = nova_flavor("lrz.medium");
+    let marker = flavor1["id"].as_str().unwrap().to_string();
+    Mock::given(method("GET"))
+        .and(path("/v2.1/flavors/detail"))
+        .and(query_param("limit", "1000"))
+        .and(query_param_is_missing("marker"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "flavors": [flavor1],
+            "flavors_links": [{
+                "href": format!("{}/v2.1/flavors/detail?marker={marker}",
+                    nova_server.uri()),
+                "rel": "next",
+            }],
+        })))
+        .expect(1)
+        .mount(&nova_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2.1/flavors/detail"))
+        .and(query_param("marker", marker.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "flavors": [flavor2],
+        })))
+        .expect(1)
+        .mount(&nova_server)
+        .await;
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let import = client.flavor.import().await.unwrap();
+
+    // assert
+    assert_eq!(
+        import,
+        FlavorImport {
+            new_flavor_count: 2,
+            new_flavor_price_count: 0,
+        }
+    );
+    let mut names = client
+        .flavor
+        .list()
+        .all()
+        .send()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|flavor| flavor.name)
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["lrz.medium", "lrz.small"]);
+}
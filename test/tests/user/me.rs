@@ -39,3 +39,111 @@ async fn e2e_lib_user_me_works() {
     assert_eq!(me.project.name, project.name);
     assert_eq!(me.project_name, project.name);
 }
+
+#[tokio::test]
+async fn e2e_lib_admin_user_can_impersonate_other_user() {
+    // arrange
+    let server = spawn_app().await;
+    let (admin, _admin_project, token) = server
+        .setup_test_user_and_project(true)
+        .await
+        .expect("Failed to setup test user and project.");
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.normals[0].user.clone();
+    let project = test_project.project.clone();
+    server
+        .mock_keystone_auth(&token, &admin.openstack_id, &admin.name)
+        .mount(&server.keystone_server)
+        .await;
+
+    // arrange
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        Some(user.id),
+        None,
+    )
+    .unwrap();
+
+    // act
+    let me = client.user.me().await.unwrap();
+
+    // assert
+    assert_eq!(me.id, user.id);
+    assert_eq!(me.name, user.name);
+    assert!(!me.is_staff);
+    assert_eq!(me.project.id, project.id);
+    assert_eq!(me.project.name, project.name);
+}
+
+#[tokio::test]
+async fn e2e_lib_normal_user_cannot_impersonate_other_user() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 2)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    let user2 = test_project.normals[1].user.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+
+    // arrange
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        Some(user2.id),
+        None,
+    )
+    .unwrap();
+
+    // act
+    let me = client.user.me().await;
+
+    // assert
+    assert!(me.is_err());
+    assert_eq!(
+        me.unwrap_err().to_string(),
+        "Admin privileges required for impersonation".to_string()
+    );
+}
+
+#[tokio::test]
+async fn e2e_lib_admin_user_cannot_impersonate_non_existing_user() {
+    // arrange
+    let server = spawn_app().await;
+    let (admin, _project, token) = server
+        .setup_test_user_and_project(true)
+        .await
+        .expect("Failed to setup test user and project.");
+    server
+        .mock_keystone_auth(&token, &admin.openstack_id, &admin.name)
+        .mount(&server.keystone_server)
+        .await;
+
+    // arrange
+    let client = Api::new(
+        format!("{}/api", server.address),
+        Token::from_str(&token).unwrap(),
+        Some(admin.id + 1000),
+        None,
+    )
+    .unwrap();
+
+    // act
+    let me = client.user.me().await;
+
+    // assert
+    assert!(me.is_err());
+    assert_eq!(
+        me.unwrap_err().to_string(),
+        "User to impersonate does not exist".to_string()
+    );
+}
@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use avina::{Api, Token};
 use avina_test::spawn_app;
+use futures::TryStreamExt;
 
 // Permission matrix:
 //                     no filter    project filter     all filter
@@ -174,3 +175,185 @@ async fn e2e_lib_admin_user_can_use_any_user_list_filters() {
     assert!(users3.contains(&user3));
     assert!(users3.contains(&user4));
 }
+
+#[tokio::test]
+async fn e2e_lib_user_list_can_be_paginated() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 4)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+
+    // arrange
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let users = client.user.list().all().send().await.unwrap();
+    let page1 = client.user.list().all().limit(2).page().await.unwrap();
+    let page3 = client
+        .user
+        .list()
+        .all()
+        .limit(2)
+        .offset(4)
+        .page()
+        .await
+        .unwrap();
+    let pages = client
+        .user
+        .list()
+        .all()
+        .limit(2)
+        .pages()
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    let limited_users = client
+        .user
+        .list()
+        .all()
+        .limit(2)
+        .offset(2)
+        .send()
+        .await
+        .unwrap();
+    let paged_users =
+        client.user.list().all().limit(2).all_pages().await.unwrap();
+
+    // assert
+    assert_eq!(users.len(), 5);
+    assert_eq!(page1.count, 5);
+    assert_eq!(page1.results, users[0..2]);
+    assert!(page1.next.unwrap().contains("offset=2"));
+    assert!(page1.previous.is_none());
+    assert_eq!(page3.count, 5);
+    assert_eq!(page3.results, users[4..5]);
+    assert!(page3.next.is_none());
+    assert!(page3.previous.unwrap().contains("offset=2"));
+    assert_eq!(pages.len(), 3);
+    assert_eq!(
+        pages
+            .into_iter()
+            .flat_map(|p| p.results)
+            .collect::<Vec<_>>(),
+        users
+    );
+    assert_eq!(limited_users, users[2..4]);
+    assert_eq!(paged_users, users);
+}
+
+#[tokio::test]
+async fn e2e_lib_user_list_can_be_ordered() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(1, 0, 2)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.admins[0].user.clone();
+    let token = test_project.admins[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+
+    // arrange
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let users1 = client
+        .user
+        .list()
+        .all()
+        .ordering("id")
+        .send()
+        .await
+        .unwrap();
+    let users2 = client
+        .user
+        .list()
+        .all()
+        .ordering("-id")
+        .send()
+        .await
+        .unwrap();
+    let users3 = client
+        .user
+        .list()
+        .all()
+        .ordering("-is_staff,name")
+        .send()
+        .await
+        .unwrap();
+
+    // assert
+    assert_eq!(users1.len(), 3);
+    assert!(users1.windows(2).all(|w| w[0].id < w[1].id));
+    assert_eq!(users2, users1.into_iter().rev().collect::<Vec<_>>());
+    assert_eq!(users3[0], user);
+    assert!(users3[1].name <= users3[2].name);
+}
+
+#[tokio::test]
+async fn e2e_lib_user_list_rejects_invalid_pagination_and_ordering() {
+    // arrange
+    let server = spawn_app().await;
+    let test_project = server
+        .setup_test_project(0, 0, 1)
+        .await
+        .expect("Failed to setup test project");
+    let user = test_project.normals[0].user.clone();
+    let token = test_project.normals[0].token.clone();
+    server
+        .mock_keystone_auth(&token, &user.openstack_id, &user.name)
+        .mount(&server.keystone_server)
+        .await;
+
+    // arrange
+    let client = Api::new(
+        format!("{}/api", &server.address),
+        Token::from_str(&token).unwrap(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    // act
+    let list1 = client.user.list().offset(1).send().await;
+    let list2 = client.user.list().limit(0).send().await;
+    let list3 = client.user.list().ordering("password").send().await;
+
+    // assert
+    assert_eq!(
+        list1.unwrap_err().to_string(),
+        "Offset requires a limit".to_string()
+    );
+    assert_eq!(
+        list2.unwrap_err().to_string(),
+        "Limit must be greater than zero".to_string()
+    );
+    assert_eq!(
+        list3.unwrap_err().to_string(),
+        "Cannot order by unknown field \"password\"".to_string()
+    );
+}
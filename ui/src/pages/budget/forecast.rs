@@ -0,0 +1,38 @@
+use avina_wire::budgeting::BudgetForecast;
+use dioxus::prelude::*;
+
+#[component]
+pub fn BudgetForecastTable(forecast: BudgetForecast) -> Element {
+    let end = forecast.end.format("%Y-%m-%d");
+    let exceeded = match (forecast.budget, forecast.exceeded_at) {
+        (None, _) => "no budget set".to_string(),
+        (Some(_), None) => format!("not before {end}"),
+        (Some(_), Some(exceeded_at)) => {
+            exceeded_at.format("%Y-%m-%d %H:%M").to_string()
+        }
+    };
+
+    rsx! {
+        table {
+            class: "table",
+            tbody {
+                tr {
+                    th { "Cost so far [EUR]" }
+                    td { "{forecast.cost:.2}" }
+                }
+                tr {
+                    th { "Cost of running servers per hour [EUR]" }
+                    td { "{forecast.hourly_cost:.4}" }
+                }
+                tr {
+                    th { "Forecast until {end} [EUR]" }
+                    td { "{forecast.forecast:.2}" }
+                }
+                tr {
+                    th { "Budget exceeded" }
+                    td { "{exceeded}" }
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use strum::{EnumIter, IntoEnumIterator};
 
 use crate::{components::button::*, pages::profile::Role};
 
+mod forecast;
 mod project;
 mod user;
 
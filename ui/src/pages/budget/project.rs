@@ -4,6 +4,7 @@ use avina::{Api, Token, error::ApiError};
 use avina_wire::{budgeting::BudgetOverTreeUser, user::UserDetailed};
 use dioxus::prelude::*;
 
+use super::forecast::BudgetForecastTable;
 use crate::components::{button::*, charts::*, dialog::*};
 
 #[component]
@@ -22,6 +23,16 @@ pub fn BudgetProjectSubPage(
             .send()
             .await
     );
+    let forecast = api_call!(
+        api_url.clone(),
+        token.clone(),
+        api,
+        api.budget_forecast
+            .get()
+            .project(user.project.id)
+            .send()
+            .await
+    );
 
     let Some(project_tree) = budget_over_tree.projects.get(&user.project_name)
     else {
@@ -81,6 +92,17 @@ pub fn BudgetProjectSubPage(
             }
         }
 
+        br {}
+        div {
+            class: "row",
+            div {
+                class: "col-md-6",
+                h3 { "Project Budget Forecast" }
+                br {}
+                BudgetForecastTable { forecast }
+            }
+        }
+
         br {}
         div {
             class: "row",
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use avina_wire::user::UserDetailed;
 use dioxus::prelude::*;
 
+use super::forecast::BudgetForecastTable;
 use crate::components::charts::*;
 
 #[component]
@@ -12,10 +13,16 @@ pub fn BudgetUserSubPage(
     user: UserDetailed,
 ) -> Element {
     let budget_over_tree = api_call!(
+        api_url.clone(),
+        token.clone(),
+        api,
+        api.budget_over_tree.get().user(user.id).send().await
+    );
+    let forecast = api_call!(
         api_url,
         token,
         api,
-        api.budget_over_tree.get().user(user.id).send().await
+        api.budget_forecast.get().user(user.id).send().await
     );
 
     let Some(project_tree) = budget_over_tree.projects.get(&user.project_name)
@@ -69,6 +76,17 @@ pub fn BudgetUserSubPage(
                     }
                 }
             }
+            div {
+                class: "col-md-6",
+                h3 { "User Budget Forecast" }
+                br {}
+                BudgetForecastTable { forecast }
+            }
+        }
+
+        br {}
+        div {
+            class: "row",
             div {
                 class: "col-md-6",
                 h3 { "Costs from Flavors" }
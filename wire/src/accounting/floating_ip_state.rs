@@ -0,0 +1,95 @@
+//! Types for avina's floating IP state endpoints.
+
+use std::fmt::Display;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, EnumString, IntoStaticStr};
+#[cfg(feature = "tabled")]
+use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[cfg(feature = "tabled")]
+use crate::common::display_option;
+
+/// Pool a floating IP is allocated from.
+///
+/// Addresses of the [Self::Mwn] pool are only reachable from within the
+/// Munich Scientific Network, those of the [Self::Internet] pool from
+/// everywhere.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(
+    clap::ValueEnum,
+    Hash,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Serialize,
+    EnumIter,
+    EnumString,
+    IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FloatingIpPool {
+    Mwn,
+    Internet,
+}
+
+impl Display for FloatingIpPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+/// Allocation of a floating IP to a user during a period of time.
+///
+/// A new floating IP state begins whenever the address, pool or owning user
+/// of the floating IP changes, and it ends when the floating IP is released.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct FloatingIpState {
+    pub id: u32,
+    pub begin: DateTime<FixedOffset>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub end: Option<DateTime<FixedOffset>>,
+    pub floating_ip_id: Uuid,
+    pub address: String,
+    pub pool: FloatingIpPool,
+    pub user: u32,
+    pub username: String,
+}
+
+impl Display for FloatingIpState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("FloatingIpState(id={})", self.id))
+    }
+}
+
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct FloatingIpStateImport {
+    pub new_state_count: u32,
+    pub end_state_count: u32,
+}
+
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+pub struct FloatingIpStateListParams {
+    pub floating_ip: Option<Uuid>,
+    pub pool: Option<FloatingIpPool>,
+    pub user: Option<u32>,
+    pub project: Option<u32>,
+    pub all: Option<bool>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub ordering: Option<String>,
+}
@@ -1,9 +1,13 @@
 //! Types for avina's accounting module.
 
+mod floating_ip_state;
 mod server_consumption;
 mod server_cost;
 mod server_state;
+mod volume_state;
 
+pub use floating_ip_state::*;
 pub use server_consumption::*;
 pub use server_cost::*;
 pub use server_state::*;
+pub use volume_state::*;
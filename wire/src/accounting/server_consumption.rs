@@ -4,30 +4,41 @@ use std::collections::HashMap;
 
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 pub type ServerConsumptionFlavors = HashMap<String, f64>;
 
 pub type ServerConsumptionServer = ServerConsumptionFlavors;
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct ServerConsumptionUser {
+    #[cfg_attr(feature = "utoipa", schema(inline))]
     pub total: ServerConsumptionFlavors,
+    #[cfg_attr(feature = "utoipa", schema(inline))]
     pub servers: HashMap<Uuid, ServerConsumptionServer>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct ServerConsumptionProject {
+    #[cfg_attr(feature = "utoipa", schema(inline))]
     pub total: ServerConsumptionFlavors,
     pub users: HashMap<String, ServerConsumptionUser>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct ServerConsumptionAll {
+    #[cfg_attr(feature = "utoipa", schema(inline))]
     pub total: ServerConsumptionFlavors,
     pub projects: HashMap<String, ServerConsumptionProject>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 /// Parameters passed to the server-consumption endpoint.
 ///
@@ -1,46 +1,87 @@
 //! Types for avina's server cost endpoint.
 
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
 
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct ServerCostSimple {
     pub total: f64,
+    /// Discount on the flavor cost granted by price overrides of the
+    /// project, so the cost at list prices is the total plus the discount.
+    #[serde(default)]
+    pub discount: f64,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct ServerCostServer {
     pub total: f64,
+    /// Discount on the flavor cost, see [ServerCostSimple::discount].
+    #[serde(default)]
+    pub discount: f64,
     pub flavors: HashMap<String, f64>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct ServerCostUser {
     pub total: f64,
+    /// Discount on the flavor cost, see [ServerCostSimple::discount].
+    #[serde(default)]
+    pub discount: f64,
     pub flavors: HashMap<String, f64>,
+    #[serde(default)]
+    pub volumes: f64,
+    #[serde(default)]
+    pub floating_ips: f64,
     pub servers: HashMap<Uuid, ServerCostServer>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct ServerCostProject {
     pub total: f64,
+    /// Discount on the flavor cost, see [ServerCostSimple::discount].
+    #[serde(default)]
+    pub discount: f64,
     pub flavors: HashMap<String, f64>,
+    #[serde(default)]
+    pub volumes: f64,
+    #[serde(default)]
+    pub floating_ips: f64,
     pub users: HashMap<String, ServerCostUser>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct ServerCostAll {
     pub total: f64,
+    /// Discount on the flavor cost, see [ServerCostSimple::discount].
+    #[serde(default)]
+    pub discount: f64,
     pub flavors: HashMap<String, f64>,
+    #[serde(default)]
+    pub volumes: f64,
+    #[serde(default)]
+    pub floating_ips: f64,
     pub projects: HashMap<String, ServerCostProject>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct ServerCostParams {
     pub begin: Option<DateTime<FixedOffset>>,
@@ -51,3 +92,72 @@ pub struct ServerCostParams {
     pub all: Option<bool>,
     pub detail: Option<bool>,
 }
+
+/// Length of the buckets of a server cost series.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(
+    clap::ValueEnum,
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Serialize,
+    PartialEq,
+    Eq,
+    Default,
+    IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ServerCostInterval {
+    Day,
+    Week,
+    #[default]
+    Month,
+}
+
+impl Display for ServerCostInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+/// Consumption and cost of a single bucket of a server cost series.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ServerCostBucket {
+    /// Beginning of the bucket.
+    pub begin: DateTime<FixedOffset>,
+    /// End of the bucket.
+    pub end: DateTime<FixedOffset>,
+    /// Consumption in seconds, summed over all flavors.
+    pub consumption: f64,
+    /// Cost of the consumption.
+    pub cost: f64,
+}
+
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+/// Parameters passed to the server cost series endpoint.
+///
+/// Only one of the filters `server`, `user`, `project`, and `all` will
+/// actually be handled by the API, like for [ServerCostParams].
+pub struct ServerCostSeriesParams {
+    /// Beginning of the series (beginning of current year by default).
+    pub begin: Option<DateTime<FixedOffset>>,
+    /// End of the series (now by default).
+    pub end: Option<DateTime<FixedOffset>>,
+    /// UUID of particular server to calculate the series for.
+    pub server: Option<Uuid>,
+    /// ID of the user to calculate the series for.
+    pub user: Option<u32>,
+    /// ID of the project to calculate the series for.
+    pub project: Option<u32>,
+    /// Activate calculating the global series for all projects.
+    pub all: Option<bool>,
+    /// Length of the buckets, which are aligned to calendar days, weeks
+    /// starting on Monday, or months in UTC (month by default).
+    pub interval: Option<ServerCostInterval>,
+}
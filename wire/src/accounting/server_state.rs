@@ -4,14 +4,18 @@ use std::fmt::Display;
 
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 #[cfg(feature = "tabled")]
 use crate::common::display_option;
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct ServerState {
     pub id: u32,
@@ -34,20 +38,59 @@ impl Display for ServerState {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct ServerStateImport {
     pub new_state_count: u32,
     pub end_state_count: u32,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ServerStateAnomalyKind {
+    MultipleUnfinished,
+    EndBeforeBegin,
+    Overlap,
+    Gap,
+}
+
+impl Display for ServerStateAnomalyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ServerStateAnomaly {
+    pub instance_id: Uuid,
+    pub kind: ServerStateAnomalyKind,
+    pub server_state: u32,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub other_server_state: Option<u32>,
+    pub description: String,
+    pub repairable: bool,
+}
+
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct ServerStateListParams {
     pub server: Option<Uuid>,
     pub user: Option<u32>,
     pub project: Option<u32>,
     pub all: Option<bool>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub ordering: Option<String>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct ServerStateCreateData {
     pub begin: DateTime<FixedOffset>,
@@ -82,6 +125,32 @@ impl ServerStateCreateData {
     }
 }
 
+/// Server state to create with the server-state-load endpoint.
+///
+/// Unlike [ServerStateCreateData], flavor and user are given by their name,
+/// ID, or OpenStack ID, as historical records usually lack avina's IDs.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ServerStateLoadData {
+    pub begin: DateTime<FixedOffset>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateTime<FixedOffset>>,
+    pub instance_id: Uuid,
+    pub instance_name: String,
+    pub flavor: String,
+    pub status: String,
+    pub user: String,
+}
+
+/// Summary of the server states created by the server-state-load endpoint.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ServerStateLoad {
+    pub new_state_count: u32,
+}
+
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct ServerStateModifyData {
     pub id: u32,
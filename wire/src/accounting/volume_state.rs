@@ -0,0 +1,62 @@
+//! Types for avina's volume state endpoints.
+
+use std::fmt::Display;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "tabled")]
+use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[cfg(feature = "tabled")]
+use crate::common::display_option;
+
+/// State of a Cinder volume during a period of time.
+///
+/// Like server states, a new volume state begins whenever the status, name,
+/// size or owning user of the volume changes, so the [Self::size] in GiB is
+/// constant during the state.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct VolumeState {
+    pub id: u32,
+    pub begin: DateTime<FixedOffset>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub end: Option<DateTime<FixedOffset>>,
+    pub volume_id: Uuid,
+    pub volume_name: String,
+    pub status: String,
+    pub size: u32,
+    pub user: u32,
+    pub username: String,
+}
+
+impl Display for VolumeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("VolumeState(id={})", self.id))
+    }
+}
+
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct VolumeStateImport {
+    pub new_state_count: u32,
+    pub end_state_count: u32,
+}
+
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+pub struct VolumeStateListParams {
+    pub volume: Option<Uuid>,
+    pub user: Option<u32>,
+    pub project: Option<u32>,
+    pub all: Option<bool>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub ordering: Option<String>,
+}
@@ -0,0 +1,96 @@
+//! Types for avina's audit endpoints.
+
+use std::fmt::Display;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum::{EnumIter, EnumString, IntoStaticStr};
+#[cfg(feature = "tabled")]
+use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
+
+#[cfg(feature = "tabled")]
+use crate::common::display_option;
+
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(
+    clap::ValueEnum,
+    Hash,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Serialize,
+    EnumIter,
+    EnumString,
+    IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ObjectType {
+    User,
+    Project,
+    Flavor,
+    FlavorGroup,
+    FlavorPrice,
+    BillingPolicy,
+    VolumePrice,
+    FloatingIpPrice,
+    ResourcePrice,
+    PriceOverride,
+    FlavorQuota,
+    ServerState,
+    ProjectBudget,
+    UserBudget,
+    JobRun,
+}
+
+impl Display for ObjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct AuditLogEntry {
+    pub id: u32,
+    pub time: DateTime<FixedOffset>,
+    pub user: u32,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub impersonated_user: Option<u32>,
+    pub method: String,
+    pub route: String,
+    pub object_type: ObjectType,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub object_id: Option<u32>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub before: Option<Value>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub after: Option<Value>,
+}
+
+impl Display for AuditLogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!(
+            "AuditLogEntry(id={}, method={}, route={})",
+            self.id, self.method, self.route
+        ))
+    }
+}
+
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+pub struct AuditLogListParams {
+    pub object_type: Option<ObjectType>,
+    pub user: Option<u32>,
+    pub begin: Option<DateTime<FixedOffset>>,
+    pub end: Option<DateTime<FixedOffset>>,
+    pub limit: Option<u32>,
+}
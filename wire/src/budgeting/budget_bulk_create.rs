@@ -3,13 +3,17 @@
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct BudgetBulkCreateData {
     pub year: i32,
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct BudgetBulkCreate {
     pub new_user_budget_count: u32,
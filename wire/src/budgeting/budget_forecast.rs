@@ -0,0 +1,47 @@
+//! Types for avina's budget-forecast endpoint.
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "tabled")]
+use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
+
+#[cfg(feature = "tabled")]
+use crate::common::display_option;
+
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct BudgetForecastParams {
+    pub project: Option<u32>,
+    pub user: Option<u32>,
+}
+
+/// Projection of the cost of a user or project to the end of the year.
+///
+/// The currently running servers are assumed to keep running with their
+/// current status, at the prices valid at each time, including price changes
+/// that are already scheduled.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct BudgetForecast {
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub budget_id: Option<u32>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub budget: Option<u32>,
+    /// Cost from the start of the year until now.
+    pub cost: f64,
+    /// Cost of the running servers for the next hour.
+    pub hourly_cost: f64,
+    /// Projected cost at the end of the year.
+    pub forecast: f64,
+    /// End of the year, up to which the cost is projected.
+    pub end: DateTime<FixedOffset>,
+    /// Time at which the budget is predicted to be exceeded, or already was.
+    ///
+    /// This is [None] if there is no budget or it lasts until the end.
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub exceeded_at: Option<DateTime<FixedOffset>>,
+}
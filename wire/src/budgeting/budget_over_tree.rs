@@ -4,8 +4,12 @@ use std::collections::HashMap;
 
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct BudgetOverTreeParams {
     pub all: Option<bool>,
@@ -14,15 +18,24 @@ pub struct BudgetOverTreeParams {
     pub end: Option<DateTime<FixedOffset>>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct BudgetOverTreeServer {
     pub total: f64,
+    /// Discount on the flavor cost, see [BudgetOverTreeUser::discount].
+    #[serde(default)]
+    pub discount: f64,
     pub flavors: HashMap<String, f64>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct BudgetOverTreeUser {
     pub cost: f64,
+    /// Discount on the flavor cost granted by price overrides of the
+    /// project, so the cost at list prices is the cost plus the discount.
+    #[serde(default)]
+    pub discount: f64,
     pub budget_id: Option<u32>,
     pub budget: Option<u64>,
     pub over: bool,
@@ -30,9 +43,13 @@ pub struct BudgetOverTreeUser {
     pub flavors: HashMap<String, f64>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct BudgetOverTreeProject {
     pub cost: f64,
+    /// Discount on the flavor cost, see [BudgetOverTreeUser::discount].
+    #[serde(default)]
+    pub discount: f64,
     pub budget_id: Option<u32>,
     pub budget: Option<u64>,
     pub over: bool,
@@ -42,10 +59,14 @@ pub struct BudgetOverTreeProject {
     pub flavors: Option<HashMap<String, f64>>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct BudgetOverTree {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cost: Option<f64>,
+    /// Discount on the flavor cost, see [BudgetOverTreeUser::discount].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<f64>,
     pub projects: HashMap<String, BudgetOverTreeProject>,
     // TODO: why is this an option?
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1,11 +1,13 @@
 //! Types for avina's budgeting module.
 
 mod budget_bulk_create;
+mod budget_forecast;
 mod budget_over_tree;
 mod project_budget;
 mod user_budget;
 
 pub use budget_bulk_create::*;
+pub use budget_forecast::*;
 pub use budget_over_tree::*;
 pub use project_budget::*;
 pub use user_budget::*;
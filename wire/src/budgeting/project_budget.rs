@@ -8,11 +8,14 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 
 use crate::common::is_false;
 
 #[cfg_attr(feature = "sqlx", derive(FromRow))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct ProjectBudget {
     #[cfg_attr(feature = "sqlx", sqlx(try_from = "i32"))]
@@ -30,14 +33,21 @@ impl Display for ProjectBudget {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct ProjectBudgetListParams {
     pub user: Option<u32>,
     pub project: Option<u32>,
     pub all: Option<bool>,
     pub year: Option<u32>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub ordering: Option<String>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct ProjectBudgetOverParams {
     pub end: Option<DateTime<FixedOffset>>,
@@ -47,6 +57,7 @@ pub struct ProjectBudgetOverParams {
     pub detail: Option<bool>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ProjectBudgetCreateData {
     pub project: u32,
@@ -66,6 +77,7 @@ impl ProjectBudgetCreateData {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ProjectBudgetModifyData {
     pub id: u32,
@@ -87,6 +99,7 @@ impl ProjectBudgetModifyData {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct ProjectBudgetOverSimple {
     pub budget_id: u32,
@@ -96,6 +109,7 @@ pub struct ProjectBudgetOverSimple {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct ProjectBudgetOverDetail {
     pub budget_id: u32,
@@ -103,5 +117,9 @@ pub struct ProjectBudgetOverDetail {
     pub project_name: String,
     pub over: bool,
     pub cost: f64,
+    /// Discount on the flavor cost granted by price overrides of the
+    /// project, so the cost at list prices is the cost plus the discount.
+    #[serde(default)]
+    pub discount: f64,
     pub budget: u32,
 }
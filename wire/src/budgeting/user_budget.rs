@@ -8,6 +8,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 
 #[cfg(feature = "tabled")]
 use crate::common::display_option;
@@ -15,6 +17,7 @@ use crate::common::is_false;
 
 #[cfg_attr(feature = "sqlx", derive(FromRow))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct UserBudget {
     #[cfg_attr(feature = "sqlx", sqlx(try_from = "i32"))]
@@ -32,14 +35,21 @@ impl Display for UserBudget {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct UserBudgetListParams {
     pub user: Option<u32>,
     pub project: Option<u32>,
     pub all: Option<bool>,
     pub year: Option<u32>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub ordering: Option<String>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct UserBudgetOverParams {
     pub end: Option<DateTime<FixedOffset>>,
@@ -51,6 +61,7 @@ pub struct UserBudgetOverParams {
     pub detail: Option<bool>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct UserBudgetCreateData {
     pub user: u32,
@@ -70,6 +81,7 @@ impl UserBudgetCreateData {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct UserBudgetModifyData {
     pub id: u32,
@@ -91,6 +103,7 @@ impl UserBudgetModifyData {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct UserBudgetOverSimple {
     pub budget_id: u32,
@@ -100,6 +113,7 @@ pub struct UserBudgetOverSimple {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct UserBudgetOverCombined {
     pub budget_id: u32,
@@ -113,6 +127,7 @@ pub struct UserBudgetOverCombined {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct UserBudgetOverDetail {
     pub budget_id: u32,
@@ -120,10 +135,15 @@ pub struct UserBudgetOverDetail {
     pub user_name: String,
     pub over: bool,
     pub cost: f64,
+    /// Discount on the flavor cost granted by price overrides of the
+    /// project, so the cost at list prices is the cost plus the discount.
+    #[serde(default)]
+    pub discount: f64,
     pub budget: u32,
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct UserBudgetOverCombinedDetail {
     pub budget_id: u32,
@@ -135,13 +155,22 @@ pub struct UserBudgetOverCombinedDetail {
     pub project_name: String,
     pub over: bool,
     pub project_cost: f64,
+    /// Discount on the flavor cost of the project, see
+    /// [UserBudgetOverDetail::discount].
+    #[serde(default)]
+    pub project_discount: f64,
     #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
     pub project_budget: Option<u32>,
     pub user_cost: f64,
+    /// Discount on the flavor cost of the user, see
+    /// [UserBudgetOverDetail::discount].
+    #[serde(default)]
+    pub user_discount: f64,
     pub user_budget: u32,
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct UserBudgetSync {
     pub updated_budget_count: u32,
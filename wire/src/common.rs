@@ -2,6 +2,8 @@
 
 use std::{borrow::Borrow, fmt::Display};
 
+use serde::{Deserialize, Deserializer};
+
 // TODO: use Option<&T> instead
 pub fn display_option<T: Display>(option: &Option<T>) -> String {
     match option {
@@ -18,3 +20,18 @@ pub fn is_true(b: impl Borrow<bool>) -> bool {
 pub fn is_false(b: impl Borrow<bool>) -> bool {
     !b.borrow()
 }
+
+/// Deserialize a present field into [Some], even if it is null.
+///
+/// Together with `#[serde(default)]` this lets `Option<Option<T>>` fields of
+/// modify data tell a missing field, which is not changed, from a null one,
+/// which is cleared.
+pub fn double_option<'de, T, D>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
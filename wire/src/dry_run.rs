@@ -0,0 +1,91 @@
+//! Types for dry runs of the import and sync endpoints.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+#[cfg(feature = "tabled")]
+use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
+
+/// Query parameters of endpoints supporting dry runs.
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+pub struct DryRunParams {
+    /// Only report the changes instead of applying them.
+    pub dry_run: Option<bool>,
+}
+
+/// Kind of change to an object.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ChangeAction {
+    Create,
+    Update,
+    End,
+}
+
+impl Display for ChangeAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+/// Type of object that is changed.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ChangeObject {
+    ServerState,
+    VolumeState,
+    FloatingIpState,
+    Flavor,
+    Project,
+    User,
+    ProjectBudget,
+    UserBudget,
+    FlavorPrice,
+}
+
+impl Display for ChangeObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+/// Single change made, or in a dry run to be made, by an import or sync.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Change {
+    /// What is done to the object.
+    pub action: ChangeAction,
+    /// Type of the object.
+    pub object: ChangeObject,
+    /// Name identifying the object, e.g., a server's UUID or a user's name.
+    pub name: String,
+    /// Why the object is changed, and how for updates.
+    pub reason: String,
+}
+
+/// Result of a dry run, which was rolled back instead of committed.
+///
+/// The summary is the one the endpoint would have returned without the dry
+/// run, the changes list what would have been done in detail.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct DryRun<T> {
+    /// Summary of the changes.
+    pub summary: T,
+    /// The individual changes.
+    pub changes: Vec<Change>,
+}
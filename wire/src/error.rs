@@ -4,8 +4,11 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
 use thiserror::Error;
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct ErrorResponse {
     pub detail: String,
@@ -5,9 +5,12 @@ use std::fmt::Display;
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
 
 /// Response from the hello-user and hello-admin endpoints.
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct Hello {
     /// Wrapped message.
@@ -0,0 +1,91 @@
+//! Types for avina's job endpoints.
+
+use std::fmt::Display;
+
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, EnumString, IntoStaticStr};
+#[cfg(feature = "tabled")]
+use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
+
+#[cfg(feature = "tabled")]
+use crate::common::display_option;
+
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(
+    clap::ValueEnum,
+    Hash,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Serialize,
+    EnumIter,
+    EnumString,
+    IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Job {
+    ServerStateImport,
+    VolumeStateImport,
+    FloatingIpStateImport,
+    FlavorImport,
+    UserImport,
+    UserSync,
+    UserBudgetSync,
+}
+
+impl Display for Job {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.into())
+    }
+}
+
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct JobRun {
+    pub id: u32,
+    pub job: Job,
+    pub manual: bool,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub user: Option<u32>,
+    pub started_at: DateTime<FixedOffset>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub finished_at: Option<DateTime<FixedOffset>>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub result: Option<String>,
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub error: Option<String>,
+}
+
+impl Display for JobRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("JobRun(id={}, job={})", self.id, self.job))
+    }
+}
+
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
+pub struct JobRunListParams {
+    pub job: Option<Job>,
+    pub limit: Option<u32>,
+}
+
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct JobRunCreateData {
+    pub job: Job,
+}
+
+impl JobRunCreateData {
+    pub fn new(job: Job) -> Self {
+        Self { job }
+    }
+}
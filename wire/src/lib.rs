@@ -9,16 +9,25 @@
 //!   database.
 //! - The `tabled` feature derives the [tabled::Tabled] trait, which is used in the
 //!   [avina-cli](https://docs.rs/avina-cli) crate for displaying these types.
+//! - The `utoipa` feature derives the [utoipa::ToSchema] and [utoipa::IntoParams]
+//!   traits, which are used in the [avina-api](https://docs.rs/avina-api) crate for
+//!   generating its OpenAPI specification.
 
 pub mod common;
+pub mod dry_run;
 pub mod error;
+pub mod pagination;
 
 #[cfg(feature = "accounting")]
 pub mod accounting;
+#[cfg(feature = "audit")]
+pub mod audit;
 #[cfg(feature = "budgeting")]
 pub mod budgeting;
 #[cfg(feature = "hello")]
 pub mod hello;
+#[cfg(feature = "jobs")]
+pub mod jobs;
 #[cfg(feature = "pricing")]
 pub mod pricing;
 #[cfg(feature = "quota")]
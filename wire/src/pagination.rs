@@ -0,0 +1,24 @@
+//! Types for paginated responses of list endpoints.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
+
+/// Single page of a list of objects.
+///
+/// List endpoints respond with a page instead of a plain list, when a `limit`
+/// is given in their list parameters. The `next` and `previous` members
+/// contain the complete URLs of the adjacent pages, so that clients can simply
+/// follow them.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct Page<T> {
+    /// Total number of objects on all pages.
+    pub count: u32,
+    /// URL of the next page, if there is one.
+    pub next: Option<String>,
+    /// URL of the previous page, if there is one.
+    pub previous: Option<String>,
+    /// Objects on this page.
+    pub results: Vec<T>,
+}
@@ -1,13 +1,19 @@
 //! Types for avina's pricing module.
 
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
 use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 
-use crate::user::UserClass;
+#[cfg(feature = "tabled")]
+use crate::common::display_option;
+use crate::{
+    accounting::FloatingIpPool, common::double_option, user::UserClass,
+};
 
 /// Price of a flavor per year for a user class.
 ///
@@ -23,6 +29,7 @@ use crate::user::UserClass;
 /// The cost is calculated proportionally if the VM ran less long in this flavor
 /// or the price was valid for less long.
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorPrice {
     /// ID of the flavor price.
@@ -50,6 +57,7 @@ impl Display for FlavorPrice {
 
 /// Response from the flavor-price-initialize endpoint.
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorPriceInitialize {
     /// Number of newly initialized flavors.
@@ -57,6 +65,7 @@ pub struct FlavorPriceInitialize {
 }
 
 /// Request data for creating a new flavor price with the flavor-price-create endpoint.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct FlavorPriceCreateData {
     /// ID of the flavor the price is for.
@@ -86,6 +95,7 @@ impl FlavorPriceCreateData {
 /// Request data for modifying a flavor price with the flavor-price-modify endpoint.
 ///
 /// All fields by [Self::id] are optional to allow partial modification.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct FlavorPriceModifyData {
     /// ID of the flavor price to modify.
@@ -121,10 +131,777 @@ impl FlavorPriceModifyData {
 /// Request URL parameters for listing prices with the flavor-price-list endpoint.
 ///
 /// All members are optional, to allow not specifying them.
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct FlavorPriceListParams {
     /// Filter by the user class, not filtered by if not set.
     pub user_class: Option<UserClass>,
     /// Filter only current prices, not filtered by if not set.
     pub current: Option<bool>,
+    /// Maximum number of prices per page, not paginated if not set.
+    pub limit: Option<u32>,
+    /// Number of prices to skip, requires a limit.
+    pub offset: Option<u32>,
+    /// Comma-separated fields to order by, prefixed with `-` for descending
+    /// order.
+    pub ordering: Option<String>,
+}
+
+/// Request data for revising flavor prices with the flavor-price-revise endpoint.
+///
+/// The revision copies the prices valid before the [Self::start_time] to new
+/// prices starting at it. Prices of a user class in [Self::factors] are
+/// multiplied by its factor, all others are changed by [Self::percentage].
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct FlavorPriceReviseData {
+    /// Start time of the new prices.
+    pub start_time: DateTime<FixedOffset>,
+    /// Optional change of the prices in percent, e.g., 5 for 5 % more.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage: Option<f64>,
+    /// Factors for the prices of individual user classes.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub factors: HashMap<UserClass, f64>,
+    /// IDs of the flavor groups to revise the prices of, all if empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flavor_groups: Vec<u32>,
+}
+
+impl FlavorPriceReviseData {
+    /// Create a new instance of [FlavorPriceReviseData] from only the mandatory parameters.
+    pub fn new(start_time: DateTime<FixedOffset>) -> Self {
+        Self {
+            start_time,
+            percentage: None,
+            factors: HashMap::new(),
+            flavor_groups: Vec::new(),
+        }
+    }
+}
+
+/// Response from the flavor-price-revise endpoint.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct FlavorPriceRevise {
+    /// Number of newly created flavor prices.
+    pub new_flavor_price_count: u32,
+}
+
+/// Request URL parameters for estimating the cost of servers with the
+/// flavor-price-estimate endpoint.
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct FlavorPriceEstimateParams {
+    /// ID of the flavor of the servers.
+    pub flavor: u32,
+    /// Number of servers, 1 if not set.
+    pub count: Option<u32>,
+    /// Duration in seconds for which the servers run.
+    pub duration: u64,
+    /// Time from which the servers run, now if not set.
+    pub begin: Option<DateTime<FixedOffset>>,
+}
+
+impl FlavorPriceEstimateParams {
+    /// Create a new instance of [FlavorPriceEstimateParams] from only the mandatory parameters.
+    pub fn new(flavor: u32, duration: u64) -> Self {
+        Self {
+            flavor,
+            count: None,
+            duration,
+            begin: None,
+        }
+    }
+}
+
+/// Response from the flavor-price-estimate endpoint.
+///
+/// The estimate is for the user class of the requesting user, and accounts
+/// for price changes scheduled within the duration.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct FlavorPriceEstimate {
+    /// ID of the flavor.
+    pub flavor: u32,
+    /// Name of the flavor.
+    pub flavor_name: String,
+    /// User class the estimate is for.
+    pub user_class: UserClass,
+    /// Number of servers.
+    pub count: u32,
+    /// Time from which the servers run.
+    pub begin: DateTime<FixedOffset>,
+    /// Time until which the servers run.
+    pub end: DateTime<FixedOffset>,
+    /// Price in EURO per VM per year, averaged over the duration.
+    pub unit_price: f64,
+    /// Price in EURO per VM per hour, derived from [Self::unit_price].
+    pub hourly_price: f64,
+    /// Price in EURO per VM per day, derived from [Self::unit_price].
+    pub daily_price: f64,
+    /// Price in EURO per VM per month, a twelfth of [Self::unit_price].
+    pub monthly_price: f64,
+    /// Estimated cost in EURO of all servers for the duration.
+    pub cost: f64,
+}
+
+/// Price of a GiB of volume storage per year for a user class.
+///
+/// Prices are unique with respect to their user class and start time, and
+/// like [FlavorPrice]s they are valid after their [Self::start_time], until
+/// another price for the same user class replaces it.
+///
+/// The [Self::unit_price] gives the amount of EUROs, that a single GiB of a
+/// Cinder volume kept for an entire year by a user of a project with the
+/// [Self::user_class] adds in cost to the project. The cost is calculated
+/// proportionally to the size and lifetime of the volume.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct VolumePrice {
+    /// ID of the volume price.
+    pub id: u32,
+    /// User class the price is for.
+    pub user_class: UserClass,
+    /// Price in EURO per GiB per year.
+    pub unit_price: f64,
+    /// Time after which the price is valid.
+    pub start_time: DateTime<FixedOffset>,
+}
+
+impl Display for VolumePrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!(
+            "VolumePrice(id={}, user_class={})",
+            self.id, self.user_class
+        ))
+    }
+}
+
+/// Request data for creating a new volume price with the volume-price-create endpoint.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct VolumePriceCreateData {
+    /// User class the price is for.
+    pub user_class: UserClass,
+    /// Optional unit price (0. by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    /// Optional start time (now by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+}
+
+impl VolumePriceCreateData {
+    /// Create a new instance of [VolumePriceCreateData] from only the mandatory parameters.
+    pub fn new(user_class: UserClass) -> Self {
+        Self {
+            user_class,
+            price: None,
+            start_time: None,
+        }
+    }
+}
+
+/// Request data for modifying a volume price with the volume-price-modify endpoint.
+///
+/// All fields by [Self::id] are optional to allow partial modification.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct VolumePriceModifyData {
+    /// ID of the volume price to modify.
+    pub id: u32,
+
+    /// User class the price is for, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_class: Option<UserClass>,
+    /// Unit price per GiB per year, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_price: Option<f64>,
+    /// Start time of the price, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+}
+
+impl VolumePriceModifyData {
+    /// Create a new instance of [VolumePriceModifyData] from only the mandatory parameters.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            user_class: None,
+            unit_price: None,
+            start_time: None,
+        }
+    }
+}
+
+/// Request URL parameters for listing prices with the volume-price-list endpoint.
+///
+/// All members are optional, to allow not specifying them.
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct VolumePriceListParams {
+    /// Filter by the user class, not filtered by if not set.
+    pub user_class: Option<UserClass>,
+    /// Filter only current prices, not filtered by if not set.
+    pub current: Option<bool>,
+    /// Maximum number of prices per page, not paginated if not set.
+    pub limit: Option<u32>,
+    /// Number of prices to skip, requires a limit.
+    pub offset: Option<u32>,
+    /// Comma-separated fields to order by, prefixed with `-` for descending
+    /// order.
+    pub ordering: Option<String>,
+}
+
+/// Price of a floating IP of a pool per year for a user class.
+///
+/// Prices are unique with respect to their user class, pool and start time,
+/// and like [FlavorPrice]s they are valid after their [Self::start_time],
+/// until another price for the same user class and pool replaces it.
+///
+/// The [Self::unit_price] gives the amount of EUROs, that a single floating
+/// IP of the [Self::pool] allocated for an entire year by a user of a project
+/// with the [Self::user_class] adds in cost to the project. The cost is
+/// calculated proportionally to the allocation time of the floating IP.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct FloatingIpPrice {
+    /// ID of the floating IP price.
+    pub id: u32,
+    /// User class the price is for.
+    pub user_class: UserClass,
+    /// Pool of the floating IPs the price is for.
+    pub pool: FloatingIpPool,
+    /// Price in EURO per floating IP per year.
+    pub unit_price: f64,
+    /// Time after which the price is valid.
+    pub start_time: DateTime<FixedOffset>,
+}
+
+impl Display for FloatingIpPrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!(
+            "FloatingIpPrice(id={}, user_class={}, pool={})",
+            self.id, self.user_class, self.pool
+        ))
+    }
+}
+
+/// Request data for creating a new floating IP price with the floating-ip-price-create endpoint.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct FloatingIpPriceCreateData {
+    /// User class the price is for.
+    pub user_class: UserClass,
+    /// Pool of the floating IPs the price is for.
+    pub pool: FloatingIpPool,
+    /// Optional unit price (0. by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    /// Optional start time (now by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+}
+
+impl FloatingIpPriceCreateData {
+    /// Create a new instance of [FloatingIpPriceCreateData] from only the mandatory parameters.
+    pub fn new(user_class: UserClass, pool: FloatingIpPool) -> Self {
+        Self {
+            user_class,
+            pool,
+            price: None,
+            start_time: None,
+        }
+    }
+}
+
+/// Request data for modifying a floating IP price with the floating-ip-price-modify endpoint.
+///
+/// All fields by [Self::id] are optional to allow partial modification.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct FloatingIpPriceModifyData {
+    /// ID of the floating IP price to modify.
+    pub id: u32,
+
+    /// User class the price is for, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_class: Option<UserClass>,
+    /// Pool of the floating IPs the price is for, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<FloatingIpPool>,
+    /// Unit price per floating IP per year, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_price: Option<f64>,
+    /// Start time of the price, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+}
+
+impl FloatingIpPriceModifyData {
+    /// Create a new instance of [FloatingIpPriceModifyData] from only the mandatory parameters.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            user_class: None,
+            pool: None,
+            unit_price: None,
+            start_time: None,
+        }
+    }
+}
+
+/// Request URL parameters for listing prices with the floating-ip-price-list endpoint.
+///
+/// All members are optional, to allow not specifying them.
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct FloatingIpPriceListParams {
+    /// Filter by the user class, not filtered by if not set.
+    pub user_class: Option<UserClass>,
+    /// Filter by the pool, not filtered by if not set.
+    pub pool: Option<FloatingIpPool>,
+    /// Filter only current prices, not filtered by if not set.
+    pub current: Option<bool>,
+    /// Maximum number of prices per page, not paginated if not set.
+    pub limit: Option<u32>,
+    /// Number of prices to skip, requires a limit.
+    pub offset: Option<u32>,
+    /// Comma-separated fields to order by, prefixed with `-` for descending
+    /// order.
+    pub ordering: Option<String>,
+}
+
+/// Prices of the resources of a flavor per year for a user class.
+///
+/// Prices are unique with respect to their user class and start time, and
+/// like [FlavorPrice]s they are valid after their [Self::start_time], until
+/// another price for the same user class replaces it.
+///
+/// Resource prices are optional, they are used to generate the
+/// [FlavorPrice]s of imported flavors from their vCPUs, RAM and GPUs, i.e.,
+/// a flavor kept for an entire year costs the sum of the prices of its
+/// resources. Admins can still override the generated price of a flavor.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ResourcePrice {
+    /// ID of the resource price.
+    pub id: u32,
+    /// User class the price is for.
+    pub user_class: UserClass,
+    /// Price in EURO per vCPU per year.
+    pub vcpu_price: f64,
+    /// Price in EURO per GiB of RAM per year.
+    pub ram_price: f64,
+    /// Price in EURO per GPU per year.
+    pub gpu_price: f64,
+    /// Time after which the price is valid.
+    pub start_time: DateTime<FixedOffset>,
+}
+
+impl Display for ResourcePrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!(
+            "ResourcePrice(id={}, user_class={})",
+            self.id, self.user_class
+        ))
+    }
+}
+
+/// Request data for creating a new resource price with the resource-price-create endpoint.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ResourcePriceCreateData {
+    /// User class the price is for.
+    pub user_class: UserClass,
+    /// Optional price per vCPU (0. by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcpu_price: Option<f64>,
+    /// Optional price per GiB of RAM (0. by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ram_price: Option<f64>,
+    /// Optional price per GPU (0. by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu_price: Option<f64>,
+    /// Optional start time (now by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+}
+
+impl ResourcePriceCreateData {
+    /// Create a new instance of [ResourcePriceCreateData] from only the mandatory parameters.
+    pub fn new(user_class: UserClass) -> Self {
+        Self {
+            user_class,
+            vcpu_price: None,
+            ram_price: None,
+            gpu_price: None,
+            start_time: None,
+        }
+    }
+}
+
+/// Request data for modifying a resource price with the resource-price-modify endpoint.
+///
+/// All fields by [Self::id] are optional to allow partial modification.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ResourcePriceModifyData {
+    /// ID of the resource price to modify.
+    pub id: u32,
+
+    /// User class the price is for, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_class: Option<UserClass>,
+    /// Price per vCPU per year, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcpu_price: Option<f64>,
+    /// Price per GiB of RAM per year, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ram_price: Option<f64>,
+    /// Price per GPU per year, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu_price: Option<f64>,
+    /// Start time of the price, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+}
+
+impl ResourcePriceModifyData {
+    /// Create a new instance of [ResourcePriceModifyData] from only the mandatory parameters.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            user_class: None,
+            vcpu_price: None,
+            ram_price: None,
+            gpu_price: None,
+            start_time: None,
+        }
+    }
+}
+
+/// Request URL parameters for listing prices with the resource-price-list endpoint.
+///
+/// All members are optional, to allow not specifying them.
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct ResourcePriceListParams {
+    /// Filter by the user class, not filtered by if not set.
+    pub user_class: Option<UserClass>,
+    /// Filter only current prices, not filtered by if not set.
+    pub current: Option<bool>,
+    /// Maximum number of prices per page, not paginated if not set.
+    pub limit: Option<u32>,
+    /// Number of prices to skip, requires a limit.
+    pub offset: Option<u32>,
+    /// Comma-separated fields to order by, prefixed with `-` for descending
+    /// order.
+    pub ordering: Option<String>,
+}
+
+/// Factor by which the runtime of servers in a status is billed.
+///
+/// Only the runtime of servers in a status with a valid policy is billable,
+/// and it is weighted by the [Self::factor] in consumption and cost, e.g., a
+/// factor of `0.5` for `SHUTOFF` bills stopped servers at half the price. A
+/// policy is valid from its [Self::start_time] until its [Self::end_time], if
+/// any. Policies for a [Self::user_class] take precedence over those for all
+/// user classes, and policies for the same status and user class must not
+/// overlap.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct BillingPolicy {
+    /// ID of the billing policy.
+    pub id: u32,
+    /// Server status the policy is for, e.g., `ACTIVE` or `SHUTOFF`.
+    pub status: String,
+    /// User class the policy is for, all user classes if not set.
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub user_class: Option<UserClass>,
+    /// Factor by which the runtime in the status is billed.
+    pub factor: f64,
+    /// Time from which the policy is valid.
+    pub start_time: DateTime<FixedOffset>,
+    /// Time until which the policy is valid, indefinitely if not set.
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub end_time: Option<DateTime<FixedOffset>>,
+}
+
+impl Display for BillingPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!(
+            "BillingPolicy(id={}, status={})",
+            self.id, self.status
+        ))
+    }
+}
+
+/// Request data for creating a new billing policy with the
+/// billing-policy-create endpoint.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct BillingPolicyCreateData {
+    /// Server status the policy is for.
+    pub status: String,
+    /// Factor by which the runtime in the status is billed.
+    pub factor: f64,
+    /// Optional user class (all user classes by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_class: Option<UserClass>,
+    /// Optional start time (now by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+    /// Optional end time (valid indefinitely by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<DateTime<FixedOffset>>,
+}
+
+impl BillingPolicyCreateData {
+    /// Create a new instance of [BillingPolicyCreateData] from only the mandatory parameters.
+    pub fn new(status: String, factor: f64) -> Self {
+        Self {
+            status,
+            factor,
+            user_class: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+}
+
+/// Request data for modifying a billing policy with the
+/// billing-policy-modify endpoint.
+///
+/// All fields by [Self::id] are optional to allow partial modification.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct BillingPolicyModifyData {
+    /// ID of the billing policy to modify.
+    pub id: u32,
+
+    /// Server status the policy is for, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// User class the policy is for, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_class: Option<UserClass>,
+    /// Factor by which the runtime is billed, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub factor: Option<f64>,
+    /// Start time of the policy, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+    /// End time of the policy, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<DateTime<FixedOffset>>,
+}
+
+impl BillingPolicyModifyData {
+    /// Create a new instance of [BillingPolicyModifyData] from only the mandatory parameters.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            status: None,
+            user_class: None,
+            factor: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+}
+
+/// Request URL parameters for listing policies with the billing-policy-list
+/// endpoint.
+///
+/// All members are optional, to allow not specifying them.
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct BillingPolicyListParams {
+    /// Filter by the server status, not filtered by if not set.
+    pub status: Option<String>,
+    /// Filter by the user class, including the policies for all user
+    /// classes, not filtered by if not set.
+    pub user_class: Option<UserClass>,
+    /// Filter only currently valid policies, not filtered by if not set.
+    pub current: Option<bool>,
+    /// Maximum number of policies per page, not paginated if not set.
+    pub limit: Option<u32>,
+    /// Number of policies to skip, requires a limit.
+    pub offset: Option<u32>,
+    /// Comma-separated fields to order by, prefixed with `-` for descending
+    /// order.
+    pub ordering: Option<String>,
+}
+
+/// Negotiated pricing of a project, overriding the prices of its user class.
+///
+/// An override either grants the project a [Self::discount] in percent on the
+/// [FlavorPrice]s of its user class, or replaces the price of a single
+/// [Self::flavor] by the absolute [Self::unit_price]. Discounts apply to all
+/// flavors, unless restricted to a [Self::flavor], in which case they take
+/// precedence over those for all flavors. An override is valid from its
+/// [Self::start_time] until its [Self::end_time], if any, and overrides for the
+/// same project and flavor must not overlap.
+#[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct PriceOverride {
+    /// ID of the price override.
+    pub id: u32,
+    /// ID of the project the override is for.
+    pub project: u32,
+    /// Name of the project the override is for.
+    pub project_name: String,
+    /// ID of the flavor the override is for, all flavors if not set.
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub flavor: Option<u32>,
+    /// Name of the flavor the override is for, all flavors if not set.
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub flavor_name: Option<String>,
+    /// Discount in percent on the flavor price of the user class.
+    ///
+    /// This covers flavors only, also for overrides of all flavors, while
+    /// volumes and floating IPs are always billed at the prices of the user
+    /// class.
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub discount: Option<f64>,
+    /// Price in EURO per year replacing that of the user class.
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub unit_price: Option<f64>,
+    /// Time from which the override is valid.
+    pub start_time: DateTime<FixedOffset>,
+    /// Time until which the override is valid, indefinitely if not set.
+    #[cfg_attr(feature = "tabled", tabled(display = "display_option"))]
+    pub end_time: Option<DateTime<FixedOffset>>,
+}
+
+impl Display for PriceOverride {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!(
+            "PriceOverride(id={}, project={})",
+            self.id, self.project_name
+        ))
+    }
+}
+
+/// Request data for creating a new price override with the
+/// price-override-create endpoint.
+///
+/// Exactly one of [Self::discount] and [Self::unit_price] has to be set, the
+/// latter only together with a [Self::flavor].
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PriceOverrideCreateData {
+    /// ID of the project the override is for.
+    pub project: u32,
+    /// Optional flavor ID (all flavors by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flavor: Option<u32>,
+    /// Discount in percent on the price of the user class.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<f64>,
+    /// Price per year replacing that of the user class.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_price: Option<f64>,
+    /// Optional start time (now by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+    /// Optional end time (valid indefinitely by default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<DateTime<FixedOffset>>,
+}
+
+impl PriceOverrideCreateData {
+    /// Create a new instance of [PriceOverrideCreateData] from only the mandatory parameters.
+    pub fn new(project: u32) -> Self {
+        Self {
+            project,
+            flavor: None,
+            discount: None,
+            unit_price: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+}
+
+/// Request data for modifying a price override with the
+/// price-override-modify endpoint.
+///
+/// All fields by [Self::id] are optional to allow partial modification.
+/// Setting either [Self::discount] or [Self::unit_price] replaces the other.
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PriceOverrideModifyData {
+    /// ID of the price override to modify.
+    pub id: u32,
+
+    /// Discount in percent, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<f64>,
+    /// Price per year, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_price: Option<f64>,
+    /// Start time of the override, not changed if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<DateTime<FixedOffset>>,
+    /// End time of the override, not changed if not set, and removed if set
+    /// to null.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "double_option"
+    )]
+    pub end_time: Option<Option<DateTime<FixedOffset>>>,
+}
+
+impl PriceOverrideModifyData {
+    /// Create a new instance of [PriceOverrideModifyData] from only the mandatory parameters.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            discount: None,
+            unit_price: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+}
+
+/// Request URL parameters for listing overrides with the price-override-list
+/// endpoint.
+///
+/// All members are optional, to allow not specifying them.
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct PriceOverrideListParams {
+    /// Filter by the project, not filtered by if not set.
+    pub project: Option<u32>,
+    /// Filter by the flavor, including the overrides for all flavors, not
+    /// filtered by if not set.
+    pub flavor: Option<u32>,
+    /// Filter only currently valid overrides, not filtered by if not set.
+    pub current: Option<bool>,
+    /// Maximum number of overrides per page, not paginated if not set.
+    pub limit: Option<u32>,
+    /// Number of overrides to skip, requires a limit.
+    pub offset: Option<u32>,
+    /// Comma-separated fields to order by, prefixed with `-` for descending
+    /// order.
+    pub ordering: Option<String>,
 }
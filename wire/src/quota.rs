@@ -7,9 +7,12 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 
 #[cfg_attr(feature = "sqlx", derive(FromRow))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorQuota {
     #[cfg_attr(feature = "sqlx", sqlx(try_from = "i32"))]
@@ -32,6 +35,8 @@ impl Display for FlavorQuota {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct FlavorQuotaCheckParams {
     // TODO: these two should rather be an enum
@@ -43,11 +48,14 @@ pub struct FlavorQuotaCheckParams {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorQuotaCheck {
     pub underquota: bool,
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct FlavorQuotaListParams {
     pub all: Option<bool>,
@@ -55,6 +63,7 @@ pub struct FlavorQuotaListParams {
     pub user: Option<u32>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct FlavorQuotaCreateData {
     pub flavor_group: u32,
@@ -73,6 +82,7 @@ impl FlavorQuotaCreateData {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct FlavorQuotaModifyData {
     pub id: u32,
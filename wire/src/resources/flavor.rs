@@ -7,12 +7,15 @@ use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Row, mysql::MySqlRow};
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 
 #[cfg(feature = "tabled")]
 use crate::common::display_option;
 use crate::resources::FlavorGroupMinimal;
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct Flavor {
     pub id: u32,
@@ -55,6 +58,7 @@ impl Display for Flavor {
 
 #[cfg_attr(feature = "sqlx", derive(FromRow))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorMinimal {
     #[cfg_attr(feature = "sqlx", sqlx(try_from = "i32"))]
@@ -70,6 +74,7 @@ impl Display for FlavorMinimal {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorDetailed {
     pub id: u32,
@@ -88,6 +93,8 @@ impl Display for FlavorDetailed {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct FlavorListParams {
     pub all: Option<bool>,
@@ -96,11 +103,14 @@ pub struct FlavorListParams {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorImport {
     pub new_flavor_count: u32,
+    pub new_flavor_price_count: u32,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FlavorCreateData {
     pub name: String,
@@ -122,6 +132,7 @@ impl FlavorCreateData {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorModifyData {
     pub id: u32,
@@ -148,6 +159,8 @@ impl FlavorModifyData {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct FlavorUsageParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -161,6 +174,7 @@ pub struct FlavorUsageParams {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorUsageSimple {
     pub user_id: u32,
@@ -176,6 +190,7 @@ pub struct FlavorUsageSimple {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorUsageAggregate {
     pub flavor_id: u32,
@@ -7,10 +7,13 @@ use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, Row, mysql::MySqlRow};
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 
 use crate::{resources::FlavorMinimal, user::ProjectMinimal};
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorGroup {
     pub id: u32,
@@ -50,6 +53,7 @@ impl Display for FlavorGroup {
 
 #[cfg_attr(feature = "sqlx", derive(FromRow))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorGroupMinimal {
     pub id: u32,
@@ -64,6 +68,7 @@ impl Display for FlavorGroupMinimal {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorGroupDetailed {
     pub id: u32,
@@ -79,12 +84,15 @@ impl Display for FlavorGroupDetailed {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 pub struct FlavorGroupListParams {
     pub all: Option<bool>,
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorGroupCreated {
     pub id: u32,
@@ -101,12 +109,14 @@ impl Display for FlavorGroupCreated {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorGroupInitialize {
     pub new_flavor_group_count: u32,
     pub new_flavor_count: u32,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct FlavorGroupCreateData {
     pub name: String,
@@ -122,6 +132,7 @@ impl FlavorGroupCreateData {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct FlavorGroupModifyData {
     pub id: u32,
@@ -142,6 +153,8 @@ impl FlavorGroupModifyData {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct FlavorGroupUsageParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -155,6 +168,7 @@ pub struct FlavorGroupUsageParams {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorGroupUsageSimple {
     pub user_id: u32,
@@ -165,6 +179,7 @@ pub struct FlavorGroupUsageSimple {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FlavorGroupUsageAggregate {
     pub flavorgroup_id: u32,
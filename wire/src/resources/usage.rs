@@ -1,19 +1,24 @@
 //! Types for avina's usage endpoint.
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct CloudUsageOverviewInner {
     pub total: u64,
     pub used: u64,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct CloudUsageOverviewInnerFloat {
     pub total: f64,
     pub used: f64,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct CloudUsageOverview {
     pub vcpus: CloudUsageOverviewInner,
@@ -24,6 +29,7 @@ pub struct CloudUsageOverview {
     pub www_ips: CloudUsageOverviewInner,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct CloudUsageFlavorSlot {
     pub name: String,
@@ -31,6 +37,7 @@ pub struct CloudUsageFlavorSlot {
     pub total: u32,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct CloudUsageAggregate {
     pub name: String,
@@ -39,6 +46,7 @@ pub struct CloudUsageAggregate {
 }
 
 // TODO: how could we handle a table representation of this?
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct CloudUsage {
     pub overview: CloudUsageOverview,
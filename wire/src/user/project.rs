@@ -12,6 +12,8 @@ use sqlx::{self, FromRow};
 use strum::EnumIter;
 #[cfg(feature = "tabled")]
 use tabled::Tabled;
+#[cfg(feature = "utoipa")]
+use utoipa::{IntoParams, ToSchema};
 
 use crate::{
     error::ConversionError, resources::FlavorGroupMinimal, user::UserMinimal,
@@ -19,6 +21,7 @@ use crate::{
 
 #[cfg_attr(feature = "sqlx", derive(FromRow))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct Project {
     #[cfg_attr(feature = "sqlx", sqlx(try_from = "i32"))]
@@ -54,6 +57,7 @@ impl PartialEq<ProjectDetailed> for Project {
 
 #[cfg_attr(feature = "sqlx", derive(FromRow))]
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct ProjectMinimal {
     #[cfg_attr(
@@ -93,6 +97,7 @@ impl Display for ProjectMinimal {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct ProjectDetailed {
     pub id: u32,
@@ -132,6 +137,7 @@ impl Display for ProjectDetailed {
 }
 
 #[cfg_attr(feature = "tabled", derive(Tabled))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(untagged)]
 pub enum ProjectRetrieved {
@@ -139,12 +145,15 @@ pub enum ProjectRetrieved {
     Normal(Project),
 }
 
+#[cfg_attr(feature = "utoipa", derive(IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
 pub struct ProjectListParams {
     pub all: Option<bool>,
     pub userclass: Option<UserClass>,
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ProjectCreateData {
     pub name: String,
@@ -163,6 +172,7 @@ impl ProjectCreateData {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ProjectModifyData {
     // TODO: why again is this here? since this is already a URL parameter
@@ -187,6 +197,7 @@ impl ProjectModifyData {
     }
 }
 
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[derive(
     clap::ValueEnum,
     Hash,